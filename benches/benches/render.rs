@@ -0,0 +1,127 @@
+use std::{cell::Cell, rc::Rc};
+
+use benches::i18n::*;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use leptos::*;
+use leptos_i18n::Locale as _;
+
+/// Rendering the keys to strings, with the `Display` implementations of `td_string!`.
+fn string(c: &mut Criterion) {
+    let mut group = c.benchmark_group("string");
+    for locale in [Locale::en, Locale::fr] {
+        let locale_name = locale.as_str();
+        group.bench_with_input(
+            BenchmarkId::new("plain", locale_name),
+            &locale,
+            |b, &locale| b.iter(|| td_string!(black_box(locale), plain).to_string()),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("interpolated", locale_name),
+            &locale,
+            |b, &locale| {
+                b.iter(|| {
+                    td_string!(black_box(locale), interpolated, name = "Alex", count = 3)
+                        .to_string()
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("plural", locale_name),
+            &locale,
+            |b, &locale| {
+                b.iter(|| td_string!(black_box(locale), plural, count = black_box(21)).to_string())
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("nested", locale_name),
+            &locale,
+            |b, &locale| {
+                b.iter(|| {
+                    td_string!(black_box(locale), nested.page.title, name = "Alex").to_string()
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+/// Building the views of the keys and rendering them to HTML, as done by server side rendering.
+fn ssr(c: &mut Criterion) {
+    let runtime = create_runtime();
+    let mut group = c.benchmark_group("ssr");
+    for locale in [Locale::en, Locale::fr] {
+        let locale_name = locale.as_str();
+        group.bench_with_input(
+            BenchmarkId::new("plain", locale_name),
+            &locale,
+            |b, &locale| b.iter(|| td!(black_box(locale), plain).into_view().render_to_string()),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("interpolated", locale_name),
+            &locale,
+            |b, &locale| {
+                b.iter(|| {
+                    td!(black_box(locale), interpolated, name = "Alex", count = || 3)
+                        .into_view()
+                        .render_to_string()
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("component", locale_name),
+            &locale,
+            |b, &locale| {
+                b.iter(|| {
+                    td!(black_box(locale), component, <b> = |children: ChildrenFn| view! { <b>{children}</b> })
+                        .into_view()
+                        .render_to_string()
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("plural", locale_name),
+            &locale,
+            |b, &locale| {
+                b.iter(|| {
+                    td!(black_box(locale), plural, count = || black_box(21))
+                        .into_view()
+                        .render_to_string()
+                })
+            },
+        );
+    }
+    group.finish();
+    runtime.dispose();
+}
+
+/// Switching the locale of the context, with an effect depending on a translation like a view would.
+fn locale_switch(c: &mut Criterion) {
+    let runtime = create_runtime();
+    let i18n = provide_i18n_context();
+    let rendered = Rc::new(Cell::new(0));
+    let effect_rendered = Rc::clone(&rendered);
+    create_isomorphic_effect(move |_| {
+        let value = t_string!(i18n, interpolated, name = "Alex", count = 3).to_string();
+        effect_rendered.set(value.len());
+    });
+
+    let mut group = c.benchmark_group("locale_switch");
+    group.bench_function("set_locale", |b| {
+        b.iter(|| {
+            let next = match i18n.get_locale_untracked() {
+                Locale::en => Locale::fr,
+                Locale::fr => Locale::en,
+            };
+            i18n.set_locale(next);
+            black_box(rendered.get())
+        })
+    });
+    group.bench_function("get_keys", |b| {
+        b.iter(|| black_box(i18n.get_keys_untracked().plain))
+    });
+    group.finish();
+    runtime.dispose();
+}
+
+criterion_group!(benches, string, ssr, locale_switch);
+criterion_main!(benches);
@@ -0,0 +1,3 @@
+//! The locales rendered by the benchmarks, see `benches/render.rs`.
+
+leptos_i18n::load_locales!();
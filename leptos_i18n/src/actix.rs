@@ -0,0 +1,101 @@
+//! This module contain an extractor and a middleware for actix-web, with the `actix` feature, to get the locale of a request
+//! in the routes and server functions rendered outside of Leptos, and a helper to set the locale cookie from a handler.
+//!
+//! The locale is negotiated like the `I18nContext` does on the server: the cookie, then the `Accept-Language` header,
+//! then the default locale (see [`find_locale`](crate::request::find_locale)), so API routes agree with the UI:
+//!
+//! ```rust,ignore
+//! use leptos_i18n::actix::{negotiate_locale, ExtractLocale};
+//!
+//! #[get("/api/not_found")]
+//! async fn not_found(ExtractLocale(locale): ExtractLocale<Locale>) -> String {
+//!     td_string!(locale, not_found).into_owned()
+//! }
+//!
+//! App::new()
+//!     .service(not_found)
+//!     .wrap(actix_web::middleware::from_fn(negotiate_locale::<Locale, _>))
+//! ```
+//!
+//! The middleware is optional, it negotiates the locale once for the handlers and the extractors of the request,
+//! without it the extractor negotiates it from the headers. The cookie options provided with
+//! `provide_cookie_options` are not available outside of Leptos, the cookie is looked up with the default options.
+
+use std::{convert::Infallible, future::Ready};
+
+use actix_web::{
+    body::MessageBody,
+    dev::{Payload, ServiceRequest, ServiceResponse},
+    middleware::Next,
+    Error, FromRequest, HttpMessage, HttpRequest,
+};
+
+use crate::{request::RequestHeaders, Locale};
+
+/// Extractor of the locale of a request.
+///
+/// This is the locale negotiated by [`negotiate_locale`] if the middleware is used, or else the one negotiated from the headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExtractLocale<T>(pub T);
+
+impl<T: Locale> FromRequest for ExtractLocale<T> {
+    type Error = Infallible;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        // the extensions must not be borrowed when negotiating, actix caches the parsed cookies in them.
+        let negotiated = req.extensions().get::<ExtractLocale<T>>().copied();
+        let locale = negotiated.unwrap_or_else(|| ExtractLocale(crate::request::find_locale(req)));
+        std::future::ready(Ok(locale))
+    }
+}
+
+/// Middleware negotiating the locale of the request and storing it in its extensions, for [`ExtractLocale`].
+///
+/// Use it with `actix_web::middleware::from_fn(negotiate_locale::<Locale, _>)`.
+pub async fn negotiate_locale<T, B>(
+    req: ServiceRequest,
+    next: Next<B>,
+) -> Result<ServiceResponse<B>, Error>
+where
+    T: Locale,
+    B: MessageBody,
+{
+    let locale = crate::request::find_locale::<T, _>(req.request());
+    req.extensions_mut().insert(ExtractLocale(locale));
+    next.call(req).await
+}
+
+/// Add the cookie persisting the locale to a response, so the next requests and the `I18nContext` use it.
+///
+/// ```rust,ignore
+/// #[post("/api/locale/{locale}")]
+/// async fn set_locale(locale: web::Path<String>) -> HttpResponse {
+///     let Some(locale) = Locale::from_tag(&locale) else {
+///         return HttpResponse::BadRequest().finish();
+///     };
+///     let mut response = HttpResponse::NoContent();
+///     set_locale_cookie(&mut response, locale, &CookieOptions::default());
+///     response.finish()
+/// }
+/// ```
+#[cfg(feature = "cookie")]
+pub fn set_locale_cookie<T: Locale>(
+    response: &mut actix_web::HttpResponseBuilder,
+    locale: T,
+    options: &crate::cookie::CookieOptions,
+) {
+    let cookie = options.set_cookie(options.get_name(), locale.as_str());
+    response.append_header((actix_web::http::header::SET_COOKIE, cookie));
+}
+
+impl RequestHeaders for HttpRequest {
+    fn header(&self, name: &str) -> Option<String> {
+        let value = self.headers().get(name)?.to_str().ok()?;
+        Some(value.to_owned())
+    }
+
+    fn cookie(&self, name: &str) -> Option<String> {
+        HttpRequest::cookie(self, name).map(|cookie| cookie.value().to_owned())
+    }
+}
@@ -0,0 +1,82 @@
+//! This module contain an extractor and a middleware for axum, with the `axum` feature, to get the locale of a request
+//! in the routes and server functions rendered outside of Leptos.
+//!
+//! The locale is negotiated like the `I18nContext` does on the server: the cookie, then the `Accept-Language` header,
+//! then the default locale (see [`find_locale`](crate::request::find_locale)), so API routes agree with the UI:
+//!
+//! ```rust,ignore
+//! use leptos_i18n::axum::{negotiate_locale, ExtractLocale};
+//!
+//! async fn not_found(ExtractLocale(locale): ExtractLocale<Locale>) -> String {
+//!     td_string!(locale, not_found).into_owned()
+//! }
+//!
+//! let app = Router::new()
+//!     .route("/api/not_found", get(not_found))
+//!     .layer(axum::middleware::from_fn(negotiate_locale::<Locale, _>));
+//! ```
+//!
+//! The middleware is optional, it negotiates the locale once for the handlers and the extractors of the request,
+//! without it the extractor negotiates it from the headers. The cookie options provided with
+//! `provide_cookie_options` are not available outside of Leptos, the cookie is looked up with the default options.
+
+use std::convert::Infallible;
+
+use axum::{
+    extract::FromRequestParts,
+    http::{header, request::Parts, HeaderMap, Request},
+    middleware::Next,
+    response::Response,
+};
+
+use crate::{request::RequestHeaders, Locale};
+
+/// Extractor of the locale of a request.
+///
+/// This is the locale negotiated by [`negotiate_locale`] if the middleware is used, or else the one negotiated from the headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExtractLocale<T>(pub T);
+
+#[axum::async_trait]
+impl<T, S> FromRequestParts<S> for ExtractLocale<T>
+where
+    T: Locale + Send + Sync,
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        if let Some(locale) = parts.extensions.get::<ExtractLocale<T>>() {
+            return Ok(*locale);
+        }
+        Ok(ExtractLocale(crate::request::find_locale(&parts.headers)))
+    }
+}
+
+/// Middleware negotiating the locale of the request and storing it in its extensions, for [`ExtractLocale`].
+///
+/// Use it with `axum::middleware::from_fn(negotiate_locale::<Locale, _>)`.
+pub async fn negotiate_locale<T, B>(mut req: Request<B>, next: Next<B>) -> Response
+where
+    T: Locale + Send + Sync,
+{
+    let locale = crate::request::find_locale::<T, _>(req.headers());
+    req.extensions_mut().insert(ExtractLocale(locale));
+    next.run(req).await
+}
+
+impl RequestHeaders for HeaderMap {
+    fn header(&self, name: &str) -> Option<String> {
+        let value = self.get(name)?.to_str().ok()?;
+        Some(value.to_owned())
+    }
+
+    fn cookie(&self, name: &str) -> Option<String> {
+        // the cookies can be split in multiple headers.
+        self.get_all(header::COOKIE)
+            .into_iter()
+            .filter_map(|cookies| std::str::from_utf8(cookies.as_bytes()).ok())
+            .find_map(|cookies| crate::request::parse_cookie(cookies, name))
+            .map(ToOwned::to_owned)
+    }
+}
@@ -0,0 +1,164 @@
+//! This module contain formatters for the ticks and labels of charts, following the current locale
+//! so dashboards don't need a second formatting stack.
+//!
+//! The formatters are closures taking the value by reference and returning a `String`,
+//! the signature expected by most charting crates, like the label formatters of `plotters`:
+//!
+//! ```rust,ignore
+//! use leptos_i18n::chart::use_chart_format;
+//!
+//! let chart_format = use_chart_format();
+//!
+//! create_effect(move |_| {
+//!     let format = chart_format.get();
+//!     chart
+//!         .configure_mesh()
+//!         .x_label_formatter(&format.timestamp_date())
+//!         .y_label_formatter(&format.compact())
+//!         .draw()
+//!         .unwrap();
+//! });
+//! ```
+
+use leptos::{create_memo, use_context, Memo, SignalGet};
+
+use crate::{
+    format::{format_compact, DateTime, FormatContext, Localized, LocalizedDisplay},
+    preferences::{region_from_tag, HourCycle, NumberingSystem},
+};
+
+/// The locale and clock used to format the ticks and labels of a chart.
+///
+/// ```rust
+/// use leptos_i18n::chart::ChartFormat;
+///
+/// let format = ChartFormat::from_tag("fr");
+/// assert_eq!(format.number()(&1234.5), "1\u{202f}234,5");
+/// assert_eq!(format.compact()(&2_500_000.0), "2,5\u{a0}M");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChartFormat {
+    tag: String,
+    hour_cycle: HourCycle,
+    numbering_system: Option<NumberingSystem>,
+}
+
+impl ChartFormat {
+    /// Format for the language of the given tag, with the given clock.
+    pub fn new(tag: impl Into<String>, hour_cycle: HourCycle) -> Self {
+        let tag = tag.into();
+        ChartFormat {
+            numbering_system: NumberingSystem::from_tag(&tag),
+            tag,
+            hour_cycle,
+        }
+    }
+
+    /// Write the digits in the given numbering system instead of the one of the tag.
+    pub fn with_numbering_system(mut self, numbering_system: NumberingSystem) -> Self {
+        self.numbering_system = Some(numbering_system);
+        self
+    }
+
+    /// Apply the numbering system to the output of a formatter.
+    fn with_digits<T: ?Sized>(
+        &self,
+        format: impl Fn(&T) -> String + 'static,
+    ) -> impl Fn(&T) -> String + 'static {
+        let numbering_system = self.numbering_system;
+        move |value| match numbering_system {
+            Some(numbering_system) => numbering_system.transliterate(&format(value)).into_owned(),
+            None => format(value),
+        }
+    }
+
+    /// Format for the language of the given tag, with the clock commonly used in its region.
+    pub fn from_tag(tag: &str) -> Self {
+        Self::new(tag, HourCycle::from_region(region_from_tag(tag)))
+    }
+
+    /// Return the tag of the language used to format.
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// Return a formatter for numbers, with the separators of the locale.
+    pub fn number<T: LocalizedDisplay + Copy>(&self) -> impl Fn(&T) -> String + 'static {
+        let ChartFormat {
+            tag, hour_cycle, ..
+        } = self.clone();
+        self.with_digits(move |value: &T| Localized(*value).format_with(&tag, hour_cycle))
+    }
+
+    /// Return a formatter for numbers in the compact notation of the locale, like `1.2K`.
+    pub fn compact<T: Into<f64> + Copy>(&self) -> impl Fn(&T) -> String + 'static {
+        let tag = self.tag.clone();
+        self.with_digits(move |value: &T| format_compact((*value).into(), &tag))
+    }
+
+    /// Return a formatter for dates, without the time.
+    pub fn date(&self) -> impl Fn(&DateTime) -> String + 'static {
+        let tag = self.tag.clone();
+        self.with_digits(move |date_time: &DateTime| date_time.format_date(&tag))
+    }
+
+    /// Return a formatter for times, without the date.
+    pub fn time(&self) -> impl Fn(&DateTime) -> String + 'static {
+        let hour_cycle = self.hour_cycle;
+        self.with_digits(move |date_time: &DateTime| date_time.format_time(hour_cycle))
+    }
+
+    /// Return a formatter for dates and times.
+    pub fn date_time(&self) -> impl Fn(&DateTime) -> String + 'static {
+        self.number()
+    }
+
+    /// Return a formatter for unix timestamps in seconds, as dates in UTC.
+    pub fn timestamp_date(&self) -> impl Fn(&i64) -> String + 'static {
+        let date = self.date();
+        move |timestamp| date(&DateTime::from_unix_timestamp(*timestamp))
+    }
+}
+
+/// Return a memo of the `ChartFormat` of the current locale of the `I18nContext`,
+/// updated when the locale, the hour cycle or the numbering system change.
+pub fn use_chart_format() -> Memo<ChartFormat> {
+    let format_context = use_context::<FormatContext>();
+    create_memo(move |_| match format_context {
+        Some(format_context) => {
+            let format =
+                ChartFormat::new(format_context.tag.get(), format_context.hour_cycle.get());
+            match format_context.numbering_system.get() {
+                Some(numbering_system) => format.with_numbering_system(numbering_system),
+                None => format,
+            }
+        }
+        // without a context the default locale is unknown, fallback to english.
+        None => ChartFormat::from_tag("en"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chart_format() {
+        let format = ChartFormat::from_tag("en-US");
+        let date_time = DateTime::new(2024, 1, 31, 13, 5).unwrap();
+        assert_eq!(format.number()(&1234), "1,234");
+        assert_eq!(format.compact()(&1234.0), "1.2K");
+        assert_eq!(format.date()(&date_time), "1/31/2024");
+        assert_eq!(format.time()(&date_time), "1:05\u{202f}PM");
+        assert_eq!(format.timestamp_date()(&1_706_706_300), "1/31/2024");
+
+        let format = ChartFormat::new("de", HourCycle::H23);
+        assert_eq!(format.compact()(&-3_400_000i32), "-3,4\u{a0}Mio.");
+        assert_eq!(format.date_time()(&date_time), "31.01.2024 13:05");
+
+        let format = ChartFormat::from_tag("ar-EG-u-nu-arab");
+        assert_eq!(format.time()(&date_time), "١:٠٥\u{202f}PM");
+        let format = format.with_numbering_system(NumberingSystem::Latn);
+        assert_eq!(format.compact()(&1234.0), "1.2K");
+    }
+}
@@ -0,0 +1,270 @@
+//! This module contain components and helpers adapting the layout to the direction of the current locale.
+//!
+//! The `dir` attribute of the `<html>` element is set to the direction of the current locale,
+//! but some elements need more than that: icons inside inputs should move to the other side,
+//! and placeholders should be aligned on the side the text starts.
+//!
+//! ```rust,ignore
+//! use crate::i18n::components::DirectionalInput;
+//!
+//! let search = create_rw_signal(String::new());
+//! view! {
+//!     <DirectionalInput
+//!         value=search
+//!         placeholder=move || t_string!(i18n, search_placeholder).into_owned()
+//!         icon=|| view! { <SearchIcon /> }
+//!     />
+//! }
+//! ```
+
+use leptos::*;
+use leptos_meta::Style;
+
+use crate::{format::FormatContext, I18nContext, Locale};
+
+/// The direction in which a language is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Direction {
+    /// Left to right, like english.
+    #[default]
+    Ltr,
+    /// Right to left, like arabic or hebrew.
+    Rtl,
+}
+
+impl Direction {
+    /// Return the direction of the language of the given tag.
+    ///
+    /// The script subtag takes precedence over the language, `pa-Arab` is right to left.
+    ///
+    /// ```rust
+    /// use leptos_i18n::components::Direction;
+    ///
+    /// assert_eq!(Direction::from_tag("ar-EG"), Direction::Rtl);
+    /// assert_eq!(Direction::from_tag("en"), Direction::Ltr);
+    /// assert_eq!(Direction::from_tag("pa-Arab"), Direction::Rtl);
+    /// ```
+    pub fn from_tag(tag: &str) -> Self {
+        let mut subtags = tag.split(['-', '_']);
+        let language = subtags.next().unwrap_or_default();
+        // extensions, like `-u-nu-arab`, start with a singleton.
+        let script = subtags
+            .take_while(|subtag| subtag.len() > 1)
+            .find(|subtag| subtag.len() == 4);
+        let is_rtl = match script {
+            Some(script) => matches!(
+                script.to_ascii_lowercase().as_str(),
+                "arab" | "hebr" | "thaa" | "syrc" | "nkoo" | "adlm" | "rohg"
+            ),
+            None => matches!(
+                language.to_ascii_lowercase().as_str(),
+                "ar" | "he" | "iw" | "fa" | "ur" | "ps" | "sd" | "ug" | "yi" | "dv" | "ckb"
+            ),
+        };
+        if is_rtl {
+            Direction::Rtl
+        } else {
+            Direction::Ltr
+        }
+    }
+
+    /// Return the value of the `dir` attribute, `"ltr"` or `"rtl"`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Direction::Ltr => "ltr",
+            Direction::Rtl => "rtl",
+        }
+    }
+
+    /// Return the physical side the text starts on, `"left"` or `"right"`.
+    pub const fn start_side(self) -> &'static str {
+        match self {
+            Direction::Ltr => "left",
+            Direction::Rtl => "right",
+        }
+    }
+
+    /// Return the physical side the text ends on, `"right"` or `"left"`.
+    pub const fn end_side(self) -> &'static str {
+        self.mirror().start_side()
+    }
+
+    /// Return the other direction.
+    pub const fn mirror(self) -> Self {
+        match self {
+            Direction::Ltr => Direction::Rtl,
+            Direction::Rtl => Direction::Ltr,
+        }
+    }
+}
+
+impl IntoAttribute for Direction {
+    fn into_attribute(self) -> Attribute {
+        self.as_str().into_attribute()
+    }
+
+    fn into_attribute_boxed(self: Box<Self>) -> Attribute {
+        (*self).into_attribute()
+    }
+}
+
+impl<T: Locale> I18nContext<T> {
+    /// Return the direction of the current locale, subscribing to any changes.
+    #[inline]
+    pub fn direction(self) -> Direction {
+        Direction::from_tag(&self.format_locale())
+    }
+}
+
+/// Return a signal of the direction of the current locale of the `I18nContext`,
+/// left to right if there is no context.
+pub fn use_direction() -> Signal<Direction> {
+    let format_context = use_context::<FormatContext>();
+    Signal::derive(move || {
+        format_context
+            .map(|format_context| format_context.tag.with(|tag| Direction::from_tag(tag)))
+            .unwrap_or_default()
+    })
+}
+
+/// The layout of [`DirectionalInput`], in a stylesheet rather than inline styles so it is allowed by a strict
+/// Content Security Policy: `leptos_meta` gives the `<style>` element the nonce of the request, if any.
+/// Only the placeholder is aligned, the typed text follows its own direction.
+const DIRECTIONAL_INPUT_STYLE: &str = "\
+.directional-input{display:inline-flex;align-items:center}\
+.directional-input>input{flex:1}\
+.directional-input-icon{display:inline-flex}\
+.directional-input-icon-start{order:-1}\
+.directional-input-icon-end{order:1}\
+.directional-input[dir=ltr]>input:placeholder-shown{text-align:left}\
+.directional-input[dir=rtl]>input:placeholder-shown{text-align:right}";
+
+/// Where to put the icon of a [`DirectionalInput`], relative to the direction of the locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum IconPosition {
+    /// Where the text starts, the left in left to right locales.
+    #[default]
+    Start,
+    /// Where the text ends, the right in left to right locales.
+    End,
+}
+
+/// The props of [`DirectionalInput`].
+///
+/// The component is written without `#[component]`, whose expansion needs the `tracing` crate when `leptos` is built with its `tracing` feature.
+#[derive(leptos::typed_builder_macro::TypedBuilder)]
+#[builder(crate_module_path = leptos::typed_builder)]
+pub struct DirectionalInputProps {
+    /// The value of the input, updated on each input.
+    #[builder(default, setter(strip_option))]
+    pub value: Option<RwSignal<String>>,
+    /// The placeholder of the input.
+    #[builder(default, setter(strip_option, into))]
+    pub placeholder: Option<TextProp>,
+    /// The `type` attribute of the input, `"text"` by default.
+    #[builder(default, setter(strip_option, into))]
+    pub input_type: Option<TextProp>,
+    /// The `name` attribute of the input.
+    #[builder(default, setter(strip_option, into))]
+    pub name: Option<TextProp>,
+    /// The class of the element wrapping the icon and the input.
+    #[builder(default, setter(strip_option, into))]
+    pub class: Option<TextProp>,
+    /// An icon rendered next to the input.
+    #[builder(default, setter(strip_option, into))]
+    pub icon: Option<ViewFn>,
+    /// The side of the icon.
+    #[builder(default)]
+    pub icon_position: IconPosition,
+}
+
+impl Props for DirectionalInputProps {
+    type Builder = DirectionalInputPropsBuilder;
+
+    fn builder() -> Self::Builder {
+        DirectionalInputProps::builder()
+    }
+}
+
+impl DynAttrs for DirectionalInputProps {}
+
+impl IntoView for DirectionalInputProps {
+    fn into_view(self) -> View {
+        DirectionalInput(self).into_view()
+    }
+}
+
+/// A text input whose icon and placeholder follow the direction of the current locale.
+///
+/// The typed text has `dir="auto"`, so it is displayed in the direction of its own content,
+/// while the placeholder is aligned on the side the text of the locale starts,
+/// and the icon is put on the start or end side of the locale.
+/// The layout comes from a stylesheet on the `directional-input` classes, without inline styles.
+#[allow(non_snake_case)]
+pub fn DirectionalInput(props: DirectionalInputProps) -> impl IntoView {
+    let DirectionalInputProps {
+        value,
+        placeholder,
+        input_type,
+        name,
+        class,
+        icon,
+        icon_position,
+    } = props;
+    leptos_dom::Component::new("DirectionalInput", move || {
+        let direction = use_direction();
+        // the wrapper is a flex row in the direction of the locale, so the start of the row follows it.
+        let icon_class = match icon_position {
+            IconPosition::Start => "directional-input-icon directional-input-icon-start",
+            IconPosition::End => "directional-input-icon directional-input-icon-end",
+        };
+        let icon = icon.map(|icon| {
+            view! {
+                <span class=icon_class>
+                    {icon.run()}
+                </span>
+            }
+        });
+        let input_type = input_type.unwrap_or_else(|| "text".into());
+        let class = move || match &class {
+            Some(class) => format!("directional-input {}", class.get()),
+            None => "directional-input".to_string(),
+        };
+
+        view! {
+            <Style id="leptos-i18n-directional-input">{DIRECTIONAL_INPUT_STYLE}</Style>
+            <span class=class dir=direction>
+                {icon}
+                <input
+                    type=input_type
+                    name=name
+                    dir="auto"
+                    placeholder=placeholder
+                    prop:value=move || value.map(|value| value.get()).unwrap_or_default()
+                    on:input=move |ev| {
+                        if let Some(value) = value {
+                            value.set(event_target_value(&ev));
+                        }
+                    }
+                />
+            </span>
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::from_tag("he"), Direction::Rtl);
+        assert_eq!(Direction::from_tag("fa_IR"), Direction::Rtl);
+        assert_eq!(Direction::from_tag("az-Arab-IR"), Direction::Rtl);
+        assert_eq!(Direction::from_tag("ku-Latn"), Direction::Ltr);
+        assert_eq!(Direction::from_tag("hi-u-nu-arab"), Direction::Ltr);
+        assert_eq!(Direction::from_tag("fr-CA"), Direction::Ltr);
+        assert_eq!(Direction::Rtl.start_side(), "right");
+        assert_eq!(Direction::Rtl.end_side(), "left");
+    }
+}
@@ -1,7 +1,19 @@
+use std::borrow::Cow;
+
 use leptos::*;
 use leptos_meta::*;
 
-use crate::{fetch_locale, locale_traits::*};
+use crate::{
+    components::Direction,
+    fetch_locale,
+    locale_traits::*,
+    preferences::{
+        FormatPreferences, HourCycle, MeasurementSystem, NumberingSystem, TemperatureUnit,
+    },
+};
+
+#[cfg(all(feature = "cookie", any(feature = "hydrate", feature = "csr")))]
+use crate::cookie::CookieOptions;
 
 /// This context is the heart of the i18n system:
 ///
@@ -9,19 +21,44 @@ use crate::{fetch_locale, locale_traits::*};
 ///
 /// You access the translations and read/update the current locale through it.
 #[derive(Debug, Clone, Copy)]
-pub struct I18nContext<T: Locale>(RwSignal<T>);
+pub struct I18nContext<T: Locale> {
+    pub(crate) locale: RwSignal<T>,
+    pub(crate) format_tag: RwSignal<Option<String>>,
+    pub(crate) preferences: RwSignal<FormatPreferences>,
+}
 
 impl<T: Locale> I18nContext<T> {
     /// Return the current locale subscribing to any changes.
     #[inline]
     pub fn get_locale(self) -> T {
-        self.0.get()
+        self.locale.get()
     }
 
     /// Return the current locale but does not subscribe to changes
     #[inline]
     pub fn get_locale_untracked(self) -> T {
-        self.0.get_untracked()
+        self.locale.get_untracked()
+    }
+
+    /// Return the locale used to resolve the translations, subscribing to any changes.
+    ///
+    /// This is the same as `get_locale`, but pairs with `format_locale`.
+    #[inline]
+    pub fn text_locale(self) -> T {
+        self.get_locale()
+    }
+
+    /// Return the language tag to use for formatting numbers and dates, subscribing to any changes.
+    ///
+    /// If the user requested a regional variant of a locale (`fr-CA` for the `fr` locale),
+    /// the translations are resolved from the base locale but the full tag is kept for formatting.
+    /// Otherwise this is the current locale.
+    pub fn format_locale(self) -> Cow<'static, str> {
+        let locale = self.locale.get();
+        self.format_tag.with(|tag| match tag {
+            Some(tag) => Cow::Owned(tag.clone()),
+            None => Cow::Borrowed(locale.as_str()),
+        })
     }
 
     /// Return the keys for the current locale subscribing to any changes
@@ -36,46 +73,310 @@ impl<T: Locale> I18nContext<T> {
         self.get_locale_untracked().get_keys()
     }
 
+    /// Return `true` if the current locale is marked as beta, subscribing to any changes.
+    ///
+    /// This can be used to badge the locale in a locale switcher.
+    #[inline]
+    pub fn is_beta(self) -> bool {
+        self.get_locale().is_beta()
+    }
+
+    /// Return the CSS class hinting the fonts of the current locale, if set in the configuration, subscribing to any changes.
+    ///
+    /// ```rust,ignore
+    /// view! {
+    ///     <main class=move || i18n.font_class().unwrap_or_default()>
+    ///         // ...
+    ///     </main>
+    /// }
+    /// ```
+    #[inline]
+    pub fn font_class(self) -> Option<&'static str> {
+        self.get_locale().font_class()
+    }
+
     /// Set the locale and notify all subscribers
+    ///
+    /// This reset the formatting tag to the locale.
     #[inline]
     pub fn set_locale(self, lang: T) {
-        self.0.set(lang)
+        self.format_tag.set(None);
+        self.locale.set(lang)
     }
 
     /// Set the locale but does not notify the subscribers
+    ///
+    /// This reset the formatting tag to the locale.
     #[inline]
     pub fn set_locale_untracked(self, lang: T) {
-        self.0.set_untracked(lang)
+        self.format_tag.set_untracked(None);
+        self.locale.set_untracked(lang)
+    }
+
+    /// Set the locale from a language tag, keeping the full tag for formatting if it matched on its base language.
+    ///
+    /// Return `false` if the tag does not match any locale.
+    pub fn set_locale_tag(self, tag: &str) -> bool {
+        self.try_set_locale_tag(tag).is_ok()
+    }
+
+    /// Same as [`I18nContext::set_locale_tag`], but return why the tag does not match any locale.
+    pub fn try_set_locale_tag(self, tag: &str) -> Result<(), LocaleParseError> {
+        let locale = T::try_from_tag(tag)?;
+        let format_tag = (locale.as_str() != tag).then(|| tag.to_owned());
+        self.format_tag.set(format_tag);
+        self.locale.set(locale);
+        Ok(())
+    }
+
+    /// Return the formatting preferences set by the user, subscribing to any changes.
+    #[inline]
+    pub fn get_preferences(self) -> FormatPreferences {
+        self.preferences.get()
+    }
+
+    /// Update the formatting preferences and notify all subscribers.
+    #[inline]
+    pub fn update_preferences(self, f: impl FnOnce(&mut FormatPreferences)) {
+        self.preferences.update(f)
+    }
+
+    /// Return the region to use for formatting, subscribing to any changes.
+    ///
+    /// This is the region set by the user, or else the region of the requested tag (`CA` for `fr-CA`).
+    pub fn region(self) -> Option<String> {
+        self.preferences
+            .with(|prefs| prefs.region.clone())
+            .or_else(|| {
+                crate::preferences::region_from_tag(&self.format_locale()).map(str::to_owned)
+            })
+    }
+
+    /// Set the region preference, `None` going back to the detected region.
+    pub fn set_region(self, region: Option<&str>) {
+        self.update_preferences(|prefs| prefs.region = region.map(str::to_ascii_uppercase))
+    }
+
+    /// Return the currency set by the user, subscribing to any changes.
+    ///
+    /// When `None`, formatters should use the currency they are given.
+    pub fn currency(self) -> Option<String> {
+        self.preferences.with(|prefs| prefs.currency.clone())
+    }
+
+    /// Set the currency preference.
+    pub fn set_currency(self, currency: Option<&str>) {
+        self.update_preferences(|prefs| prefs.currency = currency.map(str::to_ascii_uppercase))
+    }
+
+    /// Return the measurement system to use for units, subscribing to any changes.
+    ///
+    /// This is the measurement system set by the user, or else the one commonly used in the region.
+    pub fn measurement_system(self) -> MeasurementSystem {
+        self.preferences
+            .with(|prefs| prefs.measurement_system)
+            .unwrap_or_else(|| MeasurementSystem::from_region(self.region().as_deref()))
+    }
+
+    /// Override the measurement system, `None` going back to the one of the region.
+    pub fn set_measurement_system(self, measurement_system: Option<MeasurementSystem>) {
+        self.update_preferences(|prefs| prefs.measurement_system = measurement_system)
+    }
+
+    /// Return the unit to use for temperatures, subscribing to any changes.
+    ///
+    /// This is the unit set by the user, or else the one commonly used in the region.
+    pub fn temperature_unit(self) -> TemperatureUnit {
+        self.preferences
+            .with(|prefs| prefs.temperature_unit)
+            .unwrap_or_else(|| TemperatureUnit::from_region(self.region().as_deref()))
+    }
+
+    /// Override the temperature unit, `None` going back to the one of the region.
+    pub fn set_temperature_unit(self, temperature_unit: Option<TemperatureUnit>) {
+        self.update_preferences(|prefs| prefs.temperature_unit = temperature_unit)
+    }
+
+    /// Return the clock to use for times, subscribing to any changes.
+    ///
+    /// This is the hour cycle set by the user, or else the one commonly used in the region.
+    pub fn hour_cycle(self) -> HourCycle {
+        self.preferences
+            .with(|prefs| prefs.hour_cycle)
+            .unwrap_or_else(|| HourCycle::from_region(self.region().as_deref()))
+    }
+
+    /// Override the hour cycle, `None` going back to the one of the region.
+    pub fn set_hour_cycle(self, hour_cycle: Option<HourCycle>) {
+        self.update_preferences(|prefs| prefs.hour_cycle = hour_cycle)
+    }
+
+    /// Return the numbering system set by the user or requested by the formatting tag, subscribing to any changes.
+    ///
+    /// When `None`, formatters use the numbering system of the locale they are given.
+    fn requested_numbering_system(self) -> Option<NumberingSystem> {
+        self.preferences
+            .with(|prefs| prefs.numbering_system)
+            .or_else(|| NumberingSystem::from_tag(&self.format_locale()))
+    }
+
+    /// Return the digits to use for numbers, subscribing to any changes.
+    ///
+    /// This is the numbering system set by the user, or else the one requested by the `-u-nu-` extension of the locale,
+    /// latin digits by default.
+    pub fn numbering_system(self) -> NumberingSystem {
+        self.requested_numbering_system().unwrap_or_default()
+    }
+
+    /// Override the numbering system, `None` going back to the one of the locale.
+    ///
+    /// This applies to all numbers rendered in a view, counts and formatted variables of translations included.
+    pub fn set_numbering_system(self, numbering_system: Option<NumberingSystem>) {
+        self.update_preferences(|prefs| prefs.numbering_system = numbering_system)
+    }
+
+    /// Create a new context starting with the locale, formatting tag and preferences of this one.
+    ///
+    /// The signals of the fork are owned by the current owner and independent from the ones of this context:
+    /// setting the locale of one does not change the other, and the fork does not update the `<html>` attributes
+    /// nor persist its locale. Use [`I18nContext::provide`] to make it the context of a subtree.
+    pub fn fork(self) -> Self {
+        I18nContext {
+            locale: create_rw_signal(self.locale.get_untracked()),
+            format_tag: create_rw_signal(self.format_tag.get_untracked()),
+            preferences: create_rw_signal(self.preferences.get_untracked()),
+        }
+    }
+
+    /// Provide this context to the current owner and its children, shadowing the one provided by a parent.
+    ///
+    /// The formatted values rendered by the children then follow this context.
+    pub fn provide(self) {
+        provide_context(self);
+        provide_context(crate::format::FormatContext {
+            tag: Signal::derive(move || self.format_locale().into_owned()),
+            hour_cycle: Signal::derive(move || self.hour_cycle()),
+            numbering_system: Signal::derive(move || self.requested_numbering_system()),
+        });
     }
 }
 
-fn set_html_lang_attr(lang: &'static str) {
-    let lang = || lang.to_string();
+fn set_html_lang_attr(lang: impl Into<Cow<'static, str>>) {
+    let lang: Cow<'static, str> = lang.into();
+    let dir = Direction::from_tag(&lang).as_str();
+    let lang = move || lang.to_string();
     Html(HtmlProps {
         lang: Some(lang.into()),
-        dir: None,
+        dir: Some(dir.into()),
         class: None,
         attributes: vec![],
     });
 }
 
+fn set_robots_meta<T: Locale>(locale: RwSignal<T>) {
+    // only emit the tag on the pages of a beta locale, to not override the user own robots directives.
+    if !T::get_all().iter().any(|locale| locale.is_beta()) {
+        return;
+    }
+    let is_beta = create_memo(move |_| locale.get().is_beta());
+    // in the browser, the tag is removed from the head when the effect runs again.
+    create_isomorphic_effect(move |_| {
+        if is_beta.get() {
+            Meta(
+                MetaProps::builder()
+                    .name("robots")
+                    .content("noindex")
+                    .build(),
+            );
+        }
+    });
+}
+
+/// The type of a font file, guessed from its extension.
+fn font_type(url: &str) -> Option<&'static str> {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    match path.rsplit_once('.')?.1 {
+        "woff2" => Some("font/woff2"),
+        "woff" => Some("font/woff"),
+        "ttf" => Some("font/ttf"),
+        "otf" => Some("font/otf"),
+        _ => None,
+    }
+}
+
+fn set_font_preloads<T: Locale>(locale: T) {
+    // the fonts of a locale selected later are loaded by the CSS when used.
+    for &url in locale.font_preloads() {
+        let mut props = LinkProps::builder()
+            .rel("preload")
+            .as_("font")
+            .href(url)
+            .crossorigin("anonymous")
+            .build();
+        props.type_ = font_type(url).map(Into::into);
+        Link(props);
+    }
+}
+
 fn init_context<T: Locale>() -> I18nContext<T> {
     provide_meta_context();
+    crate::storage::init_persistence::<T>();
+
+    let state = crate::transfer::take_state::<T>();
 
-    let locale = fetch_locale::fetch_locale::<T>();
+    let (locale, format_tag) = match (&state, crate::storage::load_locale::<T>()) {
+        (Some((locale, format_tag, _)), _) => (*locale, format_tag.clone()),
+        (None, Some(locale)) => (locale, None),
+        (None, None) => fetch_locale::fetch_locale::<T>(),
+    };
 
     let locale = create_rw_signal(locale);
+    let format_tag = create_rw_signal(format_tag);
+
+    set_robots_meta(locale);
+    set_font_preloads(locale.get_untracked());
+
+    #[cfg(feature = "dynamic_load_strings")]
+    crate::dynamic_load::init(locale);
 
     create_isomorphic_effect(move |_| {
         let new_lang = locale.get();
-        set_html_lang_attr(new_lang.as_str());
-        #[cfg(all(feature = "cookie", any(feature = "hydrate", feature = "csr")))]
-        set_lang_cookie::<T>(new_lang);
+        // the full tag is used so the hydration can recover it.
+        match format_tag.get() {
+            Some(tag) => set_html_lang_attr(tag),
+            None => set_html_lang_attr(new_lang.as_str()),
+        }
+        if crate::storage::has_consent() && !crate::storage::save_locale(new_lang) {
+            #[cfg(all(feature = "cookie", any(feature = "hydrate", feature = "csr")))]
+            set_lang_cookie::<T>(new_lang);
+        }
     });
 
-    let context = I18nContext::<T>(locale);
+    let preferences = match state {
+        Some((_, _, preferences)) => preferences,
+        None => crate::storage::load_preferences().unwrap_or_else(fetch_locale::fetch_preferences),
+    };
+    let preferences = create_rw_signal(preferences);
+
+    create_isomorphic_effect(move |prev: Option<()>| {
+        let prefs = preferences.with(FormatPreferences::encode);
+        // don't persist the initial value, it has just been loaded.
+        if prev.is_none() || !crate::storage::has_consent() {
+            return;
+        }
+        if !crate::storage::save_preferences(&prefs) {
+            #[cfg(all(feature = "cookie", any(feature = "hydrate", feature = "csr")))]
+            set_cookie(CookieOptions::get_preferences_name, &prefs);
+        }
+    });
 
-    provide_context(context);
+    let context = I18nContext::<T> {
+        locale,
+        format_tag,
+        preferences,
+    };
+
+    context.provide();
 
     context
 }
@@ -91,6 +392,15 @@ pub fn provide_i18n_context<T: Locale>() -> I18nContext<T> {
     use_context().unwrap_or_else(init_context)
 }
 
+/// Create and provide a new `I18nContext`, even if one is already provided by a parent.
+///
+/// The locale is read from the current request, like [`provide_i18n_context`] does, but a context provided higher
+/// in the tree is never reused. When rendering on the server with an owner shared between requests,
+/// call it at the root of each request so no locale set by one request leaks into another.
+pub fn provide_isolated_i18n_context<T: Locale>() -> I18nContext<T> {
+    init_context()
+}
+
 /// Return the `I18nContext` previously set.
 ///
 /// ## Panic
@@ -103,13 +413,14 @@ pub fn use_i18n_context<T: Locale>() -> I18nContext<T> {
 
 #[cfg(all(feature = "cookie", any(feature = "hydrate", feature = "csr")))]
 fn set_lang_cookie<T: Locale>(lang: T) -> Option<()> {
-    use crate::COOKIE_PREFERED_LANG;
+    set_cookie(CookieOptions::get_name, lang.as_str())
+}
+
+#[cfg(all(feature = "cookie", any(feature = "hydrate", feature = "csr")))]
+fn set_cookie(name: fn(&CookieOptions) -> &str, value: &str) -> Option<()> {
     let document = super::get_html_document()?;
-    let cookie = format!(
-        "{}={}; SameSite=Lax; Secure; Path=/; Max-Age=31536000",
-        COOKIE_PREFERED_LANG,
-        lang.as_str()
-    );
+    let options = crate::cookie::use_cookie_options();
+    let cookie = options.set_cookie(name(&options), value);
     document.set_cookie(&cookie).ok()
 }
 
@@ -164,3 +475,69 @@ impl<T: Locale> Fn<(T,)> for I18nContext<T> {
         self.set_locale(locale)
     }
 }
+
+// the head is only rendered to a string on the server.
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    enum TestLocale {
+        #[default]
+        En,
+        Fr,
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    struct TestKeys;
+
+    impl LocaleKeys for TestKeys {
+        type Locale = TestLocale;
+
+        fn from_locale(_: TestLocale) -> &'static Self {
+            &TestKeys
+        }
+    }
+
+    impl Locale for TestLocale {
+        type Keys = TestKeys;
+
+        fn from_str(s: &str) -> Option<Self> {
+            match s {
+                "en" => Some(TestLocale::En),
+                "fr" => Some(TestLocale::Fr),
+                _ => None,
+            }
+        }
+
+        fn as_str(self) -> &'static str {
+            match self {
+                TestLocale::En => "en",
+                TestLocale::Fr => "fr",
+            }
+        }
+
+        fn get_all() -> &'static [Self] {
+            &[TestLocale::En, TestLocale::Fr]
+        }
+
+        fn is_beta(self) -> bool {
+            self == TestLocale::Fr
+        }
+    }
+
+    #[test]
+    fn test_robots_meta() {
+        let runtime = create_runtime();
+        provide_meta_context();
+        let locale = create_rw_signal(TestLocale::En);
+        set_robots_meta(locale);
+        // nothing is emitted outside of the beta locales, the app keeps its own directives.
+        assert!(!use_head().dehydrate().contains("robots"));
+        locale.set(TestLocale::Fr);
+        let head = use_head().dehydrate();
+        assert!(head.contains(r#"name="robots""#));
+        assert!(head.contains(r#"content="noindex""#));
+        runtime.dispose();
+    }
+}
@@ -0,0 +1,166 @@
+//! This module contain the options of the cookies persisting the locale and the formatting preferences, with the `cookie` feature.
+//!
+//! By default the locale is persisted for a year in the `i18n_pref_locale` cookie, with `SameSite=Lax; Secure; Path=/`.
+//! Provide your own options with [`provide_cookie_options`] before the `I18nContext`, they are used both to set the cookie on the client
+//! and to read it, on the client and on the server, so it is provided in the same way on both sides:
+//!
+//! ```rust,ignore
+//! use leptos_i18n::cookie::{provide_cookie_options, CookieOptions, SameSite};
+//!
+//! provide_cookie_options(
+//!     CookieOptions::default()
+//!         .name("lang")
+//!         .domain("example.com")
+//!         .path("/app")
+//!         .same_site(SameSite::Strict)
+//!         .max_age(Some(30 * 24 * 60 * 60)),
+//! );
+//! let i18n = provide_i18n_context();
+//! ```
+
+use std::{borrow::Cow, fmt::Write};
+
+/// The `SameSite` attribute of a cookie.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SameSite {
+    /// The cookie is only sent for requests from the same site.
+    Strict,
+    /// The cookie is also sent when navigating to the site from another one.
+    #[default]
+    Lax,
+    /// The cookie is sent with cross-site requests, it must then be `Secure`.
+    None,
+}
+
+impl SameSite {
+    /// Return the value of the attribute.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SameSite::Strict => "Strict",
+            SameSite::Lax => "Lax",
+            SameSite::None => "None",
+        }
+    }
+}
+
+/// The options of the cookies set by the `I18nContext`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CookieOptions {
+    name: Cow<'static, str>,
+    preferences_name: Cow<'static, str>,
+    max_age: Option<u64>,
+    same_site: SameSite,
+    secure: bool,
+    path: Option<Cow<'static, str>>,
+    domain: Option<Cow<'static, str>>,
+}
+
+impl Default for CookieOptions {
+    fn default() -> Self {
+        CookieOptions {
+            name: Cow::Borrowed("i18n_pref_locale"),
+            preferences_name: Cow::Borrowed("i18n_pref_format"),
+            max_age: Some(31536000),
+            same_site: SameSite::Lax,
+            secure: true,
+            path: Some(Cow::Borrowed("/")),
+            domain: None,
+        }
+    }
+}
+
+impl CookieOptions {
+    /// Set the name of the cookie persisting the locale, `i18n_pref_locale` by default.
+    pub fn name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Set the name of the cookie persisting the formatting preferences, `i18n_pref_format` by default.
+    pub fn preferences_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.preferences_name = name.into();
+        self
+    }
+
+    /// Set the number of seconds the cookies are kept, a year by default. `None` makes them session cookies.
+    pub fn max_age(mut self, max_age: Option<u64>) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Set the `SameSite` attribute of the cookies, `Lax` by default.
+    pub fn same_site(mut self, same_site: SameSite) -> Self {
+        self.same_site = same_site;
+        self
+    }
+
+    /// Set if the cookies are only sent over HTTPS, `true` by default.
+    pub fn secure(mut self, secure: bool) -> Self {
+        self.secure = secure;
+        self
+    }
+
+    /// Set the path of the cookies, `/` by default.
+    pub fn path(mut self, path: impl Into<Cow<'static, str>>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Set the domain of the cookies, by default they are only sent to the host that set them.
+    pub fn domain(mut self, domain: impl Into<Cow<'static, str>>) -> Self {
+        self.domain = Some(domain.into());
+        self
+    }
+
+    /// Return the name of the cookie persisting the locale.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return the name of the cookie persisting the formatting preferences.
+    pub fn get_preferences_name(&self) -> &str {
+        &self.preferences_name
+    }
+
+    /// Return the value of a `Set-Cookie` header (or of `document.cookie`) setting the cookie with the given name to `value`.
+    ///
+    /// ```rust
+    /// use leptos_i18n::cookie::{CookieOptions, SameSite};
+    ///
+    /// let options = CookieOptions::default()
+    ///     .domain("example.com")
+    ///     .same_site(SameSite::Strict)
+    ///     .max_age(None);
+    /// assert_eq!(
+    ///     options.set_cookie(options.get_name(), "fr"),
+    ///     "i18n_pref_locale=fr; SameSite=Strict; Secure; Path=/; Domain=example.com"
+    /// );
+    /// ```
+    pub fn set_cookie(&self, name: &str, value: &str) -> String {
+        let mut cookie = format!("{}={}; SameSite={}", name, value, self.same_site.as_str());
+        if self.secure {
+            cookie.push_str("; Secure");
+        }
+        // writing to a String can't fail
+        if let Some(path) = &self.path {
+            let _ = write!(cookie, "; Path={}", path);
+        }
+        if let Some(domain) = &self.domain {
+            let _ = write!(cookie, "; Domain={}", domain);
+        }
+        if let Some(max_age) = self.max_age {
+            let _ = write!(cookie, "; Max-Age={}", max_age);
+        }
+        cookie
+    }
+}
+
+/// Provide the options of the cookies, used by the `I18nContext` to set and read them.
+pub fn provide_cookie_options(options: CookieOptions) {
+    leptos::provide_context(options);
+}
+
+/// Return the options provided with [`provide_cookie_options`], or the default ones.
+pub fn use_cookie_options() -> CookieOptions {
+    leptos::use_context().unwrap_or_default()
+}
@@ -4,12 +4,87 @@ use std::fmt;
 
 use leptos::Attribute;
 
+use crate::raw_html::escape_html;
+
+/// Return `true` for the HTML elements that can't have children, written `<br/>` instead of `<br></br>`.
+fn is_void_element(name: &str) -> bool {
+    matches!(
+        name,
+        "area"
+            | "base"
+            | "br"
+            | "col"
+            | "embed"
+            | "hr"
+            | "img"
+            | "input"
+            | "link"
+            | "meta"
+            | "source"
+            | "track"
+            | "wbr"
+    )
+}
+
 /// This trait is used when interpolating component with the `td_string!` macro
 pub trait DisplayComponent {
     /// Takes as an input a formatter and a function to format the component children
     fn fmt<T>(&self, f: &mut fmt::Formatter<'_>, children: T) -> fmt::Result
     where
         T: Fn(&mut fmt::Formatter<'_>) -> fmt::Result;
+
+    /// Same as `fmt`, with the attributes of the tag in the translation, like `<link href="{{ url }}">`.
+    ///
+    /// They are ignored by default, the implementations writing an element add them to it.
+    fn fmt_with_attrs<T>(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        attrs: &[(&'static str, Attribute)],
+        children: T,
+    ) -> fmt::Result
+    where
+        T: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+    {
+        let _ = attrs;
+        self.fmt(f, children)
+    }
+}
+
+/// Write an attribute, its value escaped as it can contain the value of a variable.
+fn write_attribute(f: &mut fmt::Formatter<'_>, name: &str, attr: &Attribute) -> fmt::Result {
+    match attr {
+        Attribute::String(value) | Attribute::Option(Some(value)) => {
+            write!(f, " {}=\"{}\"", name, escape_html(value))
+        }
+        Attribute::Fn(value) => write_attribute(f, name, &value()),
+        Attribute::Bool(true) => write!(f, " {}", name),
+        Attribute::Bool(false) | Attribute::Option(None) => Ok(()),
+    }
+}
+
+/// Write the element `name` with the attributes of the component, then the ones of the tag in the translation.
+fn write_element<T>(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    attrs: &[(&'static str, Attribute)],
+    tag_attrs: &[(&'static str, Attribute)],
+    children: T,
+) -> fmt::Result
+where
+    T: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    write!(f, "<{}", name)?;
+    for (attr_name, attr) in attrs.iter().chain(tag_attrs) {
+        write_attribute(f, attr_name, attr)?;
+    }
+    if is_void_element(name) {
+        // void elements can't have children, those of a self-closing tag are empty.
+        f.write_str("/>")?;
+        return children(f);
+    }
+    f.write_str(">")?;
+    children(f)?;
+    write!(f, "</{}>", name)
 }
 
 impl<F> DisplayComponent for F
@@ -29,9 +104,19 @@ impl<'a> DisplayComponent for &'a str {
     where
         T: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
     {
-        write!(f, "<{}>", self)?;
-        children(f)?;
-        write!(f, "</{}>", self)
+        write_element(f, self, &[], &[], children)
+    }
+
+    fn fmt_with_attrs<T>(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        attrs: &[(&'static str, Attribute)],
+        children: T,
+    ) -> fmt::Result
+    where
+        T: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+    {
+        write_element(f, self, &[], attrs, children)
     }
 }
 
@@ -43,6 +128,19 @@ impl DisplayComponent for String {
     {
         self.as_str().fmt(f, children)
     }
+
+    #[inline]
+    fn fmt_with_attrs<T>(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        attrs: &[(&'static str, Attribute)],
+        children: T,
+    ) -> fmt::Result
+    where
+        T: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+    {
+        self.as_str().fmt_with_attrs(f, attrs, children)
+    }
 }
 
 /// This struct is made to be used with the `td_string!` macro when interpolating a component
@@ -71,13 +169,18 @@ impl DisplayComponent for DisplayComp<'_> {
     where
         T: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
     {
-        write!(f, "<{}", self.comp_name)?;
-        for (attr_name, attr) in self.attrs {
-            let value = attr.as_value_string(attr_name);
-            write!(f, " {}", value)?;
-        }
-        f.write_str(">")?;
-        children(f)?;
-        write!(f, "</{}>", self.comp_name)
+        write_element(f, self.comp_name, self.attrs, &[], children)
+    }
+
+    fn fmt_with_attrs<T>(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        attrs: &[(&'static str, Attribute)],
+        children: T,
+    ) -> fmt::Result
+    where
+        T: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+    {
+        write_element(f, self.comp_name, self.attrs, attrs, children)
     }
 }
@@ -0,0 +1,192 @@
+//! This module contain the types returned by the `Locale::get_by_key` method, generated by the `load_locales!` macro when the `dynamic_keys` feature is enabled.
+//!
+//! It resolves keys only known at runtime, like error codes or slugs sent by a backend, from their dot-separated path
+//! (`key.subkey`, or `namespace.key.subkey` with namespaces), as written in the locales files.
+//! Keys without interpolations return their string, the others a builder taking the values of the interpolations by name:
+//!
+//! ```rust,ignore
+//! use leptos_i18n::dynamic_keys::Interpolations;
+//!
+//! /* "errors": { "not_found": "<b>{{ name }}</b> was not found" } */
+//! let value = i18n.get_locale().get_by_key("errors.not_found").unwrap();
+//! let interpolations = Interpolations::from_iter([("name", "report.pdf")])
+//!     .component("b", |children| view! { <strong>{children}</strong> }.into_view());
+//! let view = value.build(&interpolations);
+//! ```
+//!
+//! The values are given as strings: the counts of the plurals and the formatted numbers are parsed from them,
+//! and the dates are read as UNIX timestamps in seconds. A missing or unparsable value is replaced by the default
+//! of its type, and a missing component renders its children.
+
+use std::{borrow::Cow, collections::HashMap, fmt, hash::Hash, rc::Rc, str::FromStr};
+
+use leptos::{Attribute, ChildrenFn, IntoView, View};
+
+use crate::format::DateTime;
+
+type Component = Rc<dyn Fn(ChildrenFn, Vec<(&'static str, Attribute)>) -> View>;
+
+/// The values of the interpolations of a key looked up at runtime, by name.
+#[derive(Clone, Default)]
+pub struct Interpolations {
+    values: HashMap<String, String>,
+    components: HashMap<String, Component>,
+}
+
+impl Interpolations {
+    /// Create an empty set of interpolations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the value of a variable, a count, a selected variable or a formatted variable.
+    pub fn value(mut self, name: impl Into<String>, value: impl ToString) -> Self {
+        self.values.insert(name.into(), value.to_string());
+        self
+    }
+
+    /// Set a component, rendering the children it is given.
+    pub fn component(
+        self,
+        name: impl Into<String>,
+        component: impl Fn(ChildrenFn) -> View + 'static,
+    ) -> Self {
+        self.component_with_attrs(name, move |children, _| component(children))
+    }
+
+    /// Set a component, rendering the children and the attributes of its tag it is given.
+    pub fn component_with_attrs(
+        mut self,
+        name: impl Into<String>,
+        component: impl Fn(ChildrenFn, Vec<(&'static str, Attribute)>) -> View + 'static,
+    ) -> Self {
+        self.components.insert(name.into(), Rc::new(component));
+        self
+    }
+
+    fn get<T: FromStr + Default>(&self, name: &str) -> T {
+        self.values
+            .get(name)
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_default()
+    }
+
+    #[doc(hidden)]
+    pub fn variable(&self, name: &str) -> String {
+        self.values.get(name).cloned().unwrap_or_default()
+    }
+
+    #[doc(hidden)]
+    pub fn count<T: FromStr + Default + Copy + 'static>(
+        &self,
+        name: &str,
+    ) -> impl Fn() -> T + Clone {
+        let count = self.get(name);
+        move || count
+    }
+
+    #[doc(hidden)]
+    pub fn number(&self, name: &str) -> impl Fn() -> f64 + Clone {
+        self.count(name)
+    }
+
+    #[doc(hidden)]
+    pub fn date_time(&self, name: &str) -> impl Fn() -> DateTime + Clone {
+        let date_time = DateTime::from_unix_timestamp(self.get(name));
+        move || date_time
+    }
+
+    #[doc(hidden)]
+    pub fn select(&self, name: &str) -> impl Fn() -> String + Clone {
+        let value = self.variable(name);
+        move || value.clone()
+    }
+
+    #[doc(hidden)]
+    pub fn children(&self, name: &str) -> impl Fn(ChildrenFn) -> View + Clone {
+        let component = self.children_with_attrs(name);
+        move |children| component(children, vec![])
+    }
+
+    #[doc(hidden)]
+    pub fn children_with_attrs(
+        &self,
+        name: &str,
+    ) -> impl Fn(ChildrenFn, Vec<(&'static str, Attribute)>) -> View + Clone {
+        let component = self.components.get(name).cloned();
+        move |children: ChildrenFn, attrs| match &component {
+            Some(component) => component(children, attrs),
+            None => children().into_view(),
+        }
+    }
+}
+
+impl fmt::Debug for Interpolations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Interpolations")
+            .field("values", &self.values)
+            .field("components", &self.components.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<K: Into<String>, V: ToString> FromIterator<(K, V)> for Interpolations {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(Self::new(), |interpolations, (name, value)| {
+                interpolations.value(name, value)
+            })
+    }
+}
+
+impl<K: Into<String> + Eq + Hash, V: ToString> From<HashMap<K, V>> for Interpolations {
+    fn from(values: HashMap<K, V>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+/// The builder of a key with interpolations, looked up at runtime.
+#[derive(Debug, Clone, Copy)]
+pub struct DynamicBuilder<L> {
+    locale: L,
+    build: fn(L, &Interpolations) -> View,
+}
+
+impl<L: Copy> DynamicBuilder<L> {
+    #[doc(hidden)]
+    pub const fn new(locale: L, build: fn(L, &Interpolations) -> View) -> Self {
+        DynamicBuilder { locale, build }
+    }
+
+    /// Render the value of the key with the given interpolations.
+    pub fn build(self, interpolations: &Interpolations) -> View {
+        (self.build)(self.locale, interpolations)
+    }
+}
+
+/// The value of a key looked up at runtime.
+#[derive(Debug, Clone)]
+pub enum DynamicValue<L> {
+    /// A key without interpolations.
+    String(Cow<'static, str>),
+    /// A key with interpolations.
+    Builder(DynamicBuilder<L>),
+}
+
+impl<L: Copy> DynamicValue<L> {
+    /// Return the string of a key without interpolations.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            DynamicValue::String(s) => Some(s),
+            DynamicValue::Builder(_) => None,
+        }
+    }
+
+    /// Render the value of the key, the interpolations being ignored by a key without any.
+    pub fn build(&self, interpolations: &Interpolations) -> View {
+        match self {
+            DynamicValue::String(s) => s.to_string().into_view(),
+            DynamicValue::Builder(builder) => builder.build(interpolations),
+        }
+    }
+}
@@ -0,0 +1,296 @@
+//! This module contain the type of the strings generated by the `load_locales!` macro when the `dynamic_load_strings` feature is enabled.
+//!
+//! Only the plain strings of the default locale are compiled in, the catalogs of the other locales are fetched as JSON
+//! when they are selected, at `{dynamic-load-url}/{locale}.json` (or `{dynamic-load-url}/{locale}/{namespace}.json` with namespaces),
+//! `dynamic-load-url` defaulting to `/locales`. You must serve your locale files at that URL.
+//!
+//! Until the catalog is loaded the strings of the default locale are rendered, then the views are updated with the fetched strings.
+//! Keys missing from a catalog keep the string of the default locale.
+//!
+//! Only the keys without interpolations are loaded, the values with variables, components or plurals are still compiled in for every locale:
+//! the binary shrinks by the size of the plain strings of the other locales, not of the whole catalogs.
+//! Override files are not fetched, merge them in the served files.
+//!
+//! On the server nothing is fetched, [`insert_catalog`] can be used to register the catalogs read from the disk
+//! so the pages are rendered in the requested locale. The catalogs are shared by the whole process,
+//! registering them once at startup is enough for every worker thread:
+//!
+//! ```rust,ignore
+//! let catalog = std::fs::read_to_string("./locales/fr.json")?;
+//! leptos_i18n::dynamic_load::insert_catalog("/locales/fr.json", &catalog)?;
+//! ```
+
+use std::{borrow::Cow, fmt};
+
+use leptos::{create_trigger, use_context, IntoView, Trigger, View};
+
+use crate::{locale_traits::BuildStr, Locale};
+
+/// A string read from the catalog fetched for the locale, falling back to the string of the default locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DynStr {
+    fallback: &'static str,
+    url: Option<&'static str>,
+    key_path: &'static [&'static str],
+}
+
+impl DynStr {
+    #[doc(hidden)]
+    pub const fn new(
+        fallback: &'static str,
+        url: Option<&'static str>,
+        key_path: &'static [&'static str],
+    ) -> Self {
+        DynStr {
+            fallback,
+            url,
+            key_path,
+        }
+    }
+
+    /// Return the string of the default locale, compiled in.
+    pub const fn fallback(self) -> &'static str {
+        self.fallback
+    }
+
+    /// Return the string of the locale if its catalog is loaded, or else the string of the default locale.
+    ///
+    /// This does not subscribe to the loading of the catalog, see [`DynStr::get`] for that.
+    pub fn get_untracked(self) -> Cow<'static, str> {
+        match self
+            .url
+            .and_then(|url| catalogs::read_key(url, self.key_path))
+        {
+            Some(value) => Cow::Owned(value),
+            None => Cow::Borrowed(self.fallback),
+        }
+    }
+
+    /// Return the string of the locale if its catalog is loaded, or else the string of the default locale,
+    /// subscribing to the loading of the catalogs.
+    pub fn get(self) -> Cow<'static, str> {
+        track_catalogs();
+        self.get_untracked()
+    }
+}
+
+impl fmt::Display for DynStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.get_untracked())
+    }
+}
+
+impl IntoView for DynStr {
+    fn into_view(self) -> View {
+        let catalogs_loaded = use_context::<CatalogsLoaded>();
+        let value = move || {
+            if let Some(CatalogsLoaded(trigger)) = catalogs_loaded {
+                trigger.track();
+            }
+            self.get_untracked().into_owned()
+        };
+        value.into_view()
+    }
+}
+
+impl BuildStr for DynStr {
+    fn build_string(self) -> Cow<'static, str> {
+        self.get()
+    }
+}
+
+/// Notified when a catalog is loaded.
+#[derive(Debug, Clone, Copy)]
+struct CatalogsLoaded(Trigger);
+
+fn track_catalogs() {
+    if let Some(CatalogsLoaded(trigger)) = use_context() {
+        trigger.track();
+    }
+}
+
+fn notify_catalogs() {
+    if let Some(CatalogsLoaded(trigger)) = use_context() {
+        trigger.notify();
+    }
+}
+
+/// Register the catalog of the given URL, as if it was fetched, and update the views using it.
+///
+/// A catalog already registered for this URL is replaced.
+pub fn insert_catalog(url: &'static str, json: &str) -> Result<(), serde_json::Error> {
+    let value = serde_json::from_str(json)?;
+    catalogs::insert(url, value);
+    notify_catalogs();
+    Ok(())
+}
+
+/// Return the URL and the JSON of the loaded catalogs.
+pub(crate) fn loaded_catalogs() -> Vec<(String, String)> {
+    catalogs::all()
+}
+
+/// Return `true` if all the catalogs of the locale are loaded, subscribing to the loading of the catalogs.
+///
+/// This is always `true` for the default locale, and can be used to show a loading indicator in a locale switcher.
+pub fn is_locale_loaded<T: Locale>(locale: T) -> bool {
+    track_catalogs();
+    locale
+        .dynamic_load_urls()
+        .iter()
+        .all(|url| catalogs::contains(url))
+}
+
+/// Provide the context notified when a catalog is loaded, and fetch the catalogs of the locale each time it changes.
+pub(crate) fn init<T: Locale>(locale: leptos::RwSignal<T>) {
+    leptos::provide_context(CatalogsLoaded(create_trigger()));
+    #[cfg(any(feature = "hydrate", feature = "csr"))]
+    leptos::create_effect(move |_| {
+        use leptos::SignalGet;
+        for url in locale.get().dynamic_load_urls() {
+            fetch::fetch_catalog(url);
+        }
+    });
+    #[cfg(not(any(feature = "hydrate", feature = "csr")))]
+    let _ = locale;
+}
+
+/// The loaded catalogs, shared by the whole process so a catalog inserted on one thread of a server
+/// is available to the requests rendered on the others.
+mod catalogs {
+    use std::{
+        collections::HashMap,
+        sync::{OnceLock, PoisonError, RwLock},
+    };
+
+    use serde_json::Value;
+
+    type Catalogs = HashMap<&'static str, Value>;
+
+    fn catalogs() -> &'static RwLock<Catalogs> {
+        static CATALOGS: OnceLock<RwLock<Catalogs>> = OnceLock::new();
+        CATALOGS.get_or_init(Default::default)
+    }
+
+    fn with<R>(f: impl FnOnce(&Catalogs) -> R) -> R {
+        let catalogs = catalogs().read().unwrap_or_else(PoisonError::into_inner);
+        f(&catalogs)
+    }
+
+    pub fn insert(url: &'static str, value: Value) {
+        let mut catalogs = catalogs().write().unwrap_or_else(PoisonError::into_inner);
+        catalogs.insert(url, value);
+    }
+
+    pub fn all() -> Vec<(String, String)> {
+        with(|catalogs| {
+            catalogs
+                .iter()
+                .map(|(url, value)| (url.to_string(), value.to_string()))
+                .collect()
+        })
+    }
+
+    pub fn contains(url: &str) -> bool {
+        with(|catalogs| catalogs.contains_key(url))
+    }
+
+    pub fn read_key(url: &str, key_path: &[&str]) -> Option<String> {
+        with(|catalogs| {
+            let value = key_path
+                .iter()
+                .try_fold(catalogs.get(url)?, |value, key| value.get(key))?;
+            value.as_str().map(str::to_owned)
+        })
+    }
+}
+
+#[cfg(any(feature = "hydrate", feature = "csr"))]
+mod fetch {
+    use std::{cell::RefCell, collections::HashSet};
+
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::JsFuture;
+
+    thread_local! {
+        static PENDING: RefCell<HashSet<&'static str>> = RefCell::new(HashSet::new());
+    }
+
+    async fn fetch_text(url: &str) -> Option<String> {
+        let response = JsFuture::from(leptos::window().fetch_with_str(url))
+            .await
+            .ok()?;
+        let response: web_sys::Response = response.dyn_into().ok()?;
+        if !response.ok() {
+            return None;
+        }
+        let text = JsFuture::from(response.text().ok()?).await.ok()?;
+        text.as_string()
+    }
+
+    /// Fetch the catalog if it is not loaded or being fetched.
+    pub fn fetch_catalog(url: &'static str) {
+        if super::catalogs::contains(url)
+            || !PENDING.with(|pending| pending.borrow_mut().insert(url))
+        {
+            return;
+        }
+        // the context is not available in the spawned future, the trigger is captured here.
+        let catalogs_loaded = leptos::use_context::<super::CatalogsLoaded>();
+        leptos::spawn_local(async move {
+            let value = fetch_text(url)
+                .await
+                .and_then(|text| serde_json::from_str(&text).ok());
+            PENDING.with(|pending| pending.borrow_mut().remove(url));
+            match value {
+                Some(value) => {
+                    super::catalogs::insert(url, value);
+                    if let Some(super::CatalogsLoaded(trigger)) = catalogs_loaded {
+                        trigger.notify();
+                    }
+                }
+                None => leptos::logging::warn!("failed to load the locale catalog at {:?}", url),
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fallback() {
+        let hello = DynStr::new("Hello", Some("/locales/test_fr.json"), &["home", "hello"]);
+        let bye = DynStr::new("Bye", Some("/locales/test_fr.json"), &["home", "bye"]);
+        let default = DynStr::new("Hello", None, &["home", "hello"]);
+        assert_eq!(hello.get_untracked(), "Hello");
+
+        insert_catalog(
+            "/locales/test_fr.json",
+            r#"{ "home": { "hello": "Bonjour" } }"#,
+        )
+        .unwrap();
+        assert_eq!(hello.to_string(), "Bonjour");
+        assert_eq!(hello.build_string(), "Bonjour");
+        assert_eq!(bye.get_untracked(), "Bye");
+        assert_eq!(default.get_untracked(), "Hello");
+        assert!(loaded_catalogs().contains(&(
+            "/locales/test_fr.json".to_owned(),
+            r#"{"home":{"hello":"Bonjour"}}"#.to_owned()
+        )));
+
+        assert!(insert_catalog("/locales/test_fr.json", "{ not json").is_err());
+        assert_eq!(hello.get_untracked(), "Bonjour");
+    }
+
+    #[test]
+    fn test_shared_between_threads() {
+        let hello = DynStr::new("Hello", Some("/locales/test_de.json"), &["hello"]);
+        std::thread::spawn(|| insert_catalog("/locales/test_de.json", r#"{ "hello": "Hallo" }"#))
+            .join()
+            .unwrap()
+            .unwrap();
+        assert_eq!(hello.get_untracked(), "Hallo");
+    }
+}
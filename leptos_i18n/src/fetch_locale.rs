@@ -1,38 +1,63 @@
-use crate::Locale;
+use crate::{preferences::FormatPreferences, Locale};
+
+/// The negotiated locale, with the requested tag if it differs from the locale and should be used for formatting.
+pub type FetchedLocale<T> = (T, Option<String>);
 
 cfg_if::cfg_if! {
     if #[cfg(all(feature = "ssr", not(any(feature = "hydrate", all(feature="csr", feature="cookie")))))] {
         #[inline]
-        pub fn fetch_locale<T: Locale>() -> T {
+        pub fn fetch_locale<T: Locale>() -> FetchedLocale<T> {
             crate::server::fetch_locale_server_side::<T>()
         }
     } else if #[cfg(all(feature = "hydrate", not(any(all(feature="csr", feature="cookie"), feature = "ssr"))))] {
-        pub fn fetch_locale<T: Locale>() -> T {
+        pub fn fetch_locale<T: Locale>() -> FetchedLocale<T> {
             leptos::document()
                 .document_element()
                 .and_then(|el| el.get_attribute("lang"))
-                .and_then(|lang| T::from_str(&lang))
+                .and_then(|lang| {
+                    let locale = T::from_tag(&lang)?;
+                    let format_tag = (locale.as_str() != lang).then_some(lang);
+                    Some((locale, format_tag))
+                })
                 .unwrap_or_default()
         }
     } else if #[cfg(all(all(feature="csr", feature="cookie"), not(any(feature = "ssr", feature = "hydrate"))))] {
-        pub fn fetch_locale<T: Locale>() -> T {
+        pub fn fetch_locale<T: Locale>() -> FetchedLocale<T> {
             fn inner<T: Locale>() -> Option<T> {
-                let document = super::get_html_document()?;
-                let cookies = document.cookie().ok()?;
-                cookies.split(';').find_map(|cookie| {
-                    let (key, value) = cookie.split_once('=')?;
-                    if key.trim() == super::COOKIE_PREFERED_LANG {
-                        T::from_str(value)
-                    } else {
-                        None
-                    }
-                })
+                if crate::storage::has_locale_storage() {
+                    return None;
+                }
+                super::get_cookie(crate::cookie::use_cookie_options().get_name())
+                    .and_then(|value| T::try_from_persisted(&value).ok())
+            }
+            match inner() {
+                Some(locale) => (locale, None),
+                None => crate::system::fetch_system_locale(),
             }
-            inner().unwrap_or_default()
         }
     } else {
         #[inline]
-        pub fn fetch_locale<T: Locale>() -> T {
+        pub fn fetch_locale<T: Locale>() -> FetchedLocale<T> {
+            crate::system::fetch_system_locale()
+        }
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(all(feature = "cookie", feature = "ssr", not(any(feature = "hydrate", feature = "csr"))))] {
+        #[inline]
+        pub fn fetch_preferences() -> FormatPreferences {
+            crate::server::fetch_preferences_server_side()
+        }
+    } else if #[cfg(all(feature = "cookie", any(feature = "hydrate", feature = "csr")))] {
+        pub fn fetch_preferences() -> FormatPreferences {
+            super::get_cookie(crate::cookie::use_cookie_options().get_preferences_name())
+                .map(|prefs| FormatPreferences::decode(&prefs))
+                .unwrap_or_default()
+        }
+    } else {
+        #[inline]
+        pub fn fetch_preferences() -> FormatPreferences {
             Default::default()
         }
     }
@@ -0,0 +1,1052 @@
+//! This module contain helpers to format numbers according to the conventions of a locale.
+//!
+//! ```rust
+//! use leptos_i18n::format::FormattedNumber;
+//!
+//! assert_eq!(FormattedNumber::new(1234567.5, "en").to_string(), "1,234,567.5");
+//! assert_eq!(FormattedNumber::new(1234567.5, "de").to_string(), "1.234.567,5");
+//! assert_eq!(FormattedNumber::new(1234, "es").to_string(), "1234");
+//! assert_eq!(FormattedNumber::new(1234, "ar-u-nu-arab").to_string(), "١,٢٣٤");
+//! ```
+//!
+//! The digits follow the `-u-nu-` extension of the tag, and in a view the numbering system set with
+//! [`I18nContext::set_numbering_system`](crate::I18nContext::set_numbering_system) takes precedence.
+
+use std::fmt::{self, Display, Write};
+
+use leptos::{create_memo, use_context, IntoView, Memo, Signal, SignalGet, View};
+
+use crate::preferences::{region_from_tag, HourCycle, NumberingSystem};
+
+pub use leptos_i18n_core::format::{
+    currency_symbol, number_separators, NumberSeparators, NumberValue,
+};
+
+/// A wrapper around a number that format it according to the conventions of a locale
+/// when displayed or rendered.
+#[derive(Debug, Clone, Copy)]
+pub struct FormattedNumber<T> {
+    value: T,
+    separators: NumberSeparators,
+    numbering_system: NumberingSystem,
+}
+
+impl<T> FormattedNumber<T> {
+    /// Format `value` with the separators and the numbering system of the language of the given tag.
+    pub fn new(value: T, tag: &str) -> Self {
+        Self::with_separators(value, number_separators(tag))
+            .with_numbering_system(NumberingSystem::from_tag(tag).unwrap_or_default())
+    }
+
+    /// Format `value` with the given separators, in latin digits.
+    pub const fn with_separators(value: T, separators: NumberSeparators) -> Self {
+        FormattedNumber {
+            value,
+            separators,
+            numbering_system: NumberingSystem::Latn,
+        }
+    }
+
+    /// Write the digits in the given numbering system.
+    pub const fn with_numbering_system(mut self, numbering_system: NumberingSystem) -> Self {
+        self.numbering_system = numbering_system;
+        self
+    }
+}
+
+/// Return the numbering system set in the `I18nContext`, if any, subscribing to any changes.
+fn context_numbering_system() -> Option<NumberingSystem> {
+    use_context::<FormatContext>().and_then(|format_context| format_context.numbering_system.get())
+}
+
+/// Return the hour cycle of the `I18nContext`, set by the user or else the one of the region of its formatting locale,
+/// subscribing to any changes.
+fn context_hour_cycle() -> Option<HourCycle> {
+    use_context::<FormatContext>().map(|format_context| format_context.hour_cycle.get())
+}
+
+impl<T: Display> Display for FormattedNumber<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let raw = self.value.to_string();
+        let (sign, unsigned) = match raw.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", raw.as_str()),
+        };
+        let (integer, fraction) = match unsigned.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (unsigned, None),
+        };
+        // "inf", "NaN" or the output of a custom `Display` are left as is.
+        if integer.is_empty() || !integer.bytes().all(|b| b.is_ascii_digit()) {
+            return f.write_str(&raw);
+        }
+
+        f.write_str(sign)?;
+        let NumberSeparators {
+            group,
+            decimal,
+            min_grouping_digits,
+        } = self.separators;
+        let numbering_system = self.numbering_system;
+        for (i, digit) in integer.chars().enumerate() {
+            if i != 0 && integer.len() >= min_grouping_digits && (integer.len() - i) % 3 == 0 {
+                f.write_str(group)?;
+            }
+            f.write_char(numbering_system.map_digit(digit))?;
+        }
+        if let Some(fraction) = fraction {
+            f.write_str(decimal)?;
+            f.write_str(&numbering_system.transliterate(fraction))?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Display> IntoView for FormattedNumber<T> {
+    fn into_view(mut self) -> View {
+        if let Some(numbering_system) = context_numbering_system() {
+            self.numbering_system = numbering_system;
+        }
+        self.to_string().into_view()
+    }
+}
+
+/// The type of the values given to the formatted variables in the `t!` macro, a function returning a number.
+///
+/// This is implemented for any `Fn() -> N + Clone + 'static` where `N` is a [`NumberValue`], like signals:
+///
+/// ```rust,ignore
+/// let (price, set_price) = create_signal(12.5);
+/// t!(i18n, cart_total, price)
+/// ```
+pub trait NumberFn: Clone + 'static {
+    /// The returned number.
+    type Value: NumberValue;
+
+    /// Return the number.
+    fn call(&self) -> Self::Value;
+}
+
+impl<F, N> NumberFn for F
+where
+    F: Fn() -> N + Clone + 'static,
+    N: NumberValue,
+{
+    type Value = N;
+
+    #[inline]
+    fn call(&self) -> N {
+        self()
+    }
+}
+
+/// A wrapper around an amount of money that format it according to the conventions of a locale
+/// when displayed or rendered.
+///
+/// ```rust
+/// use leptos_i18n::format::FormattedCurrency;
+///
+/// assert_eq!(FormattedCurrency::new(1234.5, "USD", "en").to_string(), "$1,234.50");
+/// assert_eq!(FormattedCurrency::new(1234.5, "EUR", "de").to_string(), "1.234,50\u{a0}€");
+/// assert_eq!(FormattedCurrency::new(1500, "JPY", "ja").to_string(), "¥1,500");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FormattedCurrency<'a, T> {
+    value: T,
+    code: &'a str,
+    tag: &'a str,
+    numbering_system: Option<NumberingSystem>,
+}
+
+impl<'a, T> FormattedCurrency<'a, T> {
+    /// Format `value` in the currency of the given ISO 4217 code for the language of the given tag.
+    pub const fn new(value: T, code: &'a str, tag: &'a str) -> Self {
+        FormattedCurrency {
+            value,
+            code,
+            tag,
+            numbering_system: None,
+        }
+    }
+
+    /// Write the digits in the given numbering system instead of the one of the tag.
+    pub const fn with_numbering_system(mut self, numbering_system: NumberingSystem) -> Self {
+        self.numbering_system = Some(numbering_system);
+        self
+    }
+}
+
+impl<T: NumberValue> Display for FormattedCurrency<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (symbol, decimals) = currency_symbol(self.code);
+        let value = self.value.to_f64();
+        let sign = if value < 0.0 { "-" } else { "" };
+        let mut amount = FormattedNumber::new(format!("{:.*}", decimals, value.abs()), self.tag);
+        if let Some(numbering_system) = self.numbering_system {
+            amount = amount.with_numbering_system(numbering_system);
+        }
+        // most european languages put the symbol after the amount.
+        match self.tag.split(['-', '_']).next().unwrap_or_default() {
+            "fr" | "de" | "es" | "it" | "pt" | "pl" | "nl" | "ru" | "uk" | "cs" | "sk" | "sv"
+            | "fi" | "nb" | "no" | "da" | "hu" | "ro" | "bg" | "hr" | "sl" | "lt" | "lv" | "et"
+            | "el" | "tr" => write!(f, "{}{}\u{a0}{}", sign, amount, symbol),
+            _ => write!(f, "{}{}{}", sign, symbol, amount),
+        }
+    }
+}
+
+impl<T: NumberValue> IntoView for FormattedCurrency<'_, T> {
+    fn into_view(mut self) -> View {
+        self.numbering_system = context_numbering_system().or(self.numbering_system);
+        self.to_string().into_view()
+    }
+}
+
+/// Format `value` in the compact notation of the language of the given tag, like `1.2K` or `3.4M` in english,
+/// for places with little room like the ticks of a chart.
+///
+/// Values below a thousand are formatted with at most one decimal.
+///
+/// ```rust
+/// use leptos_i18n::format::format_compact;
+///
+/// assert_eq!(format_compact(1234.0, "en"), "1.2K");
+/// assert_eq!(format_compact(2_500_000.0, "fr"), "2,5\u{a0}M");
+/// assert_eq!(format_compact(12.34, "de"), "12,3");
+/// ```
+pub fn format_compact(value: f64, tag: &str) -> String {
+    let language = tag.split(['-', '_']).next().unwrap_or_default();
+    let suffixes: [&str; 4] = match language {
+        "fr" => ["\u{a0}k", "\u{a0}M", "\u{a0}Md", "\u{a0}Bn"],
+        "de" => ["\u{a0}Tsd.", "\u{a0}Mio.", "\u{a0}Mrd.", "\u{a0}Bio."],
+        "es" => ["\u{a0}mil", "\u{a0}M", "\u{a0}mil\u{a0}M", "\u{a0}B"],
+        _ => ["K", "M", "B", "T"],
+    };
+    // one decimal for small values, none from 100 on, rounded before picking the suffix
+    // so 999 950 is "1M" and not "1000K".
+    let round = |value: f64| {
+        if value.abs() < 100.0 {
+            (value * 10.0).round() / 10.0
+        } else {
+            value.round()
+        }
+    };
+    let mut scaled = round(value);
+    let mut suffix = "";
+    for (i, candidate) in suffixes.iter().enumerate() {
+        if scaled.abs() < 1000.0 {
+            break;
+        }
+        scaled = round(value / 1000f64.powi(i as i32 + 1));
+        suffix = candidate;
+    }
+    // avoid "-0".
+    if scaled == 0.0 {
+        scaled = 0.0;
+    }
+    format!("{}{}", FormattedNumber::new(scaled, tag), suffix)
+}
+
+/// A date and a time of the day, without time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime {
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+}
+
+impl DateTime {
+    /// Create a new `DateTime`, returning `None` if the date or the time does not exist.
+    pub fn new(year: i32, month: u8, day: u8, hour: u8, minute: u8) -> Option<Self> {
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+            2 => 28,
+            _ => return None,
+        };
+        if day == 0 || day > days_in_month || hour > 23 || minute > 59 {
+            return None;
+        }
+        Some(DateTime {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+        })
+    }
+
+    /// Create a `DateTime` in UTC from the number of seconds since the unix epoch.
+    pub fn from_unix_timestamp(seconds: i64) -> Self {
+        let days = seconds.div_euclid(86_400);
+        let seconds = seconds.rem_euclid(86_400);
+        // days to civil date, from http://howardhinnant.github.io/date_algorithms.html
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        DateTime {
+            year: year as i32,
+            month: month as u8,
+            day: day as u8,
+            hour: (seconds / 3600) as u8,
+            minute: (seconds % 3600 / 60) as u8,
+        }
+    }
+
+    /// Return the year.
+    pub const fn year(self) -> i32 {
+        self.year
+    }
+
+    /// Return the month, from 1 to 12.
+    pub const fn month(self) -> u8 {
+        self.month
+    }
+
+    /// Return the day of the month, from 1 to 31.
+    pub const fn day(self) -> u8 {
+        self.day
+    }
+
+    /// Return the hour, from 0 to 23.
+    pub const fn hour(self) -> u8 {
+        self.hour
+    }
+
+    /// Return the minute, from 0 to 59.
+    pub const fn minute(self) -> u8 {
+        self.minute
+    }
+
+    /// Return the date formatted for the language of the given tag, without the time.
+    pub fn format_date(self, tag: &str) -> String {
+        let mut date = String::new();
+        let _ = self.write_date(&mut date, tag);
+        date
+    }
+
+    /// Return the time formatted with the given clock, without the date.
+    pub fn format_time(self, hour_cycle: HourCycle) -> String {
+        let mut time = String::new();
+        let _ = self.write_time(&mut time, hour_cycle);
+        time
+    }
+
+    fn write_date(self, f: &mut impl Write, tag: &str) -> fmt::Result {
+        let DateTime {
+            year, month, day, ..
+        } = self;
+        let mut subtags = tag.split(['-', '_']);
+        let language = subtags.next().unwrap_or_default();
+        match (language, region_from_tag(tag)) {
+            ("en", None | Some("US" | "PH")) => write!(f, "{}/{}/{}", month, day, year),
+            ("zh" | "ja", _) => write!(f, "{}/{:02}/{:02}", year, month, day),
+            ("sv" | "lt" | "ko" | "hu", _) => write!(f, "{}-{:02}-{:02}", year, month, day),
+            ("nl", _) => write!(f, "{:02}-{:02}-{}", day, month, year),
+            (
+                "de" | "ru" | "uk" | "pl" | "cs" | "sk" | "fi" | "nb" | "no" | "da" | "tr" | "ro"
+                | "hr" | "sl" | "sr" | "bg" | "et" | "lv",
+                _,
+            ) => write!(f, "{:02}.{:02}.{}", day, month, year),
+            _ => write!(f, "{:02}/{:02}/{}", day, month, year),
+        }
+    }
+
+    fn write_time(self, f: &mut impl Write, hour_cycle: HourCycle) -> fmt::Result {
+        let DateTime { hour, minute, .. } = self;
+        match hour_cycle {
+            HourCycle::H12 => {
+                let period = if hour < 12 { "AM" } else { "PM" };
+                let hour = if hour % 12 == 0 { 12 } else { hour % 12 };
+                write!(f, "{}:{:02}\u{202f}{}", hour, minute, period)
+            }
+            HourCycle::H23 => write!(f, "{:02}:{:02}", hour, minute),
+        }
+    }
+
+    fn write_long_date(self, f: &mut impl Write, tag: &str) -> fmt::Result {
+        const MONTHS: [[&str; 12]; 7] = [
+            [
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ],
+            [
+                "janvier",
+                "février",
+                "mars",
+                "avril",
+                "mai",
+                "juin",
+                "juillet",
+                "août",
+                "septembre",
+                "octobre",
+                "novembre",
+                "décembre",
+            ],
+            [
+                "Januar",
+                "Februar",
+                "März",
+                "April",
+                "Mai",
+                "Juni",
+                "Juli",
+                "August",
+                "September",
+                "Oktober",
+                "November",
+                "Dezember",
+            ],
+            [
+                "enero",
+                "febrero",
+                "marzo",
+                "abril",
+                "mayo",
+                "junio",
+                "julio",
+                "agosto",
+                "septiembre",
+                "octubre",
+                "noviembre",
+                "diciembre",
+            ],
+            [
+                "gennaio",
+                "febbraio",
+                "marzo",
+                "aprile",
+                "maggio",
+                "giugno",
+                "luglio",
+                "agosto",
+                "settembre",
+                "ottobre",
+                "novembre",
+                "dicembre",
+            ],
+            [
+                "janeiro",
+                "fevereiro",
+                "março",
+                "abril",
+                "maio",
+                "junho",
+                "julho",
+                "agosto",
+                "setembro",
+                "outubro",
+                "novembro",
+                "dezembro",
+            ],
+            [
+                "januari",
+                "februari",
+                "maart",
+                "april",
+                "mei",
+                "juni",
+                "juli",
+                "augustus",
+                "september",
+                "oktober",
+                "november",
+                "december",
+            ],
+        ];
+        let DateTime {
+            year, month, day, ..
+        } = self;
+        let month_name = |language: usize| MONTHS[language][month as usize - 1];
+        match tag.split(['-', '_']).next().unwrap_or_default() {
+            "en" => write!(f, "{} {}, {}", month_name(0), day, year),
+            "fr" => write!(f, "{} {} {}", day, month_name(1), year),
+            "de" => write!(f, "{}. {} {}", day, month_name(2), year),
+            "es" => write!(f, "{} de {} de {}", day, month_name(3), year),
+            "it" => write!(f, "{} {} {}", day, month_name(4), year),
+            "pt" => write!(f, "{} de {} de {}", day, month_name(5), year),
+            "nl" => write!(f, "{} {} {}", day, month_name(6), year),
+            "zh" | "ja" => write!(f, "{}年{}月{}日", year, month, day),
+            "ko" => write!(f, "{}년 {}월 {}일", year, month, day),
+            // no month names for the other languages, fallback to the numeric date.
+            _ => self.write_date(f, tag),
+        }
+    }
+}
+
+/// The length of a formatted date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DateLength {
+    /// The numeric date, `1/31/2024` in english.
+    #[default]
+    Short,
+    /// The date with the name of the month, `January 31, 2024` in english.
+    ///
+    /// Only some languages have the names of the months, the other fallback to the short date.
+    Long,
+}
+
+/// What part of a [`DateTime`] to format, and how.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateTimeFormat {
+    /// The date only, `{{ date, date }}` or `{{ date, date(long) }}`.
+    Date(DateLength),
+    /// The time only, `{{ date, time }}`.
+    Time,
+    /// The date followed by the time, `{{ date, datetime }}` or `{{ date, datetime(long) }}`.
+    DateTime(DateLength),
+}
+
+/// A date and time that can be given to a formatted variable, `{{ date, datetime(short) }}`.
+///
+/// This is implemented for [`DateTime`], and for the types of the `chrono`, `time` and `jiff` crates behind the features of the same name.
+pub trait DateTimeValue: Clone + 'static {
+    /// Convert the value to a [`DateTime`], in the time zone of the value if it has one.
+    fn to_date_time(&self) -> DateTime;
+}
+
+impl DateTimeValue for DateTime {
+    #[inline]
+    fn to_date_time(&self) -> DateTime {
+        *self
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impls {
+    use chrono::{Datelike, NaiveDate, NaiveDateTime, TimeZone, Timelike};
+
+    use super::{DateTime, DateTimeValue};
+
+    impl DateTimeValue for NaiveDateTime {
+        fn to_date_time(&self) -> DateTime {
+            DateTime {
+                year: self.year(),
+                month: self.month() as u8,
+                day: self.day() as u8,
+                hour: self.hour() as u8,
+                minute: self.minute() as u8,
+            }
+        }
+    }
+
+    impl DateTimeValue for NaiveDate {
+        fn to_date_time(&self) -> DateTime {
+            self.and_time(chrono::NaiveTime::MIN).to_date_time()
+        }
+    }
+
+    impl<Tz> DateTimeValue for chrono::DateTime<Tz>
+    where
+        Tz: TimeZone + 'static,
+    {
+        fn to_date_time(&self) -> DateTime {
+            self.naive_local().to_date_time()
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_impls {
+    use time::{Date, OffsetDateTime, PrimitiveDateTime};
+
+    use super::{DateTime, DateTimeValue};
+
+    impl DateTimeValue for PrimitiveDateTime {
+        fn to_date_time(&self) -> DateTime {
+            DateTime {
+                year: self.year(),
+                month: self.month() as u8,
+                day: self.day(),
+                hour: self.hour(),
+                minute: self.minute(),
+            }
+        }
+    }
+
+    impl DateTimeValue for OffsetDateTime {
+        fn to_date_time(&self) -> DateTime {
+            PrimitiveDateTime::new(self.date(), self.time()).to_date_time()
+        }
+    }
+
+    impl DateTimeValue for Date {
+        fn to_date_time(&self) -> DateTime {
+            self.midnight().to_date_time()
+        }
+    }
+}
+
+#[cfg(feature = "jiff")]
+mod jiff_impls {
+    use jiff::{civil, Zoned};
+
+    use super::{DateTime, DateTimeValue};
+
+    impl DateTimeValue for civil::DateTime {
+        fn to_date_time(&self) -> DateTime {
+            DateTime {
+                year: i32::from(self.year()),
+                month: self.month() as u8,
+                day: self.day() as u8,
+                hour: self.hour() as u8,
+                minute: self.minute() as u8,
+            }
+        }
+    }
+
+    impl DateTimeValue for civil::Date {
+        fn to_date_time(&self) -> DateTime {
+            self.to_datetime(civil::Time::midnight()).to_date_time()
+        }
+    }
+
+    impl DateTimeValue for Zoned {
+        fn to_date_time(&self) -> DateTime {
+            self.datetime().to_date_time()
+        }
+    }
+}
+
+/// The type of the values given to the date formatted variables in the `t!` macro, a function returning a date.
+///
+/// This is implemented for any `Fn() -> T + Clone + 'static` where `T` is a [`DateTimeValue`], see [`NumberFn`].
+pub trait DateTimeFn: Clone + 'static {
+    /// The returned date.
+    type Value: DateTimeValue;
+
+    /// Return the date.
+    fn call(&self) -> Self::Value;
+}
+
+impl<F, T> DateTimeFn for F
+where
+    F: Fn() -> T + Clone + 'static,
+    T: DateTimeValue,
+{
+    type Value = T;
+
+    #[inline]
+    fn call(&self) -> T {
+        self()
+    }
+}
+
+/// A wrapper around a date that format it according to the conventions of a locale, with the clock commonly used in its region,
+/// when displayed or rendered.
+///
+/// In a view the hour cycle of the `I18nContext` is used, the one set by the user or else the one of the region of its formatting locale.
+///
+/// ```rust
+/// use leptos_i18n::format::{DateLength, DateTime, DateTimeFormat, FormattedDateTime};
+///
+/// let date = DateTime::new(2024, 1, 31, 13, 5).unwrap();
+/// let long_date = DateTimeFormat::Date(DateLength::Long);
+/// assert_eq!(FormattedDateTime::new(date, long_date, "fr").to_string(), "31 janvier 2024");
+/// assert_eq!(FormattedDateTime::new(date, DateTimeFormat::Time, "en-US").to_string(), "1:05\u{202f}PM");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FormattedDateTime<'a> {
+    value: DateTime,
+    format: DateTimeFormat,
+    tag: &'a str,
+    numbering_system: Option<NumberingSystem>,
+    hour_cycle: Option<HourCycle>,
+}
+
+impl<'a> FormattedDateTime<'a> {
+    /// Format `value` for the language of the given tag.
+    pub fn new<T: DateTimeValue>(value: T, format: DateTimeFormat, tag: &'a str) -> Self {
+        FormattedDateTime {
+            value: value.to_date_time(),
+            format,
+            tag,
+            numbering_system: None,
+            hour_cycle: None,
+        }
+    }
+
+    /// Write the digits in the given numbering system instead of the one of the tag.
+    pub const fn with_numbering_system(mut self, numbering_system: NumberingSystem) -> Self {
+        self.numbering_system = Some(numbering_system);
+        self
+    }
+
+    /// Write the times with the given clock instead of the one commonly used in the region of the tag.
+    pub const fn with_hour_cycle(mut self, hour_cycle: HourCycle) -> Self {
+        self.hour_cycle = Some(hour_cycle);
+        self
+    }
+
+    /// Use the numbering system and the hour cycle of the `I18nContext`, if any.
+    fn in_context(mut self) -> Self {
+        self.numbering_system = context_numbering_system().or(self.numbering_system);
+        self.hour_cycle = context_hour_cycle().or(self.hour_cycle);
+        self
+    }
+
+    fn write_latn(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hour_cycle = self
+            .hour_cycle
+            .unwrap_or_else(|| HourCycle::from_region(region_from_tag(self.tag)));
+        let write_date = |f: &mut fmt::Formatter<'_>, length| match length {
+            DateLength::Short => self.value.write_date(f, self.tag),
+            DateLength::Long => self.value.write_long_date(f, self.tag),
+        };
+        match self.format {
+            DateTimeFormat::Date(length) => write_date(f, length),
+            DateTimeFormat::Time => self.value.write_time(f, hour_cycle),
+            DateTimeFormat::DateTime(length) => {
+                write_date(f, length)?;
+                f.write_char(' ')?;
+                self.value.write_time(f, hour_cycle)
+            }
+        }
+    }
+}
+
+impl Display for FormattedDateTime<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct Latn<'a, 'b>(&'a FormattedDateTime<'b>);
+
+        impl Display for Latn<'_, '_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.write_latn(f)
+            }
+        }
+
+        let numbering_system = self
+            .numbering_system
+            .or_else(|| NumberingSystem::from_tag(self.tag))
+            .unwrap_or_default();
+        match numbering_system {
+            NumberingSystem::Latn => self.write_latn(f),
+            numbering_system => {
+                f.write_str(&numbering_system.transliterate(&Latn(self).to_string()))
+            }
+        }
+    }
+}
+
+impl IntoView for FormattedDateTime<'_> {
+    fn into_view(self) -> View {
+        self.in_context().to_string().into_view()
+    }
+}
+
+/// A value that can be formatted according to the conventions of a locale, see [`Localized`].
+pub trait LocalizedDisplay {
+    /// Format the value for the language of the given tag, using the given clock for times.
+    fn fmt_localized(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        tag: &str,
+        hour_cycle: HourCycle,
+    ) -> fmt::Result;
+}
+
+macro_rules! impl_localized_number {
+    ($($t:ty)*) => {
+        $(
+            impl LocalizedDisplay for $t {
+                fn fmt_localized(&self, f: &mut fmt::Formatter<'_>, tag: &str, _: HourCycle) -> fmt::Result {
+                    Display::fmt(&FormattedNumber::new(*self, tag), f)
+                }
+            }
+        )*
+    };
+}
+
+impl_localized_number!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+
+impl LocalizedDisplay for DateTime {
+    fn fmt_localized(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        tag: &str,
+        hour_cycle: HourCycle,
+    ) -> fmt::Result {
+        self.write_date(f, tag)?;
+        f.write_char(' ')?;
+        self.write_time(f, hour_cycle)
+    }
+}
+
+/// The formatting settings of the `I18nContext`, without its locale type so `Localized` can read them.
+#[derive(Clone, Copy)]
+pub(crate) struct FormatContext {
+    pub tag: Signal<String>,
+    pub hour_cycle: Signal<HourCycle>,
+    /// The numbering system set by the user or requested by the tag, `None` to use the one of the formatted locale.
+    pub numbering_system: Signal<Option<NumberingSystem>>,
+}
+
+/// A wrapper around a value displayed outside of a translation, like a number or a [`DateTime`],
+/// that format it according to the current locale of the `I18nContext` when rendered.
+///
+/// ```rust
+/// use leptos_i18n::format::{DateTime, Localized};
+///
+/// assert_eq!(Localized(1234.5).format("de"), "1.234,5");
+/// let date = DateTime::new(2024, 1, 31, 13, 5).unwrap();
+/// assert_eq!(Localized(date).format("fr"), "31/01/2024 13:05");
+/// ```
+///
+/// In a view it uses the formatting locale and the hour cycle of the context:
+///
+/// ```rust,ignore
+/// view! { <p>{Localized(price)}</p> }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Localized<T>(pub T);
+
+impl<T: LocalizedDisplay> Localized<T> {
+    /// Format the value for the language of the given tag, with the clock commonly used in its region.
+    pub fn format(&self, tag: &str) -> String {
+        self.format_with(tag, HourCycle::from_region(region_from_tag(tag)))
+    }
+
+    /// Format the value for the language of the given tag, with the given clock.
+    ///
+    /// The digits follow the `-u-nu-` extension of the tag.
+    pub fn format_with(&self, tag: &str, hour_cycle: HourCycle) -> String {
+        struct WithTag<'a, T>(&'a T, &'a str, HourCycle);
+
+        impl<T: LocalizedDisplay> Display for WithTag<'_, T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt_localized(f, self.1, self.2)
+            }
+        }
+
+        let formatted = WithTag(&self.0, tag, hour_cycle).to_string();
+        match NumberingSystem::from_tag(tag) {
+            Some(numbering_system) => numbering_system.transliterate(&formatted).into_owned(),
+            None => formatted,
+        }
+    }
+}
+
+impl<T: LocalizedDisplay> Localized<T> {
+    fn format_in_context(&self, format_context: Option<FormatContext>) -> String {
+        match format_context {
+            Some(format_context) => {
+                let formatted =
+                    self.format_with(&format_context.tag.get(), format_context.hour_cycle.get());
+                match format_context.numbering_system.get() {
+                    Some(numbering_system) => {
+                        numbering_system.transliterate(&formatted).into_owned()
+                    }
+                    None => formatted,
+                }
+            }
+            // without a context the default locale is unknown, fallback to english.
+            None => self.format("en"),
+        }
+    }
+}
+
+impl<T: LocalizedDisplay + 'static> IntoView for Localized<T> {
+    fn into_view(self) -> View {
+        let format_context = use_context::<FormatContext>();
+        let format = move || self.format_in_context(format_context);
+        format.into_view()
+    }
+}
+
+/// Return a memo of the value formatted according to the current locale of the `I18nContext`,
+/// updated when the value or the locale change.
+///
+/// This is meant for values rendered outside of `t!`, like in charts, tables or tooltips:
+///
+/// ```rust,ignore
+/// let (total, set_total) = create_signal(1234.5);
+/// let formatted_total = use_localized(total);
+/// ```
+pub fn use_localized<T>(value: impl Into<Signal<T>>) -> Memo<String>
+where
+    T: LocalizedDisplay + Clone + 'static,
+{
+    let value = value.into();
+    let format_context = use_context::<FormatContext>();
+    create_memo(move |_| Localized(value.get()).format_in_context(format_context))
+}
+
+/// Return a memo of the number formatted with the separators of the current locale, see [`use_localized`].
+pub fn use_formatted_number<T>(value: impl Into<Signal<T>>) -> Memo<String>
+where
+    T: LocalizedDisplay + Clone + 'static,
+{
+    use_localized(value)
+}
+
+/// Return a memo of the date formatted for the current locale and hour cycle, see [`use_localized`].
+pub fn use_formatted_date(value: impl Into<Signal<DateTime>>) -> Memo<String> {
+    use_localized(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(FormattedNumber::new(0, "en").to_string(), "0");
+        assert_eq!(FormattedNumber::new(999, "en").to_string(), "999");
+        assert_eq!(FormattedNumber::new(1000, "en-US").to_string(), "1,000");
+        assert_eq!(
+            FormattedNumber::new(-1234567, "en").to_string(),
+            "-1,234,567"
+        );
+        assert_eq!(
+            FormattedNumber::new(1234.5, "fr").to_string(),
+            "1\u{202f}234,5"
+        );
+        assert_eq!(FormattedNumber::new(1234.5, "de-CH").to_string(), "1’234.5");
+        assert_eq!(FormattedNumber::new(1234, "es").to_string(), "1234");
+        assert_eq!(FormattedNumber::new(12345, "es").to_string(), "12.345");
+        assert_eq!(FormattedNumber::new(u128::MAX, "en").to_string().len(), 51);
+    }
+
+    #[test]
+    fn test_numbering_system() {
+        assert_eq!(
+            FormattedNumber::new(-1234.5, "fa-u-nu-arabext").to_string(),
+            "-۱,۲۳۴.۵"
+        );
+        assert_eq!(
+            FormattedNumber::new(12345, "hi")
+                .with_numbering_system(NumberingSystem::Deva)
+                .to_string(),
+            "१२,३४५"
+        );
+        assert_eq!(
+            FormattedCurrency::new(5, "EUR", "fr-u-nu-thai").to_string(),
+            "๕,๐๐\u{a0}€"
+        );
+        let date = DateTime::new(2024, 1, 31, 13, 5).unwrap();
+        assert_eq!(
+            FormattedDateTime::new(date, DateTimeFormat::Time, "en-GB")
+                .with_numbering_system(NumberingSystem::Beng)
+                .to_string(),
+            "১৩:০৫"
+        );
+        assert_eq!(
+            FormattedDateTime::new(date, DateTimeFormat::Time, "en-US")
+                .with_hour_cycle(HourCycle::H23)
+                .to_string(),
+            "13:05"
+        );
+        assert_eq!(format_compact(2_500_000.0, "ar-u-nu-arab"), "٢.٥M");
+        assert_eq!(Localized(date).format("ar-u-nu-arab"), "٣١/٠١/٢٠٢٤ ١٣:٠٥");
+    }
+
+    #[test]
+    fn test_date_time_in_context() {
+        let runtime = leptos::create_runtime();
+        let date = DateTime::new(2024, 1, 31, 13, 5).unwrap();
+        let time = FormattedDateTime::new(date, DateTimeFormat::Time, "en-US");
+        // without a context, the clock of the region of the tag.
+        assert_eq!(time.in_context().to_string(), "1:05\u{202f}PM");
+        // the user's preference, or the region of the formatting locale (`en-GB`), win over the tag.
+        leptos::provide_context(FormatContext {
+            tag: Signal::derive(|| "en-GB".to_owned()),
+            hour_cycle: Signal::derive(|| HourCycle::H23),
+            numbering_system: Signal::derive(|| None),
+        });
+        assert_eq!(time.in_context().to_string(), "13:05");
+        runtime.dispose();
+    }
+
+    #[test]
+    fn test_format_non_finite() {
+        assert_eq!(FormattedNumber::new(f64::INFINITY, "fr").to_string(), "inf");
+        assert_eq!(FormattedNumber::new(f64::NAN, "fr").to_string(), "NaN");
+    }
+
+    #[test]
+    fn test_format_currency() {
+        assert_eq!(
+            FormattedCurrency::new(-1234.567, "USD", "en-US").to_string(),
+            "-$1,234.57"
+        );
+        assert_eq!(
+            FormattedCurrency::new(5, "EUR", "fr").to_string(),
+            "5,00\u{a0}€"
+        );
+        assert_eq!(
+            FormattedCurrency::new(1500, "KRW", "ko").to_string(),
+            "₩1,500"
+        );
+        assert_eq!(
+            FormattedCurrency::new(12.5, "CHF", "de-CH").to_string(),
+            "12.50\u{a0}CHF"
+        );
+    }
+
+    #[test]
+    fn test_format_compact() {
+        assert_eq!(format_compact(0.0, "en"), "0");
+        assert_eq!(format_compact(-0.01, "en"), "0");
+        assert_eq!(format_compact(999.0, "en"), "999");
+        assert_eq!(format_compact(999.96, "en"), "1K");
+        assert_eq!(format_compact(12_345.0, "en"), "12.3K");
+        assert_eq!(format_compact(999_950.0, "en"), "1M");
+        assert_eq!(format_compact(-1_500_000_000.0, "en"), "-1.5B");
+        assert_eq!(format_compact(2e15, "en"), "2,000T");
+        assert_eq!(format_compact(1234.0, "de"), "1,2\u{a0}Tsd.");
+    }
+
+    #[test]
+    fn test_date_time() {
+        assert_eq!(DateTime::new(2023, 2, 29, 0, 0), None);
+        assert!(DateTime::new(2024, 2, 29, 0, 0).is_some());
+        assert_eq!(DateTime::new(2024, 1, 1, 24, 0), None);
+        assert_eq!(
+            DateTime::from_unix_timestamp(1_706_706_300),
+            DateTime::new(2024, 1, 31, 13, 5).unwrap()
+        );
+        assert_eq!(
+            DateTime::from_unix_timestamp(-60),
+            DateTime::new(1969, 12, 31, 23, 59).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_localized() {
+        let date = Localized(DateTime::new(2024, 1, 31, 13, 5).unwrap());
+        assert_eq!(date.format("en-US"), "1/31/2024 1:05\u{202f}PM");
+        assert_eq!(date.format("en-GB"), "31/01/2024 13:05");
+        assert_eq!(date.format("de-DE"), "31.01.2024 13:05");
+        assert_eq!(date.format("ja"), "2024/01/31 13:05");
+        assert_eq!(
+            date.format_with("fr", HourCycle::H12),
+            "31/01/2024 1:05\u{202f}PM"
+        );
+        assert_eq!(Localized(-1234567).format("fr"), "-1\u{202f}234\u{202f}567");
+    }
+}
@@ -0,0 +1,162 @@
+//! This module contain the type of the strings generated by the `load_locales!` macro when the `hot_reload` feature is enabled.
+//!
+//! The strings are read from the locale files when they are displayed or rendered, so editing a translation
+//! only needs a refresh of the page instead of a rebuild. The files are parsed again when their modification time changes,
+//! so on the server every request sees the last saved version.
+//!
+//! This is a partial hot reload: there is no file watcher and nothing is pushed to the browser,
+//! a page shows an edited string only when it is rendered again on the server, after a refresh.
+//! It only affects the output of server-side rendering of the keys without interpolations:
+//!
+//! - the keys with variables, components or plurals are never reloaded;
+//! - in the browser (`csr`, `hydrate`, any `wasm32` target) nothing is read, the string embedded at compile time is used,
+//!   so a hydrated page shows the reloaded string until the client renders the view again;
+//! - in release builds, or when the file or the key can't be read, the embedded string is used too.
+//!
+//! The keys are still checked at compile time, so adding or removing a key, or changing the variables and components of a value,
+//! still needs a rebuild. Only JSON files are reloaded.
+
+use std::{borrow::Cow, fmt};
+
+use leptos::{IntoView, View};
+
+use crate::locale_traits::BuildStr;
+
+/// A string read from the locale files at runtime, falling back to the string embedded at compile time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HotStr {
+    embedded: &'static str,
+    files: &'static [&'static str],
+    key_path: &'static [&'static str],
+}
+
+impl HotStr {
+    #[doc(hidden)]
+    pub const fn new(
+        embedded: &'static str,
+        files: &'static [&'static str],
+        key_path: &'static [&'static str],
+    ) -> Self {
+        HotStr {
+            embedded,
+            files,
+            key_path,
+        }
+    }
+
+    /// Return the string embedded at compile time.
+    pub const fn embedded(self) -> &'static str {
+        self.embedded
+    }
+
+    /// Return the current value of the string, read from the locale files in debug builds.
+    pub fn get(self) -> Cow<'static, str> {
+        if !cfg!(debug_assertions) || cfg!(target_arch = "wasm32") {
+            return Cow::Borrowed(self.embedded);
+        }
+        match files::read_key(self.files, self.key_path) {
+            Some(value) => Cow::Owned(value),
+            None => Cow::Borrowed(self.embedded),
+        }
+    }
+}
+
+impl fmt::Display for HotStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.get())
+    }
+}
+
+impl IntoView for HotStr {
+    fn into_view(self) -> View {
+        self.get().into_owned().into_view()
+    }
+}
+
+impl BuildStr for HotStr {
+    fn build_string(self) -> Cow<'static, str> {
+        self.get()
+    }
+}
+
+mod files {
+    use std::{cell::RefCell, collections::HashMap, time::SystemTime};
+
+    use serde_json::Value;
+
+    struct CachedFile {
+        modified: SystemTime,
+        value: Option<Value>,
+    }
+
+    thread_local! {
+        static FILES: RefCell<HashMap<&'static str, CachedFile>> = RefCell::new(HashMap::new());
+    }
+
+    fn read_file(path: &'static str, key_path: &[&str]) -> Option<String> {
+        let modified = std::fs::metadata(path).ok()?.modified().ok()?;
+        FILES.with(|files| {
+            let mut files = files.borrow_mut();
+            let cached = files.entry(path).or_insert(CachedFile {
+                modified,
+                value: None,
+            });
+            if cached.value.is_none() || cached.modified != modified {
+                cached.modified = modified;
+                cached.value = std::fs::read(path)
+                    .ok()
+                    .and_then(|content| serde_json::from_slice(&content).ok());
+            }
+            let value = key_path
+                .iter()
+                .try_fold(cached.value.as_ref()?, |value, key| value.get(key))?;
+            value.as_str().map(str::to_owned)
+        })
+    }
+
+    /// Read the value of the key in the last file declaring it.
+    pub fn read_key(files: &'static [&'static str], key_path: &[&str]) -> Option<String> {
+        files
+            .iter()
+            .rev()
+            .find_map(|path| read_file(path, key_path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use super::*;
+
+    #[test]
+    fn test_reload() {
+        let path =
+            std::env::temp_dir().join(format!("leptos_i18n_hot_{}.json", std::process::id()));
+        let path: &'static str = Box::leak(path.to_string_lossy().into_owned().into_boxed_str());
+        let files: &'static [&'static str] = Box::leak(Box::new([path]));
+
+        let hot_str = HotStr::new("Hello", files, &["home", "title"]);
+        assert_eq!(hot_str.get(), "Hello");
+
+        std::fs::write(path, r#"{ "home": { "title": "Welcome" } }"#).unwrap();
+        assert_eq!(hot_str.to_string(), "Welcome");
+
+        std::fs::write(
+            path,
+            r#"{ "home": { "title": "Welcome back", "count": 1 } }"#,
+        )
+        .unwrap();
+        // the modification time may not change between two writes on some file systems.
+        let file = std::fs::File::options().write(true).open(path).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(1))
+            .unwrap();
+        assert_eq!(hot_str.build_string(), "Welcome back");
+
+        let missing = HotStr::new("Count", files, &["home", "count"]);
+        assert_eq!(missing.get(), "Count");
+
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(hot_str.get(), "Hello");
+    }
+}
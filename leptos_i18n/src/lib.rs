@@ -17,15 +17,15 @@
 //! If you want to see what Leptos i18n is capable of, check out
 //! the [examples](https://github.com/Baptistemontan/leptos_i18n/tree/master/examples):
 //! - [`hello_world_actix`](https://github.com/Baptistemontan/leptos_i18n/tree/master/examples/hello_world_actix) is a simple example
-//!     to showcase the syntax and file structure to easily incorporate translations in you application using the actix backend
+//!   to showcase the syntax and file structure to easily incorporate translations in you application using the actix backend
 //! - [`hello_world_axum`](https://github.com/Baptistemontan/leptos_i18n/tree/master/examples/hello_world_axum) is like the actix hello world example
-//!     but uses axum as the backend, it showcases that the code you will write with this library will be the same using actix or axum as a backend.
+//!   but uses axum as the backend, it showcases that the code you will write with this library will be the same using actix or axum as a backend.
 //! - [`counter`](https://github.com/Baptistemontan/leptos_i18n/tree/master/examples/counter) is the classic
-//!     counter example, showing how you can interpolate values in the translations and switch locale without full reload.
+//!   counter example, showing how you can interpolate values in the translations and switch locale without full reload.
 //! - [`counter_plurals`](https://github.com/Baptistemontan/leptos_i18n/tree/master/examples/counter_plurals) is like the `counter` example
-//!     but show how you can use plurals to display different texts based on a count.
+//!   but show how you can use plurals to display different texts based on a count.
 //! - [`namespaces`](https://github.com/Baptistemontan/leptos_i18n/tree/master/examples/namespaces) show how to break down your translations
-//!     in multiple namespaces to avoid big files
+//!   in multiple namespaces to avoid big files
 //!
 //! Details on how to run each example can be found in its README.
 //!
@@ -33,18 +33,38 @@
 //!
 //! You can find the [book](https://github.com/Baptistemontan/leptos_i18n/tree/master/docs/book) on the github repo.
 //!
+//! # Core crate
+//!
+//! The pieces of the runtime that don't need Leptos (plural operands, locale negotiation, formatting preferences)
+//! are in the `no_std` crate `leptos_i18n_core`, re-exported here, so they can be shared with code running outside of Leptos.
+//!
 //! # Feature Flags
 //! - `cookie` (*Default*): Enable this feature to set a cookie on the client to remember the last locale set.
 //! - `json_files` (*Default*): Enable this feature if you use JSON files for declaring your locales.
 //! - `hydrate`: Enable this feature when building for the client with hydratation.
-//! - `actix`: Enable this feature when building for the server with actix as the backend (can't be enabled with the `axum` feature).
-//! - `axum`: Enable this feature when building for the server with axum as the backend (can't be enabled with the `actix` feature).
+//! - `actix`: Enable this feature when building for the server with actix as the backend (can't be enabled with the `axum` feature), it also adds an extractor, a middleware and a cookie helper for the locale of a request in the [`actix`] module.
+//! - `axum`: Enable this feature when building for the server with axum as the backend (can't be enabled with the `actix` feature), it also adds an extractor and a middleware for the locale of a request in the [`axum`] module.
+//! - `local_storage`: Implement `storage::KeyValueStore` for the `localStorage` of the browser, to persist the locale in it with `storage::KeyValueStorage::local_storage` or with `persistence = "local_storage"` in the configuration.
+//! - `system_locale`: With the `csr` feature, negotiate the locale from the languages of the webview (`navigator.languages`) when it is not persisted, for desktop builds like Tauri, see the [`system`] module.
+//! - `edge`: Enable this feature when building for the server without actix or axum, like on edge runtimes, the request headers then are given with [`request::provide_request_headers`].
 //! - `csr`: Enable this feature when building for the client with CSR.
-//! - `serde`: Enabling this feature implement `serde::Serialize` and `serde::Deserialize` for the locale enum.
-//! - `debug_interpolations`: Enable the macros to generate code to emit a warning if a key is supplied twice in interpolations and a better compilation error when a key is missing.
+//! - `serde`: Enabling this feature implement `serde::Serialize` and `serde::Deserialize` for the locale enum, serialized as its tag.
+//! - `debug_interpolations`: Enable the macros to generate code to emit a warning if a key is supplied twice in interpolations.
 //! - `suppress_key_warnings`: Disable the warning emission of the `load_locales!()` macro when some keys are missing or ignored.
-//! - `yaml_files`: Enable this feature if you use YAML files for declaring your locales (can't be used with `json_files`).
+//! - `yaml_files`: Enable this feature if you use YAML files for declaring your locales, alongside `json_files` each file is parsed according to its extension.
+//! - `fluent`: Enable this feature to accept Fluent files (`.ftl`) for declaring your locales, a subset of the Fluent syntax is supported (see the README).
+//! - `leptos_0_5` (*Default*): Select the version of leptos the macros generate code for, the only one supported for now. If you disable the default features you must enable it.
 //! - `nightly`: On `nightly` Rust, enables the function-call syntax on the i18n context to get/set the locale.
+//! - `erased_views`: Make the interpolations built by `t!` and `td!` return a type-erased `leptos::View` instead of their concrete type, for faster type-checking and smaller debug binaries in large apps at a small runtime cost.
+//! - `no_components`: Disable component interpolation, any `<tag>` in a translation or in the `t!` macro becomes a compilation error.
+//! - `obfuscate_strings`: Store the strings of the translations obfuscated in the binary, decoded when rendered, so they can't be found by grepping it. String keys then are `leptos_i18n::obfuscate::ObfuscatedStr` instead of `&'static str`.
+//! - `chrono`, `time`, `jiff`: Allow the date and time types of these crates to be given to the date formatted variables (`{{ date, datetime }}`).
+//! - `segmenter`: Enable the `segmenter` module, exposing the ICU4X text segmentation (graphemes, words, sentences).
+//! - `router`: Enable the `router` module, deriving the locale from a path prefix like `/fr/about` with `leptos_router`.
+//! - `hot_reload`: In debug builds, read the strings of the keys without interpolations from the locale files when rendered on the server, so editing them only needs a refresh. Nothing is reloaded in the browser. String keys then are `leptos_i18n::hot_reload::HotStr` instead of `&'static str` (can't be enabled with the `obfuscate_strings` feature).
+//! - `dynamic_load_strings`: Only compile in the plain strings of the default locale, the plain strings of the other locales being fetched as JSON when they are selected. The keys with interpolations are still compiled in for every locale. String keys then are `leptos_i18n::dynamic_load::DynStr` instead of `&'static str` (can't be enabled with the `obfuscate_strings` or `hot_reload` features).
+//! - `dynamic_keys`: Generate the `Locale::get_by_key` method, resolving a dot-separated key path only known at runtime to its string or to a builder taking the interpolations by name, see the [`dynamic_keys`] module. It generates a match arm for every key, so only enable it if you need it.
+//! - `usage`: Count every key rendered by `t!` and `td!` in each locale, exported as JSON by the `usage` module for the `leptos_i18n usage` command.
 //!
 //! # A Simple Counter
 //!
@@ -60,9 +80,9 @@
 //!
 //! ```json
 //! {
-//!     "click_to_change_lang": "Click to change language",
-//!     "click_count": "You clicked {{ count }} times",
-//!     "click_to_inc": "Click to increment the counter"
+//!   "click_to_change_lang": "Click to change language",
+//!   "click_count": "You clicked {{ count }} times",
+//!   "click_to_inc": "Click to increment the counter"
 //! }
 //! ```
 //!
@@ -70,9 +90,9 @@
 //!
 //! ```json
 //! {
-//!     "click_to_change_lang": "Cliquez pour changez de langue",
-//!     "click_count": "Vous avez cliqué {{ count }} fois",
-//!     "click_to_inc": "Cliquez pour incrémenter le compteur"
+//!   "click_to_change_lang": "Cliquez pour changez de langue",
+//!   "click_count": "Vous avez cliqué {{ count }} fois",
+//!   "click_to_inc": "Cliquez pour incrémenter le compteur"
 //! }
 //! ```
 //!
@@ -84,42 +104,42 @@
 //!
 //! #[component]
 //! pub fn App() -> impl IntoView {
-//!     leptos_meta::provide_meta_context();
+//!   leptos_meta::provide_meta_context();
 //!
-//!     let i18n = provide_i18n_context();
+//!   let i18n = provide_i18n_context();
 //!
-//!     let on_switch = move |_| {
+//!   let on_switch = move |_| {
 //!         let new_lang = match i18n.get_locale() {
 //!             Locale::en => Locale::fr,
 //!             Locale::fr => Locale::en,
 //!         };
 //!         i18n.set_locale(new_lang);
-//!     };
+//!   };
 //!
-//!     view! {
+//!   view! {
 //!         <button on:click=on_switch>{t!(i18n, click_to_change_lang)}</button>
 //!         <Counter />
-//!     }
+//!   }
 //! }
 //!
 //! #[component]
 //! fn Counter() -> impl IntoView {
-//!     let i18n = use_i18n();
+//!   let i18n = use_i18n();
 //!
-//!     let (counter, set_counter) = create_signal( 0);
+//!   let (counter, set_counter) = create_signal( 0);
 //!
-//!     let inc = move |_| set_counter.update(|count| *count += 1);
+//!   let inc = move |_| set_counter.update(|count| *count += 1);
 //!
-//!     let count = move || counter.get();
+//!   let count = move || counter.get();
 //!
-//!     view! {
+//!   view! {
 //!         <p>{t!(i18n, click_count, count)}</p>
 //!         // Equivalent to:
 //!         // <p>{t!(i18n, click_count, count = count)}</p>
 //!         // Could also be wrote:
 //!         // <p>{t!(i18n, click_count, count = move || counter.get())}</p>
 //!         <button on:click=inc>{t!(i18n, click_to_inc)}</button>
-//!     }
+//!   }
 //! }
 //! ```
 
@@ -132,31 +152,97 @@ mod locale_traits;
 ))]
 mod server;
 
+#[cfg(feature = "actix")]
+pub mod actix;
+#[cfg(feature = "axum")]
+pub mod axum;
+pub mod chart;
+pub mod components;
+#[cfg(feature = "cookie")]
+pub mod cookie;
 #[cfg(feature = "interpolate_display")]
 pub mod display;
+#[cfg(feature = "dynamic_keys")]
+pub mod dynamic_keys;
+#[cfg(feature = "dynamic_load_strings")]
+pub mod dynamic_load;
+
+pub mod format;
+#[cfg(feature = "hot_reload")]
+pub mod hot_reload;
+pub mod metadata;
+#[cfg(feature = "obfuscate_strings")]
+pub mod obfuscate;
+pub mod raw_html;
+pub mod request;
+pub mod rollout;
+#[cfg(feature = "router")]
+pub mod router;
+pub mod runtime_locales;
+#[cfg(feature = "segmenter")]
+pub mod segmenter;
+pub mod sitemap;
+pub mod storage;
+pub mod system;
+pub mod transfer;
+#[cfg(feature = "usage")]
+pub mod usage;
 
+pub use leptos_i18n_core::{negotiate, plurals, preferences, select};
 pub use locale_traits::*;
 
-pub use context::{provide_i18n_context, use_i18n_context, I18nContext};
+pub use context::{
+    provide_i18n_context, provide_isolated_i18n_context, use_i18n_context, I18nContext,
+};
 
 pub use leptos_i18n_macro::{load_locales, t, td};
 
+#[cfg(all(feature = "hot_reload", feature = "obfuscate_strings"))]
+compile_error!("Can't enable \"hot_reload\" and \"obfuscate_strings\" features together.");
+
+#[cfg(all(
+    feature = "dynamic_load_strings",
+    any(feature = "hot_reload", feature = "obfuscate_strings")
+))]
+compile_error!(
+    "Can't enable the \"dynamic_load_strings\" feature with \"hot_reload\" or \"obfuscate_strings\"."
+);
+
 #[cfg(feature = "interpolate_display")]
-pub use leptos_i18n_macro::{td_display, td_string};
+pub use leptos_i18n_macro::{t_string, td_display, td_string};
 
 #[doc(hidden)]
 pub mod __private {
     pub use super::locale_traits::BuildStr;
 }
 
-#[cfg(all(
-    feature = "cookie",
-    any(feature = "ssr", feature = "hydrate", feature = "csr")
-))]
-pub(crate) const COOKIE_PREFERED_LANG: &str = "i18n_pref_locale";
+// expanded in the crate calling `load_locales!`, so the error points to the missing feature there.
+#[doc(hidden)]
+#[cfg(feature = "local_storage")]
+#[macro_export]
+macro_rules! __local_storage_persistence {
+    () => {
+        $crate::storage::Persistence::LocalStorage
+    };
+}
+
+#[doc(hidden)]
+#[cfg(not(feature = "local_storage"))]
+#[macro_export]
+macro_rules! __local_storage_persistence {
+    () => {
+        compile_error!("persistence = \"local_storage\" is set in the configuration, the \"local_storage\" feature of leptos_i18n must be enabled")
+    };
+}
 
 #[cfg(all(feature = "cookie", any(feature = "hydrate", feature = "csr")))]
 pub(crate) fn get_html_document() -> Option<web_sys::HtmlDocument> {
     use wasm_bindgen::JsCast;
     leptos::document().dyn_into::<web_sys::HtmlDocument>().ok()
 }
+
+#[cfg(all(feature = "cookie", any(feature = "hydrate", feature = "csr")))]
+pub(crate) fn get_cookie(name: &str) -> Option<String> {
+    let cookies = get_html_document()?.cookie().ok()?;
+    request::parse_cookie(&cookies, name).map(ToOwned::to_owned)
+}
@@ -1,5 +1,8 @@
 use std::borrow::Cow;
 
+use leptos_i18n_core::negotiate::{check_tag, fallback_tags, language_from_tag};
+pub use leptos_i18n_core::negotiate::{normalize_tag, parse_accept_language, LocaleParseError};
+
 /// Trait implemented the enum representing the supported locales of the application
 ///
 /// Most functions of this crate are generic of type implementing this trait
@@ -13,14 +16,139 @@ pub trait Locale: 'static + Default + Clone + Copy {
     /// Return a static str that represent the locale.
     fn as_str(self) -> &'static str;
 
+    /// Return a static slice of all the available locales, the default locale being the first one.
+    fn get_all() -> &'static [Self];
+
+    /// Return `true` if the locale is marked as beta in the configuration.
+    ///
+    /// Beta locales are not meant to be indexed by search engines.
+    #[inline]
+    fn is_beta(self) -> bool {
+        false
+    }
+
+    /// Return the percentage of the traffic the locale is rolled out to, if set in the configuration.
+    ///
+    /// See the [`rollout`](crate::rollout) module.
+    #[inline]
+    fn rollout_percentage(self) -> Option<u8> {
+        None
+    }
+
+    /// Return the CSS class hinting the fonts to use for the locale, if set in the configuration.
+    ///
+    /// This can be used to switch to a font stack covering CJK or Arabic scripts.
+    #[inline]
+    fn font_class(self) -> Option<&'static str> {
+        None
+    }
+
+    /// Return the URLs of the font files to preload for the locale, set in the configuration.
+    ///
+    /// Preload links are emitted in the `<head>` for the locale the page is rendered in.
+    #[inline]
+    fn font_preloads(self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Return the URLs of the catalogs fetched when the locale is selected, with the `dynamic_load_strings` feature.
+    ///
+    /// This is empty for the default locale, compiled in, and when the feature is disabled.
+    /// See the [`dynamic_load`](crate::dynamic_load) module.
+    #[inline]
+    fn dynamic_load_urls(self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Return where the locale is persisted when no storage is provided, set in the configuration.
+    ///
+    /// See the [`storage`](crate::storage) module.
+    #[inline]
+    fn persistence() -> crate::storage::Persistence {
+        crate::storage::Persistence::Cookie
+    }
+
+    /// Try to match the given language tag to a locale, falling back to shorter tags if there is no exact match,
+    /// as the lookup of [RFC 4647](https://www.rfc-editor.org/rfc/rfc4647#section-3.4): `zh-Hant` then `zh` for `zh-Hant-TW`.
+    ///
+    /// Tags are compared in their [normalized](normalize_tag) form, so `en_us` or `EN-US` match the `en-US` locale.
+    fn from_tag(tag: &str) -> Option<Self> {
+        fallback_tags(tag).find_map(|tag| {
+            let normalized = normalize_tag(tag);
+            Self::from_str(tag).or_else(|| {
+                Self::get_all()
+                    .iter()
+                    .copied()
+                    .find(|locale| normalize_tag(locale.as_str()) == normalized)
+            })
+        })
+    }
+
+    /// Same as [`Locale::from_str`], but return why the string is not a locale.
+    ///
+    /// Use it on the values that can't be trusted, like the ones of a cookie or a query parameter,
+    /// to log or reject the malformed ones instead of falling back to the default locale.
+    fn try_from_str(s: &str) -> Result<Self, LocaleParseError> {
+        check_tag(s)?;
+        Self::from_str(s).ok_or_else(|| LocaleParseError::Unknown(s.to_owned()))
+    }
+
+    /// Same as [`Locale::from_tag`], but return why the tag is not a locale.
+    fn try_from_tag(tag: &str) -> Result<Self, LocaleParseError> {
+        let tag = tag.trim();
+        check_tag(tag)?;
+        Self::from_tag(tag).ok_or_else(|| LocaleParseError::Unknown(tag.to_owned()))
+    }
+
+    /// Same as [`Locale::try_from_str`], but also fail if the locale is not available for the current rollout,
+    /// like the locale persisted in the cookie or in the [storage](crate::storage) is checked.
+    fn try_from_persisted(s: &str) -> Result<Self, LocaleParseError> {
+        let locale = Self::try_from_str(s)?;
+        if crate::rollout::is_locale_available(locale) {
+            Ok(locale)
+        } else {
+            Err(LocaleParseError::Unavailable(locale.as_str()))
+        }
+    }
+
     /// Given a slice of accepted languages sorted in preferred order, return the locale that fit the best the request.
+    ///
+    /// Locales not available for the current rollout are skipped.
     fn find_locale<T: AsRef<str>>(accepted_langs: &[T]) -> Self {
+        Self::find_locale_with_tag(accepted_langs).0
+    }
+
+    /// Same as [`Locale::find_locale`], but also return the requested tag if the locale was matched on another tag,
+    /// the tag can then be used for formatting (`fr-CA` for the `fr` locale for example).
+    ///
+    /// Each language is matched with [`Locale::from_tag`], or else to the first locale of the same language
+    /// (`en-US` for `en-GB`), before trying the next one.
+    fn find_locale_with_tag<T: AsRef<str>>(accepted_langs: &[T]) -> (Self, Option<String>) {
         accepted_langs
             .iter()
-            .find_map(|l| Self::from_str(l.as_ref()))
+            .filter_map(|tag| {
+                let tag = tag.as_ref().trim();
+                let locale = Self::from_tag(tag).or_else(|| {
+                    let language = language_from_tag(tag);
+                    Self::get_all().iter().copied().find(|locale| {
+                        language_from_tag(locale.as_str()).eq_ignore_ascii_case(language)
+                    })
+                })?;
+                let format_tag = (locale.as_str() != tag).then(|| tag.to_owned());
+                Some((locale, format_tag))
+            })
+            .find(|(l, _)| crate::rollout::is_locale_available(*l))
             .unwrap_or_default()
     }
 
+    /// Negotiate the locale from the value of an `Accept-Language` header, such as `"fr-CH, fr;q=0.9, en;q=0.8"`.
+    ///
+    /// The languages are tried by decreasing quality (see [`parse_accept_language`]) with [`Locale::find_locale`],
+    /// the default locale being returned if none match.
+    fn find_best_match(accept_language: &str) -> Self {
+        Self::find_locale(&parse_accept_language(accept_language))
+    }
+
     /// Return the keys based on self
     #[inline]
     fn get_keys(self) -> &'static Self::Keys {
@@ -59,6 +187,12 @@ pub trait BuildStr: Sized {
     }
 
     fn build_string(self) -> Cow<'static, str>;
+
+    /// Same as `build_string`, for the `t_string!` macro which rejects the keys with components.
+    #[inline]
+    fn build_t_string(self) -> Cow<'static, str> {
+        self.build_string()
+    }
 }
 
 impl BuildStr for &'static str {
@@ -0,0 +1,136 @@
+//! This module contain the types describing the keys of the catalog, generated by the `load_locales!` macro in the `i18n::KEYS_METADATA` constant.
+//!
+//! They allow runtime layers, like the validation of overrides, admin UIs or docs generation, to reflect over the catalog without parsing the locales files again.
+//!
+//! ```rust,ignore
+//! use leptos_i18n::metadata::find_key;
+//!
+//! /* key = "Hello <b>{{ name }}</b>" */
+//! let metadata = find_key(i18n::KEYS_METADATA, "greeting").unwrap();
+//! assert_eq!(metadata.variables().collect::<Vec<_>>(), ["name"]);
+//! assert_eq!(metadata.components().collect::<Vec<_>>(), ["b"]);
+//! assert_eq!(metadata.count_type(), None);
+//! ```
+
+/// The kind of an interpolation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InterpolationKind {
+    /// A variable, `{{ name }}`.
+    Variable,
+    /// A formatted variable, `{{ price, number }}`, with the kind of its formatter (`"number"`).
+    Formatted(&'static str),
+    /// The variable of a select, `{ "$select": "gender", ... }`.
+    Select,
+    /// A component, `<b>...</b>`.
+    Component,
+    /// The count of a plural, with the name of its type (`"i32"`, `"u64"`, `"f32"`, ...).
+    Count(&'static str),
+}
+
+/// An interpolation required by a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interpolation {
+    /// The name of the interpolation, as written in the locales files.
+    pub name: &'static str,
+    /// The kind of the interpolation.
+    pub kind: InterpolationKind,
+}
+
+/// The metadata of a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyMetadata {
+    /// The path of the key, `namespace::key.subkey`.
+    pub path: &'static str,
+    /// The interpolations required by the key in any locale, sorted by name. Empty for plain strings.
+    pub interpolations: &'static [Interpolation],
+}
+
+impl KeyMetadata {
+    fn names_of(&self, kind: InterpolationKind) -> impl Iterator<Item = &'static str> + '_ {
+        self.interpolations
+            .iter()
+            .filter(move |interpolation| interpolation.kind == kind)
+            .map(|interpolation| interpolation.name)
+    }
+
+    /// Return the names of the variables of the key, formatted, selected on or not.
+    pub fn variables(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.interpolations
+            .iter()
+            .filter(|interpolation| {
+                matches!(
+                    interpolation.kind,
+                    InterpolationKind::Variable
+                        | InterpolationKind::Formatted(_)
+                        | InterpolationKind::Select
+                )
+            })
+            .map(|interpolation| interpolation.name)
+    }
+
+    /// Return the names of the components of the key.
+    pub fn components(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.names_of(InterpolationKind::Component)
+    }
+
+    /// Return the name of the count type if the key is a plural.
+    pub fn count_type(&self) -> Option<&'static str> {
+        self.interpolations
+            .iter()
+            .find_map(|interpolation| match interpolation.kind {
+                InterpolationKind::Count(count_type) => Some(count_type),
+                _ => None,
+            })
+    }
+
+    /// Return `true` if the key don't need any interpolation.
+    pub fn is_string(&self) -> bool {
+        self.interpolations.is_empty()
+    }
+}
+
+/// Find the metadata of the key at the given path in metadata sorted by path, like `i18n::KEYS_METADATA`.
+pub fn find_key(keys: &'static [KeyMetadata], path: &str) -> Option<&'static KeyMetadata> {
+    keys.binary_search_by(|key| key.path.cmp(path))
+        .ok()
+        .map(|i| &keys[i])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEYS: &[KeyMetadata] = &[
+        KeyMetadata {
+            path: "click_count",
+            interpolations: &[
+                Interpolation {
+                    name: "b",
+                    kind: InterpolationKind::Component,
+                },
+                Interpolation {
+                    name: "count",
+                    kind: InterpolationKind::Count("u32"),
+                },
+                Interpolation {
+                    name: "name",
+                    kind: InterpolationKind::Variable,
+                },
+            ],
+        },
+        KeyMetadata {
+            path: "home::title",
+            interpolations: &[],
+        },
+    ];
+
+    #[test]
+    fn test_find_key() {
+        let key = find_key(KEYS, "click_count").unwrap();
+        assert_eq!(key.variables().collect::<Vec<_>>(), ["name"]);
+        assert_eq!(key.components().collect::<Vec<_>>(), ["b"]);
+        assert_eq!(key.count_type(), Some("u32"));
+        assert!(find_key(KEYS, "home::title").unwrap().is_string());
+        assert_eq!(find_key(KEYS, "missing"), None);
+    }
+}
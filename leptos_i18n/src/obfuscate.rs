@@ -0,0 +1,87 @@
+//! This module contain the type of the strings generated by the `load_locales!` macro when the `obfuscate_strings` feature is enabled.
+//!
+//! The strings are stored XOR-ed with a keystream, so the copy of your application (marketing copy, unreleased feature names, ...)
+//! can't be found by grepping the shipped binary, and are only decoded when rendered.
+//! This is an obfuscation, not an encryption: anyone reading the code can decode them.
+
+use std::{borrow::Cow, fmt};
+
+use leptos::{IntoView, View};
+
+use crate::locale_traits::BuildStr;
+
+const KEY_STEP: u8 = 0x9d;
+
+fn keystream(key: u8, index: usize) -> u8 {
+    key.wrapping_add((index as u8).wrapping_mul(KEY_STEP))
+}
+
+/// Obfuscate a string with the given key, the reverse of [`ObfuscatedStr::decode`].
+#[doc(hidden)]
+pub fn obfuscate(s: &str, key: u8) -> Vec<u8> {
+    s.bytes()
+        .enumerate()
+        .map(|(i, byte)| byte ^ keystream(key, i))
+        .collect()
+}
+
+/// An obfuscated string, decoded when displayed or rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ObfuscatedStr {
+    bytes: &'static [u8],
+    key: u8,
+}
+
+impl ObfuscatedStr {
+    #[doc(hidden)]
+    pub const fn new(bytes: &'static [u8], key: u8) -> Self {
+        ObfuscatedStr { bytes, key }
+    }
+
+    /// Decode the string.
+    pub fn decode(self) -> String {
+        let bytes = self
+            .bytes
+            .iter()
+            .enumerate()
+            .map(|(i, byte)| byte ^ keystream(self.key, i))
+            .collect::<Vec<_>>();
+        // the bytes always come from a `str`.
+        String::from_utf8(bytes)
+            .unwrap_or_else(|err| String::from_utf8_lossy(err.as_bytes()).into_owned())
+    }
+}
+
+impl fmt::Display for ObfuscatedStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.decode())
+    }
+}
+
+impl IntoView for ObfuscatedStr {
+    fn into_view(self) -> View {
+        self.decode().into_view()
+    }
+}
+
+impl BuildStr for ObfuscatedStr {
+    fn build_string(self) -> Cow<'static, str> {
+        Cow::Owned(self.decode())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let s = "Launching Project Falcon 🚀";
+        let bytes: &'static [u8] = Box::leak(obfuscate(s, 42).into_boxed_slice());
+        assert!(!bytes.windows(7).any(|w| w == b"Project"));
+        let obfuscated = ObfuscatedStr::new(bytes, 42);
+        assert_eq!(obfuscated.decode(), s);
+        assert_eq!(obfuscated.to_string(), s);
+        assert_eq!(obfuscated.build_string(), s);
+    }
+}
@@ -0,0 +1,82 @@
+//! This module contain the escape hatch to interpolate trusted HTML, and the escaping of the values interpolated as text.
+//!
+//! The variables interpolated by `t!` and `td!` are always rendered as text: a value like `<script>` is escaped
+//! in the text and in the attributes of the tags, whether it is rendered on the server or in the browser.
+//! The translations themselves are trusted, their tags being the components given to the macros.
+//!
+//! For the rare values that are trusted HTML, like the output of a sanitizer or a CMS field edited by your team,
+//! wrap them explicitly in a [`RawHtml`]. Never give it a value coming from a user:
+//!
+//! ```rust,ignore
+//! use leptos_i18n::raw_html::RawHtml;
+//!
+//! /* "post_body": "Posted by {{ author }}: {{ body }}" */
+//! let body = RawHtml::dangerously_trust(sanitized_body);
+//! t!(i18n, post_body, author = user.name, body)
+//! ```
+//!
+//! `td_string!` and `td_display!` return text, the values of the variables are not escaped in it, but the attributes written on the
+//! elements of the components are. Render the text as a view, or escape it with [`escape_html`], don't inject it as HTML.
+
+use std::{borrow::Cow, fmt};
+
+use leptos::{html, IntoView, Oco, View};
+
+/// Escape the characters of `text` that have a meaning in HTML, so it can be written in an element or in a quoted attribute.
+///
+/// ```rust
+/// use leptos_i18n::raw_html::escape_html;
+///
+/// assert_eq!(escape_html("<b title=\"it's\">Tom & Jerry</b>"), "&lt;b title=&quot;it&#39;s&quot;&gt;Tom &amp; Jerry&lt;/b&gt;");
+/// assert_eq!(escape_html("plain text"), "plain text");
+/// ```
+pub fn escape_html(text: &str) -> Cow<'_, str> {
+    if !text.contains(['&', '<', '>', '"', '\'']) {
+        return Cow::Borrowed(text);
+    }
+    let mut escaped = String::with_capacity(text.len() + 16);
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+/// A trusted HTML snippet, rendered as is instead of being escaped when given as a variable to `t!` or `td!`.
+///
+/// It is rendered in a `<span>`, and written as is by `td_string!`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RawHtml(Oco<'static, str>);
+
+impl RawHtml {
+    /// Trust the given HTML, it will be rendered without any escaping.
+    ///
+    /// This disables the protection against HTML injection of the interpolations, never give it a value
+    /// that a user can control without sanitizing it first.
+    pub fn dangerously_trust(html: impl Into<Oco<'static, str>>) -> Self {
+        RawHtml(html.into())
+    }
+
+    /// Return the HTML.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for RawHtml {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl IntoView for RawHtml {
+    fn into_view(self) -> View {
+        html::span().inner_html(self.0).into_view()
+    }
+}
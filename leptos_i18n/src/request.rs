@@ -0,0 +1,132 @@
+//! This module contain the trait used to read the headers and the cookies of the request rendered on the server.
+//!
+//! With the `actix` and `axum` features the request is taken from the context set by the server integration.
+//! On other runtimes, like edge runtimes (Cloudflare Workers, Deno, ...) with the `edge` feature,
+//! give the request to the context with [`provide_request_headers`] before the `I18nContext`:
+//!
+//! ```rust,ignore
+//! #[event(fetch)]
+//! async fn fetch(req: worker::Request, env: Env, ctx: Context) -> Result<Response> {
+//!     let headers = req.inner().headers();
+//!     let html = leptos::ssr::render_to_string(move || {
+//!         leptos_i18n::request::provide_request_headers(headers.clone());
+//!         view! { <App /> }
+//!     });
+//!     Response::from_html(html.to_string())
+//! }
+//! ```
+//!
+//! The headers provided take precedence over the request of the server integration.
+//! Anything implementing [`RequestHeaders`] can be provided, closures can be used for the runtimes without an adapter:
+//!
+//! ```rust
+//! use leptos_i18n::request::RequestHeaders;
+//!
+//! let headers = |name: &str| match name {
+//!     "accept-language" => Some("fr-CH, fr;q=0.9".to_owned()),
+//!     "cookie" => Some("theme=dark; i18n_pref_locale=en".to_owned()),
+//!     _ => None,
+//! };
+//! assert_eq!(headers.header("accept-language").as_deref(), Some("fr-CH, fr;q=0.9"));
+//! assert_eq!(headers.cookie("i18n_pref_locale").as_deref(), Some("en"));
+//! ```
+
+use std::rc::Rc;
+
+use crate::{parse_accept_language, Locale};
+
+/// Trait for the access to the headers of a request, used for the locale negotiation on the server.
+pub trait RequestHeaders: 'static {
+    /// Return the value of the header with the given name, the name being lowercase.
+    fn header(&self, name: &str) -> Option<String>;
+
+    /// Return the value of the cookie with the given name, read in the `Cookie` header by default.
+    fn cookie(&self, name: &str) -> Option<String> {
+        let cookies = self.header("cookie")?;
+        parse_cookie(&cookies, name).map(ToOwned::to_owned)
+    }
+}
+
+impl<F> RequestHeaders for F
+where
+    F: Fn(&str) -> Option<String> + 'static,
+{
+    fn header(&self, name: &str) -> Option<String> {
+        self(name)
+    }
+}
+
+#[cfg(feature = "edge")]
+impl RequestHeaders for web_sys::Headers {
+    fn header(&self, name: &str) -> Option<String> {
+        self.get(name).ok().flatten()
+    }
+}
+
+#[cfg(feature = "edge")]
+impl RequestHeaders for web_sys::Request {
+    fn header(&self, name: &str) -> Option<String> {
+        self.headers().header(name)
+    }
+}
+
+#[derive(Clone)]
+struct RequestHeadersContext(Rc<dyn RequestHeaders>);
+
+/// Provide the headers of the request being rendered, used instead of the request of the server integration.
+pub fn provide_request_headers<H: RequestHeaders>(headers: H) {
+    leptos::provide_context(RequestHeadersContext(Rc::new(headers)));
+}
+
+/// Return the headers provided with [`provide_request_headers`], if any.
+pub fn use_request_headers() -> Option<Rc<dyn RequestHeaders>> {
+    leptos::use_context::<RequestHeadersContext>().map(|RequestHeadersContext(headers)| headers)
+}
+
+/// Negotiate the locale of a request without the `I18nContext`, like in an API handler or a server function,
+/// the same way the context does on the server.
+///
+/// This is the locale persisted in the cookie with the `cookie` feature,
+/// or else the best match of the `Accept-Language` header (see [`Locale::find_best_match`]).
+///
+/// ```rust,ignore
+/// async fn handler(headers: axum::http::HeaderMap) -> String {
+///     let locale = leptos_i18n::request::find_locale::<Locale, _>(&headers);
+///     td_string!(locale, not_found).into_owned()
+/// }
+/// ```
+pub fn find_locale<T: Locale, H: RequestHeaders + ?Sized>(headers: &H) -> T {
+    find_locale_with_tag(headers).0
+}
+
+/// Same as [`find_locale`], but also return the requested tag if the locale was matched on another tag,
+/// see [`Locale::find_locale_with_tag`].
+pub fn find_locale_with_tag<T: Locale, H: RequestHeaders + ?Sized>(
+    headers: &H,
+) -> (T, Option<String>) {
+    #[cfg(feature = "cookie")]
+    if let Some(pref) = headers
+        .cookie(crate::cookie::use_cookie_options().get_name())
+        .filter(|_| !crate::storage::has_locale_storage())
+        .and_then(|value| T::try_from_persisted(&value).ok())
+    {
+        return (pref, None);
+    }
+
+    let Some(header) = headers.header("accept-language") else {
+        return Default::default();
+    };
+
+    let langs = parse_accept_language(&header);
+
+    T::find_locale_with_tag(&langs)
+}
+
+/// Return the value of the cookie with the given name in the value of a `Cookie` header.
+pub(crate) fn parse_cookie<'a>(cookies: &'a str, name: &str) -> Option<&'a str> {
+    cookies
+        .split(';')
+        .filter_map(|cookie| cookie.trim().split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value)
+}
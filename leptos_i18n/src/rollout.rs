@@ -0,0 +1,92 @@
+//! This module contain helpers to gradually roll out new locales.
+//!
+//! A locale can be gated behind a percentage of the traffic with the `rollout` configuration key:
+//!
+//! ```toml
+//! [package.metadata.leptos-i18n]
+//! default = "en"
+//! locales = ["en", "fr", "de"]
+//! rollout = { de = 10 }
+//! ```
+//!
+//! The traffic is split in 100 buckets based on a key supplied with [`provide_rollout_key`] (a user or session id for example),
+//! and the locale is only negotiated for the buckets under the given percentage.
+//! A locale gated by a percentage is never negotiated if no key has been supplied.
+//!
+//! You can also supply a runtime callback with [`provide_locale_gate`] to decide if a locale is available.
+//!
+//! Both must be provided before the `I18nContext`, and only affect locale negotiation: a user can still manually select a gated locale.
+
+use std::rc::Rc;
+
+use crate::Locale;
+
+/// Runtime callback deciding if a locale can be negotiated.
+#[derive(Clone)]
+pub struct LocaleGate(Rc<dyn Fn(&'static str) -> bool>);
+
+/// The rollout bucket of the current request, between 0 and 99.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RolloutBucket(pub u8);
+
+impl RolloutBucket {
+    /// Compute the bucket for the given key.
+    ///
+    /// The same key always end up in the same bucket, across requests and builds.
+    pub fn from_key(key: impl AsRef<[u8]>) -> Self {
+        // FNV-1a, stable unlike `std::hash::DefaultHasher`
+        let hash = key.as_ref().iter().fold(0x811c9dc5u32, |hash, byte| {
+            (hash ^ *byte as u32).wrapping_mul(0x01000193)
+        });
+        RolloutBucket((hash % 100) as u8)
+    }
+}
+
+/// Provide a callback to decide at runtime if a locale can be negotiated.
+///
+/// The callback receives the locale as a str and return `true` if the locale is available.
+pub fn provide_locale_gate<F>(gate: F)
+where
+    F: Fn(&'static str) -> bool + 'static,
+{
+    leptos::provide_context(LocaleGate(Rc::new(gate)));
+}
+
+/// Provide the key used to compute the rollout bucket of the current request, a user or session id for example.
+pub fn provide_rollout_key(key: impl AsRef<[u8]>) {
+    leptos::provide_context(RolloutBucket::from_key(key));
+}
+
+/// Return `true` if the given locale can be negotiated.
+pub fn is_locale_available<T: Locale>(locale: T) -> bool {
+    if let Some(percentage) = locale.rollout_percentage() {
+        match leptos::use_context::<RolloutBucket>() {
+            Some(RolloutBucket(bucket)) if bucket < percentage => {}
+            _ => return false,
+        }
+    }
+    leptos::use_context::<LocaleGate>()
+        .map(|LocaleGate(gate)| gate(locale.as_str()))
+        .unwrap_or(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_stable() {
+        assert_eq!(
+            RolloutBucket::from_key("user_42"),
+            RolloutBucket::from_key(String::from("user_42"))
+        );
+    }
+
+    #[test]
+    fn test_bucket_range() {
+        for i in 0..1000 {
+            let RolloutBucket(bucket) = RolloutBucket::from_key(i.to_string());
+            assert!(bucket < 100);
+        }
+    }
+}
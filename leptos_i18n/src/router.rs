@@ -0,0 +1,293 @@
+//! This module contain helpers to derive the current locale from a path prefix with `leptos_router`,
+//! like `/en/about` and `/fr/about`.
+//!
+//! Wrap your routes in an `I18nRoute`, it registers them under the prefix of every locale,
+//! and redirects the paths without a prefix to the ones of the current locale:
+//!
+//! ```rust,ignore
+//! use leptos_i18n::router::I18nRoute;
+//!
+//! view! {
+//!     <Router>
+//!         <Routes>
+//!             <I18nRoute<Locale, _, _> view=|| view! { <Outlet /> }>
+//!                 <Route path="/" view=Home />
+//!                 <Route path="/about" view=About />
+//!             </I18nRoute<Locale, _, _>>
+//!         </Routes>
+//!     </Router>
+//! }
+//! ```
+//!
+//! Navigating to `/fr/about` sets the locale to `fr`, and setting the locale on the context navigates to the path with the new prefix.
+//! Use `I18nA` and `use_localized_navigate` instead of `A` and `use_navigate` to keep the prefix of the current locale in the links.
+
+use std::marker::PhantomData;
+
+use leptos::*;
+use leptos_router::*;
+
+use crate::{use_i18n_context, Locale, LocaleParseError};
+
+/// Return the locale of the prefix of `path` and the rest of the path, if `path` starts with the prefix of a locale.
+///
+/// ```rust,ignore
+/// assert_eq!(strip_locale_prefix::<Locale>("/fr/about"), Some((Locale::fr, "/about")));
+/// assert_eq!(strip_locale_prefix::<Locale>("/about"), None);
+/// ```
+pub fn strip_locale_prefix<L: Locale>(path: &str) -> Option<(L, &str)> {
+    try_strip_locale_prefix(path).ok()
+}
+
+/// Same as [`strip_locale_prefix`], but return why the first segment of `path` is not the prefix of a locale.
+///
+/// ```rust,ignore
+/// assert_eq!(try_strip_locale_prefix::<Locale>("/"), Err(LocaleParseError::Empty));
+/// assert_eq!(try_strip_locale_prefix::<Locale>("/about"), Err(LocaleParseError::Unknown("about".into())));
+/// assert_eq!(try_strip_locale_prefix::<Locale>("/%3Cscript%3E"), Err(LocaleParseError::Malformed("%3Cscript%3E".into())));
+/// ```
+pub fn try_strip_locale_prefix<L: Locale>(path: &str) -> Result<(L, &str), LocaleParseError> {
+    let rest = path
+        .strip_prefix('/')
+        .ok_or_else(|| LocaleParseError::Malformed(path.to_owned()))?;
+    let end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    let (prefix, rest) = rest.split_at(end);
+    L::get_all()
+        .iter()
+        .find(|locale| locale.as_str() == prefix)
+        .map(|locale| (*locale, rest))
+        .ok_or_else(|| match L::try_from_str(prefix) {
+            Err(err) => err,
+            Ok(_) => LocaleParseError::Unknown(prefix.to_owned()),
+        })
+}
+
+/// Return `path` with the prefix of `locale`, replacing the prefix of another locale if present.
+///
+/// Relative paths and urls to other origins are returned as is.
+///
+/// ```rust,ignore
+/// assert_eq!(localize_path(Locale::fr, "/about"), "/fr/about");
+/// assert_eq!(localize_path(Locale::fr, "/en/about?tab=1"), "/fr/about?tab=1");
+/// assert_eq!(localize_path(Locale::fr, "/"), "/fr");
+/// ```
+pub fn localize_path<L: Locale>(locale: L, path: &str) -> String {
+    if !path.starts_with('/') || path.starts_with("//") {
+        return path.to_owned();
+    }
+    let rest = match strip_locale_prefix::<L>(path) {
+        Some((_, rest)) => rest,
+        None if path == "/" => "",
+        None => path,
+    };
+    format!("/{}{}", locale.as_str(), rest)
+}
+
+fn current_path() -> String {
+    let location = use_location();
+    let pathname = location.pathname.get_untracked();
+    let search = location.search.get_untracked();
+    let search = search.trim_start_matches('?');
+    if search.is_empty() {
+        pathname
+    } else {
+        format!("{}?{}", pathname, search)
+    }
+}
+
+/// The props of [`I18nRoute`].
+///
+/// The router components are written without `#[component]`, whose expansion needs the `tracing` crate
+/// when `leptos` is built with its `tracing` feature.
+#[derive(leptos::typed_builder_macro::TypedBuilder)]
+#[builder(crate_module_path = leptos::typed_builder)]
+pub struct I18nRouteProps<L, E, F>
+where
+    L: Locale,
+    E: IntoView,
+    F: Fn() -> E + Clone + 'static,
+{
+    /// The view of the localized routes, usually rendering an `<Outlet />`.
+    pub view: F,
+    /// The `SsrMode` of the localized routes.
+    #[builder(default)]
+    pub ssr: SsrMode,
+    /// The routes nested in the localized routes.
+    #[builder(default, setter(strip_option))]
+    pub children: Option<Children>,
+    /// Carry the type of the locales.
+    #[builder(default)]
+    pub locale: PhantomData<L>,
+}
+
+impl<L, E, F> Props for I18nRouteProps<L, E, F>
+where
+    L: Locale,
+    E: IntoView,
+    F: Fn() -> E + Clone + 'static,
+{
+    type Builder = I18nRoutePropsBuilder<L, E, F>;
+
+    fn builder() -> Self::Builder {
+        I18nRouteProps::builder()
+    }
+}
+
+impl<L, E, F> DynAttrs for I18nRouteProps<L, E, F>
+where
+    L: Locale,
+    E: IntoView,
+    F: Fn() -> E + Clone + 'static,
+{
+}
+
+impl<L, E, F> IntoView for I18nRouteProps<L, E, F>
+where
+    L: Locale,
+    E: IntoView,
+    F: Fn() -> E + Clone + 'static,
+{
+    fn into_view(self) -> View {
+        I18nRoute(self).into_view()
+    }
+}
+
+/// Register the nested routes under the prefix of every locale, the view of a prefixed route setting the locale of the context.
+///
+/// The paths without a prefix are redirected to the ones of the current locale,
+/// and setting the locale on the context navigates to the path with the new prefix.
+#[allow(non_snake_case)]
+pub fn I18nRoute<L, E, F>(props: I18nRouteProps<L, E, F>) -> impl IntoView
+where
+    L: Locale,
+    E: IntoView,
+    F: Fn() -> E + Clone + 'static,
+{
+    let I18nRouteProps {
+        view,
+        ssr,
+        children,
+        locale: _,
+    } = props;
+    // the routes are registered once per locale, plus once without prefix.
+    let children = children
+        .map(|children| children())
+        .unwrap_or_else(|| Fragment::new(vec![]));
+
+    let redirect_route = Route(
+        RouteProps::builder()
+            .path("")
+            .view(|| {
+                let locale = use_i18n_context::<L>().get_locale_untracked();
+                let path = localize_path(locale, &current_path());
+                view! { <Redirect path=path /> }
+            })
+            .ssr(ssr)
+            .children({
+                let children = children.clone();
+                Box::new(move || children)
+            })
+            .build(),
+    )
+    .into_view();
+
+    let locale_routes = L::get_all().iter().map(|locale| {
+        let locale = *locale;
+        let view = view.clone();
+        let children = children.clone();
+        Route(
+            RouteProps::builder()
+                .path(format!("/{}", locale.as_str()))
+                .view(move || {
+                    let i18n = use_i18n_context::<L>();
+                    if i18n.get_locale_untracked().as_str() != locale.as_str() {
+                        i18n.set_locale(locale);
+                    }
+                    view()
+                })
+                .ssr(ssr)
+                .children(Box::new(move || children))
+                .build(),
+        )
+        .into_view()
+    });
+
+    let routes = locale_routes
+        .chain(std::iter::once(redirect_route))
+        .collect::<Vec<_>>();
+
+    Route(
+        RouteProps::builder()
+            .path("")
+            .view(|| {
+                let i18n = use_i18n_context::<L>();
+                let navigate = use_navigate();
+                // keep the prefix in sync when the locale is set on the context.
+                create_effect(move |_| {
+                    let locale = i18n.get_locale();
+                    let path = untrack(current_path);
+                    match strip_locale_prefix::<L>(&path) {
+                        Some((prefix, _)) if prefix.as_str() != locale.as_str() => {
+                            navigate(&localize_path(locale, &path), Default::default())
+                        }
+                        _ => {}
+                    }
+                });
+                view! { <Outlet /> }
+            })
+            .children(Box::new(move || Fragment::new(routes)))
+            .build(),
+    )
+}
+
+/// Return a function to navigate to a path with the prefix of the current locale, like the one of `use_navigate`.
+pub fn use_localized_navigate<L: Locale>() -> impl Fn(&str, NavigateOptions) {
+    let i18n = use_i18n_context::<L>();
+    let navigate = use_navigate();
+    move |path, options| navigate(&localize_path(i18n.get_locale_untracked(), path), options)
+}
+
+/// The props of [`I18nA`].
+#[derive(leptos::typed_builder_macro::TypedBuilder)]
+#[builder(crate_module_path = leptos::typed_builder)]
+pub struct I18nAProps<L: Locale> {
+    /// The path to link to, without the prefix of the locale.
+    #[builder(setter(into))]
+    pub href: String,
+    /// The content of the link.
+    pub children: Children,
+    /// Carry the type of the locales.
+    #[builder(default)]
+    pub locale: PhantomData<L>,
+}
+
+impl<L: Locale> Props for I18nAProps<L> {
+    type Builder = I18nAPropsBuilder<L>;
+
+    fn builder() -> Self::Builder {
+        I18nAProps::builder()
+    }
+}
+
+impl<L: Locale> DynAttrs for I18nAProps<L> {}
+
+impl<L: Locale> IntoView for I18nAProps<L> {
+    fn into_view(self) -> View {
+        I18nA(self).into_view()
+    }
+}
+
+/// A link to a path with the prefix of the current locale, like `A`.
+#[allow(non_snake_case)]
+pub fn I18nA<L: Locale>(props: I18nAProps<L>) -> impl IntoView {
+    let I18nAProps {
+        href,
+        children,
+        locale: _,
+    } = props;
+    leptos_dom::Component::new("I18nA", move || {
+        let i18n = use_i18n_context::<L>();
+        let href = move || localize_path(i18n.get_locale(), &href);
+        view! { <A href=href>{children()}</A> }
+    })
+}
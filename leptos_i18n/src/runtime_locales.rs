@@ -0,0 +1,288 @@
+//! This module contain an escape hatch to register locales at runtime, for locales that are not known at compile time,
+//! like language packs provided by third parties.
+//!
+//! Runtime locales are string keyed: each translation is registered under its key path (`"parent_key.child_key"`,
+//! or `"namespace::key"` with namespaces), and values are plain strings without interpolations.
+//!
+//! Each runtime locale declares a compiled locale to fall back to, the `I18nContext` is switched to that locale when the runtime locale is selected,
+//! so any key missing from the runtime locale is served by the compiled translations.
+//! Switching the `I18nContext` to another compiled locale, with [`RuntimeLocales::set_compiled_locale`] or directly with
+//! `I18nContext::set_locale`, leaves the runtime locale. Switching the context to the fallback locale itself keeps it selected,
+//! use [`RuntimeLocales::set_compiled_locale`] to leave it in that case.
+//!
+//! The runtime translations are only read by [`RuntimeLocales::t_or`] and [`RuntimeLocales::get`], `t!` and `td!` never see them,
+//! so the call sites of the keys a runtime locale can translate must use `t_or`.
+//!
+//! ```rust,ignore
+//! let i18n = provide_i18n_context();
+//! let runtime_locales = leptos_i18n::runtime_locales::provide_runtime_locales();
+//!
+//! runtime_locales.register("pt-BR", Locale::en, [("hello_world", "Olá mundo!")]);
+//! runtime_locales.set_locale("pt-BR");
+//!
+//! view! {
+//!     <p>{runtime_locales.t_or("hello_world", t!(i18n, hello_world))}</p>
+//! }
+//! ```
+
+use std::{collections::HashMap, rc::Rc};
+
+use leptos::*;
+
+use crate::{use_i18n_context, I18nContext, Locale};
+
+#[derive(Debug, Clone)]
+struct RuntimeCatalog<T> {
+    fallback: T,
+    entries: HashMap<String, String>,
+}
+
+/// Context holding the locales registered at runtime.
+#[derive(Debug, Clone, Copy)]
+pub struct RuntimeLocales<T: Locale> {
+    catalogs: RwSignal<HashMap<Rc<str>, RuntimeCatalog<T>>>,
+    current: RwSignal<Option<Rc<str>>>,
+    context: I18nContext<T>,
+}
+
+impl<T: Locale> RuntimeLocales<T> {
+    /// Register a locale, with `fallback` being the compiled locale used for the missing keys.
+    ///
+    /// If a locale with the same name is already registered it is replaced.
+    pub fn register<K, V, I>(self, name: &str, fallback: T, entries: I)
+    where
+        K: Into<String>,
+        V: Into<String>,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let catalog = RuntimeCatalog {
+            fallback,
+            entries: entries
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        };
+        self.catalogs.update(|catalogs| {
+            catalogs.insert(name.into(), catalog);
+        });
+    }
+
+    /// Remove a registered locale, if it was the current one the context stays on its fallback locale.
+    pub fn unregister(self, name: &str) {
+        self.catalogs.update(|catalogs| {
+            catalogs.remove(name);
+        });
+        if self
+            .current
+            .with_untracked(|current| current.as_deref() == Some(name))
+        {
+            self.current.set(None);
+        }
+    }
+
+    /// Return the names of the registered locales.
+    pub fn get_all(self) -> Vec<Rc<str>> {
+        self.catalogs
+            .with(|catalogs| catalogs.keys().cloned().collect())
+    }
+
+    /// Return the current runtime locale, if any, subscribing to any changes.
+    pub fn get_locale(self) -> Option<Rc<str>> {
+        self.current.get()
+    }
+
+    /// Select a registered locale and switch the `I18nContext` to its fallback locale.
+    ///
+    /// The runtime locale stays selected until the context is switched to another locale.
+    ///
+    /// Return `false` if no locale with this name is registered.
+    pub fn set_locale(self, name: &str) -> bool {
+        let Some((name, fallback)) = self.catalogs.with_untracked(|catalogs| {
+            catalogs
+                .get_key_value(name)
+                .map(|(name, catalog)| (Rc::clone(name), catalog.fallback))
+        }) else {
+            return false;
+        };
+        self.current.set(Some(name));
+        self.context.set_locale(fallback);
+        true
+    }
+
+    /// Leave the runtime locale when the context is switched to a locale other than its fallback,
+    /// for example with `I18nContext::set_locale`.
+    fn leave_on_locale_change(self) {
+        create_isomorphic_effect(move |_| {
+            let locale = self.context.get_locale();
+            let fallback = self.current.with_untracked(|current| {
+                let current = current.as_ref()?;
+                self.catalogs.with_untracked(|catalogs| {
+                    catalogs.get(current).map(|catalog| catalog.fallback)
+                })
+            });
+            if fallback.is_some_and(|fallback| fallback.as_str() != locale.as_str()) {
+                self.current.set(None);
+            }
+        });
+    }
+
+    /// Go back to a compiled locale.
+    pub fn set_compiled_locale(self, locale: T) {
+        self.current.set(None);
+        self.context.set_locale(locale);
+    }
+
+    /// Return the value for the given key in the current runtime locale, subscribing to any changes.
+    ///
+    /// Return `None` if no runtime locale is selected or if the key is missing from it.
+    pub fn get(self, key: &str) -> Option<String> {
+        let current = self.current.get()?;
+        self.catalogs.with(|catalogs| {
+            catalogs
+                .get(&current)
+                .and_then(|catalog| catalog.entries.get(key))
+                .cloned()
+        })
+    }
+
+    /// Return a view of the value for the given key in the current runtime locale,
+    /// or of the given fallback, usually the output of the `t!` macro, if missing.
+    pub fn t_or<F, V>(self, key: &'static str, fallback: F) -> impl Fn() -> View + Clone + 'static
+    where
+        F: Fn() -> V + Clone + 'static,
+        V: IntoView,
+    {
+        move || match self.get(key) {
+            Some(value) => value.into_view(),
+            None => fallback().into_view(),
+        }
+    }
+}
+
+/// Provide the `RuntimeLocales` context for the application.
+///
+/// The `I18nContext` must be provided before calling this function.
+///
+/// If called when a context is already present it will not overwrite it and just return the current context.
+pub fn provide_runtime_locales<T: Locale>() -> RuntimeLocales<T> {
+    use_context().unwrap_or_else(|| {
+        let runtime_locales = RuntimeLocales {
+            catalogs: create_rw_signal(HashMap::new()),
+            current: create_rw_signal(None),
+            context: use_i18n_context(),
+        };
+        provide_context(runtime_locales);
+        runtime_locales.leave_on_locale_change();
+        runtime_locales
+    })
+}
+
+/// Return the `RuntimeLocales` previously set.
+///
+/// ## Panic
+///
+/// Panics if the context is missing.
+#[inline]
+pub fn use_runtime_locales<T: Locale>() -> RuntimeLocales<T> {
+    use_context().expect("RuntimeLocales is missing, use provide_runtime_locales() to provide it.")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LocaleKeys;
+
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    enum TestLocale {
+        #[default]
+        En,
+        Fr,
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    struct TestKeys;
+
+    impl LocaleKeys for TestKeys {
+        type Locale = TestLocale;
+
+        fn from_locale(_: TestLocale) -> &'static Self {
+            &TestKeys
+        }
+    }
+
+    impl Locale for TestLocale {
+        type Keys = TestKeys;
+
+        fn from_str(s: &str) -> Option<Self> {
+            match s {
+                "en" => Some(TestLocale::En),
+                "fr" => Some(TestLocale::Fr),
+                _ => None,
+            }
+        }
+
+        fn as_str(self) -> &'static str {
+            match self {
+                TestLocale::En => "en",
+                TestLocale::Fr => "fr",
+            }
+        }
+
+        fn get_all() -> &'static [Self] {
+            &[TestLocale::En, TestLocale::Fr]
+        }
+    }
+
+    fn text(view: View) -> Option<String> {
+        view.as_text().map(|text| text.content.to_string())
+    }
+
+    #[test]
+    fn test_runtime_locales() {
+        let runtime = create_runtime();
+        let context = I18nContext {
+            locale: create_rw_signal(TestLocale::En),
+            format_tag: create_rw_signal(None),
+            preferences: create_rw_signal(Default::default()),
+        };
+        provide_context(context);
+        let runtime_locales = provide_runtime_locales::<TestLocale>();
+
+        runtime_locales.register("fr-BE", TestLocale::Fr, [("hello", "Bonjour !")]);
+        assert_eq!(runtime_locales.get_all(), [Rc::from("fr-BE")]);
+
+        let hello = runtime_locales.t_or("hello", || "Hello!");
+        let bye = runtime_locales.t_or("bye", || "Bye!");
+        // no runtime locale selected, the compiled translations are used.
+        assert_eq!(text(hello()).as_deref(), Some("Hello!"));
+
+        assert!(!runtime_locales.set_locale("de-AT"));
+        assert_eq!(runtime_locales.get_locale(), None);
+
+        assert!(runtime_locales.set_locale("fr-BE"));
+        assert_eq!(runtime_locales.get_locale().as_deref(), Some("fr-BE"));
+        assert_eq!(context.get_locale_untracked(), TestLocale::Fr);
+        assert_eq!(text(hello()).as_deref(), Some("Bonjour !"));
+        // missing from the runtime locale, served by the compiled translations.
+        assert_eq!(text(bye()).as_deref(), Some("Bye!"));
+
+        // registering again replaces the locale.
+        runtime_locales.register("fr-BE", TestLocale::Fr, [("bye", "Au revoir !")]);
+        assert_eq!(text(hello()).as_deref(), Some("Hello!"));
+        assert_eq!(text(bye()).as_deref(), Some("Au revoir !"));
+
+        // switching the context directly leaves the runtime locale.
+        context.set_locale(TestLocale::En);
+        assert_eq!(runtime_locales.get_locale(), None);
+        assert_eq!(text(bye()).as_deref(), Some("Bye!"));
+
+        assert!(runtime_locales.set_locale("fr-BE"));
+        assert_eq!(text(bye()).as_deref(), Some("Au revoir !"));
+        runtime_locales.unregister("fr-BE");
+        assert_eq!(runtime_locales.get_locale(), None);
+        assert_eq!(context.get_locale_untracked(), TestLocale::Fr);
+        assert_eq!(text(bye()).as_deref(), Some("Bye!"));
+
+        runtime.dispose();
+    }
+}
@@ -0,0 +1,263 @@
+//! This module expose the ICU4X text segmentation, to split translated text on user-perceived characters, words or sentences.
+//!
+//! This is useful to fit translated text into fixed-width UI without cutting multi-byte characters in half:
+//!
+//! ```rust,ignore
+//! let title = "Ame\u{301}lie";
+//! assert_eq!(leptos_i18n::segmenter::truncate_graphemes(title, 4), "Ame\u{301}l");
+//! ```
+//!
+//! For user facing text, [`truncate_localized`] also appends the ellipsis of the current locale:
+//!
+//! ```rust,ignore
+//! let i18n = use_i18n();
+//! view! {
+//!     <td>{move || truncate_localized(i18n, &product.name, 24).into_owned()}</td>
+//! }
+//! ```
+//!
+//! Languages written without spaces between words, like Chinese, Japanese or Thai, can't be wrapped by browsers
+//! that don't know where the words end. [`line_break_hints_localized`] inserts zero-width spaces at the line break opportunities,
+//! and [`line_break_view`] renders `<wbr>` elements instead:
+//!
+//! ```rust,ignore
+//! let i18n = use_i18n();
+//! view! {
+//!     <h1>{move || line_break_hints_localized(i18n, &t_string!(i18n, long_heading)).into_owned()}</h1>
+//! }
+//! ```
+
+use std::borrow::Cow;
+
+use icu_segmenter::{GraphemeClusterSegmenter, LineSegmenter, SentenceSegmenter, WordSegmenter};
+use leptos::{Fragment, IntoView, View};
+
+use crate::{I18nContext, Locale};
+
+thread_local! {
+    static GRAPHEME_SEGMENTER: GraphemeClusterSegmenter = GraphemeClusterSegmenter::new();
+    static WORD_SEGMENTER: WordSegmenter = WordSegmenter::new_auto();
+    static SENTENCE_SEGMENTER: SentenceSegmenter = SentenceSegmenter::new();
+    static LINE_SEGMENTER: LineSegmenter = LineSegmenter::new_auto();
+}
+
+/// The zero-width space, an invisible line break opportunity.
+pub const ZERO_WIDTH_SPACE: &str = "\u{200B}";
+
+/// The unit of segmentation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Granularity {
+    /// User-perceived characters, `"e\u{301}"` is a single grapheme.
+    Grapheme,
+    /// Words, including the whitespaces and punctuations between them as separate segments.
+    Word,
+    /// Sentences.
+    Sentence,
+}
+
+/// Return the byte offsets of the boundaries of the segments of `s`, starting with `0` and ending with `s.len()`.
+pub fn breakpoints(s: &str, granularity: Granularity) -> Vec<usize> {
+    match granularity {
+        Granularity::Grapheme => {
+            GRAPHEME_SEGMENTER.with(|segmenter| segmenter.segment_str(s).collect())
+        }
+        Granularity::Word => WORD_SEGMENTER.with(|segmenter| segmenter.segment_str(s).collect()),
+        Granularity::Sentence => {
+            SENTENCE_SEGMENTER.with(|segmenter| segmenter.segment_str(s).collect())
+        }
+    }
+}
+
+/// Split `s` in segments of the given granularity.
+pub fn segment(s: &str, granularity: Granularity) -> Vec<&str> {
+    breakpoints(s, granularity)
+        .windows(2)
+        .map(|bounds| &s[bounds[0]..bounds[1]])
+        .collect()
+}
+
+/// Return the number of user-perceived characters in `s`.
+pub fn grapheme_count(s: &str) -> usize {
+    breakpoints(s, Granularity::Grapheme)
+        .len()
+        .saturating_sub(1)
+}
+
+/// Return the first `n` user-perceived characters of `s`, or `s` if it is shorter.
+pub fn truncate_graphemes(s: &str, n: usize) -> &str {
+    let end =
+        GRAPHEME_SEGMENTER.with(|segmenter| segmenter.segment_str(s).nth(n).unwrap_or(s.len()));
+    &s[..end]
+}
+
+/// Return the byte offsets where `s` can be wrapped, without the start and end of `s`
+/// and the offsets following a whitespace, where browsers already break lines.
+pub fn line_break_opportunities(s: &str) -> Vec<usize> {
+    LINE_SEGMENTER.with(|segmenter| {
+        segmenter
+            .segment_str(s)
+            .filter(|&i| i > 0 && i < s.len())
+            .filter(|&i| !s[..i].ends_with(char::is_whitespace))
+            .collect()
+    })
+}
+
+/// Return `true` if the language of the given tag is written without spaces between words,
+/// its text then needs line break hints to be wrapped.
+pub fn needs_line_break_hints(tag: &str) -> bool {
+    matches!(
+        tag.split(['-', '_']).next(),
+        Some("zh" | "ja" | "th" | "lo" | "km" | "my")
+    )
+}
+
+/// Return `text` with `hint` inserted at each of its line break opportunities, see [`line_break_opportunities`].
+pub fn insert_line_break_hints<'a>(text: &'a str, hint: &str) -> Cow<'a, str> {
+    let opportunities = line_break_opportunities(text);
+    if opportunities.is_empty() {
+        return Cow::Borrowed(text);
+    }
+    let mut hinted = String::with_capacity(text.len() + opportunities.len() * hint.len());
+    let mut start = 0;
+    for end in opportunities {
+        hinted.push_str(&text[start..end]);
+        hinted.push_str(hint);
+        start = end;
+    }
+    hinted.push_str(&text[start..]);
+    Cow::Owned(hinted)
+}
+
+/// Return `text` with zero-width spaces inserted at its line break opportunities.
+pub fn line_break_hints(text: &str) -> Cow<'_, str> {
+    insert_line_break_hints(text, ZERO_WIDTH_SPACE)
+}
+
+/// Return `text` with zero-width spaces inserted at its line break opportunities if the current locale needs them,
+/// or else `text`.
+///
+/// This subscribe to the locale changes.
+pub fn line_break_hints_localized<'a, T: Locale>(
+    i18n: I18nContext<T>,
+    text: &'a str,
+) -> Cow<'a, str> {
+    if needs_line_break_hints(&i18n.format_locale()) {
+        line_break_hints(text)
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+/// Render `text` with a `<wbr>` element at each of its line break opportunities.
+///
+/// Unlike zero-width spaces, `<wbr>` are not copied with the text.
+pub fn line_break_view(text: &str) -> View {
+    let mut nodes = Vec::new();
+    let mut start = 0;
+    for end in line_break_opportunities(text) {
+        nodes.push(text[start..end].to_owned().into_view());
+        nodes.push(leptos::html::wbr().into_view());
+        start = end;
+    }
+    nodes.push(text[start..].to_owned().into_view());
+    Fragment::new(nodes).into_view()
+}
+
+/// Return the ellipsis used by the language of the given tag.
+pub fn ellipsis(tag: &str) -> &'static str {
+    match tag.split(['-', '_']).next() {
+        // Chinese typography uses a double ellipsis.
+        Some("zh") => "……",
+        _ => "…",
+    }
+}
+
+/// Return `text` truncated to `max` user-perceived characters, the given ellipsis included, or `text` if it is shorter.
+///
+/// The whitespaces before the ellipsis are trimmed. If `max` is smaller than the ellipsis,
+/// the first `max` characters of the ellipsis are returned, so the result never exceeds `max`.
+pub fn truncate_with_ellipsis<'a>(text: &'a str, max: usize, ellipsis: &str) -> Cow<'a, str> {
+    let breakpoints = breakpoints(text, Granularity::Grapheme);
+    if breakpoints.len().saturating_sub(1) <= max {
+        return Cow::Borrowed(text);
+    }
+    let Some(keep) = max.checked_sub(grapheme_count(ellipsis)) else {
+        return Cow::Owned(truncate_graphemes(ellipsis, max).to_owned());
+    };
+    let truncated = text[..breakpoints[keep]].trim_end();
+    Cow::Owned(format!("{}{}", truncated, ellipsis))
+}
+
+/// Return `text` truncated on grapheme boundaries to `max` user-perceived characters,
+/// with the ellipsis of the current locale appended, or `text` if it is shorter.
+///
+/// This subscribe to the locale changes.
+pub fn truncate_localized<'a, T: Locale>(
+    i18n: I18nContext<T>,
+    text: &'a str,
+    max: usize,
+) -> Cow<'a, str> {
+    truncate_with_ellipsis(text, max, ellipsis(&i18n.format_locale()))
+}
+
+impl<T: Locale> I18nContext<T> {
+    /// Split `s` in segments of the given granularity, see the [`segmenter`](crate::segmenter) module.
+    ///
+    /// Word segmentation uses dictionaries or models for the languages that don't use spaces between words.
+    #[inline]
+    pub fn segment(self, s: &str, granularity: Granularity) -> Vec<&str> {
+        segment(s, granularity)
+    }
+
+    /// Return the first `n` user-perceived characters of `s`, see the [`segmenter`](crate::segmenter) module.
+    #[inline]
+    pub fn truncate_graphemes(self, s: &str, n: usize) -> &str {
+        truncate_graphemes(s, n)
+    }
+
+    /// Return `s` with line break hints if the current locale needs them, see [`line_break_hints_localized`].
+    #[inline]
+    pub fn line_break_hints<'a>(self, s: &'a str) -> Cow<'a, str> {
+        line_break_hints_localized(self, s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_graphemes() {
+        assert_eq!(truncate_graphemes("Ame\u{301}lie", 4), "Ame\u{301}l");
+        assert_eq!(truncate_graphemes("abc", 10), "abc");
+        assert_eq!(grapheme_count("Ame\u{301}lie"), 6);
+    }
+
+    #[test]
+    fn test_truncate_with_ellipsis() {
+        assert_eq!(
+            truncate_with_ellipsis("hello world", 11, "…"),
+            "hello world"
+        );
+        assert_eq!(truncate_with_ellipsis("hello world", 7, "…"), "hello…");
+        assert_eq!(
+            truncate_with_ellipsis("你好世界你好世界", 6, "……"),
+            "你好世界……"
+        );
+        // no room for the text, the ellipsis is truncated instead of exceeding `max`.
+        assert_eq!(truncate_with_ellipsis("你好世界你好世界", 1, "……"), "…");
+        assert_eq!(truncate_with_ellipsis("hello world", 0, "…"), "");
+        assert_eq!(ellipsis("zh-Hant"), "……");
+        assert_eq!(ellipsis("fr"), "…");
+    }
+
+    #[test]
+    fn test_line_break_hints() {
+        assert!(line_break_opportunities("hello world").is_empty());
+        assert_eq!(line_break_hints("hello world"), "hello world");
+        assert_eq!(insert_line_break_hints("你好世界", "|"), "你|好|世|界");
+        assert!(needs_line_break_hints("ja-JP"));
+        assert!(needs_line_break_hints("th"));
+        assert!(!needs_line_break_hints("en"));
+    }
+}
@@ -1,50 +1,17 @@
-use crate::locale_traits::*;
-use axum::http::header;
+use crate::request::RequestHeaders;
 
-pub fn fetch_locale_server<T: Locale>() -> T {
+pub fn with_request<R>(f: impl FnOnce(&dyn RequestHeaders) -> R) -> Option<R> {
     // when leptos_router inspect the routes it execute the code once but don't set a RequestParts in the context,
     // so we can't expect it to be present.
-    leptos::use_context::<leptos_axum::RequestParts>()
-        .map(|req| from_req(&req))
-        .unwrap_or_default()
+    leptos::use_context::<leptos_axum::RequestParts>().map(|req| f(&req))
 }
 
-fn from_req<T: Locale>(req: &leptos_axum::RequestParts) -> T {
-    #[cfg(feature = "cookie")]
-    if let Some(pref_lang_cookie) = get_prefered_lang_cookie::<T>(req) {
-        return pref_lang_cookie;
+impl RequestHeaders for leptos_axum::RequestParts {
+    fn header(&self, name: &str) -> Option<String> {
+        self.headers.header(name)
     }
 
-    let Some(header) = req
-        .headers
-        .get(header::ACCEPT_LANGUAGE)
-        .and_then(|header| header.to_str().ok())
-    else {
-        return Default::default();
-    };
-
-    let langs = super::parse_header(header);
-
-    T::find_locale(&langs)
-}
-
-#[cfg(feature = "cookie")]
-fn get_prefered_lang_cookie<T: Locale>(req: &leptos_axum::RequestParts) -> Option<T> {
-    req.headers
-        .get_all(header::COOKIE)
-        .into_iter()
-        .filter_map(parse_cookie)
-        .filter_map(T::from_str)
-        .next()
-}
-
-#[cfg(feature = "cookie")]
-fn parse_cookie(cookie: &axum::http::HeaderValue) -> Option<&str> {
-    std::str::from_utf8(cookie.as_bytes())
-        .ok()?
-        .split(';')
-        .map(|s| s.trim())
-        .filter_map(|s| s.split_once('='))
-        .find(|(name, _)| name == &crate::COOKIE_PREFERED_LANG)
-        .map(|(_, value)| value)
+    fn cookie(&self, name: &str) -> Option<String> {
+        self.headers.cookie(name)
+    }
 }
@@ -4,69 +4,49 @@ mod actix;
 #[cfg(all(feature = "axum", not(feature = "actix")))]
 mod axum;
 
-use crate::Locale;
+use crate::{fetch_locale::FetchedLocale, request::RequestHeaders, Locale};
+
+#[cfg(feature = "cookie")]
+use crate::preferences::FormatPreferences;
 
 #[cfg(all(feature = "actix", not(feature = "axum")))]
 use actix as backend;
 #[cfg(all(feature = "axum", not(feature = "actix")))]
 use axum as backend;
 
-#[cfg(any(feature = "actix", feature = "axum"))]
-pub fn fetch_locale_server_side<T: Locale>() -> T {
-    backend::fetch_locale_server::<T>()
-}
-
-#[cfg(all(feature = "actix", feature = "axum"))]
-compile_error!("Can't enable \"actix\" and \"axum\" features together.");
-
 #[cfg(not(any(feature = "actix", feature = "axum")))]
-pub fn fetch_locale_server_side<T: Locale>() -> T {
-    compile_error!("Need either \"actix\" or \"axum\" feature to be enabled in ssr. Don't use the \"ssr\" feature, it is directly enable by the \"actix\" or \"axum\" feature.")
-}
-
-#[cfg(any(feature = "actix", feature = "axum"))]
-pub(crate) fn parse_header(header: &str) -> Vec<String> {
-    let mut parsed_lang: Vec<_> = header
-        .split(';')
-        .map(|lang| {
-            let mut langs = lang.split(',').peekable();
-            let q = if let Some(a) = langs
-                .peek()
-                .and_then(|maybe_q| maybe_q.trim().strip_prefix("q="))
-            {
-                let q = a.parse::<f32>().unwrap_or(1.0);
-                langs.next();
-                q
-            } else {
-                1.0
-            };
-            (q, langs)
-        })
-        .collect();
-
-    parsed_lang.sort_unstable_by(|a, b| b.0.total_cmp(&a.0));
+mod backend {
+    use crate::request::RequestHeaders;
 
-    parsed_lang
-        .into_iter()
-        .flat_map(|(_q, langs)| langs.map(str::trim).map(String::from))
-        .collect()
+    // without a server integration the headers can only be provided with `provide_request_headers`.
+    pub fn with_request<R>(_f: impl FnOnce(&dyn RequestHeaders) -> R) -> Option<R> {
+        None
+    }
 }
 
-#[cfg(all(test, any(feature = "actix", feature = "axum")))]
-mod tests {
-    use super::*;
+pub fn fetch_locale_server_side<T: Locale>() -> FetchedLocale<T> {
+    with_request(|req| crate::request::find_locale_with_tag(req)).unwrap_or_default()
+}
 
-    #[test]
-    fn test_parse() {
-        let parsed_lang = parse_header("fr-CH, fr;q=0.9, en;q=0.8, de;q=0.7, *;q=0.5");
+#[cfg(feature = "cookie")]
+pub fn fetch_preferences_server_side() -> FormatPreferences {
+    with_request(|req| req.cookie(crate::cookie::use_cookie_options().get_preferences_name()))
+        .flatten()
+        .filter(|_| !crate::storage::has_locale_storage())
+        .map(|prefs| FormatPreferences::decode(&prefs))
+        .unwrap_or_default()
+}
 
-        assert_eq!(parsed_lang, &["fr-CH", "fr", "en", "de", "*"]);
+/// Call `f` with the headers given to `provide_request_headers`, or else the request of the server integration.
+fn with_request<R>(f: impl FnOnce(&dyn RequestHeaders) -> R) -> Option<R> {
+    match crate::request::use_request_headers() {
+        Some(headers) => Some(f(&*headers)),
+        None => backend::with_request(f),
     }
+}
 
-    #[test]
-    fn test_parse_unsorted() {
-        let parsed_lang = parse_header("q=0.3, fr-CH, fr;q=0.9, en;de;q=0.7, *;q=0.5");
+#[cfg(all(feature = "actix", feature = "axum"))]
+compile_error!("Can't enable \"actix\" and \"axum\" features together.");
 
-        assert_eq!(parsed_lang, &["de", "en", "*", "fr-CH", "fr"]);
-    }
-}
+#[cfg(not(any(feature = "actix", feature = "axum", feature = "edge")))]
+compile_error!("Need either \"actix\", \"axum\" or \"edge\" feature to be enabled in ssr. Don't use the \"ssr\" feature, it is directly enable by these features.");
@@ -0,0 +1,102 @@
+//! This module contain helpers to generate a `sitemap.xml` listing every localized route of the application.
+//!
+//! Each route is declined for every available locale under a `/{locale}` path prefix,
+//! and every entry links to its alternates with `xhtml:link` elements so search engines can match translated pages together.
+
+use crate::Locale;
+
+const SITEMAP_HEADER: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9" xmlns:xhtml="http://www.w3.org/1999/xhtml">
+"#;
+
+const SITEMAP_FOOTER: &str = "</urlset>\n";
+
+/// Return the path of the given route for the given locale, the locale being added as the first segment of the path.
+///
+/// ```rust,ignore
+/// assert_eq!(localized_path(Locale::fr, "/about"), "/fr/about");
+/// assert_eq!(localized_path(Locale::fr, "/"), "/fr");
+/// ```
+pub fn localized_path<L: Locale>(locale: L, path: &str) -> String {
+    let path = path.trim_matches('/');
+    if path.is_empty() {
+        format!("/{}", locale.as_str())
+    } else {
+        format!("/{}/{}", locale.as_str(), path)
+    }
+}
+
+fn localized_url<L: Locale>(base_url: &str, locale: L, path: &str) -> String {
+    let base_url = base_url.trim_end_matches('/');
+    let mut url = String::with_capacity(base_url.len() + path.len() + 8);
+    url.push_str(base_url);
+    url.push_str(&localized_path(locale, path));
+    url
+}
+
+fn escape_xml(s: &str, buff: &mut String) {
+    for c in s.chars() {
+        match c {
+            '&' => buff.push_str("&amp;"),
+            '<' => buff.push_str("&lt;"),
+            '>' => buff.push_str("&gt;"),
+            '"' => buff.push_str("&quot;"),
+            '\'' => buff.push_str("&apos;"),
+            c => buff.push(c),
+        }
+    }
+}
+
+fn write_alternate(buff: &mut String, hreflang: &str, href: &str) {
+    buff.push_str("    <xhtml:link rel=\"alternate\" hreflang=\"");
+    escape_xml(hreflang, buff);
+    buff.push_str("\" href=\"");
+    escape_xml(href, buff);
+    buff.push_str("\"/>\n");
+}
+
+/// Generate the content of a `sitemap.xml` containing every given route declined for every available locale.
+///
+/// Each `<url>` entry contains an `xhtml:link` alternate for every locale, plus an `x-default` alternate pointing to the default locale.
+/// Locales marked as beta are left out as they are not meant to be indexed.
+///
+/// `base_url` is the origin of the application (e.g. `"https://example.com"`), and `paths` the unlocalized paths of the routes.
+/// With `leptos_router`, the paths can be obtained with the route list generated for your server integration.
+///
+/// ```rust,ignore
+/// let sitemap = leptos_i18n::sitemap::generate_sitemap::<Locale, _>("https://example.com", ["/", "/about"]);
+/// ```
+pub fn generate_sitemap<L, I>(base_url: &str, paths: I) -> String
+where
+    L: Locale,
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let locales = L::get_all();
+    let mut buff = String::from(SITEMAP_HEADER);
+
+    for path in paths {
+        let path = path.as_ref();
+        let urls = locales
+            .iter()
+            .filter(|locale| !locale.is_beta())
+            .map(|locale| (locale.as_str(), localized_url(base_url, *locale, path)))
+            .collect::<Vec<_>>();
+
+        for (_, loc) in &urls {
+            buff.push_str("  <url>\n    <loc>");
+            escape_xml(loc, &mut buff);
+            buff.push_str("</loc>\n");
+            for (hreflang, href) in &urls {
+                write_alternate(&mut buff, hreflang, href);
+            }
+            if let Some((_, default_href)) = urls.first() {
+                write_alternate(&mut buff, "x-default", default_href);
+            }
+            buff.push_str("  </url>\n");
+        }
+    }
+
+    buff.push_str(SITEMAP_FOOTER);
+    buff
+}
@@ -0,0 +1,279 @@
+//! This module contain the hooks to delegate the persistence of the locale to your own storage.
+//!
+//! By default the locale is persisted in a cookie (with the `cookie` feature), if you provide a storage with [`provide_locale_storage`]
+//! no cookie will ever be read or written, the storage is used instead, both on the client and for the server side negotiation.
+//! This is useful for deployments with strict consent requirements where cookies are not an option:
+//!
+//! ```rust,ignore
+//! struct UrlStorage;
+//!
+//! impl LocaleStorage for UrlStorage {
+//!     fn load(&self) -> Option<String> {
+//!         let query = leptos_router::use_query_map();
+//!         query.with_untracked(|query| query.get("lang").cloned())
+//!     }
+//!
+//!     fn save(&self, locale: &'static str) {
+//!         // update the `lang` query parameter
+//!     }
+//! }
+//!
+//! leptos_i18n::storage::provide_locale_storage(UrlStorage);
+//! let i18n = provide_i18n_context();
+//! ```
+//!
+//! The storage must be provided before the `I18nContext`.
+//!
+//! Any key-value store implementing [`KeyValueStore`] can be used with the [`KeyValueStorage`] adapter, such as a store bridged
+//! to the native side of a mobile app by its webview shell, or the `localStorage` with the `local_storage` feature:
+//!
+//! ```rust,ignore
+//! if let Some(storage) = KeyValueStorage::local_storage() {
+//!     leptos_i18n::storage::provide_locale_storage(storage);
+//! }
+//! let i18n = provide_i18n_context();
+//! ```
+//!
+//! In CSR only apps, the locale can also be persisted in the `localStorage` by setting `persistence = "local_storage"` in the configuration,
+//! with the `local_storage` feature enabled. This storage is then provided by the `I18nContext` if you don't provide one.
+//!
+//! You can also make the persistence of the locale, in the cookie or in your storage, depend on the user consent with [`provide_persistence_consent`].
+//! Without consent the chosen locale is only kept in memory, and it is persisted as soon as the consent is given if the callback is reactive:
+//!
+//! ```rust,ignore
+//! let (consent, set_consent) = create_signal(false);
+//! leptos_i18n::storage::provide_persistence_consent(move || consent.get());
+//! let i18n = provide_i18n_context();
+//! ```
+
+use std::{borrow::Cow, rc::Rc};
+
+use crate::{preferences::FormatPreferences, Locale, LocaleParseError};
+
+/// Trait for a storage used to persist the selected locale instead of the cookie.
+pub trait LocaleStorage: 'static {
+    /// Return the persisted locale, if any.
+    fn load(&self) -> Option<String>;
+
+    /// Persist the given locale, called every time the locale is set.
+    fn save(&self, locale: &'static str);
+
+    /// Return the persisted formatting preferences, encoded with [`FormatPreferences::encode`], if any.
+    fn load_preferences(&self) -> Option<String> {
+        None
+    }
+
+    /// Persist the formatting preferences, encoded with [`FormatPreferences::encode`], called every time they are updated.
+    fn save_preferences(&self, preferences: &str) {
+        let _ = preferences;
+    }
+}
+
+/// Trait for a key-value store, like the `localStorage` of a webview or a store bridged to the native side of a mobile app.
+pub trait KeyValueStore: 'static {
+    /// Return the value stored for the given key, if any.
+    fn get(&self, key: &str) -> Option<String>;
+
+    /// Store the value for the given key.
+    fn set(&self, key: &str, value: &str);
+}
+
+/// A pair of functions getting and setting the values, for stores bridged to the native side of the app:
+///
+/// ```rust,ignore
+/// let store = (
+///     |key: &str| android_bridge().get_item(key),
+///     |key: &str, value: &str| android_bridge().set_item(key, value),
+/// );
+/// provide_locale_storage(KeyValueStorage::new(store));
+/// ```
+impl<G, S> KeyValueStore for (G, S)
+where
+    G: Fn(&str) -> Option<String> + 'static,
+    S: Fn(&str, &str) + 'static,
+{
+    fn get(&self, key: &str) -> Option<String> {
+        (self.0)(key)
+    }
+
+    fn set(&self, key: &str, value: &str) {
+        (self.1)(key, value)
+    }
+}
+
+#[cfg(feature = "local_storage")]
+impl KeyValueStore for web_sys::Storage {
+    fn get(&self, key: &str) -> Option<String> {
+        self.get_item(key).ok().flatten()
+    }
+
+    fn set(&self, key: &str, value: &str) {
+        // the storage can be full or disabled, the locale is then only kept in memory.
+        let _ = self.set_item(key, value);
+    }
+}
+
+/// A [`LocaleStorage`] persisting the locale and the preferences in a [`KeyValueStore`],
+/// under the `i18n_pref_locale` and `i18n_pref_format` keys by default.
+///
+/// This is meant for hybrid apps, where the webview shell may not keep the cookies,
+/// so the locale is persisted the same way on the web and in the app.
+pub struct KeyValueStorage<S> {
+    store: S,
+    locale_key: Cow<'static, str>,
+    preferences_key: Cow<'static, str>,
+}
+
+impl<S: KeyValueStore> KeyValueStorage<S> {
+    /// Create a storage persisting in the given store.
+    pub fn new(store: S) -> Self {
+        KeyValueStorage {
+            store,
+            locale_key: Cow::Borrowed("i18n_pref_locale"),
+            preferences_key: Cow::Borrowed("i18n_pref_format"),
+        }
+    }
+
+    /// Set the keys the locale and the preferences are stored under.
+    pub fn with_keys(
+        mut self,
+        locale_key: impl Into<Cow<'static, str>>,
+        preferences_key: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.locale_key = locale_key.into();
+        self.preferences_key = preferences_key.into();
+        self
+    }
+}
+
+#[cfg(feature = "local_storage")]
+impl KeyValueStorage<web_sys::Storage> {
+    /// Create a storage persisting in the `localStorage` of the browser or of the webview, if available.
+    pub fn local_storage() -> Option<Self> {
+        let store = leptos::window().local_storage().ok()??;
+        Some(Self::new(store))
+    }
+}
+
+impl<S: KeyValueStore> LocaleStorage for KeyValueStorage<S> {
+    fn load(&self) -> Option<String> {
+        self.store.get(&self.locale_key)
+    }
+
+    fn save(&self, locale: &'static str) {
+        self.store.set(&self.locale_key, locale)
+    }
+
+    fn load_preferences(&self) -> Option<String> {
+        self.store.get(&self.preferences_key)
+    }
+
+    fn save_preferences(&self, preferences: &str) {
+        self.store.set(&self.preferences_key, preferences)
+    }
+}
+
+/// Where the chosen locale is persisted when no storage is provided, set with the `persistence` key of the configuration.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Persistence {
+    /// In a cookie with the `cookie` feature, so the server can render the page in the chosen locale.
+    #[default]
+    Cookie,
+    /// In the `localStorage` of the browser, for CSR only apps without a server reading the cookie.
+    /// This requires the `local_storage` feature.
+    LocalStorage,
+}
+
+#[derive(Clone)]
+struct StorageContext(Rc<dyn LocaleStorage>);
+
+#[derive(Clone)]
+struct ConsentContext(Rc<dyn Fn() -> bool>);
+
+/// Provide a storage to persist the locale, disabling the cookie.
+pub fn provide_locale_storage<S: LocaleStorage>(storage: S) {
+    leptos::provide_context(StorageContext(Rc::new(storage)));
+}
+
+/// Provide a callback consulted before persisting the locale, the locale is only persisted if it returns `true`.
+///
+/// The callback is called in a reactive context, if it subscribe to a signal the locale is persisted as soon as the consent is given.
+pub fn provide_persistence_consent<F>(consent: F)
+where
+    F: Fn() -> bool + 'static,
+{
+    leptos::provide_context(ConsentContext(Rc::new(consent)));
+}
+
+/// Return `true` if the locale can be persisted, meaning no consent callback has been provided or it returned `true`.
+pub(crate) fn has_consent() -> bool {
+    leptos::use_context::<ConsentContext>()
+        .map(|ConsentContext(consent)| consent())
+        .unwrap_or(true)
+}
+
+/// Return `true` if a storage has been provided, meaning the cookie is not used.
+pub fn has_locale_storage() -> bool {
+    leptos::use_context::<StorageContext>().is_some()
+}
+
+/// Provide the `localStorage` as the storage if the locale is configured to be persisted in it and no storage is provided.
+pub(crate) fn init_persistence<T: Locale>() {
+    #[cfg(all(feature = "local_storage", any(feature = "hydrate", feature = "csr")))]
+    if T::persistence() == Persistence::LocalStorage && !has_locale_storage() {
+        if let Some(storage) = KeyValueStorage::local_storage() {
+            provide_locale_storage(storage);
+        }
+    }
+}
+
+/// Return the locale persisted in the storage, if a storage has been provided and the locale is available.
+pub(crate) fn load_locale<T: Locale>() -> Option<T> {
+    try_load_locale().ok().flatten()
+}
+
+/// Return the locale persisted in the storage, `Ok(None)` if no storage has been provided or it is empty.
+///
+/// Unlike the context, which falls back to the negotiated locale, this return why the persisted value was rejected,
+/// see [`Locale::try_from_persisted`].
+pub fn try_load_locale<T: Locale>() -> Result<Option<T>, LocaleParseError> {
+    let Some(StorageContext(storage)) = leptos::use_context() else {
+        return Ok(None);
+    };
+    storage
+        .load()
+        .map(|locale| T::try_from_persisted(&locale))
+        .transpose()
+}
+
+/// Persist the locale in the storage, return `false` if no storage has been provided.
+pub(crate) fn save_locale<T: Locale>(locale: T) -> bool {
+    match leptos::use_context::<StorageContext>() {
+        Some(StorageContext(storage)) => {
+            storage.save(locale.as_str());
+            true
+        }
+        None => false,
+    }
+}
+
+/// Return the preferences persisted in the storage, if a storage has been provided.
+pub(crate) fn load_preferences() -> Option<FormatPreferences> {
+    let StorageContext(storage) = leptos::use_context()?;
+    let prefs = storage
+        .load_preferences()
+        .map(|prefs| FormatPreferences::decode(&prefs))
+        .unwrap_or_default();
+    Some(prefs)
+}
+
+/// Persist the encoded preferences in the storage, return `false` if no storage has been provided.
+pub(crate) fn save_preferences(preferences: &str) -> bool {
+    match leptos::use_context::<StorageContext>() {
+        Some(StorageContext(storage)) => {
+            storage.save_preferences(preferences);
+            true
+        }
+        None => false,
+    }
+}
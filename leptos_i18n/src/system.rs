@@ -0,0 +1,73 @@
+//! This module contain the hooks to negotiate the locale from the languages of the operating system,
+//! for desktop builds (Tauri, ...) where there is no `Accept-Language` header.
+//!
+//! When the locale is neither in the storage nor in the cookie, the languages returned by the callback given to [`provide_system_locales`]
+//! are negotiated like the `Accept-Language` header is on the server, before falling back to the default locale:
+//!
+//! ```rust,ignore
+//! leptos_i18n::system::provide_system_locales(|| sys_locale::get_locales().collect());
+//! let i18n = provide_i18n_context();
+//! ```
+//!
+//! The callback must be provided before the `I18nContext`. With the `system_locale` feature, CSR builds read the languages
+//! of the webview (`navigator.languages`) when no callback is provided, which the webviews of Tauri take from the operating system.
+//!
+//! The server and the hydration never use the system languages, the locale is then the one negotiated from the request.
+
+use std::rc::Rc;
+
+use crate::Locale;
+
+#[derive(Clone)]
+struct SystemLocalesContext(Rc<dyn Fn() -> Vec<String>>);
+
+/// Provide a callback returning the languages of the operating system, sorted in preferred order.
+pub fn provide_system_locales<F>(system_locales: F)
+where
+    F: Fn() -> Vec<String> + 'static,
+{
+    leptos::provide_context(SystemLocalesContext(Rc::new(system_locales)));
+}
+
+/// Return the languages of the operating system, given by the callback of [`provide_system_locales`],
+/// or else by the webview with the `system_locale` feature.
+pub fn system_locales() -> Vec<String> {
+    if let Some(SystemLocalesContext(system_locales)) = leptos::use_context() {
+        return system_locales();
+    }
+    webview_locales()
+}
+
+#[cfg(all(
+    feature = "system_locale",
+    feature = "csr",
+    not(any(feature = "ssr", feature = "hydrate"))
+))]
+fn webview_locales() -> Vec<String> {
+    let navigator = leptos::window().navigator();
+    let languages = navigator
+        .languages()
+        .iter()
+        .filter_map(|lang| lang.as_string())
+        .collect::<Vec<_>>();
+    if languages.is_empty() {
+        navigator.language().into_iter().collect()
+    } else {
+        languages
+    }
+}
+
+#[cfg(not(all(
+    feature = "system_locale",
+    feature = "csr",
+    not(any(feature = "ssr", feature = "hydrate"))
+)))]
+fn webview_locales() -> Vec<String> {
+    Vec::new()
+}
+
+/// Return the locale that fit the best the languages of the operating system, with the requested tag if it differs from the locale,
+/// or the default locale if there is none.
+pub fn fetch_system_locale<T: Locale>() -> (T, Option<String>) {
+    T::find_locale_with_tag(&system_locales())
+}
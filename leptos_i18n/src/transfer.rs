@@ -0,0 +1,160 @@
+//! This module contain the state of the `I18nContext`, to transfer it from a rendering to another
+//! in resumability or transfer-state setups, without negotiating the locale or fetching the catalogs again.
+//!
+//! The state is the locale, the formatting tag and preferences, and with the `dynamic_load_strings` feature the catalogs already loaded.
+//! It is encoded in a string, one entry per line, that can be embedded in the page:
+//!
+//! ```rust,ignore
+//! // when serializing the application
+//! let state = use_i18n().state().encode();
+//!
+//! // when resuming it
+//! let state = I18nState::decode(&state)?;
+//! leptos_i18n::transfer::provide_i18n_state(state);
+//! let i18n = provide_i18n_context();
+//! ```
+//!
+//! The state must be provided before the `I18nContext`, and takes precedence over the storage, the cookie and the request.
+//! A state whose locale does not exist anymore is ignored and the locale is negotiated as usual.
+
+use leptos::{SignalGetUntracked, SignalSet};
+use leptos_i18n_core::negotiate::check_tag;
+
+use crate::{preferences::FormatPreferences, I18nContext, Locale, LocaleParseError};
+
+/// The state of an `I18nContext`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct I18nState {
+    /// The tag of the current locale.
+    pub locale: String,
+    /// The language tag used for formatting, if it differs from the locale.
+    pub format_tag: Option<String>,
+    /// The formatting preferences of the user.
+    pub preferences: FormatPreferences,
+    /// The URL and the JSON of the catalogs loaded with the `dynamic_load_strings` feature, always empty without it.
+    pub catalogs: Vec<(String, String)>,
+}
+
+impl I18nState {
+    /// Encode the state in a string, like:
+    ///
+    /// ```text
+    /// locale=fr
+    /// format_tag=fr-CA
+    /// preferences=currency=EUR
+    /// catalog=/locales/fr.json {"hello":"Bonjour"}
+    /// ```
+    ///
+    /// The catalogs must be JSON on a single line, as encoded by `serde_json`.
+    pub fn encode(&self) -> String {
+        let mut buff = format!("locale={}", self.locale);
+        if let Some(format_tag) = &self.format_tag {
+            buff.push_str("\nformat_tag=");
+            buff.push_str(format_tag);
+        }
+        if !self.preferences.is_empty() {
+            buff.push_str("\npreferences=");
+            buff.push_str(&self.preferences.encode());
+        }
+        for (url, json) in &self.catalogs {
+            buff.push_str("\ncatalog=");
+            buff.push_str(url);
+            buff.push(' ');
+            buff.push_str(json);
+        }
+        buff
+    }
+
+    /// Decode a state encoded with [`I18nState::encode`], unknown entries are ignored.
+    ///
+    /// Return an error if the locale or the formatting tag is missing or malformed.
+    pub fn decode(s: &str) -> Result<Self, LocaleParseError> {
+        let mut state = I18nState::default();
+        for (key, value) in s.lines().filter_map(|line| line.split_once('=')) {
+            match key {
+                "locale" => state.locale = value.to_owned(),
+                "format_tag" => {
+                    check_tag(value)?;
+                    state.format_tag = Some(value.to_owned());
+                }
+                "preferences" => state.preferences = FormatPreferences::decode(value),
+                "catalog" => {
+                    if let Some((url, json)) = value.split_once(' ') {
+                        state.catalogs.push((url.to_owned(), json.to_owned()));
+                    }
+                }
+                _ => {}
+            }
+        }
+        check_tag(&state.locale)?;
+        Ok(state)
+    }
+}
+
+impl<T: Locale> I18nContext<T> {
+    /// Return the state of the context, without subscribing to any changes.
+    pub fn state(self) -> I18nState {
+        I18nState {
+            locale: self.get_locale_untracked().as_str().to_owned(),
+            format_tag: self.format_tag.get_untracked(),
+            preferences: self.preferences.get_untracked(),
+            catalogs: loaded_catalogs(),
+        }
+    }
+
+    /// Restore the state of the context, and register its catalogs.
+    ///
+    /// Return an error, leaving the context untouched, if the locale does not exist.
+    pub fn restore(self, state: &I18nState) -> Result<(), LocaleParseError> {
+        let locale = T::try_from_str(&state.locale)?;
+        restore_catalogs::<T>(state);
+        self.format_tag.set(state.format_tag.clone());
+        self.preferences.set(state.preferences.clone());
+        self.locale.set(locale);
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct StateContext(I18nState);
+
+/// Provide the state the `I18nContext` is created with, instead of negotiating the locale.
+pub fn provide_i18n_state(state: I18nState) {
+    leptos::provide_context(StateContext(state));
+}
+
+/// Return the locale, the formatting tag and the preferences of the provided state, registering its catalogs,
+/// or `None` if there is no state or if its locale does not exist.
+pub(crate) fn take_state<T: Locale>() -> Option<(T, Option<String>, FormatPreferences)> {
+    let StateContext(state) = leptos::use_context()?;
+    let locale = T::try_from_str(&state.locale).ok()?;
+    restore_catalogs::<T>(&state);
+    Some((locale, state.format_tag, state.preferences))
+}
+
+#[cfg(feature = "dynamic_load_strings")]
+fn loaded_catalogs() -> Vec<(String, String)> {
+    crate::dynamic_load::loaded_catalogs()
+}
+
+#[cfg(not(feature = "dynamic_load_strings"))]
+fn loaded_catalogs() -> Vec<(String, String)> {
+    Vec::new()
+}
+
+#[cfg(feature = "dynamic_load_strings")]
+fn restore_catalogs<T: Locale>(state: &I18nState) {
+    for (url, json) in &state.catalogs {
+        // the registered URLs are the ones of the locales, the others are ignored.
+        let url = T::get_all()
+            .iter()
+            .flat_map(|locale| locale.dynamic_load_urls())
+            .find(|known| *known == url);
+        if let Some(url) = url {
+            let _ = crate::dynamic_load::insert_catalog(url, json);
+        }
+    }
+}
+
+#[cfg(not(feature = "dynamic_load_strings"))]
+fn restore_catalogs<T: Locale>(_: &I18nState) {}
@@ -0,0 +1,85 @@
+//! This module contain the usage telemetry of the keys, recorded when the `usage` feature is enabled.
+//!
+//! Every time `t!` or `td!` (and their string variants) render a key, the locale and the path of the key are counted.
+//! The counts can then be exported, for example from an admin route or at the end of an end-to-end test run,
+//! and merged with the catalog by the `leptos_i18n usage` command to find the high-traffic untranslated keys
+//! and the translated keys that are never rendered:
+//!
+//! ```rust,ignore
+//! std::fs::write("usage.json", leptos_i18n::usage::encode())?;
+//! ```
+//!
+//! The counts are shared by all the threads, a view re-rendered on a locale change counts again.
+
+use std::{collections::BTreeMap, sync::Mutex};
+
+static USAGE: Mutex<BTreeMap<(&str, &str), u64>> = Mutex::new(BTreeMap::new());
+
+#[doc(hidden)]
+pub fn record(locale: &'static str, key_path: &'static str) {
+    if let Ok(mut usage) = USAGE.lock() {
+        *usage.entry((locale, key_path)).or_default() += 1;
+    }
+}
+
+/// The number of times a key was rendered in a locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyUsage {
+    /// The locale the key was rendered in.
+    pub locale: &'static str,
+    /// The path of the key, like `namespace.parent.key`, with the names of the `i18n` module (`_` in place of `-`).
+    pub key_path: &'static str,
+    /// The number of times the key was rendered.
+    pub count: u64,
+}
+
+/// Return the usage recorded so far, sorted by locale and key.
+pub fn snapshot() -> Vec<KeyUsage> {
+    let Ok(usage) = USAGE.lock() else {
+        return Vec::new();
+    };
+    usage
+        .iter()
+        .map(|(&(locale, key_path), &count)| KeyUsage {
+            locale,
+            key_path,
+            count,
+        })
+        .collect()
+}
+
+/// Clear the usage recorded so far.
+pub fn reset() {
+    if let Ok(mut usage) = USAGE.lock() {
+        usage.clear();
+    }
+}
+
+/// Encode the usage recorded so far as JSON, the counts of each locale by key, like:
+///
+/// ```json
+/// { "en": { "hello_world": 12, "home.title": 3 }, "fr": { "hello_world": 4 } }
+/// ```
+///
+/// This is the format read by the `leptos_i18n usage` command.
+pub fn encode() -> String {
+    let mut buff = String::from("{");
+    let mut current_locale = None;
+    for usage in snapshot() {
+        if current_locale != Some(usage.locale) {
+            if current_locale.is_some() {
+                buff.push_str("},");
+            }
+            buff.push_str(&format!("{:?}:{{", usage.locale));
+            current_locale = Some(usage.locale);
+        } else {
+            buff.push(',');
+        }
+        buff.push_str(&format!("{:?}:{}", usage.key_path, usage.count));
+    }
+    if current_locale.is_some() {
+        buff.push('}');
+    }
+    buff.push('}');
+    buff
+}
@@ -0,0 +1,146 @@
+use std::{collections::BTreeMap, path::Path};
+
+use serde::Deserialize;
+
+use crate::{
+    config::Config,
+    error::{Error, Result},
+};
+
+const ANNOTATIONS_FILE: &str = "annotations.toml";
+
+/// The context of a key given to the translators.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Annotation {
+    /// Where and how the value is used.
+    pub description: Option<String>,
+    /// Paths or links (a Figma frame, an image URL, ...) to screenshots showing the value in context.
+    #[serde(default)]
+    pub screenshots: Vec<String>,
+    /// The teams owning the key, like `@org/checkout-team`, also owning its subkeys when set on a parent key or a namespace.
+    #[serde(default)]
+    pub owners: Vec<String>,
+}
+
+/// The annotations of the keys, by key path (`"namespace::parent.key"`), of parent keys or of namespaces.
+#[derive(Debug, Default)]
+pub struct Annotations(pub BTreeMap<String, Annotation>);
+
+impl<'de> Deserialize<'de> for Annotations {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // a key can be annotated with its description only.
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum RawAnnotation {
+            Description(String),
+            Annotation(Annotation),
+        }
+
+        let raw = BTreeMap::<String, RawAnnotation>::deserialize(deserializer)?;
+        let annotations = raw
+            .into_iter()
+            .map(|(key, raw)| {
+                let annotation = match raw {
+                    RawAnnotation::Description(description) => Annotation {
+                        description: Some(description),
+                        screenshots: vec![],
+                        owners: vec![],
+                    },
+                    RawAnnotation::Annotation(annotation) => annotation,
+                };
+                (key, annotation)
+            })
+            .collect();
+        Ok(Annotations(annotations))
+    }
+}
+
+impl Annotations {
+    /// Load the `annotations.toml` file at the root of the locales directory, if any.
+    pub fn load(config: &Config, manifest_dir: &Path) -> Result<Option<Self>> {
+        let path = config.locales_dir.join(ANNOTATIONS_FILE);
+        let content = match std::fs::read_to_string(manifest_dir.join(&path)) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(Error::Io(path, err)),
+        };
+        let annotations = toml::de::from_str(&content)
+            .map_err(|err| Error::AnnotationsDeser(path, err.to_string()))?;
+        Ok(Some(annotations))
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Annotation> {
+        self.0.get(key)
+    }
+
+    /// The owners of a key, set on the key or inherited from its closest parent key or namespace with owners.
+    pub fn owners(&self, key: &str) -> &[String] {
+        let mut path = key;
+        loop {
+            if let Some(annotation) = self.get(path) {
+                if !annotation.owners.is_empty() {
+                    return &annotation.owners;
+                }
+            }
+            path = match path.rsplit_once('.') {
+                Some((parent, _)) => parent,
+                None => match path.split_once("::") {
+                    Some((namespace, _)) => namespace,
+                    None => return &[],
+                },
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deser() {
+        let content = r#"
+            "click_count" = "Shown under the counter button"
+
+            ["home::title"]
+            description = "Title of the browser tab"
+            screenshots = ["screens/home.png", "https://www.figma.com/file/abc"]
+        "#;
+        let annotations: Annotations = toml::de::from_str(content).unwrap();
+        assert_eq!(
+            annotations.get("click_count"),
+            Some(&Annotation {
+                description: Some("Shown under the counter button".to_owned()),
+                screenshots: vec![],
+                owners: vec![],
+            })
+        );
+        let title = annotations.get("home::title").unwrap();
+        assert_eq!(
+            title.description.as_deref(),
+            Some("Title of the browser tab")
+        );
+        assert_eq!(title.screenshots.len(), 2);
+    }
+
+    #[test]
+    fn test_owners() {
+        let content = r#"
+            home.owners = ["@org/growth"]
+
+            ["home::checkout"]
+            owners = ["@org/payments", "@alice"]
+
+            ["home::checkout.title"]
+            description = "Title of the checkout page"
+        "#;
+        let annotations: Annotations = toml::de::from_str(content).unwrap();
+        assert_eq!(
+            annotations.owners("home::checkout.title"),
+            ["@org/payments", "@alice"]
+        );
+        assert_eq!(annotations.owners("home::title"), ["@org/growth"]);
+        assert!(annotations.owners("common::title").is_empty());
+    }
+}
@@ -0,0 +1,450 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    path::Path,
+    process::Command,
+};
+
+use serde_json::Value;
+
+use crate::{
+    config::Config,
+    error::{Error, Result},
+    relaxed_json,
+};
+
+const FILE_EXTS: &[&str] = &["json", "yaml", "yml"];
+
+/// The key of a map declaring a select, its value being the name of the variable to match on.
+pub const SELECT_KEY: &str = "$select";
+
+/// The key setting the count type of a plural declared as a map.
+pub const COUNT_TYPE_KEY: &str = "count_type";
+
+/// The key marking a plural declared as a map as ordinal.
+pub const ORDINAL_KEY: &str = "ordinal";
+
+/// Where to read the locale files from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    WorkingTree,
+    Revision(String),
+}
+
+impl Source {
+    pub fn new(rev: Option<String>) -> Self {
+        rev.map(Source::Revision).unwrap_or(Source::WorkingTree)
+    }
+
+    fn read_file(&self, manifest_dir: &Path, path: &Path) -> Result<Option<String>> {
+        match self {
+            Source::WorkingTree => match std::fs::read_to_string(manifest_dir.join(path)) {
+                Ok(content) => Ok(Some(content)),
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+                Err(err) => Err(Error::Io(path.to_owned(), err)),
+            },
+            Source::Revision(rev) => {
+                // `rev:./path` is resolved relative to the current directory.
+                let object = format!("{}:./{}", rev, path.display());
+                let output = Command::new("git")
+                    .args(["show", &object])
+                    .current_dir(manifest_dir)
+                    .output()
+                    .map_err(|err| Error::Git(err.to_string()))?;
+                if output.status.success() {
+                    let content = String::from_utf8(output.stdout)
+                        .map_err(|err| Error::Git(err.to_string()))?;
+                    Ok(Some(content))
+                } else {
+                    Ok(None)
+                }
+            }
+        }
+    }
+
+    fn read_locale_file(
+        &self,
+        manifest_dir: &Path,
+        path: &Path,
+        relaxed_json: bool,
+    ) -> Result<Option<Value>> {
+        for ext in FILE_EXTS {
+            let path = path.with_extension(ext);
+            let Some(content) = self.read_file(manifest_dir, &path)? else {
+                continue;
+            };
+            let value = if *ext == "json" && relaxed_json {
+                serde_json::from_str(&relaxed_json::relax(&content)).map_err(|err| err.to_string())
+            } else if *ext == "json" {
+                serde_json::from_str(&content).map_err(|err| err.to_string())
+            } else {
+                serde_yaml::from_str(&content).map_err(|err| err.to_string())
+            };
+            return value
+                .map(Some)
+                .map_err(|err| Error::LocaleFileDeser { path, err });
+        }
+        Ok(None)
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::WorkingTree => f.write_str("working tree"),
+            Source::Revision(rev) => f.write_str(rev),
+        }
+    }
+}
+
+/// The placeholders a value expects: its variables, components and if it is a plural.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Signature {
+    pub variables: BTreeSet<String>,
+    pub components: BTreeSet<String>,
+    pub plural: bool,
+}
+
+impl Signature {
+    fn add_str(&mut self, s: &str) {
+        let mut rest = s;
+        while let Some((_, after)) = rest.split_once("{{") {
+            let Some((ident, after)) = after.split_once("}}") else {
+                break;
+            };
+            let ident = ident.trim();
+            // foreign keys are not placeholders
+            if !ident.starts_with('@') && !ident.is_empty() {
+                self.variables.insert(ident.to_owned());
+            }
+            rest = after;
+        }
+
+        let mut rest = s;
+        while let Some((_, after)) = rest.split_once('<') {
+            let Some((ident, after)) = after.split_once('>') else {
+                break;
+            };
+            let ident = ident.trim().trim_end_matches('/').trim();
+            // the attributes of the tag are not part of the name, `<link href="{{ url }}">`.
+            let ident = ident.split_whitespace().next().unwrap_or_default();
+            if !ident.starts_with('/')
+                && !ident.is_empty()
+                && ident.chars().all(|c| c.is_alphanumeric() || c == '_')
+            {
+                self.components.insert(ident.to_owned());
+            }
+            rest = after;
+        }
+    }
+
+    fn add_plural_value(&mut self, value: &Value) {
+        match value {
+            Value::String(s) => self.add_str(s),
+            // the first element of a plural can be its type, but it never contain placeholders.
+            Value::Array(values) => values.iter().for_each(|v| self.add_plural_value(v)),
+            Value::Object(map) => {
+                if let Some(value) = map.get("value") {
+                    self.add_plural_value(value);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+impl Display for Signature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let plural = self.plural.then_some("count".to_owned());
+        let components = self.components.iter().map(|c| format!("<{}>", c));
+        let placeholders = plural
+            .into_iter()
+            // the count of a plural is already listed
+            .chain(
+                self.variables
+                    .iter()
+                    .filter(|v| !self.plural || *v != "count")
+                    .cloned(),
+            )
+            .chain(components)
+            .collect::<Vec<_>>();
+        write!(f, "{{{}}}", placeholders.join(", "))
+    }
+}
+
+/// A translated value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub value: Value,
+    pub signature: Signature,
+}
+
+impl Entry {
+    pub fn new(value: Value) -> Self {
+        let mut signature = Signature::default();
+        match &value {
+            Value::String(s) => signature.add_str(s),
+            Value::Array(_) => {
+                signature.plural = true;
+                signature.add_plural_value(&value);
+            }
+            Value::Object(map) => {
+                match map.get(SELECT_KEY) {
+                    Some(Value::String(variable)) => {
+                        signature.variables.insert(variable.trim().to_owned());
+                    }
+                    _ => signature.plural = true,
+                }
+                map.iter()
+                    .filter(|(key, _)| {
+                        !matches!(key.as_str(), SELECT_KEY | COUNT_TYPE_KEY | ORDINAL_KEY)
+                    })
+                    .for_each(|(_, value)| signature.add_plural_value(value));
+            }
+            _ => {}
+        }
+        Entry { value, signature }
+    }
+
+    /// A stable hash of the value, for translation memories to match it across key renames.
+    ///
+    /// It is the 64 bits FNV-1a hash of the value as written in the locale file (JSON serialized for plurals),
+    /// so it only changes with the value and is the same on every platform and compiler version.
+    pub fn content_hash(&self) -> String {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0100_0000_01b3;
+
+        let content = match &self.value {
+            Value::String(s) => s.clone(),
+            value => value.to_string(),
+        };
+        let hash = content.bytes().fold(OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+        });
+        format!("{:016x}", hash)
+    }
+
+    /// The strings of the value as written, plural branches included.
+    pub fn strings(&self) -> Vec<&str> {
+        fn push_strings<'a>(value: &'a Value, strings: &mut Vec<&'a str>) {
+            match value {
+                Value::String(s) => strings.push(s),
+                Value::Array(values) => values.iter().for_each(|v| push_strings(v, strings)),
+                Value::Object(map) => map.values().for_each(|v| push_strings(v, strings)),
+                _ => {}
+            }
+        }
+        let mut strings = vec![];
+        push_strings(&self.value, &mut strings);
+        strings
+    }
+
+    /// Concatenate all the strings of a value, plural branches included, without the interpolations
+    /// and the `$t(...)` foreign keys.
+    pub fn text(&self) -> String {
+        fn push_without(s: &str, open: &str, close: &str, text: &mut String) {
+            let mut rest = s;
+            while let Some((before, after)) = rest.split_once(open) {
+                text.push_str(before);
+                rest = after.split_once(close).map_or("", |(_, after)| after);
+            }
+            text.push_str(rest);
+        }
+
+        fn push_strings(value: &Value, text: &mut String) {
+            match value {
+                Value::String(s) => {
+                    // interpolations are not translated text
+                    let mut without_interpolations = String::new();
+                    push_without(s, "{{", "}}", &mut without_interpolations);
+                    push_without(&without_interpolations, "$t(", ")", text);
+                    text.push('\n');
+                }
+                Value::Array(values) => values.iter().for_each(|v| push_strings(v, text)),
+                Value::Object(map) => map.values().for_each(|v| push_strings(v, text)),
+                _ => {}
+            }
+        }
+        let mut text = String::new();
+        push_strings(&self.value, &mut text);
+        text
+    }
+}
+
+/// The translations of each locale, flattened by key path (`"namespace::parent.key"`).
+#[derive(Debug, Default)]
+pub struct Catalog(pub BTreeMap<String, BTreeMap<String, Entry>>);
+
+impl Catalog {
+    pub fn load(config: &Config, manifest_dir: &Path, source: &Source) -> Result<Self> {
+        let mut catalog = Catalog::default();
+        for (locale, namespace, path) in config.locale_files() {
+            let entries = catalog.0.entry(locale.to_owned()).or_default();
+            let Some(value) = source.read_locale_file(manifest_dir, &path, config.relaxed_json)?
+            else {
+                // a missing file is reported as all its keys being added or removed.
+                continue;
+            };
+            let prefix = namespace.map(|ns| format!("{}::", ns)).unwrap_or_default();
+            flatten(value, prefix, entries, &path)?;
+        }
+        Ok(catalog)
+    }
+}
+
+fn flatten(
+    value: Value,
+    path: String,
+    entries: &mut BTreeMap<String, Entry>,
+    file_path: &Path,
+) -> Result<()> {
+    let Value::Object(map) = value else {
+        return Err(Error::LocaleFileDeser {
+            path: file_path.to_owned(),
+            err: "expected a map of keys".to_owned(),
+        });
+    };
+    for (key, value) in map {
+        let key_path = if path.is_empty() || path.ends_with("::") {
+            format!("{}{}", path, key)
+        } else {
+            format!("{}.{}", path, key)
+        };
+        if value.as_object().is_some_and(|map| !is_value_map(map)) {
+            flatten(value, key_path, entries, file_path)?;
+        } else {
+            entries.insert(key_path, Entry::new(value));
+        }
+    }
+    Ok(())
+}
+
+/// The path of a key in the `i18n` module, like `namespace.parent.key`: the namespace is separated by `.`
+/// and the `-` of the names are `_`.
+pub fn ident_path(key: &str) -> String {
+    key.replace("::", ".").replace('-', "_")
+}
+
+/// Whether a map is a value, a select or a plural declared as a map, rather than subkeys.
+///
+/// A map is a plural when its first key, `count_type` aside, is a count instead of a valid identifier,
+/// or the `"ordinal": true` marker.
+pub fn is_value_map(map: &serde_json::Map<String, Value>) -> bool {
+    let mut entries = map.iter();
+    let first_key = match entries.next() {
+        Some((key, _)) if key == SELECT_KEY => return true,
+        Some((key, _)) if key == COUNT_TYPE_KEY => entries.next(),
+        entry => entry,
+    };
+    first_key.is_some_and(|(key, value)| {
+        if key == ORDINAL_KEY && value.is_boolean() {
+            return true;
+        }
+        let key = key.trim();
+        key == "_"
+            || !key.starts_with(|c: char| c.is_alphabetic() || c == '_')
+            || !key
+                .chars()
+                .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-'))
+    })
+}
+
+/// Case insensitive search of `term` as a whole word in `text`.
+pub fn contains_term(text: &str, term: &str) -> bool {
+    let text = text.to_lowercase();
+    let term = term.to_lowercase();
+    if term.is_empty() {
+        return true;
+    }
+    text.match_indices(&term).any(|(start, _)| {
+        let end = start + term.len();
+        let before = text[..start].chars().next_back();
+        let after = text[end..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signature() {
+        let entry = Entry::new(Value::String(
+            "<b>{{ count }}</b> {{ name }} {{ @other_key }}".to_owned(),
+        ));
+        assert_eq!(entry.signature.to_string(), "{count, name, <b>}");
+        assert!(!entry.signature.plural);
+
+        let entry = Entry::new(Value::String(
+            "<link href=\"/users/{{ id }}\">profile</link><br/>".to_owned(),
+        ));
+        assert_eq!(entry.signature.to_string(), "{id, <br>, <link>}");
+    }
+
+    #[test]
+    fn test_plural_signature() {
+        let value = serde_json::json!(["u32", ["zero", 0], ["{{ count }} <i>items</i>", "_"]]);
+        let entry = Entry::new(value);
+        assert!(entry.signature.plural);
+        assert_eq!(entry.signature.to_string(), "{count, <i>}");
+    }
+
+    #[test]
+    fn test_content_hash() {
+        let entry = Entry::new(Value::String("Hello".to_owned()));
+        assert_eq!(entry.content_hash(), "63f0bfacf2c00f6b");
+        assert_eq!(
+            entry.content_hash(),
+            Entry::new("Hello".into()).content_hash()
+        );
+        assert_ne!(
+            entry.content_hash(),
+            Entry::new("Hello!".into()).content_hash()
+        );
+    }
+
+    #[test]
+    fn test_contains_term() {
+        assert!(contains_term("Open the Workspace settings", "workspace"));
+        assert!(contains_term("Workspaces: {{ count }}", "Workspaces"));
+        assert!(!contains_term("Open the workspaces", "workspace"));
+        assert!(!contains_term("Open", "workspace"));
+        let entry = Entry::new(Value::String("{{ @workspace }} {{ name }}".into()));
+        assert!(!contains_term(&entry.text(), "workspace"));
+        let entry = Entry::new(Value::String("Open $t(workspace.name)".into()));
+        assert_eq!(entry.text(), "Open \n");
+    }
+
+    #[test]
+    fn test_flatten() {
+        let value = serde_json::json!({
+            "a": "a",
+            "sub": { "b": "b", "c": ["{{ count }}"] }
+        });
+        let mut entries = BTreeMap::new();
+        flatten(value, "ns::".to_owned(), &mut entries, Path::new("")).unwrap();
+        let keys = entries.keys().map(String::as_str).collect::<Vec<_>>();
+        assert_eq!(keys, ["ns::a", "ns::sub.b", "ns::sub.c"]);
+
+        let value = serde_json::json!({
+            "plural": { "count_type": "u64", "0": "none", "_": "{{ count }}" },
+            "select": { "$select": "gender", "male": "He", "other": "They" },
+            "sub": { "count_type": "f64", "other": "not a plural" },
+            "ordinal": { "ordinal": true, "one": "{{ count }}st", "other": "{{ count }}th" }
+        });
+        let mut entries = BTreeMap::new();
+        flatten(value, String::new(), &mut entries, Path::new("")).unwrap();
+        let keys = entries.keys().map(String::as_str).collect::<Vec<_>>();
+        assert_eq!(
+            keys,
+            ["ordinal", "plural", "select", "sub.count_type", "sub.other"]
+        );
+        assert!(entries["plural"].signature.plural);
+        assert_eq!(entries["select"].signature.to_string(), "{gender}");
+    }
+}
@@ -0,0 +1,366 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    io::Write,
+    path::PathBuf,
+    process::{Command, Output, Stdio},
+};
+
+use serde::Deserialize;
+
+use crate::{
+    catalog::{Catalog, Source},
+    config::Config,
+    error::{Error, Result},
+    forbidden_words::ForbiddenWords,
+    glossary::Glossary,
+    qa::Qa,
+};
+
+const VALIDATORS_FILE: &str = "validators.toml";
+/// The key of the rules applying to every locale.
+pub const ALL_LOCALES: &str = "*";
+
+/// A problem found in a value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    pub locale: String,
+    pub key: String,
+    pub message: String,
+}
+
+impl Issue {
+    pub fn new(locale: &str, key: &str, message: impl Into<String>) -> Self {
+        Issue {
+            locale: locale.to_owned(),
+            key: key.to_owned(),
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Locale {:?} at key {:?}: {}",
+            self.locale, self.key, self.message
+        )
+    }
+}
+
+/// A custom check run on every value of the catalog, like a spellcheck.
+///
+/// It is given the text of the value, plural branches included and without the interpolations,
+/// and returns the problems found, if any.
+pub trait Validator {
+    fn validate(&mut self, locale: &str, key: &str, text: &str) -> Vec<String>;
+}
+
+impl<F> Validator for F
+where
+    F: FnMut(&str, &str, &str) -> Vec<String>,
+{
+    fn validate(&mut self, locale: &str, key: &str, text: &str) -> Vec<String> {
+        self(locale, key, text)
+    }
+}
+
+/// A validator running a command for each value, the text being written to its stdin.
+///
+/// The command is run directly, without a shell, so it works the same on every platform.
+/// Each non empty line printed by the command is reported as a problem,
+/// so `hunspell -d fr_FR -l`, listing the misspelled words, can be used as is.
+#[derive(Debug, Clone)]
+pub struct CommandValidator {
+    program: String,
+    args: Vec<String>,
+}
+
+impl CommandValidator {
+    pub fn new(
+        program: impl Into<String>,
+        args: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        CommandValidator {
+            program: program.into(),
+            args: args.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Build the validator from an argv list, the first element being the program.
+    ///
+    /// Returns `None` if the list is empty.
+    pub fn from_argv(argv: impl IntoIterator<Item = impl Into<String>>) -> Option<Self> {
+        let mut argv = argv.into_iter();
+        let program = argv.next()?;
+        Some(CommandValidator::new(program, argv))
+    }
+
+    fn command_line(&self) -> String {
+        std::iter::once(&self.program)
+            .chain(&self.args)
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn run(&self, text: &str) -> std::io::Result<Output> {
+        let mut child = Command::new(&self.program)
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        let mut stdin = child.stdin.take();
+        // written from another thread, so a command printing before reading all its input can't block on a full pipe.
+        std::thread::scope(|scope| {
+            let writer = scope.spawn(move || match stdin.as_mut() {
+                Some(stdin) => stdin.write_all(text.as_bytes()),
+                None => Ok(()),
+            });
+            let output = child.wait_with_output()?;
+            match writer.join() {
+                Ok(Err(err)) if err.kind() != std::io::ErrorKind::BrokenPipe => Err(err),
+                _ => Ok(output),
+            }
+        })
+    }
+}
+
+impl Validator for CommandValidator {
+    fn validate(&mut self, _locale: &str, _key: &str, text: &str) -> Vec<String> {
+        let command_line = self.command_line();
+        match self.run(text) {
+            Ok(output) if !output.status.success() => {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                vec![format!(
+                    "{:?} failed ({}): {}",
+                    command_line,
+                    output.status,
+                    stderr.trim()
+                )]
+            }
+            Ok(output) => String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(|line| format!("{:?}: {}", command_line, line))
+                .collect(),
+            Err(err) => vec![format!("failed to run {:?}: {}", command_line, err)],
+        }
+    }
+}
+
+/// A command of the `validators.toml` file, either an argv list or a string split on whitespaces.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ValidatorCommand {
+    Argv(Vec<String>),
+    Line(String),
+}
+
+impl ValidatorCommand {
+    fn into_validator(self) -> Option<CommandValidator> {
+        match self {
+            ValidatorCommand::Argv(argv) => CommandValidator::from_argv(argv),
+            ValidatorCommand::Line(line) => CommandValidator::from_argv(line.split_whitespace()),
+        }
+    }
+}
+
+/// Run the checks on the locales of a project: the glossary, the forbidden words, the QA rules
+/// and the validators, either given with [`Checker::validator`] or declared in the `validators.toml` file.
+pub struct Checker {
+    config: Config,
+    manifest_dir: PathBuf,
+    validators: Vec<(Option<String>, Box<dyn Validator>)>,
+}
+
+impl Checker {
+    /// Load the configuration of the project in the given directory.
+    pub fn new(manifest_dir: impl Into<PathBuf>) -> Result<Self> {
+        let manifest_dir = manifest_dir.into();
+        let config = Config::new(&manifest_dir)?;
+        Ok(Checker {
+            config,
+            manifest_dir,
+            validators: vec![],
+        })
+    }
+
+    /// Add a validator for the given locale, or for every locale if `None`.
+    pub fn validator(mut self, locale: Option<&str>, validator: impl Validator + 'static) -> Self {
+        self.validators
+            .push((locale.map(str::to_owned), Box::new(validator)));
+        self
+    }
+
+    fn load_validators_file(&mut self) -> Result<()> {
+        let path = self.config.locales_dir.join(VALIDATORS_FILE);
+        let content = match std::fs::read_to_string(self.manifest_dir.join(&path)) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(Error::Io(path, err)),
+        };
+        let commands: BTreeMap<String, ValidatorCommand> = toml::de::from_str(&content)
+            .map_err(|err| Error::ValidatorsDeser(path.clone(), err.to_string()))?;
+        for (locale, command) in commands {
+            let Some(validator) = command.into_validator() else {
+                let err = format!("empty command for locale {:?}", locale);
+                return Err(Error::ValidatorsDeser(path, err));
+            };
+            let locale = (locale != ALL_LOCALES).then_some(locale);
+            self.validators.push((locale, Box::new(validator)));
+        }
+        Ok(())
+    }
+
+    fn run_validators(&mut self, catalog: &Catalog, issues: &mut Vec<Issue>) {
+        for (locale, entries) in &catalog.0 {
+            for (key, entry) in entries {
+                let text = entry.text();
+                let text = text.trim();
+                for (_, validator) in self
+                    .validators
+                    .iter_mut()
+                    .filter(|(l, _)| l.is_none() || l.as_ref() == Some(locale))
+                {
+                    for message in validator.validate(locale, key, text) {
+                        issues.push(Issue::new(locale, key, message));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Run all the checks on the working tree and return the problems found.
+    pub fn run(mut self) -> Result<Vec<Issue>> {
+        let catalog = Catalog::load(&self.config, &self.manifest_dir, &Source::WorkingTree)?;
+        let mut issues = vec![];
+
+        if let Some(glossary) = Glossary::load(&self.config, &self.manifest_dir)? {
+            issues.extend(glossary.check(&catalog, &self.config.default));
+        }
+
+        if let Some(forbidden_words) = ForbiddenWords::load(&self.config, &self.manifest_dir)? {
+            issues.extend(forbidden_words.check(&catalog));
+        }
+
+        if let Some(qa) = Qa::load(&self.config, &self.manifest_dir)? {
+            issues.extend(qa.check(&catalog, &self.config.default));
+        }
+
+        self.load_validators_file()?;
+        self.run_validators(&catalog, &mut issues);
+
+        Ok(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::Entry;
+
+    #[test]
+    fn test_validators() {
+        let mut catalog = Catalog::default();
+        for (locale, key, value) in [
+            ("en", "hello", "Helo {{ name }}"),
+            ("fr", "hello", "Bonjour {{ name }}"),
+        ] {
+            let entry = Entry::new(serde_json::Value::String(value.to_owned()));
+            let entries = catalog.0.entry(locale.to_owned()).or_default();
+            entries.insert(key.to_owned(), entry);
+        }
+        let spellcheck = |_: &str, _: &str, text: &str| {
+            text.split_whitespace()
+                .filter(|word| *word == "Helo")
+                .map(|word| format!("misspelled word {:?}", word))
+                .collect()
+        };
+        let mut checker = Checker {
+            config: Config {
+                default: "en".to_owned(),
+                locales: vec!["en".to_owned(), "fr".to_owned()],
+                namespaces: None,
+                locales_dir: PathBuf::new(),
+                file_names: Default::default(),
+                relaxed_json: false,
+            },
+            manifest_dir: PathBuf::new(),
+            validators: vec![],
+        }
+        .validator(Some("en"), spellcheck)
+        .validator(None, |_: &str, _: &str, text: &str| {
+            vec![format!("checked {:?}", text)]
+        });
+        let mut issues = vec![];
+        checker.run_validators(&catalog, &mut issues);
+        assert_eq!(
+            issues,
+            [
+                Issue::new("en", "hello", "misspelled word \"Helo\""),
+                Issue::new("en", "hello", "checked \"Helo\""),
+                Issue::new("fr", "hello", "checked \"Bonjour\""),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validator_commands() {
+        let commands: BTreeMap<String, ValidatorCommand> = toml::de::from_str(
+            r#"
+            en = "hunspell -d en_US -l"
+            fr = ["hunspell", "-d", "fr_FR", "-p", "C:\\My Dictionaries\\fr.dic", "-l"]
+            de = []
+            "#,
+        )
+        .unwrap();
+        let validators = commands
+            .into_iter()
+            .map(|(locale, command)| (locale, command.into_validator()))
+            .collect::<BTreeMap<_, _>>();
+        assert!(validators["de"].is_none());
+        let en = validators["en"].as_ref().unwrap();
+        assert_eq!(en.program, "hunspell");
+        assert_eq!(en.args, ["-d", "en_US", "-l"]);
+        let fr = validators["fr"].as_ref().unwrap();
+        assert_eq!(fr.args[3], "C:\\My Dictionaries\\fr.dic");
+        assert_eq!(
+            fr.command_line(),
+            "hunspell -d fr_FR -p C:\\My Dictionaries\\fr.dic -l"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_command_validator() {
+        let mut validator = CommandValidator::new("sh", ["-c", "cat; echo 'bad dictionary' >&2"]);
+        let issues = validator.validate("en", "hello", "Helo\nworld");
+        assert_eq!(issues.len(), 2);
+        assert!(issues[0].ends_with(": Helo"));
+
+        // a failing command is reported, even without output.
+        let mut validator = CommandValidator::from_argv(["false"]).unwrap();
+        let issues = validator.validate("en", "hello", "Hello");
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].starts_with("\"false\" failed (exit status: 1)"));
+
+        let mut validator =
+            CommandValidator::new("sh", ["-c", "echo 'missing dictionary' >&2; exit 2"]);
+        let issues = validator.validate("en", "hello", "Hello");
+        assert!(issues[0].ends_with("failed (exit status: 2): missing dictionary"));
+
+        // a value larger than the pipe buffers, echoed back before the input is fully read.
+        let text = "word ".repeat(100_000);
+        let mut validator = CommandValidator::from_argv(["cat"]).unwrap();
+        let issues = validator.validate("en", "hello", &text);
+        assert_eq!(issues.len(), 1);
+
+        let mut validator = CommandValidator::from_argv(["leptos-i18n-missing-command"]).unwrap();
+        let issues = validator.validate("en", "hello", "Hello");
+        assert!(issues[0].starts_with("failed to run"));
+    }
+}
@@ -0,0 +1,77 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::error::{Error, Result};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Config {
+    pub default: String,
+    pub locales: Vec<String>,
+    pub namespaces: Option<Vec<String>>,
+    #[serde(default = "default_locales_dir")]
+    pub locales_dir: PathBuf,
+    #[serde(default)]
+    pub file_names: HashMap<String, String>,
+    #[serde(default)]
+    pub relaxed_json: bool,
+}
+
+fn default_locales_dir() -> PathBuf {
+    PathBuf::from("./locales")
+}
+
+impl Config {
+    /// Parse the `[package.metadata.leptos-i18n]` section of the manifest in the given directory.
+    pub fn new(manifest_dir: &Path) -> Result<Self> {
+        let manifest_path = manifest_dir.join("Cargo.toml");
+        let manifest =
+            std::fs::read_to_string(&manifest_path).map_err(|err| Error::Io(manifest_path, err))?;
+
+        let Some((_, i18n_cfg)) = manifest.split_once("[package.metadata.leptos-i18n]") else {
+            return Err(Error::ConfigNotPresent);
+        };
+
+        let mut config: Config = toml::de::from_str(i18n_cfg).map_err(Error::ConfigDeser)?;
+
+        if !config.locales.contains(&config.default) {
+            config.locales.insert(0, config.default.clone());
+        }
+
+        Ok(config)
+    }
+
+    /// Return the name of the file of a locale, without extension.
+    pub fn file_name<'a>(&'a self, locale: &'a str) -> &'a str {
+        let Some(file_name) = self.file_names.get(locale) else {
+            return locale;
+        };
+        match file_name.rsplit_once('.') {
+            Some((stem, "json" | "yaml" | "yml")) => stem,
+            _ => file_name,
+        }
+    }
+
+    /// Return the paths of the locale files relative to the manifest directory, without extension,
+    /// with the locale and the namespace they belong to.
+    pub fn locale_files(&self) -> Vec<(&str, Option<&str>, PathBuf)> {
+        let mut files = vec![];
+        for locale in &self.locales {
+            let file_name = self.file_name(locale);
+            match &self.namespaces {
+                Some(namespaces) => {
+                    for namespace in namespaces {
+                        let path = self.locales_dir.join(file_name).join(namespace);
+                        files.push((locale.as_str(), Some(namespace.as_str()), path));
+                    }
+                }
+                None => files.push((locale.as_str(), None, self.locales_dir.join(file_name))),
+            }
+        }
+        files
+    }
+}
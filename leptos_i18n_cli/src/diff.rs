@@ -0,0 +1,168 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use crate::catalog::{Catalog, Entry, Signature};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Change<'a> {
+    Added(&'a Entry),
+    Removed(&'a Entry),
+    Changed { old: &'a Entry, new: &'a Entry },
+}
+
+impl Change<'_> {
+    /// Return the old and new signatures if the placeholders changed.
+    pub fn signature_change(&self) -> Option<(&Signature, &Signature)> {
+        match self {
+            Change::Changed { old, new } if old.signature != new.signature => {
+                Some((&old.signature, &new.signature))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The changes of each locale, by key path.
+pub type CatalogDiff<'a> = BTreeMap<&'a str, BTreeMap<&'a str, Change<'a>>>;
+
+pub fn diff<'a>(old: &'a Catalog, new: &'a Catalog) -> CatalogDiff<'a> {
+    static EMPTY: BTreeMap<String, Entry> = BTreeMap::new();
+    let mut diff = CatalogDiff::new();
+    let locales = old.0.keys().chain(new.0.keys());
+    for locale in locales {
+        if diff.contains_key(locale.as_str()) {
+            continue;
+        }
+        let old_entries = old.0.get(locale).unwrap_or(&EMPTY);
+        let new_entries = new.0.get(locale).unwrap_or(&EMPTY);
+        let mut changes = BTreeMap::new();
+        for (key, old_entry) in old_entries {
+            match new_entries.get(key) {
+                None => {
+                    changes.insert(key.as_str(), Change::Removed(old_entry));
+                }
+                Some(new_entry) if new_entry != old_entry => {
+                    let change = Change::Changed {
+                        old: old_entry,
+                        new: new_entry,
+                    };
+                    changes.insert(key.as_str(), change);
+                }
+                Some(_) => {}
+            }
+        }
+        for (key, new_entry) in new_entries {
+            if !old_entries.contains_key(key) {
+                changes.insert(key.as_str(), Change::Added(new_entry));
+            }
+        }
+        diff.insert(locale.as_str(), changes);
+    }
+    diff
+}
+
+fn write_signature(report: &mut String, signature: &Signature) {
+    if !signature.is_empty() {
+        let _ = write!(report, " {}", signature);
+    }
+}
+
+/// Generate a human readable report of the changes.
+pub fn report(diff: &CatalogDiff) -> String {
+    let mut report = String::new();
+    let (mut added, mut removed, mut changed, mut signature_changed) = (0, 0, 0, 0);
+
+    // writing to a String can't fail
+    for (locale, changes) in diff {
+        if changes.is_empty() {
+            continue;
+        }
+        let _ = writeln!(report, "Locale {:?}:", locale);
+        for (key, change) in changes {
+            match change {
+                Change::Added(entry) => {
+                    added += 1;
+                    let _ = write!(report, "  + {}", key);
+                    write_signature(&mut report, &entry.signature);
+                }
+                Change::Removed(entry) => {
+                    removed += 1;
+                    let _ = write!(report, "  - {}", key);
+                    write_signature(&mut report, &entry.signature);
+                }
+                change => match change.signature_change() {
+                    Some((old, new)) => {
+                        changed += 1;
+                        signature_changed += 1;
+                        let _ = write!(
+                            report,
+                            "  ! {}: placeholders changed {} -> {}",
+                            key, old, new
+                        );
+                    }
+                    None => {
+                        changed += 1;
+                        let _ = write!(report, "  ~ {}", key);
+                    }
+                },
+            }
+            report.push('\n');
+        }
+        report.push('\n');
+    }
+
+    let _ = writeln!(
+        report,
+        "{} added, {} removed, {} changed ({} with placeholder changes)",
+        added, removed, changed, signature_changed
+    );
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn catalog(entries: &[(&str, &str)]) -> Catalog {
+        let locale = entries
+            .iter()
+            .map(|(key, value)| {
+                let value = serde_json::Value::String(value.to_string());
+                (key.to_string(), Entry::new(value))
+            })
+            .collect();
+        let mut catalog = Catalog::default();
+        catalog.0.insert("en".to_owned(), locale);
+        catalog
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = catalog(&[
+            ("kept", "kept"),
+            ("removed", "removed"),
+            ("reworded", "old wording"),
+            ("count", "{{ count }} items"),
+        ]);
+        let new = catalog(&[
+            ("kept", "kept"),
+            ("added", "added {{ name }}"),
+            ("reworded", "new wording"),
+            ("count", "<b>{{ count }}</b> items"),
+        ]);
+        let diff = diff(&old, &new);
+        let report = report(&diff);
+        assert_eq!(
+            report,
+            concat!(
+                "Locale \"en\":\n",
+                "  + added {name}\n",
+                "  ! count: placeholders changed {count} -> {count, <b>}\n",
+                "  - removed\n",
+                "  ~ reworded\n",
+                "\n",
+                "1 added, 1 removed, 2 changed (1 with placeholder changes)\n",
+            )
+        );
+    }
+}
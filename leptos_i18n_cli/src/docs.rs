@@ -0,0 +1,212 @@
+use std::{collections::BTreeSet, fmt::Write};
+
+use serde_json::Value;
+
+use crate::{annotations::Annotations, catalog::Catalog, config::Config};
+
+const STYLE: &str = "\
+body { font-family: sans-serif; margin: 2em auto; max-width: 60em; color: #222; }
+nav ul { columns: 3; font-size: 0.9em; }
+section { border-top: 1px solid #ccc; padding: 1em 0; }
+h2 { font-family: monospace; font-size: 1.1em; }
+.description { font-style: italic; }
+.placeholders code { background: #eee; padding: 0 0.3em; margin-right: 0.3em; }
+table { border-collapse: collapse; width: 100%; }
+th, td { border: 1px solid #ddd; padding: 0.4em; text-align: left; vertical-align: top; }
+th { width: 6em; }
+td.missing { color: #b00; }
+pre { margin: 0; white-space: pre-wrap; }
+.screenshot { border: 1px dashed #aaa; color: #888; padding: 1em; margin: 1em 0 0; }
+.screenshot img { max-width: 100%; }";
+
+/// Escape the HTML (and XML) special characters.
+pub(crate) fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn render_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => escape(s),
+        // plurals are shown as written in the locale files.
+        value => {
+            let json = serde_json::to_string_pretty(value).unwrap_or_default();
+            format!("<pre>{}</pre>", escape(&json))
+        }
+    }
+}
+
+fn is_image(screenshot: &str) -> bool {
+    let path = screenshot.split(['?', '#']).next().unwrap_or_default();
+    let ext = path.rsplit_once('.').map_or("", |(_, ext)| ext);
+    ["png", "jpg", "jpeg", "gif", "webp", "svg"]
+        .iter()
+        .any(|image_ext| ext.eq_ignore_ascii_case(image_ext))
+}
+
+/// Render the catalog as a standalone HTML page, listing for each key its description,
+/// its placeholders, its value in every locale and its screenshots, or a slot for one.
+pub fn render(catalog: &Catalog, config: &Config, annotations: &Annotations) -> String {
+    let keys = catalog
+        .0
+        .values()
+        .flat_map(|entries| entries.keys())
+        .collect::<BTreeSet<_>>();
+
+    let mut html = String::new();
+    // writing to a `String` never fails.
+    let _ = write!(
+        html,
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Translations</title>\n<style>\n{}\n</style>\n</head>\n<body>\n<h1>Translations</h1>\n<p>{} key(s), {} locale(s): {}</p>\n",
+        STYLE,
+        keys.len(),
+        config.locales.len(),
+        escape(&config.locales.join(", "))
+    );
+
+    html.push_str("<nav>\n<ul>\n");
+    for key in &keys {
+        let key = escape(key);
+        let _ = writeln!(html, "<li><a href=\"#{0}\">{0}</a></li>", key);
+    }
+    html.push_str("</ul>\n</nav>\n");
+
+    for key in keys {
+        let escaped_key = escape(key);
+        let _ = writeln!(html, "<section id=\"{0}\">\n<h2>{0}</h2>", escaped_key);
+        let annotation = annotations.get(key);
+        if let Some(description) = annotation.and_then(|a| a.description.as_ref()) {
+            let _ = writeln!(html, "<p class=\"description\">{}</p>", escape(description));
+        }
+
+        let signature = catalog
+            .0
+            .get(&config.default)
+            .and_then(|entries| entries.get(key))
+            .or_else(|| catalog.0.values().find_map(|entries| entries.get(key)))
+            .map(|entry| &entry.signature);
+        if let Some(signature) = signature.filter(|signature| !signature.is_empty()) {
+            html.push_str("<p class=\"placeholders\">Placeholders: ");
+            if signature.plural {
+                html.push_str("<code>count</code>");
+            }
+            for variable in &signature.variables {
+                // the count of a plural is already listed
+                if !signature.plural || variable != "count" {
+                    let _ = write!(html, "<code>{{{{ {} }}}}</code>", escape(variable));
+                }
+            }
+            for component in &signature.components {
+                let _ = write!(html, "<code>&lt;{}&gt;</code>", escape(component));
+            }
+            html.push_str("</p>\n");
+        }
+
+        html.push_str("<table>\n");
+        for locale in &config.locales {
+            let entry = catalog.0.get(locale).and_then(|entries| entries.get(key));
+            let _ = match entry {
+                Some(entry) => writeln!(
+                    html,
+                    "<tr><th>{}</th><td>{}</td></tr>",
+                    escape(locale),
+                    render_value(&entry.value)
+                ),
+                None => writeln!(
+                    html,
+                    "<tr><th>{}</th><td class=\"missing\">missing</td></tr>",
+                    escape(locale)
+                ),
+            };
+        }
+        html.push_str("</table>\n");
+        let screenshots = annotation.map_or(&[][..], |a| &a.screenshots);
+        if screenshots.is_empty() {
+            let _ = writeln!(
+                html,
+                "<figure class=\"screenshot\" data-key=\"{}\">No screenshot</figure>",
+                escaped_key
+            );
+        }
+        for screenshot in screenshots {
+            let screenshot = escape(screenshot);
+            // links to design tools can't be embedded.
+            let _ = if is_image(&screenshot) {
+                writeln!(
+                    html,
+                    "<figure class=\"screenshot\" data-key=\"{0}\"><a href=\"{1}\"><img src=\"{1}\" alt=\"{0}\"></a></figure>",
+                    escaped_key, screenshot
+                )
+            } else {
+                writeln!(
+                    html,
+                    "<figure class=\"screenshot\" data-key=\"{0}\"><a href=\"{1}\">{1}</a></figure>",
+                    escaped_key, screenshot
+                )
+            };
+        }
+        html.push_str("</section>\n");
+    }
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::{annotations::Annotation, catalog::Entry};
+
+    #[test]
+    fn test_render() {
+        let config = Config {
+            default: "en".to_owned(),
+            locales: vec!["en".to_owned(), "fr".to_owned()],
+            namespaces: None,
+            locales_dir: "./locales".into(),
+            file_names: Default::default(),
+            relaxed_json: false,
+        };
+        let mut catalog = Catalog::default();
+        catalog.0.insert(
+            "en".to_owned(),
+            BTreeMap::from([(
+                "greeting".to_owned(),
+                Entry::new(Value::String("Hello <b>{{ name }}</b> & co".to_owned())),
+            )]),
+        );
+        catalog.0.insert("fr".to_owned(), BTreeMap::new());
+        let annotations = Annotations(BTreeMap::from([(
+            "greeting".to_owned(),
+            Annotation {
+                description: Some("Shown on the home page".to_owned()),
+                screenshots: vec!["screens/home.png".to_owned()],
+                owners: vec![],
+            },
+        )]));
+
+        let html = render(&catalog, &config, &annotations);
+        assert!(html.contains("<h2>greeting</h2>"));
+        assert!(html.contains("<p class=\"description\">Shown on the home page</p>"));
+        assert!(html.contains("<code>{{ name }}</code><code>&lt;b&gt;</code>"));
+        assert!(html
+            .contains("<tr><th>en</th><td>Hello &lt;b&gt;{{ name }}&lt;/b&gt; &amp; co</td></tr>"));
+        assert!(html.contains("<tr><th>fr</th><td class=\"missing\">missing</td></tr>"));
+        assert!(html.contains("<img src=\"screens/home.png\" alt=\"greeting\">"));
+        assert!(!html.contains("No screenshot"));
+        assert!(is_image("https://example.com/home.PNG?v=2"));
+        assert!(!is_image("https://www.figma.com/file/abc"));
+    }
+}
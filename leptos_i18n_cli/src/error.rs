@@ -0,0 +1,98 @@
+use std::{fmt::Display, path::PathBuf};
+
+#[derive(Debug)]
+pub enum Error {
+    Usage(String),
+    Io(PathBuf, std::io::Error),
+    Write(PathBuf, std::io::Error),
+    ConfigNotPresent,
+    ConfigDeser(toml::de::Error),
+    Git(String),
+    LocaleFileDeser { path: PathBuf, err: String },
+    GlossaryDeser(PathBuf, String),
+    ForbiddenWordsDeser(PathBuf, String),
+    ValidatorsDeser(PathBuf, String),
+    QaDeser(PathBuf, String),
+    AnnotationsDeser(PathBuf, String),
+    UsageDeser(PathBuf, String),
+    CheckFailed(usize),
+    InlineConfig(PathBuf, String),
+    ConfigInvalid(usize),
+    UnusedKeys(usize),
+    UnknownLocale(String),
+    UnknownNamespace(String),
+    MissingNamespace,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Usage(msg) => f.write_str(msg),
+            Error::Io(path, err) => write!(f, "Could not read {:?}: {}", path, err),
+            Error::Write(path, err) => write!(f, "Could not write {:?}: {}", path, err),
+            Error::ConfigNotPresent => {
+                write!(f, "Could not found \"[package.metadata.leptos-i18n]\" in cargo manifest (Cargo.toml)")
+            }
+            Error::ConfigDeser(err) => {
+                write!(f, "Parsing of cargo manifest (Cargo.toml) failed: {}", err)
+            }
+            Error::Git(msg) => write!(f, "git command failed: {}", msg),
+            Error::LocaleFileDeser { path, err } => {
+                write!(f, "Parsing of file {:?} failed: {}", path, err)
+            }
+            Error::GlossaryDeser(path, err) => {
+                write!(f, "Parsing of glossary file {:?} failed: {}", path, err)
+            }
+            Error::ForbiddenWordsDeser(path, err) => {
+                write!(
+                    f,
+                    "Parsing of forbidden words file {:?} failed: {}",
+                    path, err
+                )
+            }
+            Error::ValidatorsDeser(path, err) => {
+                write!(f, "Parsing of validators file {:?} failed: {}", path, err)
+            }
+            Error::QaDeser(path, err) => {
+                write!(f, "Parsing of QA rules file {:?} failed: {}", path, err)
+            }
+            Error::AnnotationsDeser(path, err) => {
+                write!(f, "Parsing of annotations file {:?} failed: {}", path, err)
+            }
+            Error::UsageDeser(path, err) => {
+                write!(f, "Parsing of usage file {:?} failed: {}", path, err)
+            }
+            Error::CheckFailed(count) => write!(f, "Check failed with {} issue(s)", count),
+            Error::InlineConfig(path, err) => {
+                write!(
+                    f,
+                    "Parsing of the configuration in {:?} failed: {}",
+                    path, err
+                )
+            }
+            Error::ConfigInvalid(count) => {
+                write!(f, "The configuration has {} issue(s)", count)
+            }
+            Error::UnusedKeys(count) => write!(f, "Found {} unused key(s)", count),
+            Error::UnknownLocale(locale) => {
+                write!(
+                    f,
+                    "Locale {:?} is not declared in the configuration",
+                    locale
+                )
+            }
+            Error::UnknownNamespace(namespace) => write!(
+                f,
+                "Namespace {:?} is not declared in the configuration",
+                namespace
+            ),
+            Error::MissingNamespace => {
+                write!(f, "The project uses namespaces, a namespace must be given")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub type Result<T, E = Error> = core::result::Result<T, E>;
@@ -0,0 +1,233 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+};
+
+use serde_json::{Map, Value};
+
+use crate::{
+    catalog::{ident_path, is_value_map},
+    config::Config,
+    error::{Error, Result},
+    import::{write_file, Format},
+    relaxed_json,
+    scan::Call,
+};
+
+/// The variables and components given to a key by all its call sites.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Placeholders {
+    pub variables: BTreeSet<String>,
+    pub components: BTreeSet<String>,
+}
+
+/// The result of an extraction.
+#[derive(Debug, Default)]
+pub struct Extraction {
+    /// The keys added to each locale file of the default locale, the paths being relative to the manifest directory.
+    pub added: BTreeMap<PathBuf, Vec<String>>,
+    /// The keys that could not be added, with the reason.
+    pub skipped: Vec<(String, String)>,
+}
+
+/// Merge the keys of the `t!` calls with the placeholders given to them, by key path of the `i18n` module.
+pub fn keys(calls: &[Call]) -> BTreeMap<String, Placeholders> {
+    let mut keys: BTreeMap<String, Placeholders> = BTreeMap::new();
+    for call in calls {
+        let Call::Key {
+            path,
+            variables,
+            components,
+        } = call
+        else {
+            continue;
+        };
+        let placeholders = keys.entry(ident_path(path)).or_default();
+        placeholders.variables.extend(variables.iter().cloned());
+        placeholders.components.extend(components.iter().cloned());
+    }
+    keys
+}
+
+/// The value of a new key: its name followed by its placeholders, to be replaced by the actual text.
+fn skeleton(name: &str, placeholders: &Placeholders) -> String {
+    let mut value = name.replace('_', " ");
+    for variable in &placeholders.variables {
+        value.push_str(&format!(" {{{{ {} }}}}", variable));
+    }
+    for component in &placeholders.components {
+        value.push_str(&format!(" <{0}></{0}>", component));
+    }
+    value
+}
+
+/// Insert the key at the given path if it is missing, matching the existing keys by their name in the `i18n` module.
+///
+/// Return `Ok(true)` if the key was added, `Ok(false)` if it was already present.
+fn insert(
+    map: &mut Map<String, Value>,
+    segments: &[&str],
+    placeholders: &Placeholders,
+) -> Result<bool, String> {
+    let Some((segment, rest)) = segments.split_first() else {
+        return Ok(false);
+    };
+    let existing = map.keys().find(|key| ident_path(key) == *segment).cloned();
+    let key = existing.unwrap_or_else(|| segment.to_string());
+    match map.get_mut(&key) {
+        None if rest.is_empty() => {
+            map.insert(key, Value::String(skeleton(segment, placeholders)));
+            Ok(true)
+        }
+        None => {
+            let mut subkeys = Map::new();
+            insert(&mut subkeys, rest, placeholders)?;
+            map.insert(key, Value::Object(subkeys));
+            Ok(true)
+        }
+        Some(_) if rest.is_empty() => Ok(false),
+        Some(Value::Object(subkeys)) if !is_value_map(subkeys) => {
+            insert(subkeys, rest, placeholders)
+        }
+        Some(_) => Err(format!("{:?} is a value, not subkeys", key)),
+    }
+}
+
+/// Add the keys missing from the locale files of the default locale, with a skeleton value listing
+/// their placeholders.
+///
+/// The files keep their format, a missing file is created as JSON.
+pub fn extract(
+    config: &Config,
+    manifest_dir: &Path,
+    keys: &BTreeMap<String, Placeholders>,
+) -> Result<Extraction> {
+    let mut extraction = Extraction::default();
+    let mut files: BTreeMap<Option<&str>, Vec<(&String, &Placeholders)>> = BTreeMap::new();
+    for (key, placeholders) in keys {
+        let namespace = match &config.namespaces {
+            Some(namespaces) => {
+                let namespace = key.split('.').next().unwrap_or_default();
+                if !key.contains('.') || !namespaces.iter().any(|ns| ns == namespace) {
+                    let reason = format!("namespace {:?} is not declared", namespace);
+                    extraction.skipped.push((key.clone(), reason));
+                    continue;
+                }
+                Some(namespace)
+            }
+            None => None,
+        };
+        files
+            .entry(namespace)
+            .or_default()
+            .push((key, placeholders));
+    }
+
+    for (namespace, keys) in files {
+        let mut path = config.locales_dir.join(config.file_name(&config.default));
+        if let Some(namespace) = namespace {
+            path.push(namespace);
+        }
+        let path = ["json", "yaml", "yml"]
+            .into_iter()
+            .map(|ext| path.with_extension(ext))
+            .find(|path| manifest_dir.join(path).exists())
+            .unwrap_or_else(|| path.with_extension("json"));
+        let format = Format::new(&path);
+
+        let mut value = match std::fs::read_to_string(manifest_dir.join(&path)) {
+            Ok(content) if format == Format::Json && config.relaxed_json => {
+                Format::Json.parse(&relaxed_json::relax(&content))
+            }
+            Ok(content) => format.parse(&content),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Value::Object(Map::new())),
+            Err(err) => return Err(Error::Io(path, err)),
+        }
+        .map_err(|err| Error::LocaleFileDeser {
+            path: path.clone(),
+            err,
+        })?;
+        let Value::Object(map) = &mut value else {
+            return Err(Error::LocaleFileDeser {
+                path,
+                err: "expected a map of keys".to_owned(),
+            });
+        };
+
+        let mut added = vec![];
+        for (key, placeholders) in keys {
+            let segments: Vec<&str> = key.split('.').skip(namespace.iter().len()).collect();
+            match insert(map, &segments, placeholders) {
+                Ok(true) => added.push(key.clone()),
+                Ok(false) => {}
+                Err(reason) => extraction.skipped.push((key.clone(), reason)),
+            }
+        }
+        if added.is_empty() {
+            continue;
+        }
+        let content = format
+            .serialize(&value)
+            .map_err(|err| Error::LocaleFileDeser {
+                path: path.clone(),
+                err,
+            })?;
+        write_file(&manifest_dir.join(&path), &content)?;
+        extraction.added.insert(path, added);
+    }
+    Ok(extraction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scan::calls;
+
+    #[test]
+    fn test_insert() {
+        let keys = keys(&calls(
+            r#"
+            t!(i18n, home.greeting, name = user.name(), <b>);
+            t!(i18n, home.greeting, count = || 2);
+            t!(i18n, home.sub_title);
+            t!(i18n, click_count, count);
+            t!(i18n, title.main);
+            "#,
+        ));
+        let mut value = serde_json::json!({
+            "title": "Title",
+            "home": { "sub-title": "Welcome" },
+        });
+        let map = value.as_object_mut().unwrap();
+        let results: Vec<_> = keys
+            .iter()
+            .map(|(key, placeholders)| {
+                let segments: Vec<&str> = key.split('.').collect();
+                (key.as_str(), insert(map, &segments, placeholders))
+            })
+            .collect();
+        assert_eq!(
+            results,
+            [
+                ("click_count", Ok(true)),
+                ("home.greeting", Ok(true)),
+                ("home.sub_title", Ok(false)),
+                (
+                    "title.main",
+                    Err("\"title\" is a value, not subkeys".to_owned())
+                ),
+            ]
+        );
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "title": "Title",
+                "home": {
+                    "sub-title": "Welcome",
+                    "greeting": "greeting {{ count }} {{ name }} <b></b>",
+                },
+                "click_count": "click count {{ count }}",
+            })
+        );
+    }
+}
@@ -0,0 +1,559 @@
+//! Export the catalog as a [Fluent](https://projectfluent.org/fluent/guide/) (`.ftl`) resource, so services using
+//! other Fluent based tools can consume the same catalog:
+//!
+//! - variables `{{ name }}` become `{ $name }`, formatted numbers and dates use the `NUMBER` and `DATETIME` functions,
+//! - foreign keys `{{ @key }}` and `$t(key)` become message references,
+//! - plurals become selectors on `$count`, with their counts and plural categories as variants, the fallback being `*[other]`,
+//!   ordinal plurals selecting on `NUMBER($count, type: "ordinal")`,
+//! - selects become selectors on their variable,
+//! - subkeys become attributes of the message of their parent key, the parents of deeper keys and the namespace
+//!   being joined with `-`: `namespace::parent.sub.key` is the attribute `key` of the message `namespace-parent-sub`.
+//!
+//! Components are kept as text, and the descriptions of the keys are written as comments.
+//! Ranges, plural constants and foreign keys with arguments have no Fluent equivalent, the keys using them are skipped.
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Write,
+};
+
+use serde_json::Value;
+
+use crate::{
+    annotations::Annotations,
+    catalog::{Catalog, COUNT_TYPE_KEY, ORDINAL_KEY, SELECT_KEY},
+    config::Config,
+    error::{Error, Result},
+};
+
+const PLURAL_CATEGORIES: &[&str] = &["zero", "one", "two", "few", "many", "other"];
+
+/// The key of the default variant, the fallback of plurals and selects.
+const OTHER: &str = "other";
+
+/// The result of an export.
+#[derive(Debug)]
+pub struct Export {
+    /// The Fluent resource.
+    pub content: String,
+    /// The keys using constructs without a Fluent equivalent, that are not exported.
+    pub skipped: Vec<String>,
+}
+
+#[derive(Default)]
+struct Message {
+    comments: Vec<String>,
+    value: Option<String>,
+    attributes: Vec<(String, String)>,
+}
+
+/// Export the values of a locale as a Fluent resource.
+pub fn export(
+    catalog: &Catalog,
+    config: &Config,
+    annotations: &Annotations,
+    locale: &str,
+) -> Result<Export> {
+    if !config.locales.iter().any(|l| l == locale) {
+        return Err(Error::UnknownLocale(locale.to_owned()));
+    }
+    let empty = Default::default();
+    let entries = catalog.0.get(locale).unwrap_or(&empty);
+
+    let mut messages = BTreeMap::<String, Message>::new();
+    let mut skipped = vec![];
+    for (key, entry) in entries {
+        // the key falls back to the default locale, as it would with the Fluent fallback chain.
+        if entry.value.is_null() {
+            continue;
+        }
+        let (Some((id, attribute)), Some(pattern)) = (message_id(key), pattern(&entry.value))
+        else {
+            skipped.push(key.clone());
+            continue;
+        };
+        let message = messages.entry(id).or_default();
+        let description = annotations
+            .get(key)
+            .and_then(|annotation| annotation.description.as_deref());
+        match attribute {
+            Some(attribute) => {
+                if let Some(description) = description {
+                    message
+                        .comments
+                        .push(format!(".{}: {}", attribute, description));
+                }
+                message.attributes.push((attribute, pattern));
+            }
+            None => {
+                message.comments.extend(description.map(str::to_owned));
+                message.value = Some(pattern);
+            }
+        }
+    }
+
+    let mut content = String::new();
+    for (id, message) in messages {
+        if !content.is_empty() {
+            content.push('\n');
+        }
+        for line in message.comments.iter().flat_map(|comment| comment.lines()) {
+            // writing to a `String` never fails.
+            let _ = writeln!(content, "# {}", line);
+        }
+        let _ = write!(content, "{} =", id);
+        if let Some(value) = &message.value {
+            write_pattern(&mut content, value, "    ");
+        }
+        content.push('\n');
+        for (attribute, pattern) in &message.attributes {
+            let _ = write!(content, "    .{} =", attribute);
+            write_pattern(&mut content, pattern, "        ");
+            content.push('\n');
+        }
+    }
+
+    Ok(Export { content, skipped })
+}
+
+/// Write a pattern after the `=` of its message or attribute, on the next lines if it spans multiple lines.
+fn write_pattern(content: &mut String, pattern: &str, indent: &str) {
+    if !pattern.contains('\n') {
+        content.push(' ');
+        content.push_str(pattern);
+        return;
+    }
+    for line in pattern.lines() {
+        content.push('\n');
+        if !line.is_empty() {
+            content.push_str(indent);
+            content.push_str(line);
+        }
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'))
+}
+
+/// Split a key path (`"namespace::parent.key"`) in the identifier of its message and its attribute.
+fn message_id(key: &str) -> Option<(String, Option<String>)> {
+    let (namespace, path) = match key.split_once("::") {
+        Some((namespace, path)) => (Some(namespace), path),
+        None => (None, key),
+    };
+    let mut segments = path.split('.').map(str::trim).collect::<Vec<_>>();
+    let attribute = if segments.len() > 1 {
+        segments.pop()
+    } else {
+        None
+    };
+    let id = namespace.into_iter().chain(segments).collect::<Vec<_>>();
+    let id = id.join("-");
+    let is_valid = is_identifier(&id) && attribute.into_iter().all(is_identifier);
+    is_valid.then(|| (id, attribute.map(str::to_owned)))
+}
+
+/// Convert a value to a Fluent pattern, `None` if it can't be expressed.
+fn pattern(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => text_pattern(s),
+        Value::Array(plurals) => {
+            let mut variants = vec![];
+            for plural in plurals {
+                match plural {
+                    // the type of the count.
+                    Value::String(_) => {}
+                    Value::Array(values) => {
+                        let (value, counts) = values.split_first()?;
+                        push_variants(&mut variants, value, counts)?;
+                    }
+                    Value::Object(map) => {
+                        let counts = map.get("count").cloned().into_iter().collect::<Vec<_>>();
+                        push_variants(&mut variants, map.get("value")?, &counts)?;
+                    }
+                    _ => return None,
+                }
+            }
+            selector("$count", variants)
+        }
+        Value::Object(map) => match map.get(SELECT_KEY) {
+            Some(Value::String(variable)) => {
+                let variants = map
+                    .iter()
+                    .filter(|(branch, _)| *branch != SELECT_KEY)
+                    .map(|(branch, value)| {
+                        let branch = branch.trim();
+                        is_identifier(branch).then_some(())?;
+                        Some((branch.to_owned(), text_pattern(value.as_str()?)?))
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                let variable = variable.trim();
+                is_identifier(variable).then_some(())?;
+                selector(&format!("${}", variable), variants)
+            }
+            Some(_) => None,
+            None => {
+                let mut variants = vec![];
+                for (count, value) in map {
+                    if count != COUNT_TYPE_KEY && count != ORDINAL_KEY {
+                        push_variants(&mut variants, value, &[Value::String(count.clone())])?;
+                    }
+                }
+                match map.get(ORDINAL_KEY) {
+                    Some(Value::Bool(true)) => {
+                        selector("NUMBER($count, type: \"ordinal\")", variants)
+                    }
+                    _ => selector("$count", variants),
+                }
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Push the variants of a plural branch, one for each of its counts.
+fn push_variants(
+    variants: &mut Vec<(String, String)>,
+    value: &Value,
+    counts: &[Value],
+) -> Option<()> {
+    let pattern = text_pattern(value.as_str()?)?;
+    if counts.is_empty() {
+        variants.push((OTHER.to_owned(), pattern));
+        return Some(());
+    }
+    let counts = counts.iter().flat_map(|count| match count {
+        Value::Array(counts) => counts.as_slice(),
+        count => std::slice::from_ref(count),
+    });
+    for count in counts {
+        let count = match count {
+            Value::Number(count) => count.to_string(),
+            Value::String(count) => count.clone(),
+            _ => return None,
+        };
+        for count in count.split('|') {
+            variants.push((variant_key(count.trim())?, pattern.clone()));
+        }
+    }
+    Some(())
+}
+
+/// The variant key of a count, `None` for ranges and plural constants.
+fn variant_key(count: &str) -> Option<String> {
+    if matches!(count, "_" | "..") {
+        return Some(OTHER.to_owned());
+    }
+    let digits = count.strip_prefix('-').unwrap_or(count);
+    let is_number = !digits.is_empty()
+        && !digits.starts_with('.')
+        && !digits.ends_with('.')
+        && digits.matches('.').count() <= 1
+        && digits.bytes().all(|b| b.is_ascii_digit() || b == b'.');
+    (is_number || PLURAL_CATEGORIES.contains(&count)).then(|| count.to_owned())
+}
+
+fn selector(expression: &str, variants: Vec<(String, String)>) -> Option<String> {
+    // the first branch matching a count is rendered, the others are unreachable.
+    let mut keys = HashSet::new();
+    let variants = variants
+        .into_iter()
+        .filter(|(key, _)| keys.insert(key.clone()))
+        .collect::<Vec<_>>();
+    let default = variants
+        .iter()
+        .position(|(key, _)| key == OTHER)
+        .or_else(|| variants.len().checked_sub(1))?;
+
+    let mut selector = format!("{{ {} ->", expression);
+    for (i, (key, pattern)) in variants.iter().enumerate() {
+        let marker = if i == default { "   *" } else { "    " };
+        let _ = write!(
+            selector,
+            "\n{}[{}] {}",
+            marker,
+            key,
+            pattern.replace('\n', "\n        ")
+        );
+    }
+    selector.push_str("\n}");
+    Some(selector)
+}
+
+/// Convert a string value to a Fluent pattern, `None` if it can't be expressed.
+fn text_pattern(s: &str) -> Option<String> {
+    let mut pattern = String::new();
+    let mut rest = s;
+    loop {
+        let interpolation = rest.find("{{").map(|start| (start, "{{", "}}"));
+        let foreign_key = rest.find("$t(").map(|start| (start, "$t(", ")"));
+        let next = match (interpolation, foreign_key) {
+            (Some(a), Some(b)) => Some(if a.0 < b.0 { a } else { b }),
+            (a, b) => a.or(b),
+        };
+        let Some((start, open, close)) = next else {
+            break;
+        };
+        let Some((inner, after)) = rest[start + open.len()..].split_once(close) else {
+            break;
+        };
+        push_text(&mut pattern, &rest[..start]);
+        match (open, inner.trim().strip_prefix('@')) {
+            ("$t(", _) => pattern.push_str(&reference(inner)?),
+            (_, Some(path)) => pattern.push_str(&reference(path)?),
+            _ => {
+                let (name, formatter) = match inner.split_once(',') {
+                    Some((name, formatter)) => (name.trim(), Some(formatter.trim())),
+                    None => (inner.trim(), None),
+                };
+                let variable = format!("${}", name);
+                match formatter.map(|formatter| function(&variable, formatter)) {
+                    None if is_identifier(name) => {
+                        let _ = write!(pattern, "{{ {} }}", variable);
+                    }
+                    Some(Some(function)) if is_identifier(name) => {
+                        let _ = write!(pattern, "{{ {} }}", function);
+                    }
+                    // unknown formatters stay in the text.
+                    Some(None) => push_text(&mut pattern, &rest[start..rest.len() - after.len()]),
+                    _ => return None,
+                }
+            }
+        }
+        rest = after;
+    }
+    push_text(&mut pattern, rest);
+
+    if pattern.is_empty() {
+        return Some("{ \"\" }".to_owned());
+    }
+    // the lines starting with those characters would be parsed as variants or attributes.
+    let mut lines = pattern.split('\n');
+    let mut escaped = lines.next().unwrap_or_default().to_owned();
+    for line in lines {
+        escaped.push('\n');
+        let text = line.trim_start();
+        escaped.push_str(&line[..line.len() - text.len()]);
+        match text.chars().next() {
+            Some(c @ ('[' | '*' | '.')) => {
+                let _ = write!(escaped, "{{ \"{}\" }}{}", c, &text[1..]);
+            }
+            _ => escaped.push_str(text),
+        }
+    }
+    Some(escaped)
+}
+
+/// Push text to a pattern, escaping the braces.
+fn push_text(pattern: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '{' | '}' => {
+                let _ = write!(pattern, "{{ \"{}\" }}", c);
+            }
+            c => pattern.push(c),
+        }
+    }
+}
+
+/// The Fluent function equivalent to a formatter, `number`, `currency(USD)` or `date(long)`.
+fn function(variable: &str, formatter: &str) -> Option<String> {
+    let (name, arg) = match formatter.split_once('(') {
+        Some((name, arg)) => (name.trim(), Some(arg.strip_suffix(')')?.trim())),
+        None => (formatter, None),
+    };
+    match (name, arg) {
+        ("number", None) => Some(format!("NUMBER({})", variable)),
+        ("currency", Some(code))
+            if code.len() == 3 && code.bytes().all(|b| b.is_ascii_alphabetic()) =>
+        {
+            Some(format!(
+                "NUMBER({}, style: \"currency\", currency: \"{}\")",
+                variable,
+                code.to_ascii_uppercase()
+            ))
+        }
+        ("date", None) => Some(format!("DATETIME({}, dateStyle: \"short\")", variable)),
+        ("date", Some(length @ ("short" | "long"))) => {
+            Some(format!("DATETIME({}, dateStyle: \"{}\")", variable, length))
+        }
+        ("time", None) => Some(format!("DATETIME({}, timeStyle: \"short\")", variable)),
+        ("datetime", None) => Some(format!(
+            "DATETIME({}, dateStyle: \"short\", timeStyle: \"short\")",
+            variable
+        )),
+        ("datetime", Some(length @ ("short" | "long"))) => Some(format!(
+            "DATETIME({}, dateStyle: \"{}\", timeStyle: \"short\")",
+            variable, length
+        )),
+        _ => None,
+    }
+}
+
+/// Convert the path of a foreign key to a message reference, `None` if it has arguments.
+fn reference(path: &str) -> Option<String> {
+    let path = path.trim().trim_matches(['"', '\'']);
+    if path.contains(',') {
+        return None;
+    }
+    match message_id(path)? {
+        (id, Some(attribute)) => Some(format!("{{ {}.{} }}", id, attribute)),
+        (id, None) => Some(format!("{{ {} }}", id)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::{annotations::Annotation, catalog::Entry};
+
+    fn config() -> Config {
+        Config {
+            default: "en".to_owned(),
+            locales: vec!["en".to_owned(), "fr".to_owned()],
+            namespaces: None,
+            locales_dir: "./locales".into(),
+            file_names: Default::default(),
+            relaxed_json: false,
+        }
+    }
+
+    #[test]
+    fn test_export() {
+        let mut catalog = Catalog::default();
+        let entries = [
+            (
+                "hello",
+                serde_json::json!("Hello {{ name }}, see <b>{{ @about }}</b>"),
+            ),
+            ("about", serde_json::json!("About $t(brand.name) {braces}")),
+            ("brand.name", serde_json::json!("Leptos")),
+            ("brand.tagline", serde_json::json!("")),
+            (
+                "home::stats.visits.total",
+                serde_json::json!("{{ n, number }} on {{ d, date(long) }}"),
+            ),
+            (
+                "price",
+                serde_json::json!("{{ price, currency(usd) }} {{ x, unknown }}"),
+            ),
+            (
+                "emails",
+                serde_json::json!(["u32", { "count": [0, "zero"], "value": "no emails" }, ["one email", "one"], { "value": "{{ count }} emails" }]),
+            ),
+            (
+                "progress",
+                serde_json::json!({ "count_type": "f64", "0": "Not started", "99.5 | 100": "Almost done", "_": "{{ count }}% done" }),
+            ),
+            (
+                "replied",
+                serde_json::json!({ "$select": "gender", "male": "He replied", "other": "They replied" }),
+            ),
+            (
+                "rank",
+                serde_json::json!({ "ordinal": true, "one": "{{ count }}st", "other": "{{ count }}th" }),
+            ),
+            (
+                "ranges",
+                serde_json::json!([["few", "1..5"], ["many", "_"]]),
+            ),
+            (
+                "with_args",
+                serde_json::json!("{{ @brand.name, kind = 'i18n' }}"),
+            ),
+            (
+                "multiline",
+                serde_json::json!("first line\n[not a variant]\n.not an attribute"),
+            ),
+        ];
+        catalog.0.insert(
+            "en".to_owned(),
+            entries
+                .into_iter()
+                .map(|(key, value)| (key.to_owned(), Entry::new(value)))
+                .collect(),
+        );
+        let annotations = Annotations(BTreeMap::from([
+            (
+                "hello".to_owned(),
+                Annotation {
+                    description: Some("Greeting of the home page".to_owned()),
+                    screenshots: vec![],
+                    owners: vec![],
+                },
+            ),
+            (
+                "brand.name".to_owned(),
+                Annotation {
+                    description: Some("Never translated".to_owned()),
+                    screenshots: vec![],
+                    owners: vec![],
+                },
+            ),
+        ]));
+
+        let exported = export(&catalog, &config(), &annotations, "en").unwrap();
+        assert_eq!(exported.skipped, ["ranges", "with_args"]);
+        assert_eq!(
+            exported.content,
+            r#"about = About { brand.name } { "{" }braces{ "}" }
+
+# .name: Never translated
+brand =
+    .name = Leptos
+    .tagline = { "" }
+
+emails =
+    { $count ->
+        [0] no emails
+        [zero] no emails
+        [one] one email
+       *[other] { $count } emails
+    }
+
+# Greeting of the home page
+hello = Hello { $name }, see <b>{ about }</b>
+
+home-stats-visits =
+    .total = { NUMBER($n) } on { DATETIME($d, dateStyle: "long") }
+
+multiline =
+    first line
+    { "[" }not a variant]
+    { "." }not an attribute
+
+price = { NUMBER($price, style: "currency", currency: "USD") } { "{" }{ "{" } x, unknown { "}" }{ "}" }
+
+progress =
+    { $count ->
+        [0] Not started
+        [99.5] Almost done
+        [100] Almost done
+       *[other] { $count }% done
+    }
+
+rank =
+    { NUMBER($count, type: "ordinal") ->
+        [one] { $count }st
+       *[other] { $count }th
+    }
+
+replied =
+    { $gender ->
+        [male] He replied
+       *[other] They replied
+    }
+"#
+        );
+        assert!(matches!(
+            export(&catalog, &config(), &annotations, "de"),
+            Err(Error::UnknownLocale(_))
+        ));
+    }
+}
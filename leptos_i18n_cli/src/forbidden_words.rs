@@ -0,0 +1,85 @@
+use std::{collections::BTreeMap, path::Path};
+
+use crate::{
+    catalog::{contains_term, Catalog},
+    check::{Issue, ALL_LOCALES},
+    config::Config,
+    error::{Error, Result},
+};
+
+const FORBIDDEN_WORDS_FILE: &str = "forbidden_words.toml";
+
+/// Words that can't be used in the values of each locale.
+#[derive(Debug, Default)]
+pub struct ForbiddenWords(pub BTreeMap<String, Vec<String>>);
+
+impl ForbiddenWords {
+    /// Load the `forbidden_words.toml` file at the root of the locales directory, if any.
+    pub fn load(config: &Config, manifest_dir: &Path) -> Result<Option<Self>> {
+        let path = config.locales_dir.join(FORBIDDEN_WORDS_FILE);
+        let content = match std::fs::read_to_string(manifest_dir.join(&path)) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(Error::Io(path, err)),
+        };
+        let words = toml::de::from_str(&content)
+            .map_err(|err| Error::ForbiddenWordsDeser(path, err.to_string()))?;
+        Ok(Some(ForbiddenWords(words)))
+    }
+
+    /// Check that no value of a locale uses one of its forbidden words,
+    /// or one of the words forbidden in every locale.
+    pub fn check(&self, catalog: &Catalog) -> Vec<Issue> {
+        let mut issues = vec![];
+        let all_locales = self
+            .0
+            .get(ALL_LOCALES)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        for (locale, entries) in &catalog.0 {
+            let locale_words = self.0.get(locale).map(Vec::as_slice).unwrap_or_default();
+            for (key, entry) in entries {
+                let text = entry.text();
+                for word in all_locales.iter().chain(locale_words) {
+                    if contains_term(&text, word) {
+                        let message = format!("use of forbidden word {:?}", word);
+                        issues.push(Issue::new(locale, key, message));
+                    }
+                }
+            }
+        }
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::Entry;
+
+    #[test]
+    fn test_check() {
+        let forbidden_words = ForbiddenWords(BTreeMap::from([
+            ("*".to_owned(), vec!["Acme".to_owned()]),
+            ("fr".to_owned(), vec!["bureau".to_owned()]),
+        ]));
+        let mut catalog = Catalog::default();
+        for (locale, key, value) in [
+            ("en", "desk", "Open the desk"),
+            ("en", "brand", "Powered by acme"),
+            ("fr", "desk", "Ouvrir le bureau"),
+        ] {
+            let entry = Entry::new(serde_json::Value::String(value.to_owned()));
+            let entries = catalog.0.entry(locale.to_owned()).or_default();
+            entries.insert(key.to_owned(), entry);
+        }
+        let issues = forbidden_words.check(&catalog);
+        assert_eq!(
+            issues,
+            [
+                Issue::new("en", "brand", "use of forbidden word \"Acme\""),
+                Issue::new("fr", "desk", "use of forbidden word \"bureau\""),
+            ]
+        );
+    }
+}
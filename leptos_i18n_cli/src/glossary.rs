@@ -0,0 +1,114 @@
+use std::{collections::BTreeMap, path::Path};
+
+use crate::{
+    catalog::{contains_term, Catalog},
+    check::Issue,
+    config::Config,
+    error::{Error, Result},
+};
+
+const GLOSSARY_FILE: &str = "glossary.toml";
+
+/// Source terms of the default locale, mapped to their required translation in each locale.
+#[derive(Debug, Default)]
+pub struct Glossary(pub BTreeMap<String, BTreeMap<String, String>>);
+
+impl Glossary {
+    /// Load the `glossary.toml` file at the root of the locales directory, if any.
+    pub fn load(config: &Config, manifest_dir: &Path) -> Result<Option<Self>> {
+        let path = config.locales_dir.join(GLOSSARY_FILE);
+        let content = match std::fs::read_to_string(manifest_dir.join(&path)) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(Error::Io(path, err)),
+        };
+        let terms = toml::de::from_str(&content)
+            .map_err(|err| Error::GlossaryDeser(path, err.to_string()))?;
+        Ok(Some(Glossary(terms)))
+    }
+
+    /// Check that every value whose default locale counterpart contains a glossary term
+    /// uses the required translation of that term.
+    pub fn check(&self, catalog: &Catalog, default_locale: &str) -> Vec<Issue> {
+        let mut issues = vec![];
+        let Some(default_entries) = catalog.0.get(default_locale) else {
+            return issues;
+        };
+        for (key, default_entry) in default_entries {
+            let source_text = default_entry.text();
+            for (term, translations) in &self.0 {
+                if !contains_term(&source_text, term) {
+                    continue;
+                }
+                for (locale, expected) in translations {
+                    // missing keys are reported by the macro, not here.
+                    let Some(entry) = catalog.0.get(locale).and_then(|entries| entries.get(key))
+                    else {
+                        continue;
+                    };
+                    if !contains_term(&entry.text(), expected) {
+                        let message = format!("{:?} should be translated as {:?}", term, expected);
+                        issues.push(Issue::new(locale, key, message));
+                    }
+                }
+            }
+        }
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::Entry;
+
+    fn catalog(locales: &[(&str, &[(&str, &str)])]) -> Catalog {
+        let mut catalog = Catalog::default();
+        for (locale, entries) in locales {
+            let entries = entries
+                .iter()
+                .map(|(key, value)| {
+                    let value = serde_json::Value::String(value.to_string());
+                    (key.to_string(), Entry::new(value))
+                })
+                .collect();
+            catalog.0.insert(locale.to_string(), entries);
+        }
+        catalog
+    }
+
+    #[test]
+    fn test_check() {
+        let glossary = Glossary(BTreeMap::from([(
+            "workspace".to_owned(),
+            BTreeMap::from([("fr".to_owned(), "espace de travail".to_owned())]),
+        )]));
+        let catalog = catalog(&[
+            (
+                "en",
+                &[
+                    ("open", "Open the workspace"),
+                    ("close", "Close the workspace"),
+                    ("hello", "Hello"),
+                ],
+            ),
+            (
+                "fr",
+                &[
+                    ("open", "Ouvrir l'espace de travail"),
+                    ("close", "Fermer le bureau"),
+                    ("hello", "Bonjour"),
+                ],
+            ),
+        ]);
+        let issues = glossary.check(&catalog, "en");
+        assert_eq!(
+            issues,
+            [Issue::new(
+                "fr",
+                "close",
+                "\"workspace\" should be translated as \"espace de travail\""
+            )]
+        );
+    }
+}
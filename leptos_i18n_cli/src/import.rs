@@ -0,0 +1,232 @@
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    config::Config,
+    error::{Error, Result},
+    printf::{convert_str, convert_value, Conversion},
+};
+
+/// The result of an import.
+#[derive(Debug)]
+pub struct Import {
+    /// The path of the written locale file, relative to the manifest directory.
+    pub output_path: PathBuf,
+    /// The printf placeholders converted to variables, to be renamed by hand.
+    pub conversions: Vec<Conversion>,
+    /// If the comments, key ordering and formatting of the source were kept.
+    ///
+    /// They are lost when the conversion could not be done on the text of the file,
+    /// the file then being serialized again from its parsed value.
+    pub formatting_preserved: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Format {
+    Json,
+    Yaml,
+}
+
+impl Format {
+    pub(crate) fn new(path: &Path) -> Self {
+        let is_yaml = path
+            .extension()
+            .is_some_and(|ext| ext == "yaml" || ext == "yml");
+        if is_yaml {
+            Format::Yaml
+        } else {
+            Format::Json
+        }
+    }
+
+    pub(crate) fn parse(self, content: &str) -> Result<Value, String> {
+        match self {
+            Format::Json => serde_json::from_str(content).map_err(|err| err.to_string()),
+            Format::Yaml => serde_yaml::from_str(content).map_err(|err| err.to_string()),
+        }
+    }
+
+    pub(crate) fn serialize(self, value: &Value) -> Result<String, String> {
+        match self {
+            Format::Json => {
+                let mut content = vec![];
+                // same indentation as the examples
+                let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
+                let mut serializer =
+                    serde_json::Serializer::with_formatter(&mut content, formatter);
+                value
+                    .serialize(&mut serializer)
+                    .map_err(|err| err.to_string())?;
+                content.push(b'\n');
+                String::from_utf8(content).map_err(|err| err.to_string())
+            }
+            Format::Yaml => serde_yaml::to_string(value).map_err(|err| err.to_string()),
+        }
+    }
+}
+
+/// Split a YAML line between its content and its trailing comment.
+fn split_comment(line: &str) -> (&str, &str) {
+    let mut quote = None;
+    let mut prev = ' ';
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (None, '#') if prev.is_whitespace() => return line.split_at(i),
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if c == q && prev != '\\' => quote = None,
+            _ => {}
+        }
+        prev = c;
+    }
+    (line, "")
+}
+
+/// Convert the printf placeholders line by line on the text of the file, leaving the comments untouched.
+fn convert_text(content: &str, format: Format) -> String {
+    // conversions are already collected on the parsed value.
+    let mut conversions = vec![];
+    let mut output = String::with_capacity(content.len());
+    for line in content.split_inclusive('\n') {
+        let (line, comment) = match format {
+            Format::Json => (line, ""),
+            Format::Yaml => split_comment(line),
+        };
+        output.push_str(&convert_str(line, "", &mut conversions));
+        output.push_str(comment);
+    }
+    output
+}
+
+pub(crate) fn write_file(path: &Path, content: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|err| Error::Write(parent.to_owned(), err))?;
+    }
+    std::fs::write(path, content).map_err(|err| Error::Write(path.to_owned(), err))
+}
+
+/// Convert the content of the file, returning the new content and if the formatting was preserved.
+///
+/// The conversion is first done on the text, and kept only if it gives the same value
+/// as the conversion of the parsed value, else the converted value is serialized.
+fn convert(
+    content: &str,
+    format: Format,
+    conversions: &mut Vec<Conversion>,
+) -> Result<(String, bool), String> {
+    let mut value = format.parse(content)?;
+    convert_value(&mut value, "", conversions);
+
+    let text = convert_text(content, format);
+    if format
+        .parse(&text)
+        .is_ok_and(|text_value| text_value == value)
+    {
+        Ok((text, true))
+    } else {
+        format.serialize(&value).map(|content| (content, false))
+    }
+}
+
+/// Import a catalog exported from another tool as the locale file of the given locale (and namespace),
+/// converting its printf placeholders (`%s`, `%d`, `%1$s`, ...) to variables.
+///
+/// The file keeps its format, and its comments and key ordering when possible.
+pub fn import(
+    config: &Config,
+    manifest_dir: &Path,
+    source: &Path,
+    locale: &str,
+    namespace: Option<&str>,
+) -> Result<Import> {
+    if !config.locales.iter().any(|l| l == locale) {
+        return Err(Error::UnknownLocale(locale.to_owned()));
+    }
+    let namespaces = config.namespaces.as_deref().unwrap_or_default();
+    match namespace {
+        Some(namespace) if !namespaces.iter().any(|ns| ns == namespace) => {
+            return Err(Error::UnknownNamespace(namespace.to_owned()))
+        }
+        None if !namespaces.is_empty() => return Err(Error::MissingNamespace),
+        _ => {}
+    }
+
+    let content =
+        std::fs::read_to_string(source).map_err(|err| Error::Io(source.to_owned(), err))?;
+    let format = Format::new(source);
+    let mut conversions = vec![];
+    let (content, formatting_preserved) =
+        convert(&content, format, &mut conversions).map_err(|err| Error::LocaleFileDeser {
+            path: source.to_owned(),
+            err,
+        })?;
+
+    let mut output_path = config.locales_dir.join(locale);
+    if let Some(namespace) = namespace {
+        output_path.push(namespace);
+    }
+    let ext = match format {
+        Format::Json => "json",
+        Format::Yaml => source
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or("yaml"),
+    };
+    output_path.set_extension(ext);
+    write_file(&manifest_dir.join(&output_path), &content)?;
+
+    Ok(Import {
+        output_path,
+        conversions,
+        formatting_preserved,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preserve_comments() {
+        let content = concat!(
+            "# Exported from the legacy app, 100% translated\n",
+            "zeta: \"Hello %s\" # shown on the home page\n",
+            "alpha:\n",
+            "  count: '%1$d new messages for %2$s'\n",
+        );
+        let mut conversions = vec![];
+        let (output, preserved) = convert(content, Format::Yaml, &mut conversions).unwrap();
+        assert!(preserved);
+        assert_eq!(
+            output,
+            concat!(
+                "# Exported from the legacy app, 100% translated\n",
+                "zeta: \"Hello {{ arg_1 }}\" # shown on the home page\n",
+                "alpha:\n",
+                "  count: '{{ arg_1 }} new messages for {{ arg_2 }}'\n",
+            )
+        );
+        assert_eq!(conversions.len(), 3);
+    }
+
+    #[test]
+    fn test_fallback() {
+        // an unquoted variable at the start of a value would be parsed as a map.
+        let content = "greeting: \"%s\"\nplain: hello %s\nkey_%s: \"value\"\n";
+        let mut conversions = vec![];
+        let (output, preserved) = convert(content, Format::Yaml, &mut conversions).unwrap();
+        assert!(!preserved);
+        let value = Format::Yaml.parse(&output).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "greeting": "{{ arg_1 }}",
+                "plain": "hello {{ arg_1 }}",
+                "key_%s": "value",
+            })
+        );
+        let keys = value.as_object().unwrap().keys().collect::<Vec<_>>();
+        assert_eq!(keys, ["greeting", "plain", "key_%s"]);
+    }
+}
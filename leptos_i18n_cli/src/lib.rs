@@ -0,0 +1,34 @@
+#![forbid(unsafe_code)]
+//! # About Leptos i18n cli
+//!
+//! This crate contains the tools behind the `leptos_i18n` binary: comparing the locales of two git revisions,
+//! checking them against the rules of the locales directory and QA rules on their punctuation and spacing, importing catalogs from other tools,
+//! exporting them to XLIFF or Fluent, rendering the catalog as a static HTML reference
+//! mapping the locale files to the teams owning their keys, and merging the usage telemetry of the keys
+//! with the catalog to prioritize the translations. The sources are scanned to list the keys they never reference
+//! and to add the keys they reference that are missing from the default locale.
+//! It also validates the configuration against its JSON schema, see [`validate`].
+//!
+//! The checks can be extended with custom validators, like a spellcheck, using [`check::Checker::validator`].
+
+pub mod annotations;
+pub mod catalog;
+pub mod check;
+pub mod config;
+pub mod diff;
+pub mod docs;
+pub mod error;
+pub mod extract;
+pub mod fluent;
+pub mod forbidden_words;
+pub mod glossary;
+pub mod import;
+pub mod owners;
+pub mod printf;
+pub mod qa;
+pub mod relaxed_json;
+pub mod scan;
+pub mod unused;
+pub mod usage;
+pub mod validate;
+pub mod xliff;
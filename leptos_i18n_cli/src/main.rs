@@ -0,0 +1,422 @@
+use std::path::PathBuf;
+
+use leptos_i18n_cli::{
+    annotations::Annotations,
+    catalog::{Catalog, Source},
+    check::Checker,
+    config::Config,
+    diff, docs,
+    error::{Error, Result},
+    extract::{self, extract},
+    fluent,
+    import::import,
+    owners::{self, Ownership},
+    scan,
+    unused::{self, References},
+    usage::{self, Usage},
+    validate, xliff,
+};
+
+const USAGE: &str = "\
+Usage: leptos_i18n <command> [--manifest-dir <path>]
+
+Commands:
+    diff <old-rev> [<new-rev>]  Compare the locale files at two git revisions and report the added,
+                                removed and changed keys of each locale.
+                                If <new-rev> is omitted the working tree is used.
+    check                       Check the locale files against the rules of the locales directory:
+                                the glossary.toml, forbidden_words.toml, qa.toml and validators.toml
+                                files.
+    import <file> <locale> [<namespace>]
+                                Import a JSON or YAML catalog exported from another tool as the
+                                locale file of the given locale, converting its printf placeholders
+                                (%s, %d, %1$s, ...) to variables.
+    export <locale> [<output>]  Export the values of a locale as an XLIFF 1.2 file, with the values of
+                                the default locale as sources and the annotations of the keys as notes.
+                                The file is written to <locale>.xlf if <output> is omitted.
+    export-ftl <locale> [<output>]
+                                Export the values of a locale as a Fluent resource, for other Fluent
+                                based tools, with the descriptions of the keys as comments.
+                                The file is written to <locale>.ftl if <output> is omitted.
+    docs [<output>]             Render the catalog as a static HTML page listing every key with its
+                                annotations, placeholders and value in each locale.
+                                The page is written to i18n-docs.html if <output> is omitted.
+    owners                      List the keys owned by each team, as set by the owners of the keys,
+                                parent keys or namespaces in the annotations, and the unowned keys.
+    codeowners [<output>]       Generate a CODEOWNERS fragment mapping the locale files to the owners of
+                                their keys. The fragment is printed if <output> is omitted.
+    usage <usage-file>          Merge the usage exported by the usage feature of leptos_i18n with the
+                                catalog, listing the rendered keys that are not translated, the most
+                                rendered first, and the keys that are never rendered.
+    unused [<dir>...]           List the keys of the default locale never referenced by the t!, td!,
+                                t_string!, td_string!, td_display! or gettext! macros of the Rust files
+                                in the given directories, the src directory if none is given.
+    extract [<dir>...]          Add the keys of the t!, td!, t_string!, td_string! and td_display! calls
+                                of the Rust files in the given directories, the src directory if none is
+                                given, that are missing from the default locale, with a value listing
+                                the variables and components given to them.
+    validate-config [<file>]    Check the configuration for unknown keys, values of the wrong type and
+                                undeclared locales, without building the project. <file> is a cargo
+                                manifest or a Rust file calling load_locales!(config { .. }), the
+                                Cargo.toml of the manifest directory is used if it is omitted.
+    schema [<output>]           Write the JSON schema of the configuration, for editors and other tools.
+                                The schema is printed if <output> is omitted.";
+
+struct Args {
+    positionals: Vec<String>,
+    manifest_dir: PathBuf,
+}
+
+fn usage_error(msg: &str) -> Error {
+    Error::Usage(format!("{}\n\n{}", msg, USAGE))
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args> {
+    let mut positionals = vec![];
+    let mut manifest_dir = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--manifest-dir" => {
+                let path = args
+                    .next()
+                    .ok_or_else(|| usage_error("Missing value for --manifest-dir."))?;
+                manifest_dir = Some(PathBuf::from(path));
+            }
+            "-h" | "--help" => return Err(Error::Usage(USAGE.to_owned())),
+            _ if arg.starts_with('-') => {
+                return Err(usage_error(&format!("Unknown option {:?}.", arg)))
+            }
+            _ => positionals.push(arg),
+        }
+    }
+
+    Ok(Args {
+        positionals,
+        manifest_dir: manifest_dir.unwrap_or_else(|| PathBuf::from(".")),
+    })
+}
+
+fn run_diff(args: Args) -> Result<()> {
+    let mut revs = args.positionals.into_iter();
+    let old = revs
+        .next()
+        .map(Source::Revision)
+        .ok_or_else(|| usage_error("Missing the revision to compare against."))?;
+    let new = Source::new(revs.next());
+    if revs.next().is_some() {
+        return Err(usage_error("Too many revisions given."));
+    }
+
+    let config = Config::new(&args.manifest_dir)?;
+    let old_catalog = Catalog::load(&config, &args.manifest_dir, &old)?;
+    let new_catalog = Catalog::load(&config, &args.manifest_dir, &new)?;
+    let diff = diff::diff(&old_catalog, &new_catalog);
+    println!("Comparing {} to {}\n", old, new);
+    print!("{}", diff::report(&diff));
+    Ok(())
+}
+
+fn run_check(args: Args) -> Result<()> {
+    if !args.positionals.is_empty() {
+        return Err(usage_error("The check command takes no arguments."));
+    }
+
+    let issues = Checker::new(args.manifest_dir)?.run()?;
+    for issue in &issues {
+        println!("{}", issue);
+    }
+
+    if !issues.is_empty() {
+        Err(Error::CheckFailed(issues.len()))
+    } else {
+        println!("No issues found.");
+        Ok(())
+    }
+}
+
+fn run_import(args: Args) -> Result<()> {
+    let mut positionals = args.positionals.into_iter();
+    let (Some(file), Some(locale)) = (positionals.next(), positionals.next()) else {
+        return Err(usage_error("Missing the file to import and its locale."));
+    };
+    let namespace = positionals.next();
+    if positionals.next().is_some() {
+        return Err(usage_error("Too many arguments given."));
+    }
+
+    let config = Config::new(&args.manifest_dir)?;
+    let import = import(
+        &config,
+        &args.manifest_dir,
+        file.as_ref(),
+        &locale,
+        namespace.as_deref(),
+    )?;
+    println!("Imported {:?} to {:?}", file, import.output_path);
+    if !import.formatting_preserved {
+        println!("The placeholders could not be converted in place, the comments and formatting of the file were lost.");
+    }
+    if !import.conversions.is_empty() {
+        println!("\nThe printf placeholders were converted to variables with generated names, consider renaming them:");
+        for conversion in &import.conversions {
+            println!("  {}", conversion);
+        }
+        println!("\n{} placeholder(s) converted", import.conversions.len());
+    }
+    Ok(())
+}
+
+fn run_export(args: Args) -> Result<()> {
+    let mut positionals = args.positionals.into_iter();
+    let locale = positionals
+        .next()
+        .ok_or_else(|| usage_error("Missing the locale to export."))?;
+    let output = positionals
+        .next()
+        .map_or_else(|| PathBuf::from(format!("{}.xlf", locale)), PathBuf::from);
+    if positionals.next().is_some() {
+        return Err(usage_error("Too many arguments given."));
+    }
+
+    let config = Config::new(&args.manifest_dir)?;
+    let catalog = Catalog::load(&config, &args.manifest_dir, &Source::WorkingTree)?;
+    let annotations = Annotations::load(&config, &args.manifest_dir)?.unwrap_or_default();
+    let export = xliff::export(&catalog, &config, &annotations, &locale)?;
+    std::fs::write(&output, export.content).map_err(|err| Error::Write(output.clone(), err))?;
+    println!("Exported {:?} to {:?}", locale, output);
+    if !export.skipped_plurals.is_empty() {
+        println!("\nPlurals can't be exported, those keys were skipped:");
+        for key in &export.skipped_plurals {
+            println!("  {}", key);
+        }
+    }
+    Ok(())
+}
+
+fn run_export_ftl(args: Args) -> Result<()> {
+    let mut positionals = args.positionals.into_iter();
+    let locale = positionals
+        .next()
+        .ok_or_else(|| usage_error("Missing the locale to export."))?;
+    let output = positionals
+        .next()
+        .map_or_else(|| PathBuf::from(format!("{}.ftl", locale)), PathBuf::from);
+    if positionals.next().is_some() {
+        return Err(usage_error("Too many arguments given."));
+    }
+
+    let config = Config::new(&args.manifest_dir)?;
+    let catalog = Catalog::load(&config, &args.manifest_dir, &Source::WorkingTree)?;
+    let annotations = Annotations::load(&config, &args.manifest_dir)?.unwrap_or_default();
+    let export = fluent::export(&catalog, &config, &annotations, &locale)?;
+    std::fs::write(&output, export.content).map_err(|err| Error::Write(output.clone(), err))?;
+    println!("Exported {:?} to {:?}", locale, output);
+    if !export.skipped.is_empty() {
+        println!("\nThose keys have no Fluent equivalent and were skipped:");
+        for key in &export.skipped {
+            println!("  {}", key);
+        }
+    }
+    Ok(())
+}
+
+fn run_docs(args: Args) -> Result<()> {
+    let mut positionals = args.positionals.into_iter();
+    let output = positionals
+        .next()
+        .map_or_else(|| PathBuf::from("i18n-docs.html"), PathBuf::from);
+    if positionals.next().is_some() {
+        return Err(usage_error("Too many arguments given."));
+    }
+
+    let config = Config::new(&args.manifest_dir)?;
+    let catalog = Catalog::load(&config, &args.manifest_dir, &Source::WorkingTree)?;
+    let annotations = Annotations::load(&config, &args.manifest_dir)?.unwrap_or_default();
+    let html = docs::render(&catalog, &config, &annotations);
+    std::fs::write(&output, html).map_err(|err| Error::Write(output.clone(), err))?;
+    println!("Catalog documentation written to {:?}", output);
+    Ok(())
+}
+
+fn load_ownership(args: &Args) -> Result<Ownership> {
+    let config = Config::new(&args.manifest_dir)?;
+    let catalog = Catalog::load(&config, &args.manifest_dir, &Source::WorkingTree)?;
+    let annotations = Annotations::load(&config, &args.manifest_dir)?.unwrap_or_default();
+    Ok(Ownership::new(&catalog, &config, &annotations))
+}
+
+fn run_owners(args: Args) -> Result<()> {
+    if !args.positionals.is_empty() {
+        return Err(usage_error("The owners command takes no arguments."));
+    }
+
+    let ownership = load_ownership(&args)?;
+    print!("{}", owners::report(&ownership));
+    Ok(())
+}
+
+fn run_codeowners(args: Args) -> Result<()> {
+    let mut positionals = args.positionals.iter();
+    let output = positionals.next().map(PathBuf::from);
+    if positionals.next().is_some() {
+        return Err(usage_error("Too many arguments given."));
+    }
+
+    let ownership = load_ownership(&args)?;
+    let prefix = owners::repository_prefix(&args.manifest_dir);
+    let codeowners = owners::codeowners(&ownership, &prefix);
+    match output {
+        Some(output) => {
+            std::fs::write(&output, codeowners).map_err(|err| Error::Write(output.clone(), err))?;
+            println!("CODEOWNERS fragment written to {:?}", output);
+        }
+        None => print!("{}", codeowners),
+    }
+    Ok(())
+}
+
+fn run_usage(args: Args) -> Result<()> {
+    let mut positionals = args.positionals.into_iter();
+    let file = positionals
+        .next()
+        .ok_or_else(|| usage_error("Missing the usage file."))?;
+    if positionals.next().is_some() {
+        return Err(usage_error("Too many arguments given."));
+    }
+
+    let config = Config::new(&args.manifest_dir)?;
+    let catalog = Catalog::load(&config, &args.manifest_dir, &Source::WorkingTree)?;
+    let usage = Usage::load(file.as_ref())?;
+    let report = usage::report(&catalog, &config, &usage);
+    print!("{}", usage::render(&report));
+    Ok(())
+}
+
+fn run_unused(args: Args) -> Result<()> {
+    let dirs = if args.positionals.is_empty() {
+        vec![args.manifest_dir.join("src")]
+    } else {
+        args.positionals.into_iter().map(PathBuf::from).collect()
+    };
+
+    let config = Config::new(&args.manifest_dir)?;
+    let catalog = Catalog::load(&config, &args.manifest_dir, &Source::WorkingTree)?;
+    let mut references = References::default();
+    for dir in &dirs {
+        references.scan_dir(dir)?;
+    }
+    let keys = unused::unused_keys(&catalog, &config.default, &references);
+    for key in &keys {
+        println!("{}", key);
+    }
+
+    if !keys.is_empty() {
+        Err(Error::UnusedKeys(keys.len()))
+    } else {
+        println!("Every key is used.");
+        Ok(())
+    }
+}
+
+fn run_extract(args: Args) -> Result<()> {
+    let dirs = if args.positionals.is_empty() {
+        vec![args.manifest_dir.join("src")]
+    } else {
+        args.positionals.into_iter().map(PathBuf::from).collect()
+    };
+
+    let config = Config::new(&args.manifest_dir)?;
+    let mut calls = vec![];
+    for dir in &dirs {
+        for path in scan::rust_files(dir)? {
+            let source = std::fs::read_to_string(&path).map_err(|err| Error::Io(path, err))?;
+            calls.extend(scan::calls(&source));
+        }
+    }
+    let keys = extract::keys(&calls);
+    let extraction = extract(&config, &args.manifest_dir, &keys)?;
+
+    for (path, keys) in &extraction.added {
+        println!("Added {} key(s) to {:?}:", keys.len(), path);
+        for key in keys {
+            println!("  {}", key);
+        }
+    }
+    for (key, reason) in &extraction.skipped {
+        eprintln!("Skipped key {:?}: {}", key, reason);
+    }
+    if extraction.added.is_empty() {
+        println!("No key to add.");
+    }
+    Ok(())
+}
+
+fn run_validate_config(args: Args) -> Result<()> {
+    let mut positionals = args.positionals.into_iter();
+    let file = positionals
+        .next()
+        .map_or_else(|| args.manifest_dir.join("Cargo.toml"), PathBuf::from);
+    if positionals.next().is_some() {
+        return Err(usage_error("Too many arguments given."));
+    }
+
+    let config = validate::load(&file)?;
+    let issues = validate::validate(&config);
+    for issue in &issues {
+        println!("{}", issue);
+    }
+
+    if !issues.is_empty() {
+        Err(Error::ConfigInvalid(issues.len()))
+    } else {
+        println!("The configuration is valid.");
+        Ok(())
+    }
+}
+
+fn run_schema(args: Args) -> Result<()> {
+    let mut positionals = args.positionals.into_iter();
+    let output = positionals.next().map(PathBuf::from);
+    if positionals.next().is_some() {
+        return Err(usage_error("Too many arguments given."));
+    }
+
+    match output {
+        Some(output) => {
+            std::fs::write(&output, validate::SCHEMA)
+                .map_err(|err| Error::Write(output.clone(), err))?;
+            println!("Configuration schema written to {:?}", output);
+        }
+        None => print!("{}", validate::SCHEMA),
+    }
+    Ok(())
+}
+
+fn run() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    match args.next().as_deref() {
+        Some("diff") => run_diff(parse_args(args)?),
+        Some("check") => run_check(parse_args(args)?),
+        Some("import") => run_import(parse_args(args)?),
+        Some("export") => run_export(parse_args(args)?),
+        Some("export-ftl") => run_export_ftl(parse_args(args)?),
+        Some("docs") => run_docs(parse_args(args)?),
+        Some("owners") => run_owners(parse_args(args)?),
+        Some("codeowners") => run_codeowners(parse_args(args)?),
+        Some("usage") => run_usage(parse_args(args)?),
+        Some("unused") => run_unused(parse_args(args)?),
+        Some("extract") => run_extract(parse_args(args)?),
+        Some("validate-config") => run_validate_config(parse_args(args)?),
+        Some("schema") => run_schema(parse_args(args)?),
+        Some("-h" | "--help") => Err(Error::Usage(USAGE.to_owned())),
+        Some(cmd) => Err(usage_error(&format!("Unknown command {:?}.", cmd))),
+        None => Err(usage_error("Missing command.")),
+    }
+}
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,168 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+    path::{Component, Path},
+    process::Command,
+};
+
+use crate::{annotations::Annotations, catalog::Catalog, config::Config};
+
+/// The owners of the keys of the default locale and of the locale files, as set in the annotations.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Ownership {
+    /// The keys owned by each owner.
+    pub keys: BTreeMap<String, Vec<String>>,
+    /// The keys without owners.
+    pub unowned: Vec<String>,
+    /// The owners of each locale file, given as a path relative to the manifest directory without extension,
+    /// being the owners of the keys it contains.
+    pub files: Vec<(String, BTreeSet<String>)>,
+}
+
+impl Ownership {
+    pub fn new(catalog: &Catalog, config: &Config, annotations: &Annotations) -> Self {
+        let mut ownership = Ownership::default();
+        let mut namespaces_owners = BTreeMap::<Option<&str>, BTreeSet<String>>::new();
+        let keys = catalog.0.get(&config.default).into_iter().flatten();
+        for (key, _) in keys {
+            let owners = annotations.owners(key);
+            if owners.is_empty() {
+                ownership.unowned.push(key.clone());
+                continue;
+            }
+            for owner in owners {
+                let keys = ownership.keys.entry(owner.clone()).or_default();
+                keys.push(key.clone());
+            }
+            let namespace = key.split_once("::").map(|(namespace, _)| namespace);
+            namespaces_owners
+                .entry(namespace)
+                .or_default()
+                .extend(owners.iter().cloned());
+        }
+
+        for (_, namespace, path) in config.locale_files() {
+            let Some(owners) = namespaces_owners.get(&namespace) else {
+                continue;
+            };
+            ownership.files.push((normalize(&path), owners.clone()));
+        }
+        ownership
+    }
+}
+
+/// Write the path with `/` separators and without `.` components, for it to be a valid CODEOWNERS pattern.
+fn normalize(path: &Path) -> String {
+    let components = path
+        .components()
+        .filter(|component| !matches!(component, Component::CurDir))
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>();
+    components.join("/")
+}
+
+/// List the keys of each owner, then the keys without owners.
+pub fn report(ownership: &Ownership) -> String {
+    let mut report = String::new();
+    for (owner, keys) in &ownership.keys {
+        let _ = writeln!(report, "{} ({} key(s))", owner, keys.len());
+        for key in keys {
+            let _ = writeln!(report, "  {}", key);
+        }
+        report.push('\n');
+    }
+    if !ownership.unowned.is_empty() {
+        let _ = writeln!(report, "Unowned ({} key(s))", ownership.unowned.len());
+        for key in &ownership.unowned {
+            let _ = writeln!(report, "  {}", key);
+        }
+        report.push('\n');
+    }
+    let _ = writeln!(
+        report,
+        "{} owner(s), {} unowned key(s)",
+        ownership.keys.len(),
+        ownership.unowned.len()
+    );
+    report
+}
+
+/// The path of the manifest directory relative to the root of its git repository, like `app/`,
+/// empty if it is the root or is not in a repository.
+pub fn repository_prefix(manifest_dir: &Path) -> String {
+    Command::new("git")
+        .args(["rev-parse", "--show-prefix"])
+        .current_dir(manifest_dir)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|prefix| prefix.trim().to_owned())
+        .unwrap_or_default()
+}
+
+/// Render the lines of a CODEOWNERS file mapping the locale files to their owners.
+///
+/// The paths are relative to the manifest directory, `prefix` being the path of the manifest directory
+/// relative to the root of the repository (like `app/`), empty if it is the root.
+pub fn codeowners(ownership: &Ownership, prefix: &str) -> String {
+    let mut codeowners = String::from(
+        "# Locale files, generated by `leptos_i18n codeowners` from the annotations\n",
+    );
+    for (path, owners) in &ownership.files {
+        let owners = owners.iter().map(String::as_str).collect::<Vec<_>>();
+        // the files are matched regardless of their format.
+        let _ = writeln!(codeowners, "/{}{}.* {}", prefix, path, owners.join(" "));
+    }
+    codeowners
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::*;
+    use crate::{annotations::Annotation, catalog::Entry};
+
+    #[test]
+    fn test_ownership() {
+        let config = Config {
+            default: "en".to_owned(),
+            locales: vec!["en".to_owned(), "fr".to_owned()],
+            namespaces: Some(vec!["common".to_owned(), "home".to_owned()]),
+            locales_dir: "./locales".into(),
+            file_names: Default::default(),
+            relaxed_json: false,
+        };
+        let entry = || Entry::new(Value::String("value".to_owned()));
+        let mut catalog = Catalog::default();
+        catalog.0.insert(
+            "en".to_owned(),
+            BTreeMap::from([
+                ("common::ok".to_owned(), entry()),
+                ("home::checkout.title".to_owned(), entry()),
+                ("home::title".to_owned(), entry()),
+            ]),
+        );
+        let owners = |owners: &[&str]| Annotation {
+            owners: owners.iter().map(|owner| owner.to_string()).collect(),
+            ..Default::default()
+        };
+        let annotations = Annotations(BTreeMap::from([
+            ("home".to_owned(), owners(&["@org/growth"])),
+            ("home::checkout".to_owned(), owners(&["@org/payments"])),
+        ]));
+
+        let ownership = Ownership::new(&catalog, &config, &annotations);
+        assert_eq!(ownership.unowned, ["common::ok"]);
+        assert_eq!(ownership.keys["@org/growth"], ["home::title"]);
+        assert_eq!(ownership.keys["@org/payments"], ["home::checkout.title"]);
+        assert_eq!(
+            codeowners(&ownership, "app/"),
+            "# Locale files, generated by `leptos_i18n codeowners` from the annotations\n\
+            /app/locales/en/home.* @org/growth @org/payments\n\
+            /app/locales/fr/home.* @org/growth @org/payments\n"
+        );
+        assert!(report(&ownership).ends_with("2 owner(s), 1 unowned key(s)\n"));
+    }
+}
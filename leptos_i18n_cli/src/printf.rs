@@ -0,0 +1,232 @@
+use std::{fmt::Display, iter::Peekable, str::CharIndices};
+
+use serde_json::Value;
+
+const FLAGS: &[char] = &['-', '+', '0', '#', '\''];
+const LENGTH_MODIFIERS: &[char] = &['h', 'l', 'L', 'q', 'j', 'z', 't'];
+const CONVERSIONS: &[char] = &[
+    'd', 'i', 'o', 'u', 'x', 'X', 'e', 'E', 'f', 'F', 'g', 'G', 'a', 'A', 'c', 's', 'p', '@',
+];
+
+/// A printf placeholder replaced by a variable with a generated name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conversion {
+    pub key: String,
+    pub placeholder: String,
+    pub variable: String,
+}
+
+impl Display for Conversion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {:?} -> {{{{ {} }}}}",
+            self.key, self.placeholder, self.variable
+        )
+    }
+}
+
+fn skip_while(chars: &mut Peekable<CharIndices>, f: impl Fn(char) -> bool) {
+    while chars.next_if(|(_, c)| f(*c)).is_some() {}
+}
+
+fn parse_number(chars: &mut Peekable<CharIndices>) -> Option<usize> {
+    let mut number = None;
+    while let Some((_, c)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+        let digit = c.to_digit(10).unwrap_or_default() as usize;
+        number = Some(number.unwrap_or(0) * 10 + digit);
+    }
+    number
+}
+
+/// Parse the placeholder following a `%`, returning its explicit position if any,
+/// or `None` if it is not a valid placeholder.
+fn parse_placeholder(chars: &mut Peekable<CharIndices>) -> Option<Option<usize>> {
+    // `%1$s`: the number is a position only if followed by `$`, else it's the width.
+    let mut position = None;
+    let mut lookahead = chars.clone();
+    if let Some(n) = parse_number(&mut lookahead) {
+        if lookahead.next_if(|(_, c)| *c == '$').is_some() {
+            position = Some(n);
+            *chars = lookahead;
+        }
+    }
+    skip_while(chars, |c| FLAGS.contains(&c));
+    skip_while(chars, |c| c.is_ascii_digit() || c == '*');
+    if chars.next_if(|(_, c)| *c == '.').is_some() {
+        skip_while(chars, |c| c.is_ascii_digit() || c == '*');
+    }
+    skip_while(chars, |c| LENGTH_MODIFIERS.contains(&c));
+    chars
+        .next_if(|(_, c)| CONVERSIONS.contains(c))
+        .map(|_| position)
+}
+
+/// A `%%` or a placeholder found in a string, with its byte range.
+enum Percent {
+    Escaped(usize),
+    Placeholder {
+        start: usize,
+        end: usize,
+        position: Option<usize>,
+    },
+}
+
+fn find_percents(s: &str) -> Vec<Percent> {
+    let mut percents = vec![];
+    let mut chars = s.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if c != '%' {
+            continue;
+        }
+        if chars.next_if(|(_, c)| *c == '%').is_some() {
+            percents.push(Percent::Escaped(start));
+            continue;
+        }
+        let mut placeholder_chars = chars.clone();
+        // not a placeholder, the `%` is kept as is.
+        if let Some(position) = parse_placeholder(&mut placeholder_chars) {
+            chars = placeholder_chars;
+            let end = chars.peek().map_or(s.len(), |(i, _)| *i);
+            percents.push(Percent::Placeholder {
+                start,
+                end,
+                position,
+            });
+        }
+    }
+    percents
+}
+
+/// Replace the printf placeholders of a string by `{{ arg_N }}` variables.
+///
+/// `N` is the position of the argument, explicit positions (`%2$s`) are respected,
+/// so the same argument has the same name in every locale. The placeholders without a position
+/// are numbered after the highest explicit position, so they never reuse the name of another argument.
+pub fn convert_str(s: &str, key: &str, conversions: &mut Vec<Conversion>) -> String {
+    let percents = find_percents(s);
+    let mut next_position = percents
+        .iter()
+        .filter_map(|percent| match percent {
+            Percent::Placeholder { position, .. } => *position,
+            Percent::Escaped(_) => None,
+        })
+        .max()
+        .unwrap_or(0)
+        + 1;
+    let mut output = String::with_capacity(s.len());
+    let mut last = 0;
+    for percent in percents {
+        match percent {
+            Percent::Escaped(start) => {
+                output.push_str(&s[last..=start]);
+                last = start + 2;
+            }
+            Percent::Placeholder {
+                start,
+                end,
+                position,
+            } => {
+                output.push_str(&s[last..start]);
+                last = end;
+                let position = position.unwrap_or_else(|| {
+                    let position = next_position;
+                    next_position += 1;
+                    position
+                });
+                let variable = format!("arg_{}", position);
+                output.push_str(&format!("{{{{ {} }}}}", variable));
+                conversions.push(Conversion {
+                    key: key.to_owned(),
+                    placeholder: s[start..end].to_owned(),
+                    variable,
+                });
+            }
+        }
+    }
+    output.push_str(&s[last..]);
+    output
+}
+
+/// Convert the printf placeholders of every string in the value, plural branches included.
+pub fn convert_value(value: &mut Value, key: &str, conversions: &mut Vec<Conversion>) {
+    match value {
+        Value::String(s) => *s = convert_str(s, key, conversions),
+        Value::Array(values) => values
+            .iter_mut()
+            .for_each(|value| convert_value(value, key, conversions)),
+        Value::Object(map) => {
+            for (sub_key, value) in map.iter_mut() {
+                let key = if key.is_empty() {
+                    sub_key.clone()
+                } else {
+                    format!("{}.{}", key, sub_key)
+                };
+                convert_value(value, &key, conversions);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(s: &str) -> (String, Vec<String>) {
+        let mut conversions = vec![];
+        let output = convert_str(s, "key", &mut conversions);
+        let placeholders = conversions.into_iter().map(|c| c.placeholder).collect();
+        (output, placeholders)
+    }
+
+    #[test]
+    fn test_convert_str() {
+        assert_eq!(
+            convert("Hello %s, you have %d messages"),
+            (
+                "Hello {{ arg_1 }}, you have {{ arg_2 }} messages".to_owned(),
+                vec!["%s".to_owned(), "%d".to_owned()]
+            )
+        );
+        assert_eq!(
+            convert("%2$s de %1$s: 100%% %-5.2f"),
+            (
+                "{{ arg_2 }} de {{ arg_1 }}: 100% {{ arg_3 }}".to_owned(),
+                vec!["%2$s".to_owned(), "%1$s".to_owned(), "%-5.2f".to_owned()]
+            )
+        );
+        assert_eq!(
+            convert("%s and %1$s").0,
+            "{{ arg_2 }} and {{ arg_1 }}".to_owned()
+        );
+        assert_eq!(convert("50 % off"), ("50 % off".to_owned(), vec![]));
+        assert_eq!(convert("100%% é%%"), ("100% é%".to_owned(), vec![]));
+        assert_eq!(
+            convert("%lu items"),
+            ("{{ arg_1 }} items".to_owned(), vec!["%lu".to_owned()])
+        );
+    }
+
+    #[test]
+    fn test_convert_value() {
+        let mut value = serde_json::json!({
+            "greeting": "Hi %s",
+            "sub": { "items": [["%d item", 1], ["%d items", "_"]] }
+        });
+        let mut conversions = vec![];
+        convert_value(&mut value, "", &mut conversions);
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "greeting": "Hi {{ arg_1 }}",
+                "sub": { "items": [["{{ arg_1 }} item", 1], ["{{ arg_1 }} items", "_"]] }
+            })
+        );
+        let keys = conversions
+            .iter()
+            .map(|c| c.key.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(keys, ["greeting", "sub.items", "sub.items"]);
+    }
+}
@@ -0,0 +1,299 @@
+use std::{collections::BTreeMap, path::Path};
+
+use serde::Deserialize;
+
+use crate::{
+    catalog::{Catalog, Entry},
+    check::Issue,
+    config::Config,
+    error::{Error, Result},
+};
+
+const QA_FILE: &str = "qa.toml";
+
+/// The QA rules, all enabled unless disabled in the `qa.toml` file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    /// Leading or trailing whitespace that the value of the default locale doesn't have.
+    pub trailing_space: bool,
+    /// Two consecutive spaces.
+    pub double_spaces: bool,
+    /// Unbalanced brackets and quotes.
+    pub brackets: bool,
+    /// A terminal punctuation (`.`, `?`, `!`, `:` or `…`) differing from the one of the default locale.
+    pub terminal_punctuation: bool,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules {
+            trailing_space: true,
+            double_spaces: true,
+            brackets: true,
+            terminal_punctuation: true,
+        }
+    }
+}
+
+/// The rules set in the file, the others being inherited.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+struct RulesOverrides {
+    trailing_space: Option<bool>,
+    double_spaces: Option<bool>,
+    brackets: Option<bool>,
+    terminal_punctuation: Option<bool>,
+}
+
+impl Rules {
+    fn apply(self, overrides: RulesOverrides) -> Self {
+        Rules {
+            trailing_space: overrides.trailing_space.unwrap_or(self.trailing_space),
+            double_spaces: overrides.double_spaces.unwrap_or(self.double_spaces),
+            brackets: overrides.brackets.unwrap_or(self.brackets),
+            terminal_punctuation: overrides
+                .terminal_punctuation
+                .unwrap_or(self.terminal_punctuation),
+        }
+    }
+}
+
+/// The QA checks on the punctuation and spacing of the values, configured for every locale
+/// by the top level keys of the `qa.toml` file and for each locale by its table:
+///
+/// ```toml
+/// double-spaces = false
+///
+/// [th]
+/// terminal-punctuation = false
+/// ```
+#[derive(Debug, Default)]
+pub struct Qa {
+    pub rules: Rules,
+    pub locales: BTreeMap<String, Rules>,
+}
+
+impl Qa {
+    /// Load the `qa.toml` file at the root of the locales directory, if any.
+    pub fn load(config: &Config, manifest_dir: &Path) -> Result<Option<Self>> {
+        let path = config.locales_dir.join(QA_FILE);
+        let content = match std::fs::read_to_string(manifest_dir.join(&path)) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(Error::Io(path, err)),
+        };
+        Self::parse(&content)
+            .map(Some)
+            .map_err(|err| Error::QaDeser(path, err))
+    }
+
+    fn parse(content: &str) -> Result<Self, String> {
+        let table: toml::Table = toml::de::from_str(content).map_err(|err| err.to_string())?;
+        let (locales, rules): (toml::Table, toml::Table) =
+            table.into_iter().partition(|(_, value)| value.is_table());
+        let overrides: RulesOverrides = rules.try_into().map_err(|err| err.to_string())?;
+        let rules = Rules::default().apply(overrides);
+        let mut qa = Qa {
+            rules,
+            locales: BTreeMap::new(),
+        };
+        for (locale, overrides) in locales {
+            let overrides: RulesOverrides = overrides
+                .try_into()
+                .map_err(|err| format!("in [{}]: {}", locale, err))?;
+            qa.locales.insert(locale, rules.apply(overrides));
+        }
+        Ok(qa)
+    }
+
+    fn rules(&self, locale: &str) -> Rules {
+        self.locales.get(locale).copied().unwrap_or(self.rules)
+    }
+
+    /// Check the values of every locale, comparing them to the values of the default locale for the parity rules.
+    pub fn check(&self, catalog: &Catalog, default_locale: &str) -> Vec<Issue> {
+        let mut issues = vec![];
+        let default_entries = catalog.0.get(default_locale);
+        for (locale, entries) in &catalog.0 {
+            let rules = self.rules(locale);
+            for (key, entry) in entries {
+                let source = default_entries.and_then(|entries| entries.get(key));
+                for message in check_entry(rules, entry, source) {
+                    issues.push(Issue::new(locale, key, message));
+                }
+            }
+        }
+        issues
+    }
+}
+
+fn edge_whitespace(strings: &[&str]) -> (bool, bool) {
+    let leading = strings.iter().any(|s| s.starts_with(char::is_whitespace));
+    let trailing = strings.iter().any(|s| s.ends_with(char::is_whitespace));
+    (leading, trailing)
+}
+
+/// Return the first unbalanced bracket or quote of a line.
+fn unbalanced(line: &str) -> Option<char> {
+    let mut stack = vec![];
+    for c in line.chars() {
+        match c {
+            '(' | '[' | '{' | '«' => stack.push(c),
+            ')' | ']' | '}' | '»' => {
+                let open = match c {
+                    ')' => '(',
+                    ']' => '[',
+                    '}' => '{',
+                    _ => '«',
+                };
+                if stack.pop() != Some(open) {
+                    return Some(c);
+                }
+            }
+            _ => {}
+        }
+    }
+    if let Some(open) = stack.pop() {
+        return Some(open);
+    }
+    let count = |c: char| line.matches(c).count();
+    if count('"') % 2 != 0 {
+        return Some('"');
+    }
+    // German quotes open with „ and close with “, Polish ones with ”.
+    let curly_balanced = if line.contains('„') {
+        count('„') == count('“') + count('”')
+    } else {
+        count('“') == count('”')
+    };
+    (!curly_balanced).then_some('“')
+}
+
+fn terminal_punctuation(text: &str) -> Option<&'static str> {
+    let text = text.trim_end();
+    if text.ends_with("...") || text.ends_with('…') {
+        return Some("…");
+    }
+    match text.chars().last()? {
+        '.' | '。' | '।' | '։' | '۔' => Some("."),
+        '?' | '？' | '؟' => Some("?"),
+        '!' | '！' => Some("!"),
+        ':' | '：' => Some(":"),
+        _ => None,
+    }
+}
+
+fn check_entry(rules: Rules, entry: &Entry, source: Option<&Entry>) -> Vec<String> {
+    let mut messages = vec![];
+    let values = entry.strings();
+    let source_values = source.map(Entry::strings).unwrap_or_default();
+
+    if rules.trailing_space {
+        let (leading, trailing) = edge_whitespace(&values);
+        let (source_leading, source_trailing) = edge_whitespace(&source_values);
+        if leading && !source_leading {
+            messages.push("leading whitespace not in the default locale".to_owned());
+        }
+        if trailing && !source_trailing {
+            messages.push("trailing whitespace not in the default locale".to_owned());
+        }
+    }
+
+    if rules.double_spaces && values.iter().any(|s| s.contains("  ")) {
+        messages.push("double space".to_owned());
+    }
+
+    let text = entry.text();
+    if rules.brackets {
+        if let Some(c) = text.lines().find_map(unbalanced) {
+            messages.push(format!("unbalanced {:?}", c));
+        }
+    }
+
+    // empty values are explicit defaults, they have no text to compare.
+    let source_text = source.map(Entry::text).unwrap_or_default();
+    if rules.terminal_punctuation && !text.trim().is_empty() && !source_text.trim().is_empty() {
+        match (
+            terminal_punctuation(&text),
+            terminal_punctuation(&source_text),
+        ) {
+            (found, expected) if found == expected => {}
+            (Some(found), Some(expected)) => messages.push(format!(
+                "ends with {:?} but the default locale ends with {:?}",
+                found, expected
+            )),
+            (Some(found), None) => messages.push(format!(
+                "ends with {:?} but the default locale has no terminal punctuation",
+                found
+            )),
+            (None, Some(expected)) => messages.push(format!(
+                "missing the terminal punctuation {:?} of the default locale",
+                expected
+            )),
+            (None, None) => {}
+        }
+    }
+
+    messages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    #[test]
+    fn test_parse() {
+        let qa = Qa::parse("double-spaces = false\n[th]\nterminal-punctuation = false").unwrap();
+        assert!(!qa.rules.double_spaces && qa.rules.brackets);
+        let th = qa.rules("th");
+        assert!(!th.double_spaces && !th.terminal_punctuation && th.brackets);
+        assert!(Qa::parse("double-space = false").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let mut catalog = Catalog::default();
+        for (locale, key, value) in [
+            ("en", "save", "Save your changes."),
+            ("en", "open", "Open (new tab)"),
+            ("en", "ask", "Are you sure?"),
+            ("en", "quote", "He said \"hello\""),
+            ("fr", "save", "Enregistrer  vos modifications "),
+            ("fr", "open", "Ouvrir (nouvel onglet"),
+            ("fr", "ask", "Êtes-vous sûr ?"),
+            ("fr", "quote", "Il a dit « bonjour »"),
+            ("de", "save", "Änderungen speichern."),
+            ("de", "open", "Öffnen (neuer Tab)"),
+            ("de", "ask", "Sind Sie sicher!"),
+            ("de", "quote", "Er sagte „hallo“"),
+        ] {
+            let entry = Entry::new(Value::String(value.to_owned()));
+            let entries = catalog.0.entry(locale.to_owned()).or_default();
+            entries.insert(key.to_owned(), entry);
+        }
+        let issues = Qa::default().check(&catalog, "en");
+        assert_eq!(
+            issues,
+            [
+                Issue::new(
+                    "de",
+                    "ask",
+                    "ends with \"!\" but the default locale ends with \"?\""
+                ),
+                Issue::new("fr", "open", "unbalanced '('"),
+                Issue::new(
+                    "fr",
+                    "save",
+                    "trailing whitespace not in the default locale"
+                ),
+                Issue::new("fr", "save", "double space"),
+                Issue::new(
+                    "fr",
+                    "save",
+                    "missing the terminal punctuation \".\" of the default locale"
+                ),
+            ]
+        );
+    }
+}
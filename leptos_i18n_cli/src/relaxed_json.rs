@@ -0,0 +1,77 @@
+/// Turn relaxed JSON (JSON with comments, trailing commas and a byte order mark) into strict JSON.
+///
+/// Comments and trailing commas are replaced by spaces, so the errors of the parser still point
+/// to the right line and column of the file.
+pub fn relax(content: &str) -> String {
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+    let mut relaxed = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut in_string = false;
+    // a comma only followed by whitespaces and comments until now.
+    let mut last_comma = None;
+    while let Some(c) = chars.next() {
+        if in_string {
+            relaxed.push(c);
+            match c {
+                '\\' => relaxed.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '/' if chars.peek() == Some(&'/') => {
+                relaxed.push(' ');
+                while chars.next_if(|c| *c != '\n').is_some() {
+                    relaxed.push(' ');
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                relaxed.push_str("  ");
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    relaxed.push(if c == '\n' { '\n' } else { ' ' });
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+            }
+            ',' => {
+                last_comma = Some(relaxed.len());
+                relaxed.push(c);
+            }
+            '}' | ']' => {
+                if let Some(i) = last_comma.take() {
+                    relaxed.replace_range(i..i + 1, " ");
+                }
+                relaxed.push(c);
+            }
+            c if c.is_whitespace() => relaxed.push(c),
+            c => {
+                in_string = c == '"';
+                last_comma = None;
+                relaxed.push(c);
+            }
+        }
+    }
+    relaxed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relax() {
+        let content = "\u{feff}{\n  // comment\n  \"a\": \"// not a comment, \\\"}\", /* block\n comment */\n  \"b\": [1, 2,],\n}";
+        let relaxed = relax(content);
+        assert_eq!(
+            relaxed,
+            "{\n            \n  \"a\": \"// not a comment, \\\"}\",         \n           \n  \"b\": [1, 2 ] \n}"
+        );
+        let value: serde_json::Value = serde_json::from_str(&relaxed).unwrap();
+        assert_eq!(value["b"], serde_json::json!([1, 2]));
+    }
+}
@@ -0,0 +1,193 @@
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+
+/// The macros taking a key path as their second argument.
+const KEY_MACROS: &[&str] = &["t", "td", "t_string", "td_string", "td_display"];
+
+/// The macros taking a source string of the default locale as their second argument.
+const GETTEXT_MACROS: &[&str] = &["gettext", "ngettext"];
+
+/// A call of a translation macro found in a Rust source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Call {
+    /// A call of the `t!` family of macros, with the path of the key as written (`parent.key` or `namespace::key`)
+    /// and the names of the variables and components given to it.
+    Key {
+        path: String,
+        variables: Vec<String>,
+        components: Vec<String>,
+    },
+    /// A call of `gettext!` or `ngettext!`, with its source string.
+    Gettext(String),
+}
+
+/// Return the Rust files of a directory and its subdirectories.
+pub fn rust_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    let entries = std::fs::read_dir(dir).map_err(|err| Error::Io(dir.to_owned(), err))?;
+    for entry in entries {
+        let path = entry.map_err(|err| Error::Io(dir.to_owned(), err))?.path();
+        if path.is_dir() {
+            files.extend(rust_files(&path)?);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Find the calls of the translation macros in a Rust source file.
+pub fn calls(source: &str) -> Vec<Call> {
+    let mut calls = vec![];
+    for (bang, _) in source.match_indices('!') {
+        let before = &source[..bang];
+        let start = before
+            .rfind(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .map_or(0, |i| i + 1);
+        let name = &before[start..];
+        let is_key_macro = KEY_MACROS.contains(&name);
+        if !is_key_macro && !GETTEXT_MACROS.contains(&name) {
+            continue;
+        }
+        let Some(args) = arguments(&source[bang + 1..]) else {
+            continue;
+        };
+        // the first argument is the context or the locale.
+        let Some(arg) = args.get(1) else {
+            continue;
+        };
+        if !is_key_macro {
+            if let Some(source) = string_literal(arg) {
+                calls.push(Call::Gettext(source));
+            }
+            continue;
+        }
+        let path: String = arg
+            .chars()
+            .take_while(|c| c.is_alphanumeric() || matches!(c, '_' | '.' | ':'))
+            .collect();
+        if path.is_empty() {
+            continue;
+        }
+        let mut variables = vec![];
+        let mut components = vec![];
+        for arg in &args[2..] {
+            let (is_comp, arg) = match arg.strip_prefix('<') {
+                Some(arg) => (true, arg.trim_start()),
+                None => (false, *arg),
+            };
+            let name: String = arg
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '_')
+                .collect();
+            match (name.is_empty(), is_comp) {
+                (true, _) => {}
+                (false, true) => components.push(name),
+                (false, false) => variables.push(name),
+            }
+        }
+        calls.push(Call::Key {
+            path,
+            variables,
+            components,
+        });
+    }
+    calls
+}
+
+/// Split the arguments of a macro call at the commas outside of brackets and string literals.
+fn arguments(call: &str) -> Option<Vec<&str>> {
+    let call = call.trim_start();
+    if !call.starts_with(['(', '[', '{']) {
+        return None;
+    }
+    let mut args = vec![];
+    let mut depth = 0usize;
+    let mut arg_start = 1;
+    let mut chars = call.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    let arg = call[arg_start..i].trim();
+                    if !arg.is_empty() {
+                        args.push(arg);
+                    }
+                    return Some(args);
+                }
+            }
+            '"' => {
+                while let Some((_, c)) = chars.next() {
+                    match c {
+                        '\\' => {
+                            chars.next();
+                        }
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            ',' if depth == 1 => {
+                args.push(call[arg_start..i].trim());
+                arg_start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn string_literal(s: &str) -> Option<String> {
+    let mut chars = s.strip_prefix('"')?.chars();
+    let mut literal = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return Some(literal),
+            '\\' => match chars.next()? {
+                'n' => literal.push('\n'),
+                't' => literal.push('\t'),
+                c => literal.push(c),
+            },
+            c => literal.push(c),
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calls() {
+        let calls = calls(
+            r#"
+            view! {
+                <p>{t!(i18n, hello)}</p>
+                <p>{leptos_i18n::td!(Locale::fr, home::title, name = "a, b", <b>)}</p>
+                <p>{t_string!(use_i18n(), menu.open_menu, count = move || f(1, 2))}</p>
+                <p>{gettext!(i18n, "Click \"here\"")}</p>
+            }
+            let not_a_macro = tt!(i18n, other);
+            "#,
+        );
+        let key = |path: &str, variables: &[&str], components: &[&str]| Call::Key {
+            path: path.to_owned(),
+            variables: variables.iter().map(|s| s.to_string()).collect(),
+            components: components.iter().map(|s| s.to_string()).collect(),
+        };
+        assert_eq!(
+            calls,
+            [
+                key("hello", &[], &[]),
+                key("home::title", &["name"], &["b"]),
+                key("menu.open_menu", &["count"], &[]),
+                Call::Gettext("Click \"here\"".to_owned()),
+            ]
+        );
+    }
+}
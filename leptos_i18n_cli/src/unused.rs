@@ -0,0 +1,133 @@
+use std::{collections::BTreeSet, path::Path};
+
+use crate::{
+    catalog::{ident_path, Catalog},
+    error::{Error, Result},
+    scan::{self, Call},
+};
+
+/// The keys referenced in the sources of a project.
+#[derive(Debug, Default)]
+pub struct References {
+    /// The key paths given to the `t!` family of macros, as paths of the `i18n` module (`namespace.parent.key`).
+    pub keys: BTreeSet<String>,
+    /// The source strings given to `gettext!` and `ngettext!`.
+    pub sources: BTreeSet<String>,
+}
+
+impl References {
+    /// Scan the Rust files of a directory and its subdirectories.
+    pub fn scan_dir(&mut self, dir: &Path) -> Result<()> {
+        for path in scan::rust_files(dir)? {
+            let source = std::fs::read_to_string(&path).map_err(|err| Error::Io(path, err))?;
+            self.scan(&source);
+        }
+        Ok(())
+    }
+
+    /// Collect the keys referenced by the macro calls of a Rust source file.
+    pub fn scan(&mut self, source: &str) {
+        for call in scan::calls(source) {
+            match call {
+                Call::Key { path, .. } => self.keys.insert(ident_path(&path)),
+                Call::Gettext(source) => self.sources.insert(source),
+            };
+        }
+    }
+}
+
+/// Return the keys referenced by a value with `{{ @key }}` or `$t(key)`.
+fn foreign_keys(value: &str) -> Vec<String> {
+    let mut keys = vec![];
+    for (open, prefix) in [("{{", "@"), ("$t(", "")] {
+        for part in value.split(open).skip(1) {
+            let Some(path) = part.trim_start().strip_prefix(prefix) else {
+                continue;
+            };
+            let path: String = path
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | ':'))
+                .collect();
+            if !path.is_empty() {
+                keys.push(ident_path(&path));
+            }
+        }
+    }
+    keys
+}
+
+/// Return the keys of the default locale that are never referenced: not given to a `t!` macro, without their value
+/// of the default locale given to `gettext!`, and not the foreign key of a referenced key.
+pub fn unused_keys(
+    catalog: &Catalog,
+    default_locale: &str,
+    references: &References,
+) -> Vec<String> {
+    let Some(default_entries) = catalog.0.get(default_locale) else {
+        return vec![];
+    };
+    let mut used: BTreeSet<String> = references.keys.clone();
+    for (key, entry) in default_entries {
+        if entry
+            .strings()
+            .iter()
+            .any(|s| references.sources.contains(*s))
+        {
+            used.insert(ident_path(key));
+        }
+    }
+
+    // the foreign keys of the used keys are used too, in any locale.
+    let mut pending: Vec<String> = used.iter().cloned().collect();
+    while let Some(path) = pending.pop() {
+        for entries in catalog.0.values() {
+            let Some(entry) = entries.iter().find(|(key, _)| ident_path(key) == path) else {
+                continue;
+            };
+            for foreign_key in entry.1.strings().into_iter().flat_map(foreign_keys) {
+                if used.insert(foreign_key.clone()) {
+                    pending.push(foreign_key);
+                }
+            }
+        }
+    }
+
+    default_entries
+        .keys()
+        .filter(|key| !used.contains(&ident_path(key)))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::Entry;
+
+    #[test]
+    fn test_unused_keys() {
+        let mut catalog = Catalog::default();
+        for (locale, key, value) in [
+            ("en", "hello", "Hello {{ @brand }}"),
+            ("en", "brand", "Acme"),
+            ("en", "home::title", "Home"),
+            ("en", "home::sub-title", "Welcome"),
+            ("en", "click", "Click here"),
+            ("en", "dead", "Dead string $t(dead_ref)"),
+            ("en", "dead_ref", "Only used by a dead string"),
+            ("fr", "hello", "Bonjour {{ @brand }}"),
+        ] {
+            let entry = Entry::new(serde_json::Value::String(value.to_owned()));
+            let entries = catalog.0.entry(locale.to_owned()).or_default();
+            entries.insert(key.to_owned(), entry);
+        }
+        let references = References {
+            keys: BTreeSet::from(["hello".to_owned(), "home.sub_title".to_owned()]),
+            sources: BTreeSet::from(["Click here".to_owned()]),
+        };
+        assert_eq!(
+            unused_keys(&catalog, "en", &references),
+            ["dead", "dead_ref", "home::title"]
+        );
+    }
+}
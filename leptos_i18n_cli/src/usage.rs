@@ -0,0 +1,216 @@
+use std::{collections::BTreeMap, fmt::Write, path::Path};
+
+use crate::{
+    catalog::{ident_path, Catalog},
+    config::Config,
+    error::{Error, Result},
+};
+
+/// The usage telemetry exported by `leptos_i18n::usage::encode`: the number of times each key was rendered,
+/// by locale then by key path.
+#[derive(Debug, Default)]
+pub struct Usage(pub BTreeMap<String, BTreeMap<String, u64>>);
+
+impl Usage {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        serde_json::from_str(s)
+            .map(Usage)
+            .map_err(|err| err.to_string())
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let content =
+            std::fs::read_to_string(path).map_err(|err| Error::Io(path.to_owned(), err))?;
+        Self::parse(&content).map_err(|err| Error::UsageDeser(path.to_owned(), err))
+    }
+
+    fn count(&self, locale: &str, key: &str) -> u64 {
+        let key = ident_path(key);
+        self.0
+            .get(locale)
+            .and_then(|keys| keys.get(&key))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// The usage merged with the catalog.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct UsageReport {
+    /// For each locale other than the default, the keys rendered in it without being translated,
+    /// missing or set to `null`, with their count, the most rendered first.
+    pub untranslated: BTreeMap<String, Vec<(String, u64)>>,
+    /// The keys of the default locale never rendered in any locale, with the number of other locales translating them.
+    pub never_rendered: Vec<(String, usize)>,
+    /// The keys recorded in the usage but not in the catalog, like renamed or removed keys.
+    pub unknown: Vec<String>,
+}
+
+pub fn report(catalog: &Catalog, config: &Config, usage: &Usage) -> UsageReport {
+    let mut report = UsageReport::default();
+    let Some(default_entries) = catalog.0.get(&config.default) else {
+        return report;
+    };
+    let is_translated = |locale: &str, key: &str| {
+        catalog
+            .0
+            .get(locale)
+            .and_then(|entries| entries.get(key))
+            .is_some_and(|entry| !entry.value.is_null())
+    };
+    let other_locales = || config.locales.iter().filter(|l| **l != config.default);
+
+    for locale in other_locales() {
+        let mut keys: Vec<(String, u64)> = default_entries
+            .keys()
+            .filter(|key| !is_translated(locale, key))
+            .map(|key| (key.clone(), usage.count(locale, key)))
+            .filter(|(_, count)| *count > 0)
+            .collect();
+        keys.sort_by(|(k1, c1), (k2, c2)| c2.cmp(c1).then_with(|| k1.cmp(k2)));
+        if !keys.is_empty() {
+            report.untranslated.insert(locale.clone(), keys);
+        }
+    }
+
+    for key in default_entries.keys() {
+        let rendered = config
+            .locales
+            .iter()
+            .any(|locale| usage.count(locale, key) > 0);
+        if !rendered {
+            let translations = other_locales()
+                .filter(|locale| is_translated(locale, key))
+                .count();
+            report.never_rendered.push((key.clone(), translations));
+        }
+    }
+
+    let known: Vec<String> = default_entries.keys().map(|key| ident_path(key)).collect();
+    let mut unknown: Vec<String> = usage
+        .0
+        .values()
+        .flat_map(|keys| keys.keys())
+        .filter(|key| !known.contains(key))
+        .cloned()
+        .collect();
+    unknown.sort_unstable();
+    unknown.dedup();
+    report.unknown = unknown;
+    report
+}
+
+/// Render the report, the high-traffic untranslated keys first to prioritize the translations.
+pub fn render(report: &UsageReport) -> String {
+    let mut buff = String::new();
+    for (locale, keys) in &report.untranslated {
+        let _ = writeln!(
+            buff,
+            "Locale {:?}: {} rendered key(s) not translated",
+            locale,
+            keys.len()
+        );
+        for (key, count) in keys {
+            let _ = writeln!(buff, "  {:>8}  {}", count, key);
+        }
+        buff.push('\n');
+    }
+    if !report.never_rendered.is_empty() {
+        let _ = writeln!(
+            buff,
+            "{} key(s) never rendered:",
+            report.never_rendered.len()
+        );
+        for (key, translations) in &report.never_rendered {
+            let _ = writeln!(buff, "  {} (translated in {} locale(s))", key, translations);
+        }
+        buff.push('\n');
+    }
+    if !report.unknown.is_empty() {
+        let _ = writeln!(
+            buff,
+            "{} recorded key(s) not in the catalog:",
+            report.unknown.len()
+        );
+        for key in &report.unknown {
+            let _ = writeln!(buff, "  {}", key);
+        }
+        buff.push('\n');
+    }
+    if buff.is_empty() {
+        buff.push_str("Every key is translated and rendered.\n");
+    }
+    buff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::Entry;
+    use serde_json::Value;
+
+    fn catalog(locales: &[(&str, Value)]) -> Catalog {
+        let mut catalog = Catalog::default();
+        for (locale, value) in locales {
+            let entries = value
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(key, value)| (key.clone(), Entry::new(value.clone())))
+                .collect();
+            catalog.0.insert(locale.to_string(), entries);
+        }
+        catalog
+    }
+
+    #[test]
+    fn test_report() {
+        let config = Config {
+            default: "en".to_owned(),
+            locales: vec!["en".to_owned(), "fr".to_owned()],
+            namespaces: None,
+            locales_dir: "./locales".into(),
+            file_names: Default::default(),
+            relaxed_json: false,
+        };
+        let catalog = catalog(&[
+            (
+                "en",
+                serde_json::json!({
+                    "hello": "Hello",
+                    "home::title": "Home",
+                    "home::sub-title": "Welcome",
+                    "legal": "Legal",
+                    "banner": "Sale!",
+                }),
+            ),
+            (
+                "fr",
+                serde_json::json!({ "hello": "Bonjour", "home::title": null, "banner": "Soldes !" }),
+            ),
+        ]);
+        let usage = Usage::parse(
+            r#"{
+                "en": { "hello": 40, "legal": 2 },
+                "fr": { "hello": 12, "home.title": 9, "home.sub_title": 30, "old_key": 1 }
+            }"#,
+        )
+        .unwrap();
+
+        let report = report(&catalog, &config, &usage);
+        assert_eq!(
+            report,
+            UsageReport {
+                untranslated: BTreeMap::from([(
+                    "fr".to_owned(),
+                    vec![
+                        ("home::sub-title".to_owned(), 30),
+                        ("home::title".to_owned(), 9)
+                    ]
+                )]),
+                never_rendered: vec![("banner".to_owned(), 1)],
+                unknown: vec!["old_key".to_owned()],
+            }
+        );
+    }
+}
@@ -0,0 +1,552 @@
+use std::{collections::BTreeSet, fmt::Display, path::Path};
+
+use toml::{Table, Value};
+
+use crate::error::{Error, Result};
+
+/// The JSON schema of the configuration, for editors and other tools.
+pub const SCHEMA: &str = include_str!("../config.schema.json");
+
+const COUNT_TYPES: &[&str] = &[
+    "i8", "i16", "i32", "i64", "i128", "u8", "u16", "u32", "u64", "u128", "f32", "f64",
+];
+
+/// The value expected for a key of the configuration.
+enum Expected {
+    String,
+    Bool,
+    Number,
+    Percentage,
+    OneOf(&'static [&'static str]),
+    /// A locale declared in `locales`.
+    Locale,
+    Array(&'static Expected),
+    /// A table keyed by the declared locales.
+    PerLocale(&'static Expected),
+    /// A table with any keys.
+    Table(&'static Expected),
+}
+
+/// The keys of the configuration, kept in sync with `config.schema.json`.
+const FIELDS: &[(&str, Expected)] = &[
+    ("default", Expected::String),
+    ("locales", Expected::Array(&Expected::String)),
+    ("namespaces", Expected::Array(&Expected::String)),
+    ("locales-dir", Expected::String),
+    ("beta-locales", Expected::Array(&Expected::Locale)),
+    ("rollout", Expected::PerLocale(&Expected::Percentage)),
+    ("size-report", Expected::String),
+    ("timings", Expected::Bool),
+    ("plural-constants", Expected::Table(&Expected::Number)),
+    (
+        "count-types",
+        Expected::Table(&Expected::OneOf(COUNT_TYPES)),
+    ),
+    ("negative-counts", Expected::OneOf(&["literal", "absolute"])),
+    ("format-counts", Expected::Bool),
+    ("locales-url", Expected::String),
+    ("file-names", Expected::PerLocale(&Expected::String)),
+    ("relaxed-json", Expected::Bool),
+    ("dynamic-load-url", Expected::String),
+    ("font-classes", Expected::PerLocale(&Expected::String)),
+    (
+        "font-preloads",
+        Expected::PerLocale(&Expected::Array(&Expected::String)),
+    ),
+    ("gettext", Expected::Bool),
+    ("missing-keys", Expected::OneOf(&["warn", "error"])),
+    (
+        "fallbacks",
+        Expected::PerLocale(&Expected::Array(&Expected::Locale)),
+    ),
+    ("persistence", Expected::OneOf(&["cookie", "local_storage"])),
+    ("normalize-emoji", Expected::Bool),
+    ("pseudo-locale", Expected::String),
+    ("normalize-unicode", Expected::Bool),
+];
+
+const REQUIRED: &[&str] = &["default", "locales"];
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expected::String => f.write_str("a string"),
+            Expected::Bool => f.write_str("a boolean"),
+            Expected::Number => f.write_str("a number"),
+            Expected::Percentage => f.write_str("a percentage between 0 and 100"),
+            Expected::OneOf(values) => {
+                f.write_str("one of ")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{:?}", value)?;
+                }
+                Ok(())
+            }
+            Expected::Locale => f.write_str("a locale"),
+            Expected::Array(_) => f.write_str("an array"),
+            Expected::PerLocale(_) | Expected::Table(_) => f.write_str("a table"),
+        }
+    }
+}
+
+/// A problem found in the configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigIssue {
+    /// The path of the value, like `rollout.fr` or `fallbacks.fr-CA[0]`.
+    pub key: String,
+    pub message: String,
+}
+
+impl ConfigIssue {
+    pub fn new(key: &str, message: impl Into<String>) -> Self {
+        ConfigIssue {
+            key: key.to_owned(),
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for ConfigIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Key {:?}: {}", self.key, self.message)
+    }
+}
+
+/// Return the levenshtein distance between two strings.
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diag = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let up = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                diag
+            } else {
+                1 + diag.min(up).min(row[j])
+            };
+            diag = up;
+        }
+    }
+    row[b.len()]
+}
+
+/// Return the candidate closest to the given word, if close enough to be a typo.
+fn closest<'a>(word: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    candidates
+        .map(|candidate| (distance(word, candidate), candidate))
+        .filter(|(d, candidate)| *d <= (candidate.len() / 3).max(2))
+        .min_by_key(|(d, _)| *d)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Suggest a key for an unknown one, first by the whole key then by its first segment,
+/// so `defaut-locale` suggests `default`.
+fn suggest_key(key: &str) -> Option<&'static str> {
+    let names = || FIELDS.iter().map(|(name, _)| *name);
+    closest(key, names()).or_else(|| {
+        let (segment, _) = key.split_once(['-', '_'])?;
+        closest(segment, names())
+    })
+}
+
+fn found(value: &Value) -> String {
+    match value {
+        Value::String(s) => format!("{:?}", s),
+        Value::Integer(_) | Value::Float(_) | Value::Boolean(_) => value.to_string(),
+        Value::Datetime(_) => "a date".to_owned(),
+        Value::Array(_) => "an array".to_owned(),
+        Value::Table(_) => "a table".to_owned(),
+    }
+}
+
+fn check_value(
+    path: &str,
+    value: &Value,
+    expected: &Expected,
+    locales: &BTreeSet<&str>,
+    issues: &mut Vec<ConfigIssue>,
+) {
+    match (expected, value) {
+        (Expected::String, Value::String(_))
+        | (Expected::Bool, Value::Boolean(_))
+        | (Expected::Number, Value::Integer(_) | Value::Float(_)) => {}
+        (Expected::Percentage, Value::Integer(percentage)) if (0..=100).contains(percentage) => {}
+        (Expected::OneOf(values), Value::String(s)) if values.contains(&s.as_str()) => {}
+        (Expected::Locale, Value::String(locale)) => {
+            if !locales.contains(locale.as_str()) {
+                let message = format!("locale {:?} is not declared in `locales`", locale);
+                issues.push(ConfigIssue::new(path, message));
+            }
+        }
+        (Expected::Array(item), Value::Array(values)) => {
+            for (i, value) in values.iter().enumerate() {
+                check_value(&format!("{}[{}]", path, i), value, item, locales, issues);
+            }
+        }
+        (Expected::PerLocale(item), Value::Table(table)) => {
+            for (locale, value) in table {
+                let path = format!("{}.{}", path, locale);
+                if !locales.contains(locale.as_str()) {
+                    let message = format!("locale {:?} is not declared in `locales`", locale);
+                    issues.push(ConfigIssue::new(&path, message));
+                }
+                check_value(&path, value, item, locales, issues);
+            }
+        }
+        (Expected::Table(item), Value::Table(table)) => {
+            for (key, value) in table {
+                check_value(&format!("{}.{}", path, key), value, item, locales, issues);
+            }
+        }
+        _ => {
+            let mut message = format!("expected {}, found {}", expected, found(value));
+            if let (Expected::OneOf(values), Value::String(s)) = (expected, value) {
+                if let Some(suggestion) = closest(s, values.iter().copied()) {
+                    message = format!("{}, did you mean {:?}?", message, suggestion);
+                }
+            }
+            issues.push(ConfigIssue::new(path, message));
+        }
+    }
+}
+
+/// Check the configuration: unknown keys, with a suggestion for typos, values of the wrong type,
+/// and locales used by other keys but not declared in `locales`.
+pub fn validate(config: &Table) -> Vec<ConfigIssue> {
+    let mut issues = vec![];
+    for key in REQUIRED {
+        if !config.contains_key(*key) {
+            issues.push(ConfigIssue::new(key, "missing required key"));
+        }
+    }
+
+    // the default locale is added to the locales if missing, and the pseudo locale is added to them.
+    let mut locales: BTreeSet<&str> = config
+        .get("locales")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect();
+    locales.extend(config.get("default").and_then(Value::as_str));
+    locales.extend(config.get("pseudo-locale").and_then(Value::as_str));
+
+    for (key, value) in config {
+        match FIELDS.iter().find(|(name, _)| name == key) {
+            Some((_, expected)) => check_value(key, value, expected, &locales, &mut issues),
+            None => {
+                let message = match suggest_key(key) {
+                    Some(suggestion) => format!("unknown key, did you mean {:?}?", suggestion),
+                    None => "unknown key".to_owned(),
+                };
+                issues.push(ConfigIssue::new(key, message));
+            }
+        }
+    }
+    issues
+}
+
+/// Return the `[package.metadata.leptos-i18n]` table of a cargo manifest.
+pub fn manifest_config(manifest: &str) -> Result<Table> {
+    let manifest: Table = toml::from_str(manifest).map_err(Error::ConfigDeser)?;
+    manifest
+        .get("package")
+        .and_then(|package| package.get("metadata"))
+        .and_then(|metadata| metadata.get("leptos-i18n"))
+        .and_then(Value::as_table)
+        .cloned()
+        .ok_or(Error::ConfigNotPresent)
+}
+
+/// Load the configuration to validate from a cargo manifest, or from a Rust file calling
+/// `load_locales!(config { .. })`.
+pub fn load(path: &Path) -> Result<Table> {
+    let content = std::fs::read_to_string(path).map_err(|err| Error::Io(path.to_owned(), err))?;
+    if path.extension().is_some_and(|ext| ext == "rs") {
+        inline_config(&content)
+            .map_err(|err| Error::InlineConfig(path.to_owned(), err))?
+            .ok_or_else(|| {
+                let msg = "no `load_locales!(config { .. })` found".to_owned();
+                Error::InlineConfig(path.to_owned(), msg)
+            })
+    } else {
+        manifest_config(&content)
+    }
+}
+
+/// A parser for the configuration given to `load_locales!`, with the same syntax and key
+/// normalization as the macro: `_` becomes `-` in the top level keys and `path` is `locales-dir`.
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+
+    fn skip_trivia(&mut self) {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.pos += rest.len() - trimmed.len();
+            if trimmed.starts_with("//") {
+                self.pos += trimmed.find('\n').unwrap_or(trimmed.len());
+            } else if let Some(comment) = trimmed.strip_prefix("/*") {
+                self.pos += comment.find("*/").map_or(trimmed.len(), |end| end + 4);
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_trivia();
+        self.rest().chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let eaten = self.peek() == Some(c);
+        if eaten {
+            self.pos += c.len_utf8();
+        }
+        eaten
+    }
+
+    fn error(&self, msg: &str) -> String {
+        let line = self.src[..self.pos].lines().count().max(1);
+        format!("line {}: {}", line, msg)
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{}`", c)))
+        }
+    }
+
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        self.skip_trivia();
+        let rest = self.rest();
+        let len = rest.find(|c| !f(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    fn ident(&mut self) -> &'a str {
+        self.take_while(|c| c.is_alphanumeric() || c == '_')
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+        let mut s = String::new();
+        let mut chars = self.rest().char_indices();
+        while let Some((i, c)) = chars.next() {
+            let c = match c {
+                '"' => {
+                    self.pos += i + 1;
+                    return Ok(s);
+                }
+                '\\' => match chars.next().map(|(_, c)| c) {
+                    Some('n') => '\n',
+                    Some('t') => '\t',
+                    Some('r') => '\r',
+                    Some('0') => '\0',
+                    Some(c @ ('\\' | '"' | '\'')) => c,
+                    _ => return Err(self.error("unsupported escape in string")),
+                },
+                c => c,
+            };
+            s.push(c);
+        }
+        Err(self.error("unterminated string"))
+    }
+
+    fn key(&mut self, top_level: bool) -> Result<String, String> {
+        if self.peek() == Some('"') {
+            return self.string();
+        }
+        let key = self.ident();
+        if key.is_empty() {
+            Err(self.error("expected a key"))
+        } else if !top_level {
+            Ok(key.to_owned())
+        } else if key == "path" {
+            Ok("locales-dir".to_owned())
+        } else {
+            Ok(key.replace('_', "-"))
+        }
+    }
+
+    fn table(&mut self, top_level: bool) -> Result<Table, String> {
+        self.expect('{')?;
+        let mut table = Table::new();
+        while !self.eat('}') {
+            let key = self.key(top_level)?;
+            self.expect(':')?;
+            let value = self.value()?;
+            if table.insert(key.clone(), value).is_some() {
+                return Err(self.error(&format!("duplicate key {:?}", key)));
+            }
+            if !self.eat(',') {
+                self.expect('}')?;
+                break;
+            }
+        }
+        Ok(table)
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        match self.peek() {
+            Some('"') => self.string().map(Value::String),
+            Some('{') => self.table(false).map(Value::Table),
+            Some('[') => {
+                self.expect('[')?;
+                let mut values = vec![];
+                while !self.eat(']') {
+                    values.push(self.value()?);
+                    if !self.eat(',') {
+                        self.expect(']')?;
+                        break;
+                    }
+                }
+                Ok(Value::Array(values))
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let number = self.take_while(|c| c.is_ascii_alphanumeric() || "._".contains(c));
+                let number = number.replace('_', "");
+                if let Ok(i) = number.parse() {
+                    Ok(Value::Integer(i))
+                } else if let Ok(f) = number.parse() {
+                    Ok(Value::Float(f))
+                } else {
+                    Err(self.error(&format!("invalid number {:?}", number)))
+                }
+            }
+            _ => match self.ident() {
+                "true" => Ok(Value::Boolean(true)),
+                "false" => Ok(Value::Boolean(false)),
+                _ => Err(self.error("expected a string, a boolean, a number, an array or a table")),
+            },
+        }
+    }
+}
+
+/// Return the configuration given to the first `load_locales!` of a Rust source file,
+/// or `None` if there is no call or it has no configuration.
+pub fn inline_config(source: &str) -> Result<Option<Table>, String> {
+    let Some(start) = source.find("load_locales!") else {
+        return Ok(None);
+    };
+    let mut parser = Parser {
+        src: source,
+        pos: start + "load_locales!".len(),
+    };
+    let close = match parser.peek() {
+        Some('(') => ')',
+        Some('{') => '}',
+        Some('[') => ']',
+        _ => return Err(parser.error("expected a delimiter after `load_locales!`")),
+    };
+    parser.pos += 1;
+    if parser.eat(close) {
+        return Ok(None);
+    }
+    if parser.ident() != "config" {
+        return Err(parser.error("expected `config { .. }` or nothing"));
+    }
+    let table = parser.table(true)?;
+    parser.expect(close)?;
+    Ok(Some(table))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_matches_fields() {
+        let schema: serde_json::Value = serde_json::from_str(SCHEMA).unwrap();
+        let properties: Vec<&str> = schema["properties"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect();
+        let fields: Vec<&str> = FIELDS.iter().map(|(name, _)| *name).collect();
+        assert_eq!(properties, fields);
+        assert_eq!(schema["required"], serde_json::json!(REQUIRED));
+    }
+
+    #[test]
+    fn test_validate() {
+        let config = manifest_config(
+            r#"
+            [package]
+            name = "app"
+
+            [package.metadata.leptos-i18n]
+            defaut-locale = "en"
+            locales = ["en", "fr"]
+            rollout = { fr = 150, de = 10 }
+            missing-keys = "wran"
+            gettext = "yes"
+            fallbacks = { fr = ["it"] }
+
+            [dependencies]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            validate(&config),
+            vec![
+                ConfigIssue::new("default", "missing required key"),
+                ConfigIssue::new("defaut-locale", "unknown key, did you mean \"default\"?"),
+                ConfigIssue::new(
+                    "fallbacks.fr[0]",
+                    "locale \"it\" is not declared in `locales`"
+                ),
+                ConfigIssue::new("gettext", "expected a boolean, found \"yes\""),
+                ConfigIssue::new(
+                    "missing-keys",
+                    "expected one of \"warn\", \"error\", found \"wran\", did you mean \"warn\"?"
+                ),
+                ConfigIssue::new("rollout.de", "locale \"de\" is not declared in `locales`"),
+                ConfigIssue::new(
+                    "rollout.fr",
+                    "expected a percentage between 0 and 100, found 150"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_inline_config() {
+        let source = r#"
+            // the configuration is given to the macro instead of the manifest.
+            leptos_i18n::load_locales!(config {
+                default: "en",
+                locales: ["en", "fr", "fr-CA"],
+                path: "./i18n", /* relative to the manifest */
+                rollout: { "fr-CA": 50 },
+                relaxed_json: true,
+            });
+        "#;
+        let config = inline_config(source).unwrap().unwrap();
+        assert_eq!(config["default"].as_str(), Some("en"));
+        assert_eq!(config["locales-dir"].as_str(), Some("./i18n"));
+        assert_eq!(config["rollout"]["fr-CA"].as_integer(), Some(50));
+        assert_eq!(config["relaxed-json"].as_bool(), Some(true));
+        assert!(validate(&config).is_empty());
+
+        assert_eq!(inline_config("leptos_i18n::load_locales!();"), Ok(None));
+        assert!(inline_config("load_locales!(config { default: 'e' })").is_err());
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt::Write;
+
+use serde_json::Value;
+
+use crate::{
+    annotations::Annotations,
+    catalog::Catalog,
+    config::Config,
+    docs::escape,
+    error::{Error, Result},
+};
+
+/// The result of an export.
+#[derive(Debug)]
+pub struct Export {
+    /// The XLIFF 1.2 document.
+    pub content: String,
+    /// The plural keys, that are not exported.
+    pub skipped_plurals: Vec<String>,
+}
+
+/// Export the values of a locale as an XLIFF 1.2 document for a translation management system,
+/// the values of the default locale being the sources.
+///
+/// The annotations of a key are carried as notes of its unit, giving their context to the translators,
+/// and the [content hash](crate::catalog::Entry::content_hash) of its source as a match context.
+pub fn export(
+    catalog: &Catalog,
+    config: &Config,
+    annotations: &Annotations,
+    locale: &str,
+) -> Result<Export> {
+    if !config.locales.iter().any(|l| l == locale) {
+        return Err(Error::UnknownLocale(locale.to_owned()));
+    }
+    let empty = Default::default();
+    let sources = catalog.0.get(&config.default).unwrap_or(&empty);
+    let targets = catalog.0.get(locale).unwrap_or(&empty);
+
+    let mut content = String::new();
+    let mut skipped_plurals = vec![];
+    // writing to a `String` never fails.
+    let _ = write!(
+        content,
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<xliff version=\"1.2\" xmlns=\"urn:oasis:names:tc:xliff:document:1.2\">\n<file original=\"{}\" source-language=\"{}\" target-language=\"{}\" datatype=\"plaintext\">\n<body>\n",
+        escape(&config.locales_dir.to_string_lossy()),
+        escape(&config.default),
+        escape(locale)
+    );
+    for (key, entry) in sources {
+        let Value::String(source) = &entry.value else {
+            skipped_plurals.push(key.clone());
+            continue;
+        };
+        let _ = write!(
+            content,
+            "<trans-unit id=\"{}\">\n<source>{}</source>\n",
+            escape(key),
+            escape(source)
+        );
+        if let Some(Value::String(target)) = targets.get(key).map(|entry| &entry.value) {
+            let _ = writeln!(content, "<target>{}</target>", escape(target));
+        }
+        if let Some(annotation) = annotations.get(key) {
+            if let Some(description) = &annotation.description {
+                let _ = writeln!(
+                    content,
+                    "<note from=\"description\">{}</note>",
+                    escape(description)
+                );
+            }
+            for screenshot in &annotation.screenshots {
+                let _ = writeln!(
+                    content,
+                    "<note from=\"screenshot\">{}</note>",
+                    escape(screenshot)
+                );
+            }
+        }
+        // the hash of the source lets translation memories match the unit if the key is renamed.
+        let _ = writeln!(
+            content,
+            "<context-group purpose=\"match\"><context context-type=\"x-content-hash\">{}</context></context-group>",
+            entry.content_hash()
+        );
+        content.push_str("</trans-unit>\n");
+    }
+    content.push_str("</body>\n</file>\n</xliff>\n");
+
+    Ok(Export {
+        content,
+        skipped_plurals,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::{annotations::Annotation, catalog::Entry};
+
+    #[test]
+    fn test_export() {
+        let config = Config {
+            default: "en".to_owned(),
+            locales: vec!["en".to_owned(), "fr".to_owned()],
+            namespaces: None,
+            locales_dir: "./locales".into(),
+            file_names: Default::default(),
+            relaxed_json: false,
+        };
+        let mut catalog = Catalog::default();
+        catalog.0.insert(
+            "en".to_owned(),
+            BTreeMap::from([
+                ("ok".to_owned(), Entry::new(Value::String("OK".to_owned()))),
+                (
+                    "items".to_owned(),
+                    Entry::new(serde_json::json!([["{{ count }} items", "_"]])),
+                ),
+            ]),
+        );
+        catalog.0.insert(
+            "fr".to_owned(),
+            BTreeMap::from([(
+                "ok".to_owned(),
+                Entry::new(Value::String("D'accord".into())),
+            )]),
+        );
+        let annotations = Annotations(BTreeMap::from([(
+            "ok".to_owned(),
+            Annotation {
+                description: Some("Button confirming a dialog".to_owned()),
+                screenshots: vec!["https://www.figma.com/file/abc?node-id=1&mode=dev".to_owned()],
+                owners: vec![],
+            },
+        )]));
+
+        let exported = export(&catalog, &config, &annotations, "fr").unwrap();
+        assert_eq!(exported.skipped_plurals, ["items"]);
+        assert!(exported.content.contains(
+            "<trans-unit id=\"ok\">\n<source>OK</source>\n<target>D&#39;accord</target>\n\
+             <note from=\"description\">Button confirming a dialog</note>\n\
+             <note from=\"screenshot\">https://www.figma.com/file/abc?node-id=1&amp;mode=dev</note>\n\
+             <context-group purpose=\"match\"><context context-type=\"x-content-hash\">\
+             091d3d07b5b3076f</context></context-group>\n\
+             </trans-unit>"
+        ));
+        assert!(matches!(
+            export(&catalog, &config, &annotations, "de"),
+            Err(Error::UnknownLocale(_))
+        ));
+    }
+}
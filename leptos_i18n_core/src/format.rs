@@ -0,0 +1,102 @@
+//! This module contain the primitives used to format numbers according to the conventions of a locale.
+//!
+//! ```rust
+//! use leptos_i18n_core::format::{currency_symbol, number_separators};
+//!
+//! assert_eq!(number_separators("de").decimal, ",");
+//! assert_eq!(number_separators("de-CH").group, "’");
+//! assert_eq!(currency_symbol("JPY"), ("¥", 0));
+//! ```
+
+use core::fmt::Display;
+
+use crate::preferences::region_from_tag;
+
+/// The separators used to format numbers in a language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberSeparators {
+    /// The separator between groups of thousands.
+    pub group: &'static str,
+    /// The separator between the integer and the fractional parts.
+    pub decimal: &'static str,
+    /// The minimum number of digits in the integer part for it to be grouped,
+    /// some languages like spanish don't group 4-digit numbers.
+    pub min_grouping_digits: usize,
+}
+
+impl NumberSeparators {
+    const fn new(group: &'static str, decimal: &'static str) -> Self {
+        NumberSeparators {
+            group,
+            decimal,
+            min_grouping_digits: 4,
+        }
+    }
+
+    const fn min_grouping_digits(mut self, min_grouping_digits: usize) -> Self {
+        self.min_grouping_digits = min_grouping_digits;
+        self
+    }
+}
+
+/// Return the separators used by the language of the given tag, defaulting to the english ones.
+pub fn number_separators(tag: &str) -> NumberSeparators {
+    const NBSP: &str = "\u{a0}";
+    const NARROW_NBSP: &str = "\u{202f}";
+
+    let language = tag.split(['-', '_']).next().unwrap_or_default();
+    match (language, region_from_tag(tag)) {
+        ("de" | "it", Some("CH")) => NumberSeparators::new("’", "."),
+        ("pt", Some("PT")) => NumberSeparators::new(NBSP, ",").min_grouping_digits(5),
+        ("fr", _) => NumberSeparators::new(NARROW_NBSP, ","),
+        ("es", _) => NumberSeparators::new(".", ",").min_grouping_digits(5),
+        ("pl", _) => NumberSeparators::new(NBSP, ",").min_grouping_digits(5),
+        (
+            "de" | "it" | "nl" | "pt" | "tr" | "id" | "da" | "el" | "ro" | "hr" | "sl" | "sr"
+            | "vi",
+            _,
+        ) => NumberSeparators::new(".", ","),
+        (
+            "ru" | "uk" | "cs" | "sk" | "sv" | "fi" | "nb" | "no" | "hu" | "bg" | "lt" | "lv"
+            | "et",
+            _,
+        ) => NumberSeparators::new(NBSP, ","),
+        _ => NumberSeparators::new(",", "."),
+    }
+}
+
+/// A number that can be given to a formatted variable, `{{ price, number }}` or `{{ price, currency(USD) }}`.
+pub trait NumberValue: Display + Copy + 'static {
+    /// Convert the number to a float, for the formatters rounding it.
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_number_value {
+    ($($t:ty)*) => {
+        $(
+            impl NumberValue for $t {
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_number_value!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+
+/// Return the symbol of the currency of the given ISO 4217 code and its number of decimals,
+/// the code itself being used for the currencies without a known symbol.
+pub fn currency_symbol(code: &str) -> (&str, usize) {
+    match code {
+        "USD" => ("$", 2),
+        "EUR" => ("€", 2),
+        "GBP" => ("£", 2),
+        "JPY" => ("¥", 0),
+        "CNY" => ("¥", 2),
+        "KRW" => ("₩", 0),
+        "INR" => ("₹", 2),
+        _ => (code, 2),
+    }
+}
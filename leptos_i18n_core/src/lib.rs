@@ -0,0 +1,34 @@
+#![no_std]
+#![deny(missing_docs)]
+#![forbid(unsafe_code)]
+#![deny(warnings)]
+//! # About Leptos i18n core
+//!
+//! The runtime pieces of [Leptos i18n](https://github.com/Baptistemontan/leptos_i18n) that don't need Leptos:
+//! the operands of the plural rules, the negotiation of the locale from language tags,
+//! the formatting preferences and the primitives used to format numbers.
+//!
+//! This crate is `no_std` (it only needs `alloc`) and has no dependencies, so the crates holding your business logic
+//! can depend on it to pick a locale or a plural branch and be shared between a Leptos frontend and an embedded or edge runtime.
+//!
+//! Everything in here is re-exported by `leptos_i18n` under the same paths, the items of [`format`] being in `leptos_i18n::format`
+//! next to the formatters needing Leptos, so a Leptos application doesn't need to depend on it.
+//!
+//! ```rust
+//! use leptos_i18n_core::negotiate::{lookup, parse_accept_language};
+//! use leptos_i18n_core::plurals::PluralOperands;
+//!
+//! let available = ["en", "fr", "zh-Hant"];
+//! let langs = parse_accept_language("zh-Hant-TW, fr;q=0.8");
+//! assert_eq!(lookup(langs[0], &available), Some("zh-Hant"));
+//!
+//! assert_eq!(PluralOperands::from(1.50).v, 1);
+//! ```
+
+extern crate alloc;
+
+pub mod format;
+pub mod negotiate;
+pub mod plurals;
+pub mod preferences;
+pub mod select;
@@ -0,0 +1,224 @@
+//! This module contain the functions used to negotiate a locale from language tags,
+//! working on the tags of the available locales so they don't need the `Locale` enum generated by `load_locales!`.
+//!
+//! ```rust
+//! use leptos_i18n_core::negotiate::negotiate;
+//!
+//! let available = ["en-US", "fr", "zh-Hant"];
+//! assert_eq!(negotiate(&["zh-Hant-TW", "fr"], &available), Some("zh-Hant"));
+//! assert_eq!(negotiate(&["de", "fr-CA"], &available), Some("fr"));
+//! assert_eq!(negotiate(&["en-GB"], &available), Some("en-US"));
+//! assert_eq!(negotiate(&["de"], &available), None);
+//! ```
+
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use core::fmt;
+
+/// The error returned when parsing a locale from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LocaleParseError {
+    /// The string is empty.
+    Empty,
+    /// The string is not a well formed language tag, it is likely to have been tampered with.
+    Malformed(String),
+    /// The string is a language tag, but doesn't match any locale.
+    Unknown(String),
+    /// The string matches a locale that is not available for the current rollout.
+    Unavailable(&'static str),
+}
+
+impl fmt::Display for LocaleParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LocaleParseError::Empty => f.write_str("empty locale"),
+            LocaleParseError::Malformed(s) => write!(f, "malformed language tag {:?}", s),
+            LocaleParseError::Unknown(s) => write!(f, "unknown locale {:?}", s),
+            LocaleParseError::Unavailable(s) => {
+                write!(f, "locale {:?} is not available for the current rollout", s)
+            }
+        }
+    }
+}
+
+impl core::error::Error for LocaleParseError {}
+
+/// Check that `tag` is made of subtags of 1 to 8 alphanumeric characters, separated by `-` or `_`.
+pub fn check_tag(tag: &str) -> Result<(), LocaleParseError> {
+    if tag.is_empty() {
+        return Err(LocaleParseError::Empty);
+    }
+    let well_formed = tag.split(['-', '_']).all(|subtag| {
+        (1..=8).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+    });
+    if well_formed {
+        Ok(())
+    } else {
+        Err(LocaleParseError::Malformed(tag.to_owned()))
+    }
+}
+
+/// Return the canonical form of a language tag per BCP 47: `-` separated, lowercase language,
+/// titlecase script and uppercase region.
+///
+/// ```rust
+/// use leptos_i18n_core::negotiate::normalize_tag;
+///
+/// assert_eq!(normalize_tag("en_us"), "en-US");
+/// assert_eq!(normalize_tag("ZH-hant-tw"), "zh-Hant-TW");
+/// assert_eq!(normalize_tag("es-419"), "es-419");
+/// ```
+pub fn normalize_tag(tag: &str) -> String {
+    tag.trim()
+        .split(['-', '_'])
+        .enumerate()
+        .map(|(i, subtag)| match subtag.len() {
+            _ if i == 0 => subtag.to_ascii_lowercase(),
+            2 if subtag.bytes().all(|b| b.is_ascii_alphabetic()) => subtag.to_ascii_uppercase(),
+            4 if subtag.bytes().all(|b| b.is_ascii_alphabetic()) => {
+                let (first, rest) = subtag.split_at(1);
+                first.to_ascii_uppercase() + &rest.to_ascii_lowercase()
+            }
+            _ => subtag.to_ascii_lowercase(),
+        })
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Return the language subtag of the given language tag.
+pub fn language_from_tag(tag: &str) -> &str {
+    tag.trim().split(['-', '_']).next().unwrap_or_default()
+}
+
+/// Return the given tag then the shorter tags it falls back to, as the lookup of [RFC 4647](https://www.rfc-editor.org/rfc/rfc4647#section-3.4).
+///
+/// ```rust
+/// use leptos_i18n_core::negotiate::fallback_tags;
+///
+/// let tags = fallback_tags("zh-Hant-TW-x-private").collect::<Vec<_>>();
+/// assert_eq!(tags, ["zh-Hant-TW-x-private", "zh-Hant-TW", "zh-Hant", "zh"]);
+/// ```
+pub fn fallback_tags(tag: &str) -> impl Iterator<Item = &str> {
+    core::iter::successors(Some(tag.trim()), |tag| {
+        let (rest, _) = tag.rsplit_once(['-', '_'])?;
+        // a single character subtag introduces an extension, it is removed with it.
+        match rest.rsplit_once(['-', '_']) {
+            Some((before, singleton)) if singleton.len() == 1 => Some(before),
+            _ => Some(rest),
+        }
+    })
+}
+
+/// Find the locale matching the given language tag among the tags of the available locales,
+/// falling back to shorter tags if there is no exact match (see [`fallback_tags`]).
+///
+/// Tags are compared in their [normalized](normalize_tag) form, so `en_us` or `EN-US` match the `en-US` locale.
+pub fn lookup<'a>(tag: &str, available: &[&'a str]) -> Option<&'a str> {
+    fallback_tags(tag).find_map(|tag| {
+        let normalized = normalize_tag(tag);
+        available
+            .iter()
+            .copied()
+            .find(|locale| normalize_tag(locale) == normalized)
+    })
+}
+
+/// Given a slice of accepted languages sorted in preferred order, return the available locale that fit the best.
+///
+/// Each language is matched with [`lookup`], or else to the first locale of the same language
+/// (`en-US` for `en-GB`), before trying the next one.
+pub fn negotiate<'a, T: AsRef<str>>(
+    accepted_langs: &[T],
+    available: &[&'a str],
+) -> Option<&'a str> {
+    accepted_langs.iter().find_map(|tag| {
+        let tag = tag.as_ref();
+        lookup(tag, available).or_else(|| {
+            let language = language_from_tag(tag);
+            available
+                .iter()
+                .copied()
+                .find(|locale| language_from_tag(locale).eq_ignore_ascii_case(language))
+        })
+    })
+}
+
+/// Parse the value of an `Accept-Language` header into its language ranges, sorted by decreasing quality (`q` parameter).
+///
+/// Ranges of the same quality keep the order of the header, the ones with a quality of 0 (not acceptable),
+/// an invalid quality or the `*` wildcard are left out.
+///
+/// ```rust
+/// use leptos_i18n_core::negotiate::parse_accept_language;
+///
+/// let langs = parse_accept_language("en;q=0.8, fr-CH, de;q=0, fr;q=0.9, *;q=0.5");
+/// assert_eq!(langs, ["fr-CH", "fr", "en"]);
+/// ```
+pub fn parse_accept_language(header: &str) -> Vec<&str> {
+    let mut langs = header
+        .split(',')
+        .filter_map(|lang| {
+            let mut params = lang.split(';');
+            let tag = params.next()?.trim();
+            let quality = params
+                .filter_map(|param| param.trim().strip_prefix("q="))
+                .map(|q| {
+                    q.trim()
+                        .parse::<f32>()
+                        .ok()
+                        .filter(|q| (0.0..=1.0).contains(q))
+                })
+                .next()
+                .unwrap_or(Some(1.0))?;
+            let is_range = !tag.is_empty()
+                && tag.split('-').all(|subtag| {
+                    !subtag.is_empty() && subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+                });
+            (is_range && quality > 0.0).then_some((tag, quality))
+        })
+        .collect::<Vec<_>>();
+    // the sort is stable, keeping the order of the header.
+    langs.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+    langs.into_iter().map(|(tag, _)| tag).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_accept_language() {
+        let parsed_lang = parse_accept_language("fr-CH, fr;q=0.9, en;q=0.8, de;q=0.7, *;q=0.5");
+
+        assert_eq!(parsed_lang, &["fr-CH", "fr", "en", "de"]);
+    }
+
+    #[test]
+    fn test_parse_accept_language_unsorted() {
+        let parsed_lang =
+            parse_accept_language("de;q=0.3, fr-CH, en;level=1;q=0.7, fr;q=0.9, it;q=2, es;q=0");
+
+        assert_eq!(parsed_lang, &["fr-CH", "fr", "en", "de"]);
+    }
+
+    #[test]
+    fn test_check_tag() {
+        assert_eq!(check_tag("en-US"), Ok(()));
+        assert_eq!(check_tag("zh_Hant_TW"), Ok(()));
+        assert_eq!(check_tag(""), Err(LocaleParseError::Empty));
+        for tag in ["en--US", "en-", "fr;drop", "<script>", "abcdefghi"] {
+            assert_eq!(
+                check_tag(tag),
+                Err(LocaleParseError::Malformed(tag.to_owned()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_lookup() {
+        let available = ["en-US", "sr-Latn", "fr"];
+        assert_eq!(lookup("EN_us", &available), Some("en-US"));
+        assert_eq!(lookup("sr-Latn-RS-u-nu-latn", &available), Some("sr-Latn"));
+        assert_eq!(lookup("fr-CA", &available), Some("fr"));
+        assert_eq!(lookup("en-GB", &available), None);
+    }
+}
@@ -0,0 +1,129 @@
+//! This module contain the operands of the CLDR plural rules, used by the code generated for plurals using plural categories.
+//!
+//! ```rust
+//! use leptos_i18n_core::plurals::PluralOperands;
+//!
+//! let operands = PluralOperands::from(-1.50);
+//! assert_eq!((operands.n, operands.i, operands.v, operands.f), (1.5, 1, 1, 5));
+//! ```
+
+use alloc::string::ToString;
+
+/// The operands of a number, as defined by [CLDR](https://unicode.org/reports/tr35/tr35-numbers.html#Operands).
+///
+/// Floats have the visible fraction digits of their shortest representation, so `1.0` has none.
+/// The integer digits of the floats above `u128::MAX` are replaced by their last 37 digits plus 10^38,
+/// a number ending with the same digits and greater than any value of the rules, so the rules stay exact.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PluralOperands {
+    /// The absolute value of the number.
+    pub n: f64,
+    /// The integer digits of the number.
+    pub i: u128,
+    /// The number of visible fraction digits, with trailing zeros.
+    pub v: u64,
+    /// The number of visible fraction digits, without trailing zeros.
+    pub w: u64,
+    /// The visible fraction digits, with trailing zeros.
+    pub f: u64,
+    /// The visible fraction digits, without trailing zeros.
+    pub t: u64,
+}
+
+impl PluralOperands {
+    fn from_integer(n: f64, i: u128) -> Self {
+        PluralOperands {
+            n,
+            i,
+            v: 0,
+            w: 0,
+            f: 0,
+            t: 0,
+        }
+    }
+
+    fn from_decimal(n: f64, repr: &str) -> Self {
+        let (integer, fraction) = repr.split_once('.').unwrap_or((repr, ""));
+        let trimmed = fraction.trim_end_matches('0');
+        // only the last digits of the fraction matter to the rules, and they could overflow.
+        let parse_digits = |digits: &str| digits[digits.len().saturating_sub(18)..].parse();
+        PluralOperands {
+            n,
+            i: integer.parse().unwrap_or_else(|_| {
+                10u128.pow(38)
+                    + integer[integer.len() - 37..]
+                        .parse::<u128>()
+                        .unwrap_or_default()
+            }),
+            v: fraction.len() as u64,
+            w: trimmed.len() as u64,
+            f: parse_digits(fraction).unwrap_or_default(),
+            t: parse_digits(trimmed).unwrap_or_default(),
+        }
+    }
+}
+
+macro_rules! impl_from_integers {
+    ($($num_type:ty => $abs:expr),*) => {
+        $(
+            impl From<$num_type> for PluralOperands {
+                fn from(value: $num_type) -> Self {
+                    let abs = $abs(value);
+                    Self::from_integer(abs as f64, abs as u128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integers!(
+    i8 => i8::unsigned_abs,
+    i16 => i16::unsigned_abs,
+    i32 => i32::unsigned_abs,
+    i64 => i64::unsigned_abs,
+    i128 => i128::unsigned_abs,
+    u8 => core::convert::identity,
+    u16 => core::convert::identity,
+    u32 => core::convert::identity,
+    u64 => core::convert::identity,
+    u128 => core::convert::identity
+);
+
+impl From<f32> for PluralOperands {
+    fn from(value: f32) -> Self {
+        let abs = value.abs();
+        Self::from_decimal(abs as f64, &abs.to_string())
+    }
+}
+
+impl From<f64> for PluralOperands {
+    fn from(value: f64) -> Self {
+        let abs = value.abs();
+        Self::from_decimal(abs, &abs.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operands() {
+        assert_eq!(PluralOperands::from(-21i8), PluralOperands::from(21u64));
+        assert_eq!(PluralOperands::from(21u8).i, 21);
+        assert_eq!(PluralOperands::from(u128::MAX).i, u128::MAX);
+        let above_u64 = u64::MAX as u128 * 100 + 21;
+        assert_eq!(PluralOperands::from(above_u64).i % 100, 21);
+        assert_eq!(PluralOperands::from(i128::MIN).i, i128::MIN.unsigned_abs());
+        assert_eq!(PluralOperands::from(1e20f64).i, 10u128.pow(20));
+        let operands = PluralOperands::from(2e40f64);
+        assert_eq!((operands.i % 1_000_000, operands.v), (0, 0));
+        assert_eq!(operands.i, 10u128.pow(38));
+        assert_eq!(PluralOperands::from(1.0f64), PluralOperands::from(1u8));
+        let operands = PluralOperands::from(1.1f32);
+        assert_eq!((operands.i, operands.v, operands.f), (1, 1, 1));
+        let operands = PluralOperands::from(0.25);
+        assert_eq!((operands.i, operands.v, operands.w), (0, 2, 2));
+        assert_eq!((operands.f, operands.t), (25, 25));
+    }
+}
@@ -0,0 +1,367 @@
+//! This module contain the formatting preferences of the user, decoupled from the display language.
+//!
+//! Users frequently read the UI in one language but want amounts in their own currency,
+//! the preferences are held by the `I18nContext` and are meant to be consulted by the formatters instead of the locale:
+//!
+//! ```rust,ignore
+//! let i18n = use_i18n();
+//! i18n.set_currency(Some("EUR"));
+//!
+//! // the region is detected from the requested tag (`CA` for `fr-CA`) unless set by the user
+//! let region = i18n.region();
+//! ```
+//!
+//! The measurement system, temperature unit and hour cycle can also be overridden,
+//! when not set they are derived from the region. The numbering system, the digits used to write numbers,
+//! defaults to the one requested by the `-u-nu-` extension of the tag (`ar-EG-u-nu-arab`), or latin digits.
+//!
+//! The preferences are persisted alongside the locale, in a cookie with the `cookie` feature of `leptos_i18n` or in the storage provided with
+//! `leptos_i18n::storage::provide_locale_storage`.
+
+use alloc::{borrow::Cow, string::String};
+use core::fmt::Write;
+
+/// The measurement system used for units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeasurementSystem {
+    /// Meters, kilograms, liters.
+    Metric,
+    /// Feet, pounds, US gallons.
+    UsCustomary,
+    /// Metric with miles and pints, as used in the United Kingdom.
+    Imperial,
+}
+
+/// The unit used for temperatures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemperatureUnit {
+    /// Degrees Celsius.
+    Celsius,
+    /// Degrees Fahrenheit.
+    Fahrenheit,
+}
+
+/// The clock used for times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HourCycle {
+    /// 12-hour clock (`1:00 PM`).
+    H12,
+    /// 24-hour clock (`13:00`).
+    H23,
+}
+
+/// The digits used to write numbers, as named by the `-u-nu-` extension of language tags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NumberingSystem {
+    /// Latin digits, `0123456789`.
+    #[default]
+    Latn,
+    /// Arabic-Indic digits, `٠١٢٣٤٥٦٧٨٩`.
+    Arab,
+    /// Extended Arabic-Indic digits, used for persian and urdu, `۰۱۲۳۴۵۶۷۸۹`.
+    Arabext,
+    /// Bengali digits, `০১২৩৪৫৬৭৮৯`.
+    Beng,
+    /// Devanagari digits, `०१२३४५६७८९`.
+    Deva,
+    /// Thai digits, `๐๑๒๓๔๕๖๗๘๙`.
+    Thai,
+}
+
+macro_rules! impl_str_conv {
+    ($t:ty { $($variant:ident => $s:literal),* }) => {
+        impl $t {
+            /// Return a static str that represent the value.
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $s,)*
+                }
+            }
+
+            /// Try to match the given str to a value and returns it.
+            pub fn parse(s: &str) -> Option<Self> {
+                match s {
+                    $($s => Some(Self::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+impl_str_conv!(MeasurementSystem { Metric => "metric", UsCustomary => "us", Imperial => "uk" });
+impl_str_conv!(TemperatureUnit { Celsius => "celsius", Fahrenheit => "fahrenheit" });
+impl_str_conv!(HourCycle { H12 => "h12", H23 => "h23" });
+impl_str_conv!(NumberingSystem {
+    Latn => "latn",
+    Arab => "arab",
+    Arabext => "arabext",
+    Beng => "beng",
+    Deva => "deva",
+    Thai => "thai"
+});
+
+impl MeasurementSystem {
+    /// Return the measurement system commonly used in the given region, defaulting to the metric system.
+    pub fn from_region(region: Option<&str>) -> Self {
+        match region {
+            Some("US" | "LR" | "MM") => MeasurementSystem::UsCustomary,
+            Some("GB") => MeasurementSystem::Imperial,
+            _ => MeasurementSystem::Metric,
+        }
+    }
+}
+
+impl TemperatureUnit {
+    /// Return the temperature unit commonly used in the given region, defaulting to Celsius.
+    pub fn from_region(region: Option<&str>) -> Self {
+        match region {
+            Some("US" | "BS" | "BZ" | "KY" | "PR" | "PW" | "LR" | "FM" | "MH") => {
+                TemperatureUnit::Fahrenheit
+            }
+            _ => TemperatureUnit::Celsius,
+        }
+    }
+}
+
+impl HourCycle {
+    /// Return the clock commonly used in the given region, defaulting to the 24-hour clock.
+    pub fn from_region(region: Option<&str>) -> Self {
+        match region {
+            Some(
+                "US" | "CA" | "AU" | "NZ" | "IN" | "PH" | "PK" | "BD" | "EG" | "SA" | "MY" | "KR"
+                | "TW" | "CO" | "MX",
+            ) => HourCycle::H12,
+            _ => HourCycle::H23,
+        }
+    }
+}
+
+impl NumberingSystem {
+    const ALL: [Self; 6] = [
+        NumberingSystem::Latn,
+        NumberingSystem::Arab,
+        NumberingSystem::Arabext,
+        NumberingSystem::Beng,
+        NumberingSystem::Deva,
+        NumberingSystem::Thai,
+    ];
+
+    /// Return the numbering system requested by the `-u-nu-` extension of the given tag, if any.
+    ///
+    /// ```rust
+    /// use leptos_i18n_core::preferences::NumberingSystem;
+    ///
+    /// assert_eq!(NumberingSystem::from_tag("ar-EG-u-nu-arab"), Some(NumberingSystem::Arab));
+    /// assert_eq!(NumberingSystem::from_tag("hi-u-ca-indian-nu-deva"), Some(NumberingSystem::Deva));
+    /// assert_eq!(NumberingSystem::from_tag("ar-EG"), None);
+    /// ```
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let mut subtags = tag.split(['-', '_']);
+        subtags.find(|subtag| subtag.eq_ignore_ascii_case("u"))?;
+        // the keywords of the extension end at the next singleton.
+        let mut keywords = subtags.take_while(|subtag| subtag.len() > 1);
+        keywords.find(|subtag| subtag.eq_ignore_ascii_case("nu"))?;
+        Self::parse(&keywords.next()?.to_ascii_lowercase())
+    }
+
+    const fn zero(self) -> char {
+        match self {
+            NumberingSystem::Latn => '0',
+            NumberingSystem::Arab => '\u{660}',
+            NumberingSystem::Arabext => '\u{6f0}',
+            NumberingSystem::Beng => '\u{9e6}',
+            NumberingSystem::Deva => '\u{966}',
+            NumberingSystem::Thai => '\u{e50}',
+        }
+    }
+
+    /// Return the given char written in this numbering system if it's a digit of any supported numbering system,
+    /// else the char itself.
+    pub fn map_digit(self, c: char) -> char {
+        let value = Self::ALL.iter().find_map(|system| {
+            let value = (c as u32).wrapping_sub(system.zero() as u32);
+            (value < 10).then_some(value)
+        });
+        match value {
+            Some(value) => char::from_u32(self.zero() as u32 + value).unwrap_or(c),
+            None => c,
+        }
+    }
+
+    /// Write the digits of the given string in this numbering system.
+    ///
+    /// ```rust
+    /// use leptos_i18n_core::preferences::NumberingSystem;
+    ///
+    /// assert_eq!(NumberingSystem::Arab.transliterate("12.5%"), "١٢.٥%");
+    /// assert_eq!(NumberingSystem::Latn.transliterate("١٢"), "12");
+    /// ```
+    pub fn transliterate(self, s: &str) -> Cow<'_, str> {
+        if s.chars().all(|c| self.map_digit(c) == c) {
+            Cow::Borrowed(s)
+        } else {
+            Cow::Owned(s.chars().map(|c| self.map_digit(c)).collect())
+        }
+    }
+}
+
+/// The formatting preferences of the user.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FormatPreferences {
+    /// The region set by the user, as an ISO 3166-1 alpha-2 code (`"FR"`) or a UN M.49 code (`"419"`).
+    pub region: Option<String>,
+    /// The currency set by the user, as an ISO 4217 code (`"EUR"`).
+    pub currency: Option<String>,
+    /// The measurement system set by the user.
+    pub measurement_system: Option<MeasurementSystem>,
+    /// The temperature unit set by the user.
+    pub temperature_unit: Option<TemperatureUnit>,
+    /// The hour cycle set by the user.
+    pub hour_cycle: Option<HourCycle>,
+    /// The numbering system set by the user.
+    pub numbering_system: Option<NumberingSystem>,
+}
+
+impl FormatPreferences {
+    /// Return `true` if no preference is set.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Encode the preferences in a string that can be stored in a cookie or an URL,
+    /// like `region=CA&currency=EUR`.
+    pub fn encode(&self) -> String {
+        let mut buff = String::new();
+        let mut push = |key: &str, value: Option<&str>| {
+            if let Some(value) = value {
+                if !buff.is_empty() {
+                    buff.push('&');
+                }
+                // writing to a String can't fail
+                let _ = write!(buff, "{}={}", key, value);
+            }
+        };
+        push("region", self.region.as_deref());
+        push("currency", self.currency.as_deref());
+        push(
+            "measurement",
+            self.measurement_system.map(MeasurementSystem::as_str),
+        );
+        push(
+            "temperature",
+            self.temperature_unit.map(TemperatureUnit::as_str),
+        );
+        push("hour_cycle", self.hour_cycle.map(HourCycle::as_str));
+        push(
+            "numbering",
+            self.numbering_system.map(NumberingSystem::as_str),
+        );
+        buff
+    }
+
+    /// Decode preferences encoded with [`FormatPreferences::encode`], invalid entries are ignored.
+    pub fn decode(s: &str) -> Self {
+        fn is_code(value: &str) -> bool {
+            !value.is_empty()
+                && value.len() <= 3
+                && value.bytes().all(|b| b.is_ascii_alphanumeric())
+        }
+
+        let mut prefs = FormatPreferences::default();
+        for (key, value) in s.split('&').filter_map(|entry| entry.split_once('=')) {
+            match key {
+                "region" if is_code(value) => prefs.region = Some(value.to_ascii_uppercase()),
+                "currency" if is_code(value) => prefs.currency = Some(value.to_ascii_uppercase()),
+                "measurement" => prefs.measurement_system = MeasurementSystem::parse(value),
+                "temperature" => prefs.temperature_unit = TemperatureUnit::parse(value),
+                "hour_cycle" => prefs.hour_cycle = HourCycle::parse(value),
+                "numbering" => prefs.numbering_system = NumberingSystem::parse(value),
+                _ => {}
+            }
+        }
+        prefs
+    }
+}
+
+/// Return the region subtag of the given language tag, if any.
+///
+/// ```rust,ignore
+/// assert_eq!(region_from_tag("fr-CA"), Some("CA"));
+/// assert_eq!(region_from_tag("zh-Hant-TW"), Some("TW"));
+/// assert_eq!(region_from_tag("es-419"), Some("419"));
+/// assert_eq!(region_from_tag("fr"), None);
+/// ```
+pub fn region_from_tag(tag: &str) -> Option<&str> {
+    // extensions, like `-u-nu-arab`, start with a singleton.
+    let mut subtags = tag
+        .split(['-', '_'])
+        .skip(1)
+        .take_while(|subtag| subtag.len() > 1);
+    subtags.find(|subtag| {
+        (subtag.len() == 2 && subtag.bytes().all(|b| b.is_ascii_alphabetic()))
+            || (subtag.len() == 3 && subtag.bytes().all(|b| b.is_ascii_digit()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_region_from_tag() {
+        assert_eq!(region_from_tag("fr-CA"), Some("CA"));
+        assert_eq!(region_from_tag("en_GB"), Some("GB"));
+        assert_eq!(region_from_tag("zh-Hant-TW"), Some("TW"));
+        assert_eq!(region_from_tag("es-419"), Some("419"));
+        assert_eq!(region_from_tag("fr"), None);
+        assert_eq!(region_from_tag("sr-Latn"), None);
+        assert_eq!(region_from_tag("ar-u-nu-arab"), None);
+        assert_eq!(region_from_tag("ar-EG-u-nu-arab"), Some("EG"));
+    }
+
+    #[test]
+    fn test_numbering_system() {
+        assert_eq!(
+            NumberingSystem::from_tag("fa-u-nu-arabext"),
+            Some(NumberingSystem::Arabext)
+        );
+        assert_eq!(
+            NumberingSystem::from_tag("th-TH-u-nu-THAI"),
+            Some(NumberingSystem::Thai)
+        );
+        assert_eq!(NumberingSystem::from_tag("en-u-ca-gregory"), None);
+        assert_eq!(NumberingSystem::from_tag("en-x-nu-arab"), None);
+        assert_eq!(NumberingSystem::from_tag("ar-u-nu-unknown"), None);
+        assert_eq!(NumberingSystem::Deva.transliterate("1,234.5"), "१,२३४.५");
+        assert_eq!(NumberingSystem::Beng.transliterate("٣"), "৩");
+        assert_eq!(NumberingSystem::Thai.transliterate("abc"), "abc");
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let prefs = FormatPreferences {
+            region: Some("CA".into()),
+            currency: Some("EUR".into()),
+            measurement_system: Some(MeasurementSystem::Metric),
+            temperature_unit: None,
+            hour_cycle: Some(HourCycle::H23),
+            numbering_system: Some(NumberingSystem::Arab),
+        };
+        let encoded = prefs.encode();
+        assert_eq!(
+            encoded,
+            "region=CA&currency=EUR&measurement=metric&hour_cycle=h23&numbering=arab"
+        );
+        assert_eq!(FormatPreferences::decode(&encoded), prefs);
+        assert_eq!(FormatPreferences::default().encode(), "");
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        let prefs = FormatPreferences::decode("region=C;A&currency=eur&temperature=kelvin&foo=bar");
+        assert_eq!(prefs.region, None);
+        assert_eq!(prefs.currency.as_deref(), Some("EUR"));
+        assert_eq!(prefs.temperature_unit, None);
+    }
+}
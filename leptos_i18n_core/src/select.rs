@@ -0,0 +1,72 @@
+//! This module contain the trait used for the variable of selects, values choosing a branch by matching a variable on strings:
+//!
+//! ```json
+//! {
+//!   "replied": {
+//!     "$select": "gender",
+//!     "male": "He replied",
+//!     "female": "She replied",
+//!     "other": "They replied"
+//!   }
+//! }
+//! ```
+//!
+//! The variable can be a function returning anything implementing `AsRef<str>`, so an enum can be used:
+//!
+//! ```rust
+//! use leptos_i18n_core::select::SelectFn;
+//!
+//! #[derive(Clone, Copy)]
+//! enum Gender {
+//!     Male,
+//!     Female,
+//!     Unknown,
+//! }
+//!
+//! impl AsRef<str> for Gender {
+//!     fn as_ref(&self) -> &str {
+//!         match self {
+//!             Gender::Male => "male",
+//!             Gender::Female => "female",
+//!             Gender::Unknown => "other",
+//!         }
+//!     }
+//! }
+//!
+//! let gender = || Gender::Female;
+//! assert_eq!(SelectFn::call(&gender).as_ref(), "female");
+//! assert_eq!(SelectFn::call(&"male"), "male");
+//! ```
+
+/// The variable of a select, returning the name of the branch to render.
+///
+/// Unknown names render the `"other"` branch.
+pub trait SelectFn: Clone + 'static {
+    /// The value matched on the branches.
+    type Value: AsRef<str>;
+
+    /// Return the current value of the variable.
+    fn call(&self) -> Self::Value;
+}
+
+impl<F, T> SelectFn for F
+where
+    F: Fn() -> T + Clone + 'static,
+    T: AsRef<str>,
+{
+    type Value = T;
+
+    #[inline]
+    fn call(&self) -> Self::Value {
+        self()
+    }
+}
+
+impl SelectFn for &'static str {
+    type Value = Self;
+
+    #[inline]
+    fn call(&self) -> Self::Value {
+        self
+    }
+}
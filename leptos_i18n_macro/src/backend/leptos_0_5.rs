@@ -0,0 +1,74 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// The trait of the values that can be rendered.
+pub fn into_view_trait() -> TokenStream {
+    quote!(leptos::IntoView)
+}
+
+/// The type of a rendered value, erasing the type of what was rendered.
+pub fn view_type() -> TokenStream {
+    quote!(leptos::View)
+}
+
+/// The type of the children given to a component.
+pub fn children_fn_type() -> TokenStream {
+    quote!(leptos::ChildrenFn)
+}
+
+/// The type of the attributes given to a component, from the attributes of its tag.
+pub fn attributes_type() -> TokenStream {
+    quote!(std::vec::Vec<(&'static str, leptos::Attribute)>)
+}
+
+/// An attribute with the given string value, or a boolean attribute set if there is no value.
+pub fn attribute(value: Option<TokenStream>) -> TokenStream {
+    match value {
+        Some(value) => quote!(leptos::Attribute::String(leptos::Oco::from(#value))),
+        None => quote!(leptos::Attribute::Bool(true)),
+    }
+}
+
+/// Render a value.
+pub fn into_view(value: TokenStream) -> TokenStream {
+    quote!(leptos::IntoView::into_view(#value))
+}
+
+/// Turn a closure returning a view into children for a component.
+pub fn to_children(f: TokenStream) -> TokenStream {
+    quote!(leptos::ToChildren::to_children(#f))
+}
+
+/// Render multiple values one after the other.
+pub fn collect_view(values: &[TokenStream]) -> TokenStream {
+    quote!(leptos::CollectView::collect_view([#(#values,)*]))
+}
+
+/// Render nothing.
+pub fn empty_view() -> TokenStream {
+    quote!(leptos::View::default())
+}
+
+/// Implement the rendering trait for the given type, `body` consuming `self` and returning a view.
+pub fn impl_into_view(
+    generics: TokenStream,
+    self_type: TokenStream,
+    body: TokenStream,
+) -> TokenStream {
+    quote! {
+        impl<#generics> leptos::IntoView for #self_type {
+            fn into_view(self) -> leptos::View {
+                #body
+            }
+        }
+    }
+}
+
+/// Render the component `comp_name` with the given attributes and children.
+pub fn component_view(
+    comp_name: &Ident,
+    attrs: &TokenStream,
+    children: TokenStream,
+) -> TokenStream {
+    quote!(leptos::view! { <#comp_name #attrs>{move || #children()}</#comp_name> })
+}
@@ -0,0 +1,12 @@
+//! Leptos specific code generation.
+//!
+//! Every path to a leptos item and every leptos specific construct used by the generated code goes through this module,
+//! the backend being selected with the `leptos_*` features, so the macros can target multiple versions of leptos.
+//! A new backend only has to expose the same functions.
+//!
+//! `leptos_0_5` is the only backend for now, so it is also used when no backend is selected,
+//! this way disabling the default features keeps working without having to enable it back.
+
+mod leptos_0_5;
+
+pub use leptos_0_5::*;
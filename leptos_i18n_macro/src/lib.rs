@@ -8,6 +8,7 @@
 //!
 //! This crate must be used with `leptos_i18n` and should'nt be used outside of it.
 
+pub(crate) mod backend;
 pub(crate) mod load_locales;
 pub(crate) mod t_macro;
 
@@ -23,9 +24,27 @@ use t_macro::{InputType, OutputType};
 ///
 /// - `Locale`: an enum representing the available locales of the application.
 /// - `I18nKeys`: a struct representing the translation keys.
+///
+/// The configuration can also be given to the macro, for build systems that don't expose the cargo manifest,
+/// with the keys of the manifest written with `_` instead of `-`:
+///
+/// ```rust, ignore
+/// load_locales!(config {
+///     default: "en",
+///     locales: ["en", "fr"],
+///     path: "./locales",
+/// });
+/// ```
 #[proc_macro]
-pub fn load_locales(_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    match load_locales::load_locales() {
+pub fn load_locales(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let inline_cfg = if tokens.is_empty() {
+        None
+    } else {
+        Some(syn::parse_macro_input!(
+            tokens as load_locales::inline_cfg::InlineConfig
+        ))
+    };
+    match load_locales::load_locales(inline_cfg) {
         Ok(ts) => ts.into(),
         Err(err) => err.into(),
     }
@@ -109,6 +128,29 @@ pub fn td_string(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
     t_macro::t_macro(tokens, InputType::Locale, OutputType::String)
 }
 
+/// Just like the `t!` macro but return a `Cow<'static, str>`, for places where a view can't be used
+/// like the attributes of an element or the body of an email.
+///
+/// Components can't be rendered to a string, it is a compilation error to interpolate one.
+///
+/// Usage:
+///
+/// ```rust, ignore
+/// use crate::i18n::*;
+///
+/// let i18n = use_i18n();
+///
+/// // search_placeholder = "Search in {{ count }} items"
+/// view! {
+///     <input placeholder=move || t_string!(i18n, search_placeholder, count = 10) />
+/// }
+///```
+#[cfg(feature = "interpolate_display")]
+#[proc_macro]
+pub fn t_string(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    t_macro::t_string_macro(tokens)
+}
+
 /// Just like the `td_string!` macro but return either a struct implementing `Display` or a `&'static str` instead of a `Cow<'static, str>`.
 ///
 /// This is usefull if you will print the value or use it in any formatting operation, as it will avoid a temporary `String`.
@@ -3,8 +3,17 @@ use serde::de::DeserializeOwned;
 use super::{
     error::{Error, Result},
     key::Key,
+    locale::FILE_EXTS,
+    parsed_value::ParsedValue,
+    plural::{CountTypes, NegativeCounts, PluralConstants, PluralType},
+    warning::MissingKeys,
+};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    rc::Rc,
 };
-use std::{borrow::Cow, collections::HashSet, path::PathBuf, rc::Rc};
 
 #[derive(Debug)]
 pub struct ConfigFile {
@@ -12,6 +21,71 @@ pub struct ConfigFile {
     pub locales: Vec<Rc<Key>>,
     pub name_spaces: Option<Vec<Rc<Key>>>,
     pub locales_dir: Cow<'static, str>,
+    pub beta_locales: Vec<Rc<Key>>,
+    pub rollout: HashMap<Rc<Key>, u8>,
+    pub size_report: Option<String>,
+    pub timings: bool,
+    pub plural_constants: Rc<PluralConstants>,
+    pub count_types: Rc<CountTypes>,
+    pub negative_counts: NegativeCounts,
+    pub format_counts: bool,
+    pub locales_url: Option<String>,
+    pub file_names: HashMap<Rc<Key>, String>,
+    pub relaxed_json: bool,
+    pub dynamic_load_url: Option<String>,
+    pub font_classes: HashMap<Rc<Key>, String>,
+    pub font_preloads: HashMap<Rc<Key>, Vec<String>>,
+    pub gettext: bool,
+    pub missing_keys: MissingKeys,
+    /// The locales to take the missing keys of a locale from, in order, before the default locale.
+    pub fallbacks: HashMap<Rc<Key>, Vec<Rc<Key>>>,
+    pub persistence: Persistence,
+    /// Normalize the variation selectors of the emoji of the values, `true` unless disabled.
+    pub normalize_emoji: bool,
+    /// A locale synthesized from the default locale to spot the hardcoded strings and the layout overflows.
+    pub pseudo_locale: Option<Rc<Key>>,
+    /// Normalize the values to NFC, `true` unless disabled.
+    pub normalize_unicode: bool,
+}
+
+/// Where the chosen locale is persisted on the client.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Persistence {
+    /// In a cookie, readable by the server.
+    #[default]
+    Cookie,
+    /// In the `localStorage`, for CSR only apps.
+    LocalStorage,
+}
+
+struct PersistenceVisitor;
+
+impl<'de> serde::Deserialize<'de> for Persistence {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(PersistenceVisitor)
+    }
+}
+
+impl<'de> serde::de::Visitor<'de> for PersistenceVisitor {
+    type Value = Persistence;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "either \"cookie\" or \"local_storage\"")
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "cookie" => Ok(Persistence::Cookie),
+            "local_storage" => Ok(Persistence::LocalStorage),
+            _ => Err(E::unknown_variant(v, &["cookie", "local_storage"])),
+        }
+    }
 }
 
 impl ConfigFile {
@@ -33,6 +107,87 @@ impl ConfigFile {
         duplicates
     }
 
+    /// Return two locales that only differ by case or by their subtags separator, like `en-US` and `en_us`.
+    fn find_conflicting_locales(locales: &[Rc<Key>]) -> Option<(Rc<Key>, Rc<Key>)> {
+        locales.iter().enumerate().find_map(|(i, first)| {
+            locales[i + 1..]
+                .iter()
+                .find(|second| tags_loosely_eq(&first.name, &second.name))
+                .map(|second| (Rc::clone(first), Rc::clone(second)))
+        })
+    }
+
+    /// Return the name of the file of a locale, without extension.
+    pub fn file_name<'a>(&'a self, locale: &'a Key) -> &'a str {
+        self.file_names
+            .get(locale)
+            .map(String::as_str)
+            .unwrap_or(&locale.name)
+    }
+
+    /// Return a locale given font hints but not declared, with the name of the field.
+    fn find_unknown_font_locale(&self) -> Option<(&Rc<Key>, &'static str)> {
+        let is_unknown = |locale: &&Rc<Key>| !self.locales.contains(locale);
+        self.font_classes
+            .keys()
+            .find(is_unknown)
+            .map(|locale| (locale, "font-classes"))
+            .or_else(|| {
+                self.font_preloads
+                    .keys()
+                    .find(is_unknown)
+                    .map(|locale| (locale, "font-preloads"))
+            })
+    }
+
+    /// Return a locale with a fallback chain and the invalid fallback in it, the locale being its own fallback
+    /// if it is the default locale, the pseudo locale or is not declared.
+    fn find_invalid_fallback(&self) -> Option<(&Rc<Key>, &Rc<Key>)> {
+        self.fallbacks.iter().find_map(|(locale, fallbacks)| {
+            if !self.locales.contains(locale)
+                || *locale == self.default
+                || self.is_pseudo_locale(locale)
+            {
+                return Some((locale, locale));
+            }
+            fallbacks
+                .iter()
+                .find(|fallback| {
+                    !self.locales.contains(fallback)
+                        || *fallback == locale
+                        || self.is_pseudo_locale(fallback)
+                })
+                .map(|fallback| (locale, fallback))
+        })
+    }
+
+    /// Return if the locale is the pseudo locale, that has no files.
+    pub fn is_pseudo_locale(&self, locale: &Key) -> bool {
+        self.pseudo_locale.as_deref() == Some(locale)
+    }
+
+    /// Return the locales whose catalogs are fetched with dynamic loading, all but the default and the pseudo locales.
+    pub fn fetched_locales(&self) -> impl Iterator<Item = &Rc<Key>> {
+        self.locales
+            .iter()
+            .skip(1)
+            .filter(|locale| !self.is_pseudo_locale(locale))
+    }
+
+    /// Remove the extension of the mapped file names, the file can then be of any enabled format.
+    fn strip_file_names_extension(file_names: &mut HashMap<Rc<Key>, String>) {
+        for file_name in file_names.values_mut() {
+            let path = Path::new(file_name.as_str());
+            let has_ext = path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| FILE_EXTS.contains(&ext));
+            if let (true, Some(stem)) = (has_ext, path.file_stem().and_then(|s| s.to_str())) {
+                *file_name = stem.to_owned();
+            }
+        }
+    }
+
     pub fn new(manifest_dir_path: &mut PathBuf) -> Result<ConfigFile> {
         manifest_dir_path.push("Cargo.toml");
 
@@ -53,9 +208,22 @@ impl ConfigFile {
             .chain(i18n_cfg.chars())
             .collect::<String>();
 
-        let mut cfg: ConfigFile =
+        let cfg: ConfigFile =
             toml::de::from_str(&cfg_file_whitespaced).map_err(Error::ConfigFileDeser)?;
 
+        Self::check(cfg)
+    }
+
+    /// Load the configuration given to the macro instead of the one of the manifest.
+    pub fn from_inline(table: toml::Table) -> Result<ConfigFile> {
+        let cfg: ConfigFile = toml::Value::Table(table)
+            .try_into()
+            .map_err(Error::InlineConfigDeser)?;
+
+        Self::check(cfg)
+    }
+
+    fn check(mut cfg: ConfigFile) -> Result<ConfigFile> {
         if let Some(i) = cfg.locales.iter().position(|l| l == &cfg.default) {
             // put default as first locale
             cfg.locales.swap(0, i);
@@ -65,8 +233,47 @@ impl ConfigFile {
             cfg.locales.swap(0, len);
         }
 
+        if let Some(pseudo_locale) = &cfg.pseudo_locale {
+            if cfg.locales.contains(pseudo_locale) {
+                return Err(Error::DeclaredPseudoLocale(Rc::clone(pseudo_locale)));
+            }
+            cfg.locales.push(Rc::clone(pseudo_locale));
+            // it can only be selected manually: never negotiated, indexed or listed in the sitemap.
+            cfg.rollout.entry(Rc::clone(pseudo_locale)).or_insert(0);
+            if !cfg.beta_locales.contains(pseudo_locale) {
+                cfg.beta_locales.push(Rc::clone(pseudo_locale));
+            }
+        }
+
         if let Some(duplicates) = Self::contain_duplicates(&cfg.locales) {
             Err(Error::DuplicateLocalesInConfig(duplicates))
+        } else if let Some((first, second)) = Self::find_conflicting_locales(&cfg.locales) {
+            Err(Error::ConflictingLocales { first, second })
+        } else if let Some(locale) = cfg.beta_locales.iter().find(|l| !cfg.locales.contains(l)) {
+            Err(Error::UnknownBetaLocale(Rc::clone(locale)))
+        } else if cfg.beta_locales.contains(&cfg.default) {
+            Err(Error::BetaDefaultLocale(Rc::clone(&cfg.default)))
+        } else if let Some((locale, percentage)) = cfg
+            .rollout
+            .iter()
+            .find(|(l, p)| !cfg.locales.contains(l) || **l == cfg.default || **p > 100)
+        {
+            Err(Error::InvalidRollout {
+                locale: Rc::clone(locale),
+                percentage: *percentage,
+            })
+        } else if let Some(locale) = cfg.file_names.keys().find(|l| !cfg.locales.contains(l)) {
+            Err(Error::UnknownFileNameLocale(Rc::clone(locale)))
+        } else if let Some((locale, field)) = Self::find_unknown_font_locale(&cfg) {
+            Err(Error::UnknownFontLocale {
+                locale: Rc::clone(locale),
+                field,
+            })
+        } else if let Some((locale, fallback)) = cfg.find_invalid_fallback() {
+            Err(Error::InvalidFallbackChain {
+                locale: Rc::clone(locale),
+                fallback: Rc::clone(fallback),
+            })
         } else if let Some(duplicates) = cfg
             .name_spaces
             .as_deref()
@@ -74,14 +281,41 @@ impl ConfigFile {
         {
             Err(Error::DuplicateNamespacesInConfig(duplicates))
         } else {
+            Self::strip_file_names_extension(&mut cfg.file_names);
             Ok(cfg)
         }
     }
 }
 
-/// -----------------------------------------
-/// Deserialization
-/// -----------------------------------------
+/// Compare two names ignoring the case and the subtags separator (`-` or `_`).
+pub fn tags_loosely_eq(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.bytes().zip(b.bytes()).all(|(a, b)| {
+            a.eq_ignore_ascii_case(&b) || (matches!(a, b'-' | b'_') && matches!(b, b'-' | b'_'))
+        })
+}
+
+/// Return the canonical form of a language tag per BCP 47: `-` separated, lowercase language,
+/// titlecase script and uppercase region (`zh-Hant-TW`).
+pub fn normalize_tag(tag: &str) -> String {
+    tag.split(['-', '_'])
+        .enumerate()
+        .map(|(i, subtag)| match subtag.len() {
+            _ if i == 0 => subtag.to_ascii_lowercase(),
+            2 if subtag.bytes().all(|b| b.is_ascii_alphabetic()) => subtag.to_ascii_uppercase(),
+            4 if subtag.bytes().all(|b| b.is_ascii_alphabetic()) => {
+                let (first, rest) = subtag.split_at(1);
+                first.to_ascii_uppercase() + &rest.to_ascii_lowercase()
+            }
+            _ => subtag.to_ascii_lowercase(),
+        })
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+// -----------------------------------------
+// Deserialization
+// -----------------------------------------
 
 struct CfgFileVisitor;
 
@@ -99,11 +333,58 @@ enum Field {
     Locales,
     Namespaces,
     LocalesDir,
+    BetaLocales,
+    Rollout,
+    SizeReport,
+    Timings,
+    PluralConstants,
+    CountTypes,
+    NegativeCounts,
+    FormatCounts,
+    LocalesUrl,
+    FileNames,
+    RelaxedJson,
+    DynamicLoadUrl,
+    FontClasses,
+    FontPreloads,
+    Gettext,
+    MissingKeys,
+    Fallbacks,
+    Persistence,
+    NormalizeEmoji,
+    PseudoLocale,
+    NormalizeUnicode,
     Unknown,
 }
 
 impl Field {
-    const FIELDS: &'static [&'static str] = &["default", "locales", "namespaces", "locales-dir"];
+    const FIELDS: &'static [&'static str] = &[
+        "default",
+        "locales",
+        "namespaces",
+        "locales-dir",
+        "beta-locales",
+        "rollout",
+        "size-report",
+        "timings",
+        "plural-constants",
+        "count-types",
+        "negative-counts",
+        "format-counts",
+        "locales-url",
+        "file-names",
+        "relaxed-json",
+        "dynamic-load-url",
+        "font-classes",
+        "font-preloads",
+        "gettext",
+        "missing-keys",
+        "fallbacks",
+        "persistence",
+        "normalize-emoji",
+        "pseudo-locale",
+        "normalize-unicode",
+    ];
 }
 
 struct FieldVisitor;
@@ -137,6 +418,27 @@ impl<'de> serde::de::Visitor<'de> for FieldVisitor {
             "locales" => Ok(Field::Locales),
             "namespaces" => Ok(Field::Namespaces),
             "locales-dir" => Ok(Field::LocalesDir),
+            "beta-locales" => Ok(Field::BetaLocales),
+            "rollout" => Ok(Field::Rollout),
+            "size-report" => Ok(Field::SizeReport),
+            "timings" => Ok(Field::Timings),
+            "plural-constants" => Ok(Field::PluralConstants),
+            "count-types" => Ok(Field::CountTypes),
+            "negative-counts" => Ok(Field::NegativeCounts),
+            "format-counts" => Ok(Field::FormatCounts),
+            "locales-url" => Ok(Field::LocalesUrl),
+            "file-names" => Ok(Field::FileNames),
+            "relaxed-json" => Ok(Field::RelaxedJson),
+            "dynamic-load-url" => Ok(Field::DynamicLoadUrl),
+            "font-classes" => Ok(Field::FontClasses),
+            "font-preloads" => Ok(Field::FontPreloads),
+            "gettext" => Ok(Field::Gettext),
+            "missing-keys" => Ok(Field::MissingKeys),
+            "fallbacks" => Ok(Field::Fallbacks),
+            "persistence" => Ok(Field::Persistence),
+            "normalize-emoji" => Ok(Field::NormalizeEmoji),
+            "pseudo-locale" => Ok(Field::PseudoLocale),
+            "normalize-unicode" => Ok(Field::NormalizeUnicode),
             _ => Ok(Field::Unknown), // skip unknown fields
         }
     }
@@ -168,12 +470,64 @@ impl<'de> serde::de::Visitor<'de> for CfgFileVisitor {
         let mut locales = None;
         let mut name_spaces = None;
         let mut locales_dir = None;
+        let mut beta_locales = None;
+        let mut rollout = None;
+        let mut size_report = None;
+        let mut timings = None;
+        let mut plural_constants = None;
+        let mut count_types = None;
+        let mut negative_counts = None;
+        let mut format_counts = None;
+        let mut locales_url = None;
+        let mut file_names = None;
+        let mut relaxed_json = None;
+        let mut dynamic_load_url = None;
+        let mut font_classes = None;
+        let mut font_preloads = None;
+        let mut gettext = None;
+        let mut missing_keys = None;
+        let mut fallbacks = None;
+        let mut persistence = None;
+        let mut normalize_emoji = None;
+        let mut pseudo_locale = None;
+        let mut normalize_unicode = None;
         while let Some(field) = map.next_key::<Field>()? {
             match field {
                 Field::Default => deser_field(&mut default, &mut map, "default")?,
                 Field::Locales => deser_field(&mut locales, &mut map, "locales")?,
                 Field::Namespaces => deser_field(&mut name_spaces, &mut map, "namespaces")?,
                 Field::LocalesDir => deser_field(&mut locales_dir, &mut map, "locales-dir")?,
+                Field::BetaLocales => deser_field(&mut beta_locales, &mut map, "beta-locales")?,
+                Field::Rollout => deser_field(&mut rollout, &mut map, "rollout")?,
+                Field::SizeReport => deser_field(&mut size_report, &mut map, "size-report")?,
+                Field::Timings => deser_field(&mut timings, &mut map, "timings")?,
+                Field::PluralConstants => {
+                    deser_field(&mut plural_constants, &mut map, "plural-constants")?
+                }
+                Field::CountTypes => deser_field(&mut count_types, &mut map, "count-types")?,
+                Field::NegativeCounts => {
+                    deser_field(&mut negative_counts, &mut map, "negative-counts")?
+                }
+                Field::FormatCounts => deser_field(&mut format_counts, &mut map, "format-counts")?,
+                Field::LocalesUrl => deser_field(&mut locales_url, &mut map, "locales-url")?,
+                Field::FileNames => deser_field(&mut file_names, &mut map, "file-names")?,
+                Field::RelaxedJson => deser_field(&mut relaxed_json, &mut map, "relaxed-json")?,
+                Field::DynamicLoadUrl => {
+                    deser_field(&mut dynamic_load_url, &mut map, "dynamic-load-url")?
+                }
+                Field::FontClasses => deser_field(&mut font_classes, &mut map, "font-classes")?,
+                Field::FontPreloads => deser_field(&mut font_preloads, &mut map, "font-preloads")?,
+                Field::Gettext => deser_field(&mut gettext, &mut map, "gettext")?,
+                Field::MissingKeys => deser_field(&mut missing_keys, &mut map, "missing-keys")?,
+                Field::Fallbacks => deser_field(&mut fallbacks, &mut map, "fallbacks")?,
+                Field::Persistence => deser_field(&mut persistence, &mut map, "persistence")?,
+                Field::NormalizeEmoji => {
+                    deser_field(&mut normalize_emoji, &mut map, "normalize-emoji")?
+                }
+                Field::PseudoLocale => deser_field(&mut pseudo_locale, &mut map, "pseudo-locale")?,
+                Field::NormalizeUnicode => {
+                    deser_field(&mut normalize_unicode, &mut map, "normalize-unicode")?
+                }
                 Field::Unknown => continue,
             }
         }
@@ -189,11 +543,46 @@ impl<'de> serde::de::Visitor<'de> for CfgFileVisitor {
             .map(Cow::Owned)
             .unwrap_or(Cow::Borrowed("./locales"));
 
+        let count_types = count_types
+            .unwrap_or_else(HashMap::<String, String>::new)
+            .into_iter()
+            .map(|(path, count_type)| {
+                let key_path = ParsedValue::parse_key_path(&path)
+                    .ok_or_else(|| Error::InvalidKey(path.clone()))
+                    .map_err(serde::de::Error::custom)?;
+                let count_type = PluralType::parse(&count_type)
+                    .ok_or(Error::InvalidPluralType(count_type))
+                    .map_err(serde::de::Error::custom)?;
+                Ok((key_path, count_type))
+            })
+            .collect::<Result<_, A::Error>>()?;
+
         Ok(ConfigFile {
             default,
             locales,
             name_spaces,
             locales_dir,
+            beta_locales: beta_locales.unwrap_or_default(),
+            rollout: rollout.unwrap_or_default(),
+            size_report,
+            timings: timings.unwrap_or_default(),
+            plural_constants: plural_constants.unwrap_or_default(),
+            count_types: Rc::new(count_types),
+            negative_counts: negative_counts.unwrap_or_default(),
+            format_counts: format_counts.unwrap_or_default(),
+            locales_url,
+            file_names: file_names.unwrap_or_default(),
+            relaxed_json: relaxed_json.unwrap_or_default(),
+            dynamic_load_url,
+            font_classes: font_classes.unwrap_or_default(),
+            font_preloads: font_preloads.unwrap_or_default(),
+            gettext: gettext.unwrap_or_default(),
+            missing_keys: missing_keys.unwrap_or_default(),
+            fallbacks: fallbacks.unwrap_or_default(),
+            persistence: persistence.unwrap_or_default(),
+            normalize_emoji: normalize_emoji.unwrap_or(true),
+            pseudo_locale,
+            normalize_unicode: normalize_unicode.unwrap_or(true),
         })
     }
 
@@ -204,3 +593,166 @@ impl<'de> serde::de::Visitor<'de> for CfgFileVisitor {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_tag() {
+        assert_eq!(normalize_tag("en_us"), "en-US");
+        assert_eq!(normalize_tag("ZH-hant-tw"), "zh-Hant-TW");
+        assert_eq!(normalize_tag("es-419"), "es-419");
+        assert!(tags_loosely_eq("en-US", "en_us"));
+        assert!(!tags_loosely_eq("en-US", "en-GB"));
+        assert!(!tags_loosely_eq("en", "en-US"));
+    }
+
+    #[test]
+    fn test_file_names() {
+        let mut cfg: ConfigFile = toml::de::from_str(
+            r#"
+            default = "en"
+            locales = ["en", "fr", "de"]
+            file-names = { en = "english.json", fr = "french" }
+            "#,
+        )
+        .unwrap();
+        ConfigFile::strip_file_names_extension(&mut cfg.file_names);
+        let [en, fr, de] = &cfg.locales[..] else {
+            panic!("expected 3 locales");
+        };
+        assert_eq!(cfg.file_name(en), "english");
+        assert_eq!(cfg.file_name(fr), "french");
+        assert_eq!(cfg.file_name(de), "de");
+    }
+
+    #[test]
+    fn test_font_hints() {
+        let cfg: ConfigFile = toml::de::from_str(
+            r#"
+            default = "en"
+            locales = ["en", "ja"]
+            font-classes = { ja = "font-cjk" }
+            font-preloads = { ar = ["/fonts/noto-naskh.woff2"] }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(cfg.font_classes.len(), 1);
+        let (locale, field) = cfg.find_unknown_font_locale().unwrap();
+        assert_eq!(locale.name, "ar");
+        assert_eq!(field, "font-preloads");
+    }
+
+    #[test]
+    fn test_count_types() {
+        let cfg: ConfigFile = toml::de::from_str(
+            r#"
+            default = "en"
+            locales = ["en"]
+            count-types = { progress = "f64", "home::downloads.total" = "u64" }
+            "#,
+        )
+        .unwrap();
+        let path = ParsedValue::parse_key_path("home::downloads.total").unwrap();
+        assert_eq!(cfg.count_types.get(&path), Some(&PluralType::U64));
+        let path = ParsedValue::parse_key_path("progress").unwrap();
+        assert_eq!(cfg.count_types.get(&path), Some(&PluralType::F64));
+
+        let err = toml::de::from_str::<ConfigFile>(
+            r#"
+            default = "en"
+            locales = ["en"]
+            count-types = { progress = "float" }
+            "#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("invalid plural type"));
+    }
+
+    #[test]
+    fn test_missing_keys() {
+        let parse = |missing_keys: &str| {
+            toml::de::from_str::<ConfigFile>(&format!(
+                "default = \"en\"\nlocales = [\"en\"]\n{}",
+                missing_keys
+            ))
+        };
+        let cfg = parse("").unwrap();
+        assert_eq!(cfg.missing_keys, MissingKeys::Warn);
+        let cfg = parse("missing-keys = \"error\"").unwrap();
+        assert_eq!(cfg.missing_keys, MissingKeys::Error);
+        let err = parse("missing-keys = \"ignore\"").unwrap_err();
+        assert!(err.to_string().contains("unknown variant"));
+    }
+
+    #[test]
+    fn test_from_inline() {
+        let parse = |cfg: &str| {
+            let inline: super::super::inline_cfg::InlineConfig = syn::parse_str(cfg).unwrap();
+            ConfigFile::from_inline(inline.0)
+        };
+        let cfg = parse(
+            r#"config { default: "fr", locales: ["en", "fr"], path: "./i18n", missing_keys: "error" }"#,
+        )
+        .unwrap();
+        assert_eq!(cfg.locales[0], cfg.default);
+        assert_eq!(cfg.locales_dir, "./i18n");
+        assert_eq!(cfg.missing_keys, MissingKeys::Error);
+
+        assert!(matches!(
+            parse(r#"config { locales: ["en"] }"#),
+            Err(Error::InlineConfigDeser(_))
+        ));
+        assert!(matches!(
+            parse(r#"config { default: "en", locales: ["en", "en"] }"#),
+            Err(Error::DuplicateLocalesInConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_persistence() {
+        let parse = |persistence: &str| {
+            toml::de::from_str::<ConfigFile>(&format!(
+                "default = \"en\"\nlocales = [\"en\"]\n{}",
+                persistence
+            ))
+        };
+        let cfg = parse("").unwrap();
+        assert_eq!(cfg.persistence, Persistence::Cookie);
+        let cfg = parse("persistence = \"local_storage\"").unwrap();
+        assert_eq!(cfg.persistence, Persistence::LocalStorage);
+        let err = parse("persistence = \"session_storage\"").unwrap_err();
+        assert!(err.to_string().contains("unknown variant"));
+    }
+
+    #[test]
+    fn test_fallbacks() {
+        let parse = |fallbacks: &str| {
+            toml::de::from_str::<ConfigFile>(&format!(
+                "default = \"en\"\nlocales = [\"en\", \"fr\", \"fr-CA\"]\nfallbacks = {}",
+                fallbacks
+            ))
+            .unwrap()
+        };
+        let cfg = parse("{ fr-CA = [\"fr\"] }");
+        assert!(cfg.find_invalid_fallback().is_none());
+        let [_, fr, fr_ca] = &cfg.locales[..] else {
+            panic!("expected 3 locales");
+        };
+        assert_eq!(cfg.fallbacks.get(fr_ca), Some(&vec![Rc::clone(fr)]));
+
+        let invalid = |fallbacks: &str| {
+            let cfg = parse(fallbacks);
+            let (locale, fallback) = cfg.find_invalid_fallback().unwrap();
+            (locale.name.clone(), fallback.name.clone())
+        };
+        assert_eq!(
+            invalid("{ fr-CA = [\"es\"] }"),
+            ("fr-CA".into(), "es".into())
+        );
+        assert_eq!(invalid("{ fr = [\"fr\"] }"), ("fr".into(), "fr".into()));
+        assert_eq!(invalid("{ en = [\"fr\"] }"), ("en".into(), "en".into()));
+        assert_eq!(invalid("{ es = [\"fr\"] }"), ("es".into(), "es".into()));
+    }
+}
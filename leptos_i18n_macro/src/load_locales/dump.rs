@@ -0,0 +1,262 @@
+use std::{cell::RefCell, path::Path, rc::Rc};
+
+use proc_macro2::{Delimiter, Spacing, TokenStream, TokenTree};
+
+use super::{
+    error::{Error, Result},
+    key::{Key, KeyPath},
+    parsed_value::ParsedValue,
+};
+
+/// The environment variable naming the key, subkeys or namespace to dump the generated code of.
+const DUMP_VAR: &str = "LEPTOS_I18N_DUMP";
+/// The environment variable overriding the path of the dump, relative to the manifest directory.
+const DUMP_FILE_VAR: &str = "LEPTOS_I18N_DUMP_FILE";
+const DEFAULT_DUMP_FILE: &str = "leptos_i18n_dump.rs";
+
+#[derive(Debug)]
+struct Dump {
+    target: KeyPath,
+    tokens: Vec<TokenStream>,
+}
+
+thread_local! {
+    // `None` when no dump is requested.
+    static DUMP: RefCell<Option<Dump>> = const { RefCell::new(None) };
+}
+
+/// Parse the target of the dump, a key path like `nested.key` or `namespace::key`, or the name of a namespace.
+fn parse_target(target: &str, uses_namespaces: bool) -> Option<KeyPath> {
+    let target = target.trim();
+    if uses_namespaces && !target.contains("::") {
+        let namespace = Key::new(target)?;
+        return Some(KeyPath::new(Some(Rc::new(namespace))));
+    }
+    ParsedValue::parse_key_path(target)
+}
+
+/// Start recording the code generated for the target of `LEPTOS_I18N_DUMP`, if set.
+pub fn enable(uses_namespaces: bool) -> Result<()> {
+    let Ok(target) = std::env::var(DUMP_VAR) else {
+        DUMP.with(|dump| *dump.borrow_mut() = None);
+        return Ok(());
+    };
+    let key_path =
+        parse_target(&target, uses_namespaces).ok_or(Error::InvalidDumpTarget(target))?;
+    DUMP.with(|dump| {
+        *dump.borrow_mut() = Some(Dump {
+            target: key_path,
+            tokens: Vec::new(),
+        })
+    });
+    Ok(())
+}
+
+/// Record the code generated for `key_path` if it is the target of the dump, `f` is only called in that case.
+pub fn record(key_path: &KeyPath, f: impl FnOnce() -> TokenStream) {
+    DUMP.with(|dump| {
+        if let Some(dump) = dump.borrow_mut().as_mut() {
+            if dump.target == *key_path {
+                dump.tokens.push(f());
+            }
+        }
+    });
+}
+
+/// Write the recorded code to the dump file, if a dump was requested.
+pub fn write(manifest_dir_path: &Path) -> Result<()> {
+    let Some(dump) = DUMP.with(|dump| dump.borrow_mut().take()) else {
+        return Ok(());
+    };
+    let file_name = std::env::var(DUMP_FILE_VAR);
+    let path = manifest_dir_path.join(file_name.as_deref().unwrap_or(DEFAULT_DUMP_FILE));
+
+    let mut content = format!(
+        "// code generated by `load_locales!()` for {}\n",
+        dump.target
+    );
+    if dump.tokens.is_empty() {
+        content.push_str("// no code was generated for this key, check the path of the key\n");
+    }
+    for tokens in dump.tokens {
+        content.push('\n');
+        write_tokens(tokens, &mut content);
+    }
+
+    std::fs::write(&path, content).map_err(|err| Error::DumpFile {
+        path: path.clone(),
+        err,
+    })?;
+    eprintln!(
+        "leptos_i18n: dumped the code generated for {} to {:?}",
+        dump.target, path
+    );
+    Ok(())
+}
+
+/// Write tokens with a line per statement, field, match arm or attribute, and the blocks indented.
+///
+/// This is not rustfmt, only enough to read the dump without running it through a formatter.
+#[derive(Default)]
+struct Writer {
+    out: String,
+    indent: usize,
+    line_start: bool,
+    space: bool,
+    /// The depth of generics, their commas don't break the line.
+    angle_depth: usize,
+}
+
+impl Writer {
+    fn new_line(&mut self) {
+        if !self.line_start {
+            self.out.push('\n');
+            self.line_start = true;
+        }
+        self.space = false;
+    }
+
+    fn push(&mut self, s: &str, space: bool) {
+        if self.line_start {
+            self.out.push_str(&"    ".repeat(self.indent));
+            self.line_start = false;
+        } else if space && self.space {
+            self.out.push(' ');
+        }
+        self.out.push_str(s);
+        self.space = true;
+    }
+
+    fn write(&mut self, tokens: TokenStream, inline: bool) {
+        let mut after_attr = false;
+        let mut after_block = false;
+        for token in tokens {
+            let is_attr = std::mem::take(&mut after_attr);
+            // items following a block start on their own line, `else` and `,` stay on it.
+            if std::mem::take(&mut after_block) {
+                match &token {
+                    TokenTree::Ident(ident) if ident == "else" => {}
+                    TokenTree::Punct(punct) if matches!(punct.as_char(), ',' | ';' | '.' | '?') => {
+                    }
+                    _ => self.new_line(),
+                }
+            }
+            match token {
+                TokenTree::Group(group) => {
+                    let (open, close) = match group.delimiter() {
+                        Delimiter::Brace => ("{", "}"),
+                        Delimiter::Bracket => ("[", "]"),
+                        Delimiter::Parenthesis => ("(", ")"),
+                        Delimiter::None => ("", ""),
+                    };
+                    if group.delimiter() == Delimiter::Brace && !inline {
+                        self.push("{", true);
+                        self.new_line();
+                        self.indent += 1;
+                        self.write(group.stream(), false);
+                        self.indent -= 1;
+                        self.new_line();
+                        self.push("}", true);
+                        after_block = true;
+                        continue;
+                    }
+                    // calls and indexes stick to what they apply to.
+                    let space = group.delimiter() == Delimiter::Brace
+                        || !self.out.ends_with(|c: char| {
+                            c.is_alphanumeric() || c == '_' || c == '!' || c == '>'
+                        });
+                    self.push(open, space && !is_attr);
+                    self.space = group.delimiter() == Delimiter::Brace;
+                    let angle_depth = std::mem::take(&mut self.angle_depth);
+                    self.write(group.stream(), true);
+                    self.angle_depth = angle_depth;
+                    self.push(close, group.delimiter() == Delimiter::Brace);
+                    if is_attr && !inline {
+                        self.new_line();
+                    }
+                }
+                TokenTree::Punct(punct) => {
+                    let c = punct.as_char();
+                    let joint = punct.spacing() == Spacing::Joint;
+                    match c {
+                        ',' | ';' => {
+                            self.push(&c.to_string(), false);
+                            if !inline && (c == ';' || self.angle_depth == 0) {
+                                self.new_line();
+                            }
+                            continue;
+                        }
+                        '<' => self.angle_depth += 1,
+                        '>' if !self.out.ends_with(['-', '=']) => {
+                            self.angle_depth = self.angle_depth.saturating_sub(1)
+                        }
+                        _ => {}
+                    }
+                    // paths are written without spaces, `Locale::en`.
+                    let space = !(self.out.ends_with("::") || (joint && c == ':'));
+                    self.push(&c.to_string(), space);
+                    self.space = !joint && c != '#' && c != '&';
+                    after_attr = c == '#';
+                }
+                token => {
+                    let space = !self.out.ends_with("::");
+                    self.push(&token.to_string(), space);
+                }
+            }
+        }
+    }
+}
+
+fn write_tokens(tokens: TokenStream, out: &mut String) {
+    let mut writer = Writer {
+        out: std::mem::take(out),
+        line_start: true,
+        ..Default::default()
+    };
+    writer.write(tokens, false);
+    writer.new_line();
+    *out = writer.out;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    #[test]
+    fn test_parse_target() {
+        let target = parse_target("home", true).unwrap();
+        assert_eq!(target.namespace.unwrap().name, "home");
+        assert!(target.path.is_empty());
+
+        let target = parse_target("home::menu.open", true).unwrap();
+        assert_eq!(target.namespace.unwrap().name, "home");
+        assert_eq!(target.path.len(), 2);
+
+        let target = parse_target("menu.open", false).unwrap();
+        assert!(target.namespace.is_none());
+        assert_eq!(target.path.len(), 2);
+
+        assert!(parse_target("not a key", false).is_none());
+    }
+
+    #[test]
+    fn test_write_tokens() {
+        let tokens = quote! {
+            impl Foo {
+                pub const fn new(_locale: Locale) -> Self {
+                    match _locale {
+                        Locale::en => Self { key: "value" },
+                        Locale::fr => Self { key: "valeur" }
+                    }
+                }
+            }
+        };
+        let mut out = String::new();
+        write_tokens(tokens, &mut out);
+        assert_eq!(
+            out,
+            "impl Foo {\n    pub const fn new(_locale : Locale) -> Self {\n        match _locale {\n            Locale::en => Self {\n                key : \"value\"\n            },\n            Locale::fr => Self {\n                key : \"valeur\"\n            }\n        }\n    }\n}\n"
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Deref,
+    rc::Rc,
+};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::{
+    cfg_file::ConfigFile,
+    formatter::FormatterKind,
+    key::Key,
+    locale::{BuildersKeys, Locale, LocaleValue},
+    parsed_value::{InterpolateKey, ParsedValue},
+};
+
+struct Context<'a> {
+    default_locale: &'a Key,
+    top_locales: HashSet<&'a Key>,
+}
+
+/// Bind the value of an interpolation given at runtime to the name used by the generated view.
+fn interpolation_binding(key: &InterpolateKey) -> TokenStream {
+    let ident = key.as_ident();
+    let name = key.get_real_name();
+    let value = match key {
+        InterpolateKey::Variable(_) | InterpolateKey::Attribute(_) => {
+            quote!(__interpolations.variable(#name))
+        }
+        InterpolateKey::Formatted(_, FormatterKind::Number) => {
+            quote!(__interpolations.number(#name))
+        }
+        InterpolateKey::Formatted(_, FormatterKind::DateTime) => {
+            quote!(__interpolations.date_time(#name))
+        }
+        InterpolateKey::Select(_) => quote!(__interpolations.select(#name)),
+        InterpolateKey::Count(plural_type) => {
+            quote!(__interpolations.count::<#plural_type>(#name))
+        }
+        InterpolateKey::Component(_) => quote!(__interpolations.children(#name)),
+        InterpolateKey::ComponentWithAttrs(_) => {
+            quote!(__interpolations.children_with_attrs(#name))
+        }
+    };
+    quote!(let #ident = #value;)
+}
+
+fn builder_arm(
+    key: &Rc<Key>,
+    interpolations: &HashSet<InterpolateKey>,
+    locales: &[Locale],
+    ctx: &Context,
+) -> TokenStream {
+    let mut bindings = interpolations.iter().collect::<Vec<_>>();
+    bindings.sort_by_key(|key| key.as_ident());
+    let bindings = bindings.into_iter().map(interpolation_binding);
+
+    let default_match = super::get_default_match(ctx.default_locale, &ctx.top_locales, locales);
+    let locales_impls = super::group_identical_locales(locales, &default_match, |locale| {
+        locale
+            .keys
+            .get(key)
+            .filter(|value| !matches!(value, ParsedValue::Default))
+    })
+    .into_iter()
+    .map(|(pattern, value)| quote!(#pattern => { #value }));
+
+    quote! {
+        leptos_i18n::dynamic_keys::DynamicValue::Builder(
+            leptos_i18n::dynamic_keys::DynamicBuilder::new(self, |__locale, __interpolations| {
+                #(#bindings)*
+                match __locale {
+                    #(#locales_impls,)*
+                }
+            })
+        )
+    }
+}
+
+fn collect_arms(
+    keys: &HashMap<Rc<Key>, LocaleValue>,
+    locales: &[Locale],
+    path: &mut Vec<Rc<Key>>,
+    ctx: &Context,
+    arms: &mut Vec<(String, TokenStream)>,
+) {
+    for (key, value) in keys {
+        path.push(Rc::clone(key));
+        let value = match value {
+            LocaleValue::Subkeys { locales, keys } => {
+                collect_arms(&keys.0, locales, path, ctx, arms);
+                None
+            }
+            LocaleValue::Value(None) => {
+                let fields = path.iter();
+                Some(quote! {
+                    leptos_i18n::dynamic_keys::DynamicValue::String(
+                        leptos_i18n::__private::BuildStr::build_string(
+                            leptos_i18n::Locale::get_keys(self) #(.#fields)*
+                        )
+                    )
+                })
+            }
+            LocaleValue::Value(Some(interpolations)) => {
+                Some(builder_arm(key, interpolations, locales, ctx))
+            }
+        };
+        if let Some(value) = value {
+            let path_str = path
+                .iter()
+                .map(|key| key.name.as_str())
+                .collect::<Vec<_>>()
+                .join(".");
+            arms.push((path_str, value));
+        }
+        path.pop();
+    }
+}
+
+/// Generate the `Locale::get_by_key` method, resolving a dot-separated key path at runtime,
+/// when the `dynamic_keys` feature is enabled.
+pub fn create_get_by_key(keys: &BuildersKeys, cfg_file: &ConfigFile) -> TokenStream {
+    if !cfg!(feature = "dynamic_keys") {
+        return quote!();
+    }
+    let ctx = Context {
+        default_locale: &cfg_file.default,
+        top_locales: cfg_file.locales.iter().map(Deref::deref).collect(),
+    };
+    let mut arms = vec![];
+    match keys {
+        BuildersKeys::NameSpaces { namespaces, keys } => {
+            for namespace in namespaces.iter() {
+                let keys = &keys[&namespace.key];
+                let mut path = vec![Rc::clone(&namespace.key)];
+                collect_arms(&keys.0, &namespace.locales, &mut path, &ctx, &mut arms);
+            }
+        }
+        BuildersKeys::Locales { locales, keys } => {
+            collect_arms(&keys.0, locales, &mut vec![], &ctx, &mut arms);
+        }
+    }
+    arms.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let arms = arms
+        .into_iter()
+        .map(|(path, value)| quote!(#path => core::option::Option::Some(#value)));
+
+    quote! {
+        impl Locale {
+            /// Return the value of the key at the given dot-separated path (`namespace.key.subkey` with namespaces)
+            /// in this locale, as written in the locales files, or `None` if there is no such key.
+            pub fn get_by_key(self, key: &str) -> core::option::Option<leptos_i18n::dynamic_keys::DynamicValue<Self>> {
+                match key {
+                    #(#arms,)*
+                    _ => core::option::Option::None,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use super::{
+    cfg_file::ConfigFile,
+    hot_reload,
+    key::{Key, KeyPath},
+};
+
+const DEFAULT_URL: &str = "/locales";
+
+/// The type of the string keys, a `DynStr` when the `dynamic_load_strings` feature is enabled.
+///
+/// Only the keys without interpolations are loaded at runtime, the builders of the others keep the values of every locale.
+pub fn str_type() -> TokenStream {
+    if cfg!(feature = "dynamic_load_strings") {
+        quote!(leptos_i18n::dynamic_load::DynStr)
+    } else {
+        hot_reload::str_type()
+    }
+}
+
+/// The tokens of a string key, a `DynStr` reading the value of the key at `key_path` in the catalog fetched
+/// for the locale when the `dynamic_load_strings` feature is enabled, `s` being the value of the default locale.
+pub fn str_value(s: &str, key_path: &KeyPath, key: &Key) -> TokenStream {
+    if !cfg!(feature = "dynamic_load_strings") {
+        return hot_reload::str_value(s, key_path, key);
+    }
+    let url_method = url_method_ident(key_path.namespace.as_deref());
+    let path = key_path.path.iter().map(|key| &key.name);
+    let key = &key.name;
+    quote!(leptos_i18n::dynamic_load::DynStr::new(
+        #s,
+        _locale.#url_method(),
+        &[#(#path,)* #key]
+    ))
+}
+
+/// The generated methods are called in the `const fn new` of the keys types, so one method is generated per namespace
+/// instead of matching on the name of the namespace.
+fn url_method_ident(namespace: Option<&Key>) -> syn::Ident {
+    match namespace {
+        Some(namespace) => format_ident!("__dynamic_load_url_{}", namespace.ident),
+        None => format_ident!("__dynamic_load_url"),
+    }
+}
+
+fn catalog_url(cfg_file: &ConfigFile, locale: &Key, namespace: Option<&Key>) -> String {
+    let base_url = cfg_file
+        .dynamic_load_url
+        .as_deref()
+        .unwrap_or(DEFAULT_URL)
+        .trim_end_matches('/');
+    let file_name = cfg_file.file_name(locale);
+    match namespace {
+        Some(namespace) => format!("{}/{}/{}.json", base_url, file_name, namespace.name),
+        None => format!("{}/{}.json", base_url, file_name),
+    }
+}
+
+fn url_method(cfg_file: &ConfigFile, namespace: Option<&Key>) -> TokenStream {
+    let method_ident = url_method_ident(namespace);
+    // the default locale is compiled in, it is never fetched, and the pseudo locale has no catalog.
+    let match_arms = cfg_file.fetched_locales().map(|locale| {
+        let url = catalog_url(cfg_file, locale, namespace);
+        quote!(Locale::#locale => Some(#url))
+    });
+    quote! {
+        #[doc(hidden)]
+        #[allow(unreachable_patterns)]
+        pub const fn #method_ident(self) -> Option<&'static str> {
+            match self {
+                #(#match_arms,)*
+                _ => None
+            }
+        }
+    }
+}
+
+/// Generate the `Locale::__dynamic_load_url` methods, returning the URL of the catalog to fetch for a locale,
+/// nothing when the `dynamic_load_strings` feature is disabled.
+pub fn create_url_methods(cfg_file: &ConfigFile) -> Option<TokenStream> {
+    if !cfg!(feature = "dynamic_load_strings") {
+        return None;
+    }
+    let methods = match &cfg_file.name_spaces {
+        None => vec![url_method(cfg_file, None)],
+        Some(namespaces) => namespaces
+            .iter()
+            .map(|namespace| url_method(cfg_file, Some(namespace)))
+            .collect(),
+    };
+    Some(quote! {
+        impl Locale {
+            #(#methods)*
+        }
+    })
+}
+
+/// Generate the `leptos_i18n::Locale::dynamic_load_urls` method, returning the URLs of all the catalogs of a locale,
+/// nothing when the `dynamic_load_strings` feature is disabled.
+pub fn create_urls_trait_method(cfg_file: &ConfigFile) -> Option<TokenStream> {
+    if !cfg!(feature = "dynamic_load_strings") {
+        return None;
+    }
+    let match_arms = cfg_file.fetched_locales().map(|locale| {
+        let urls = match &cfg_file.name_spaces {
+            None => vec![catalog_url(cfg_file, locale, None)],
+            Some(namespaces) => namespaces
+                .iter()
+                .map(|namespace| catalog_url(cfg_file, locale, Some(namespace)))
+                .collect(),
+        };
+        quote!(Locale::#locale => &[#(#urls,)*])
+    });
+    Some(quote! {
+        #[allow(unreachable_patterns)]
+        fn dynamic_load_urls(self) -> &'static [&'static str] {
+            match self {
+                #(#match_arms,)*
+                _ => &[]
+            }
+        }
+    })
+}
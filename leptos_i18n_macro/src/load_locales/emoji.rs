@@ -0,0 +1,229 @@
+use std::{borrow::Cow, cell::Cell};
+
+thread_local! {
+    static NORMALIZE_EMOJI: Cell<bool> = const { Cell::new(true) };
+}
+
+const TEXT_SELECTOR: char = '\u{FE0E}';
+const EMOJI_SELECTOR: char = '\u{FE0F}';
+const KEYCAP: char = '\u{20E3}';
+
+/// The emoji displayed as text unless followed by the emoji variation selector
+/// (`Emoji=Yes` and `Emoji_Presentation=No` in the Unicode emoji data), pictographs only:
+/// the letterlike symbols, arrows and geometric shapes also in that set are left as text.
+const TEXT_DEFAULT_PICTOGRAPHS: &[(char, char)] = &[
+    ('\u{2600}', '\u{2604}'),
+    ('\u{260E}', '\u{260E}'),
+    ('\u{2611}', '\u{2611}'),
+    ('\u{2618}', '\u{2618}'),
+    ('\u{261D}', '\u{261D}'),
+    ('\u{2620}', '\u{2620}'),
+    ('\u{2622}', '\u{2623}'),
+    ('\u{2626}', '\u{2626}'),
+    ('\u{262A}', '\u{262A}'),
+    ('\u{262E}', '\u{262F}'),
+    ('\u{2638}', '\u{263A}'),
+    ('\u{2640}', '\u{2640}'),
+    ('\u{2642}', '\u{2642}'),
+    ('\u{265F}', '\u{2660}'),
+    ('\u{2663}', '\u{2663}'),
+    ('\u{2665}', '\u{2666}'),
+    ('\u{2668}', '\u{2668}'),
+    ('\u{267B}', '\u{267B}'),
+    ('\u{267E}', '\u{267E}'),
+    ('\u{2692}', '\u{2692}'),
+    ('\u{2694}', '\u{2697}'),
+    ('\u{2699}', '\u{2699}'),
+    ('\u{269B}', '\u{269C}'),
+    ('\u{26A0}', '\u{26A0}'),
+    ('\u{26A7}', '\u{26A7}'),
+    ('\u{26B0}', '\u{26B1}'),
+    ('\u{26C8}', '\u{26C8}'),
+    ('\u{26CF}', '\u{26CF}'),
+    ('\u{26D1}', '\u{26D1}'),
+    ('\u{26D3}', '\u{26D3}'),
+    ('\u{26E9}', '\u{26E9}'),
+    ('\u{26F0}', '\u{26F1}'),
+    ('\u{26F4}', '\u{26F4}'),
+    ('\u{26F7}', '\u{26F8}'),
+    ('\u{2702}', '\u{2702}'),
+    ('\u{2708}', '\u{2709}'),
+    ('\u{270C}', '\u{270D}'),
+    ('\u{270F}', '\u{270F}'),
+    ('\u{2712}', '\u{2712}'),
+    ('\u{2714}', '\u{2714}'),
+    ('\u{2716}', '\u{2716}'),
+    ('\u{271D}', '\u{271D}'),
+    ('\u{2721}', '\u{2721}'),
+    ('\u{2733}', '\u{2734}'),
+    ('\u{2744}', '\u{2744}'),
+    ('\u{2747}', '\u{2747}'),
+    ('\u{2763}', '\u{2764}'),
+    ('\u{1F321}', '\u{1F321}'),
+    ('\u{1F324}', '\u{1F32C}'),
+    ('\u{1F336}', '\u{1F336}'),
+    ('\u{1F37D}', '\u{1F37D}'),
+    ('\u{1F396}', '\u{1F397}'),
+    ('\u{1F399}', '\u{1F39B}'),
+    ('\u{1F39E}', '\u{1F39F}'),
+    ('\u{1F3CB}', '\u{1F3CE}'),
+    ('\u{1F3D4}', '\u{1F3DF}'),
+    ('\u{1F3F3}', '\u{1F3F3}'),
+    ('\u{1F3F5}', '\u{1F3F5}'),
+    ('\u{1F3F7}', '\u{1F3F7}'),
+    ('\u{1F43F}', '\u{1F43F}'),
+    ('\u{1F441}', '\u{1F441}'),
+    ('\u{1F4FD}', '\u{1F4FD}'),
+    ('\u{1F549}', '\u{1F54A}'),
+    ('\u{1F56F}', '\u{1F570}'),
+    ('\u{1F573}', '\u{1F579}'),
+    ('\u{1F587}', '\u{1F587}'),
+    ('\u{1F58A}', '\u{1F58D}'),
+    ('\u{1F590}', '\u{1F590}'),
+    ('\u{1F5A5}', '\u{1F5A5}'),
+    ('\u{1F5A8}', '\u{1F5A8}'),
+    ('\u{1F5B1}', '\u{1F5B2}'),
+    ('\u{1F5BC}', '\u{1F5BC}'),
+    ('\u{1F5C2}', '\u{1F5C4}'),
+    ('\u{1F5D1}', '\u{1F5D3}'),
+    ('\u{1F5DC}', '\u{1F5DE}'),
+    ('\u{1F5E1}', '\u{1F5E1}'),
+    ('\u{1F5E3}', '\u{1F5E3}'),
+    ('\u{1F5E8}', '\u{1F5E8}'),
+    ('\u{1F5EF}', '\u{1F5EF}'),
+    ('\u{1F5F3}', '\u{1F5F3}'),
+    ('\u{1F5FA}', '\u{1F5FA}'),
+    ('\u{1F6CB}', '\u{1F6CB}'),
+    ('\u{1F6CD}', '\u{1F6CF}'),
+    ('\u{1F6E0}', '\u{1F6E5}'),
+    ('\u{1F6E9}', '\u{1F6E9}'),
+    ('\u{1F6F0}', '\u{1F6F0}'),
+    ('\u{1F6F3}', '\u{1F6F3}'),
+];
+
+/// Set if the emoji of the values are normalized when parsed, the default.
+pub fn set_normalize_emoji(normalize: bool) {
+    NORMALIZE_EMOJI.with(|normalize_emoji| normalize_emoji.set(normalize));
+}
+
+fn is_text_default_pictograph(c: char) -> bool {
+    TEXT_DEFAULT_PICTOGRAPHS
+        .binary_search_by(|&(start, end)| {
+            if end < c {
+                std::cmp::Ordering::Less
+            } else if start > c {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+fn is_selector(c: char) -> bool {
+    c == TEXT_SELECTOR || c == EMOJI_SELECTOR
+}
+
+fn is_skin_tone(c: char) -> bool {
+    ('\u{1F3FB}'..='\u{1F3FF}').contains(&c)
+}
+
+fn is_keycap_base(c: char) -> bool {
+    c.is_ascii_digit() || c == '#' || c == '*'
+}
+
+fn may_need_normalization(c: char) -> bool {
+    is_selector(c) || c == KEYCAP || is_text_default_pictograph(c)
+}
+
+/// Normalize the variation selectors of the emoji, so an emoji pasted with or without them
+/// renders the same in every locale:
+///
+/// - the pictographs displayed as text by default, like `❤` or `☀`, get the emoji selector,
+///   unless they are explicitly followed by the text selector or a skin tone;
+/// - the keycaps (`1⃣`) get the emoji selector before the keycap;
+/// - the selectors before a skin tone and the repeated selectors are removed.
+pub fn normalize_emoji(value: &str) -> Cow<'_, str> {
+    if !NORMALIZE_EMOJI.with(Cell::get) || !value.chars().any(may_need_normalization) {
+        return Cow::Borrowed(value);
+    }
+    let mut normalized = String::with_capacity(value.len() + 8);
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        if is_selector(c) {
+            // a selector applies to the previous character, and none is allowed before a skin tone.
+            let after_selector = normalized.ends_with(is_selector);
+            if !after_selector && !chars.peek().copied().is_some_and(is_skin_tone) {
+                normalized.push(c);
+            }
+            continue;
+        }
+        normalized.push(c);
+        let next = chars.peek().copied();
+        let needs_selector = match next {
+            Some(next) if is_selector(next) || is_skin_tone(next) => false,
+            Some(KEYCAP) => is_keycap_base(c),
+            _ => is_text_default_pictograph(c),
+        };
+        if needs_selector {
+            normalized.push(EMOJI_SELECTOR);
+        }
+    }
+    if normalized == value {
+        Cow::Borrowed(value)
+    } else {
+        Cow::Owned(normalized)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_default_pictographs() {
+        assert_eq!(
+            normalize_emoji("I \u{2764} Rust"),
+            "I \u{2764}\u{FE0F} Rust"
+        );
+        assert_eq!(
+            normalize_emoji("I \u{2764}\u{FE0F} Rust"),
+            "I \u{2764}\u{FE0F} Rust"
+        );
+        // an explicit text presentation is kept.
+        assert_eq!(normalize_emoji("\u{2764}\u{FE0E}"), "\u{2764}\u{FE0E}");
+        // letterlike symbols and emoji displayed as emoji by default are left as is.
+        assert_eq!(
+            normalize_emoji("\u{A9} \u{2122} \u{1F600}"),
+            "\u{A9} \u{2122} \u{1F600}"
+        );
+        assert!(matches!(normalize_emoji("plain text"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn sequences() {
+        // heart on fire, with the selector missing on the heart.
+        assert_eq!(
+            normalize_emoji("\u{2764}\u{200D}\u{1F525}"),
+            "\u{2764}\u{FE0F}\u{200D}\u{1F525}"
+        );
+        assert_eq!(normalize_emoji("1\u{20E3}"), "1\u{FE0F}\u{20E3}");
+        assert_eq!(normalize_emoji("1 2"), "1 2");
+        // selector before a skin tone.
+        assert_eq!(
+            normalize_emoji("\u{270C}\u{FE0F}\u{1F3FD}"),
+            "\u{270C}\u{1F3FD}"
+        );
+        assert_eq!(
+            normalize_emoji("\u{1F44D}\u{FE0F}\u{FE0F}"),
+            "\u{1F44D}\u{FE0F}"
+        );
+    }
+
+    #[test]
+    fn disabled() {
+        set_normalize_emoji(false);
+        assert_eq!(normalize_emoji("\u{2764}"), "\u{2764}");
+        set_normalize_emoji(true);
+    }
+}
@@ -3,15 +3,37 @@ use std::{collections::HashSet, fmt::Display, path::PathBuf, rc::Rc};
 use super::{
     key::{Key, KeyPath},
     plural::PluralType,
+    plural_rules::{PluralCategory, PluralRuleType},
 };
 use quote::quote;
 
-#[cfg(feature = "json_files")]
-pub type SerdeError = serde_json::Error;
-#[cfg(feature = "yaml_files")]
-pub type SerdeError = serde_yaml::Error;
-#[cfg(not(any(feature = "json_files", feature = "yaml_files")))]
-pub type SerdeError = &'static str; // whatever impl Display
+/// The error of the deserializer of the format of a locale file.
+#[derive(Debug)]
+pub enum SerdeError {
+    #[cfg(feature = "json_files")]
+    Json(serde_json::Error),
+    #[cfg(feature = "yaml_files")]
+    Yaml(serde_yaml::Error),
+    #[cfg(feature = "fluent")]
+    Fluent(super::fluent::FluentError),
+    #[cfg(not(any(feature = "json_files", feature = "yaml_files", feature = "fluent")))]
+    None,
+}
+
+impl Display for SerdeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            #[cfg(feature = "json_files")]
+            SerdeError::Json(err) => Display::fmt(err, f),
+            #[cfg(feature = "yaml_files")]
+            SerdeError::Yaml(err) => Display::fmt(err, f),
+            #[cfg(feature = "fluent")]
+            SerdeError::Fluent(err) => Display::fmt(err, f),
+            #[cfg(not(any(feature = "json_files", feature = "yaml_files", feature = "fluent")))]
+            SerdeError::None => Ok(()),
+        }
+    }
+}
 
 #[derive(Debug)]
 pub enum Error {
@@ -20,6 +42,7 @@ pub enum Error {
     ManifestNotFound(std::io::Error),
     ConfigNotPresent,
     ConfigFileDeser(toml::de::Error),
+    InlineConfigDeser(toml::de::Error),
     LocaleFileNotFound(Vec<(PathBuf, std::io::Error)>),
     LocaleFileDeser {
         path: PathBuf,
@@ -27,6 +50,59 @@ pub enum Error {
     },
     DuplicateLocalesInConfig(HashSet<String>),
     DuplicateNamespacesInConfig(HashSet<String>),
+    ConflictingLocales {
+        first: Rc<Key>,
+        second: Rc<Key>,
+    },
+    LocaleFileNameMismatch {
+        expected: PathBuf,
+        found: PathBuf,
+    },
+    UnknownBetaLocale(Rc<Key>),
+    BetaDefaultLocale(Rc<Key>),
+    UnknownFileNameLocale(Rc<Key>),
+    UnknownFontLocale {
+        locale: Rc<Key>,
+        field: &'static str,
+    },
+    InvalidRollout {
+        locale: Rc<Key>,
+        percentage: u8,
+    },
+    InvalidFallbackChain {
+        locale: Rc<Key>,
+        fallback: Rc<Key>,
+    },
+    DeclaredPseudoLocale(Rc<Key>),
+    SizeReport {
+        path: PathBuf,
+        err: std::io::Error,
+    },
+    InvalidDumpTarget(String),
+    DumpFile {
+        path: PathBuf,
+        err: std::io::Error,
+    },
+    RenamesFileDeser(toml::de::Error),
+    LockfileDeser(toml::de::Error),
+    LockfileMissing(PathBuf),
+    LockfileWrite {
+        path: PathBuf,
+        err: std::io::Error,
+    },
+    RemoteLocaleFetch(Vec<(String, String)>),
+    RemoteLocaleNotCached(PathBuf),
+    RemoteLocaleNotPinned(String),
+    RemoteLocaleHashMismatch {
+        url: String,
+        expected: String,
+        found: String,
+    },
+    InvalidRename {
+        old: String,
+        new: String,
+        reason: &'static str,
+    },
     MissingKeyInLocale {
         locale: Rc<Key>,
         key_path: KeyPath,
@@ -48,6 +124,8 @@ pub enum Error {
         key_path: KeyPath,
         type1: PluralType,
         type2: PluralType,
+        /// The locale and the rendered value that don't match the others.
+        preview: Option<(Rc<Key>, String)>,
     },
     InvalidKey(String),
     EmptyPlural,
@@ -56,7 +134,24 @@ pub enum Error {
     InvalidFallback,
     MultipleFallbacks,
     MissingFallback(PluralType),
+    MissingOtherCategory,
+    UnknownPluralRules(String),
+    UnusedPluralCategory {
+        category: PluralCategory,
+        rule_type: PluralRuleType,
+        locale: String,
+    },
     PluralSubkeys,
+    MisplacedOrdinal,
+    DisabledComponent {
+        name: String,
+    },
+    UnknownPluralConstant(String),
+    PluralConstantType {
+        name: String,
+        found: PluralType,
+        expected: PluralType,
+    },
     PluralNumberType {
         found: PluralType,
         expected: PluralType,
@@ -76,6 +171,15 @@ pub enum Error {
         locale: Rc<Key>,
         key_path: KeyPath,
     },
+    VariableKindMissmatch {
+        key_path: KeyPath,
+        name: String,
+    },
+    ComponentAttrsMissmatch {
+        key_path: KeyPath,
+        name: String,
+    },
+    MissingSelectOther(String),
 }
 
 impl Display for Error {
@@ -93,6 +197,9 @@ impl Display for Error {
             Error::ConfigFileDeser(err) => {
                 write!(f, "Parsing of cargo manifest (Cargo.toml) failed: {}", err)
             }
+            Error::InlineConfigDeser(err) => {
+                write!(f, "Parsing of the configuration given to load_locales! failed: {}", err)
+            }
             Error::LocaleFileNotFound(errs) => {
                 for (path, err) in errs {
                     writeln!(f,
@@ -107,7 +214,7 @@ impl Display for Error {
                 path, err
             ),
             Error::MissingKeyInLocale { key_path, locale } => write!(f,
-                "Some keys are different beetween locale files, locale {:?} is missing key: {}",
+                "Some keys are different beetween locale files, locale {:?} is missing key: {}. Set `missing-keys = \"warn\"` to fall back to the value of the default locale instead.",
                 locale, key_path
             ),
             Error::PluralParse {
@@ -142,7 +249,100 @@ impl Display for Error {
                 "Found duplicates namespaces in configuration (Cargo.toml): {:?}", 
                 duplicates
             ),
-            Error::PluralTypeMissmatch { key_path, type1, type2 } => write!(f, "Conflicting plural value type at key {}, found type {} but also type {}.", key_path, type1, type2),
+            Error::ConflictingLocales { first, second } => write!(f,
+                "Locales {:?} and {:?} in configuration (Cargo.toml) only differ by case or separator, they are the same locale per BCP 47 ({:?}). Keep only one of them.",
+                first, second, super::cfg_file::normalize_tag(&first.name)
+            ),
+            Error::LocaleFileNameMismatch { expected, found } => write!(f,
+                "Could not found file {:?}, but found {:?}. Locales and namespaces names must match the files names exactly, including the case and the separator.",
+                expected, found
+            ),
+            Error::UnknownBetaLocale(locale) => write!(f,
+                "Locale {:?} is marked as beta in configuration (Cargo.toml) but is not declared in \"locales\"",
+                locale
+            ),
+            Error::BetaDefaultLocale(locale) => write!(f,
+                "The default locale {:?} can't be marked as beta in configuration (Cargo.toml)",
+                locale
+            ),
+            Error::UnknownFileNameLocale(locale) => write!(f,
+                "Locale {:?} is given a file name in \"file-names\" in configuration (Cargo.toml) but is not declared in \"locales\"",
+                locale
+            ),
+            Error::UnknownFontLocale { locale, field } => write!(f,
+                "Locale {:?} is given font hints in {:?} in configuration (Cargo.toml) but is not declared in \"locales\"",
+                locale, field
+            ),
+            Error::InvalidRollout { locale, percentage } => write!(f,
+                "Invalid rollout of {}% for locale {:?} in configuration (Cargo.toml), the locale must be declared in \"locales\", can't be the default locale and the percentage must be between 0 and 100",
+                percentage, locale
+            ),
+            Error::InvalidFallbackChain { locale, fallback } => write!(f,
+                "Invalid fallback {:?} for locale {:?} in \"fallbacks\" in configuration (Cargo.toml), both must be declared in \"locales\", a locale can't fall back to itself, the default locale can't have fallbacks and the pseudo locale can't be part of a chain",
+                fallback, locale
+            ),
+            Error::DeclaredPseudoLocale(locale) => write!(f,
+                "Pseudo locale {:?} is declared in \"locales\" in configuration (Cargo.toml), it is synthesized from the default locale and can't have locale files",
+                locale
+            ),
+            Error::RenamesFileDeser(err) => {
+                write!(f, "Parsing of renames file (renames.toml) failed: {}", err)
+            }
+            Error::LockfileDeser(err) => {
+                write!(f, "Parsing of lockfile (i18n.lock) failed: {}", err)
+            }
+            Error::LockfileMissing(path) => write!(f,
+                "The lockfile {:?} is missing, it is required in CI to pin the remote locale files. Build once locally with LEPTOS_I18N_FETCH=1 and commit the generated i18n.lock.",
+                path
+            ),
+            Error::LockfileWrite { path, err } => write!(f,
+                "Could not write lockfile to file {:?} : {}",
+                path, err
+            ),
+            Error::RemoteLocaleFetch(errs) => {
+                for (url, err) in errs {
+                    writeln!(f,
+                        "Could not fetch file {:?} : {}",
+                        url, err
+                    )?;
+                }
+                Ok(())
+            }
+            Error::RemoteLocaleNotCached(path) => write!(f,
+                "The remote locale file {:?} is not in the cache and fetching it is disabled. Set LEPTOS_I18N_FETCH=1 to fetch the remote locales at build time.",
+                path
+            ),
+            Error::RemoteLocaleNotPinned(url) => write!(f,
+                "The file {:?} is not pinned in the lockfile (i18n.lock), new files can't be pinned in CI. Build once locally with LEPTOS_I18N_FETCH=1 and commit the lockfile.",
+                url
+            ),
+            Error::RemoteLocaleHashMismatch { url, expected, found } => write!(f,
+                "The content of file {:?} changed since it was pinned in the lockfile (i18n.lock), expected hash {} but found {}. Remove its entry from the lockfile to accept the new content.",
+                url, expected, found
+            ),
+            Error::InvalidRename { old, new, reason } => write!(f,
+                "Invalid rename of key {:?} to {:?} in renames file (renames.toml): {}",
+                old, new, reason
+            ),
+            Error::SizeReport { path, err } => write!(f,
+                "Could not write size report to file {:?} : {}",
+                path, err
+            ),
+            Error::InvalidDumpTarget(target) => write!(f,
+                "Invalid key {:?} in LEPTOS_I18N_DUMP, expected a key path like \"key.subkey\" or \"namespace::key\", or the name of a namespace",
+                target
+            ),
+            Error::DumpFile { path, err } => write!(f,
+                "Could not write the dump of the generated code to file {:?} : {}",
+                path, err
+            ),
+            Error::PluralTypeMissmatch { key_path, type1, type2, preview } => {
+                write!(f, "Conflicting plural value type at key {}, found type {} but also type {}.", key_path, type1, type2)?;
+                if let Some((locale, preview)) = preview {
+                    write!(f, "\nvalue in locale {:?}: {}", locale, preview)?;
+                }
+                Ok(())
+            }
             Error::InvalidKey(key) => write!(f, "invalid key {:?}, it can't be used as a rust identifier, try removing whitespaces and special characters.", key),
             Error::EmptyPlural => write!(f, "empty plurals are not allowed"),
             Error::InvalidPluralType(t) => write!(f, "invalid plural type {:?}", t),
@@ -150,16 +350,30 @@ impl Display for Error {
             Error::InvalidFallback => write!(f, "fallbacks are only allowed in last position"),
             Error::MultipleFallbacks => write!(f, "only one fallback is allowed"),
             Error::MissingFallback(t) => write!(f, "plural type {} require a fallback (or a fullrange \"..\")", t),
+            Error::MissingOtherCategory => write!(f, "plurals using plural categories require an \"other\" category (or a fallback \"_\")"),
+            Error::UnknownPluralRules(locale) => write!(f, "The plural rules of locale {:?} are unknown, plural categories can't be used for it, use counts and ranges instead", locale),
+            Error::UnusedPluralCategory { category, rule_type, locale } => write!(f, "The plural category {:?} is not used by the {} plural rules of locale {:?}", category.to_string(), rule_type, locale),
             Error::PluralSubkeys => write!(f, "subkeys for plurals are not allowed"),
+            Error::MisplacedOrdinal => write!(f, "\"ordinal\": true must come before the categories of the plural, or the map is read as subkeys"),
+            Error::DisabledComponent { name } => write!(
+                f,
+                "found component <{}>, but components are disabled by the \"no_components\" feature",
+                name
+            ),
             Error::SubKeyMissmatch { locale, key_path } => {
                 write!(f, "Missmatch value type beetween locale {:?} and default at key {}: one has subkeys and the other has direct value.", locale, key_path)
             },
+            Error::UnknownPluralConstant(name) => write!(f, "unknown plural constant {:?}, plural constants must be declared in the \"plural-constants\" table of the configuration", name),
+            Error::PluralConstantType { name, found, expected } => write!(f, "plural constant {:?} of type {} can't be used for plural type {}", name, found, expected),
             Error::PluralNumberType { found, expected } => write!(f, "number type {} can't be used for plural type {}", found, expected),
             Error::ExplicitDefaultInDefault(key_path) => write!(f, "Explicit defaults (null) are not allowed in default locale, at key {}", key_path),
             Error::RecursiveForeignKey { locale, key_path } => write!(f, "Borrow Error while linking foreign key at key {} in locale {:?}, check for recursive foreign key.", key_path, locale),
             Error::MissingForeignKey { foreign_key, locale, key_path } => write!(f, "Invalid foreign key {} at key {} in locale {:?}, key don't exist.", foreign_key, key_path, locale),
             Error::Custom(s) => f.write_str(s),
             Error::InvalidForeignKey { foreign_key, locale, key_path } => write!(f, "Invalid foreign key {} at key {} in locale {:?}, foreign key to plurals or subkeys are not allowed.", foreign_key, key_path, locale),
+            Error::VariableKindMissmatch { key_path, name } => write!(f, "Conflicting usage of variable {:?} at key {}, a variable must be used the same way in every locale: with the same kind of formatter (`{{{{ {}, number }}}}` and `{{{{ {}, currency(USD) }}}}` can be mixed, but not with `{{{{ {} }}}}`), as the variable of a \"$select\" in every locale, or in the attributes of a component in every locale.", name, key_path, name, name, name),
+            Error::ComponentAttrsMissmatch { key_path, name } => write!(f, "Conflicting usage of component <{}> at key {}, a component must have attributes in all of its tags or in none of them.", name, key_path),
+            Error::MissingSelectOther(name) => write!(f, "the select on variable {:?} require an \"other\" branch", name),
         }
     }
 }
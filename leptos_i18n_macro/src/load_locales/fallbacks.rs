@@ -0,0 +1,193 @@
+use std::{collections::HashMap, rc::Rc};
+
+use super::{
+    cfg_file::ConfigFile,
+    key::{Key, KeyPath},
+    locale::{Locale, LocalesOrNamespaces},
+    parsed_value::{ParsedValue, FOREIGN_KEYS},
+};
+
+/// Fill the keys missing in the locales with a fallback chain, or explicitly set to the default,
+/// with the value of the first locale of the chain declaring it.
+///
+/// The values are taken from the files of the fallbacks, not from their own chains,
+/// and the keys missing in all of the chain still fall back to the default locale.
+pub fn resolve_fallbacks(locales: &mut LocalesOrNamespaces, cfg_file: &ConfigFile) {
+    if cfg_file.fallbacks.is_empty() {
+        return;
+    }
+    match locales {
+        LocalesOrNamespaces::Locales(locales) => {
+            resolve_locales(locales, &cfg_file.fallbacks, None);
+        }
+        LocalesOrNamespaces::NameSpaces(namespaces) => {
+            for namespace in namespaces {
+                resolve_locales(
+                    &mut namespace.locales,
+                    &cfg_file.fallbacks,
+                    Some(&namespace.key),
+                );
+            }
+        }
+    }
+}
+
+fn resolve_locales(
+    locales: &mut [Locale],
+    fallbacks: &HashMap<Rc<Key>, Vec<Rc<Key>>>,
+    namespace: Option<&Rc<Key>>,
+) {
+    let Some((default_locale, _)) = locales.split_first() else {
+        return;
+    };
+    let find_locale = |name: &Rc<Key>| locales.iter().find(|locale| locale.name == *name);
+
+    let resolved = locales
+        .iter()
+        .enumerate()
+        .filter_map(|(i, locale)| {
+            let chain = fallbacks
+                .get(&locale.name)?
+                .iter()
+                .filter_map(find_locale)
+                .collect::<Vec<_>>();
+            let mut locale = locale.clone();
+            let mut key_path = KeyPath::new(namespace.cloned());
+            fill_missing_keys(&mut locale, default_locale, &chain, &mut key_path);
+            Some((i, locale))
+        })
+        .collect::<Vec<_>>();
+
+    for (i, locale) in resolved {
+        locales[i] = locale;
+    }
+}
+
+/// Return the subkeys `key` of the locales of the chain declaring them.
+fn chain_subkeys<'a>(chain: &[&'a Locale], key: &Key) -> Vec<&'a Locale> {
+    chain
+        .iter()
+        .filter_map(|locale| match locale.keys.get(key) {
+            Some(ParsedValue::Subkeys(Some(subkeys))) => Some(subkeys),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Fill the keys of the default locale missing in `locale` with the values of the chain.
+///
+/// Values of a different kind than in the default locale are kept, they are reported when merging the locales.
+fn fill_missing_keys(
+    locale: &mut Locale,
+    default_locale: &Locale,
+    chain: &[&Locale],
+    key_path: &mut KeyPath,
+) {
+    for (key, default_value) in &default_locale.keys {
+        key_path.push_key(Rc::clone(key));
+        match (locale.keys.get_mut(key), default_value) {
+            (
+                Some(ParsedValue::Subkeys(Some(subkeys))),
+                ParsedValue::Subkeys(Some(default_subkeys)),
+            ) => {
+                let chain = chain_subkeys(chain, key);
+                fill_missing_keys(subkeys, default_subkeys, &chain, key_path);
+            }
+            (None | Some(ParsedValue::Default), _) => {
+                let fallback = chain
+                    .iter()
+                    .find_map(|fallback| match fallback.keys.get(key) {
+                        None | Some(ParsedValue::Default) => None,
+                        Some(value) => Some((fallback, value)),
+                    });
+                if let Some((fallback, value)) = fallback {
+                    let value = match (value, default_value) {
+                        // rebuild the subkeys for this locale, they can be partially translated along the chain.
+                        (
+                            ParsedValue::Subkeys(Some(_)),
+                            ParsedValue::Subkeys(Some(default_subkeys)),
+                        ) => {
+                            let mut subkeys = Locale {
+                                top_locale_name: Rc::clone(&locale.top_locale_name),
+                                name: Rc::clone(key),
+                                keys: HashMap::new(),
+                                files: vec![],
+                            };
+                            let chain = chain_subkeys(chain, key);
+                            fill_missing_keys(&mut subkeys, default_subkeys, &chain, key_path);
+                            ParsedValue::Subkeys(Some(subkeys))
+                        }
+                        (value, _) => {
+                            copy_foreign_keys(
+                                &fallback.top_locale_name,
+                                &locale.top_locale_name,
+                                key_path,
+                            );
+                            value.clone()
+                        }
+                    };
+                    locale.keys.insert(Rc::clone(key), value);
+                }
+            }
+            _ => {}
+        }
+        key_path.pop_key();
+    }
+}
+
+/// The foreign keys of a copied value are resolved in the locale it is copied to.
+fn copy_foreign_keys(from: &Rc<Key>, to: &Rc<Key>, key_path: &KeyPath) {
+    FOREIGN_KEYS.with(|foreign_keys| {
+        let mut foreign_keys = foreign_keys.borrow_mut();
+        if foreign_keys.contains(&(Rc::clone(from), key_path.clone())) {
+            foreign_keys.insert((Rc::clone(to), key_path.clone()));
+        }
+    });
+}
+
+#[cfg(all(test, feature = "json_files"))]
+mod tests {
+    use super::*;
+    use crate::load_locales::locale::locale_from_json;
+
+    fn string_at<'a>(locale: &'a Locale, path: &[&str]) -> Option<&'a str> {
+        let path = path
+            .iter()
+            .map(|key| Rc::new(Key::new(key).unwrap()))
+            .collect::<Vec<_>>();
+        locale.get_value_at(&path)?.is_string()
+    }
+
+    #[test]
+    fn test_fallback_chain() {
+        let mut locales = [
+            locale_from_json(
+                "en",
+                r#"{ "hello": "Hello", "color": "color", "car": "car", "menu": { "open": "Open", "close": "Close" } }"#,
+            ),
+            locale_from_json(
+                "fr",
+                r#"{ "hello": "Bonjour", "car": "voiture", "menu": { "open": "Ouvrir", "close": "Fermer" } }"#,
+            ),
+            locale_from_json(
+                "fr-CA",
+                r#"{ "car": "char", "hello": null, "menu": { "close": "Fermer le menu" } }"#,
+            ),
+        ];
+        let fallbacks = HashMap::from([(
+            Rc::clone(&locales[2].name),
+            vec![Rc::clone(&locales[1].name)],
+        )]);
+        resolve_locales(&mut locales, &fallbacks, None);
+
+        let fr_ca = &locales[2];
+        assert_eq!(string_at(fr_ca, &["car"]), Some("char"));
+        assert_eq!(string_at(fr_ca, &["hello"]), Some("Bonjour"));
+        assert_eq!(string_at(fr_ca, &["menu", "open"]), Some("Ouvrir"));
+        assert_eq!(string_at(fr_ca, &["menu", "close"]), Some("Fermer le menu"));
+        // missing in all of the chain, left to the default locale.
+        assert_eq!(string_at(fr_ca, &["color"]), None);
+        // the fallback itself is untouched.
+        assert_eq!(string_at(&locales[1], &["color"]), None);
+    }
+}
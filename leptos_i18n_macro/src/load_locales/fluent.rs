@@ -0,0 +1,717 @@
+//! Parse a subset of the [Fluent](https://projectfluent.org/fluent/guide/) syntax (`.ftl` files) into the
+//! same structure as the JSON and YAML locale files, to deserialize it with the same seeds:
+//!
+//! - placeables of variables `{ $name }` become `{{ name }}`,
+//! - references to messages `{ message }` and terms `{ -term(arg: "value") }` become foreign keys `{{ @term, arg = 'value' }}`,
+//!   terms being loaded as regular keys,
+//! - a selector on `$count` becomes a plural, with its variants keyed by counts or plural categories,
+//!   the default variant being the fallback,
+//! - the attributes of a message become subkeys.
+//!
+//! Identifiers can contain `-`, they are converted to `_` to be valid keys.
+//! Functions, selectors on other variables and messages with both a value and attributes are not supported.
+
+use std::{collections::HashSet, fmt::Display, io::Read};
+
+use serde::de::{
+    value::{MapDeserializer, SeqDeserializer},
+    DeserializeSeed, IntoDeserializer, MapAccess, Visitor,
+};
+
+/// An error while parsing or deserializing a Fluent file.
+#[derive(Debug)]
+pub struct FluentError {
+    line: Option<usize>,
+    message: String,
+}
+
+impl FluentError {
+    fn new(line: usize, message: impl Display) -> Self {
+        FluentError {
+            line: Some(line),
+            message: message.to_string(),
+        }
+    }
+
+    fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+}
+
+impl Display for FluentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{} at line {}", self.message, line),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+impl std::error::Error for FluentError {}
+
+impl serde::de::Error for FluentError {
+    fn custom<T: Display>(msg: T) -> Self {
+        FluentError {
+            line: None,
+            message: msg.to_string(),
+        }
+    }
+}
+
+/// A value in the structure of the locale files.
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    String(String),
+    Seq(Vec<Value>),
+    Map(Vec<(String, Value)>),
+}
+
+/// The entries of a Fluent file, with the line they start at.
+#[derive(Debug, PartialEq)]
+pub struct Resource(Vec<(String, Value, usize)>);
+
+impl Resource {
+    pub fn from_reader(mut reader: impl Read) -> Result<Self, FluentError> {
+        let mut content = String::new();
+        reader
+            .read_to_string(&mut content)
+            .map_err(serde::de::Error::custom)?;
+        Self::parse(&content)
+    }
+
+    fn parse(content: &str) -> Result<Self, FluentError> {
+        let lines = content.lines().collect::<Vec<_>>();
+        let mut entries = vec![];
+        let mut names = HashSet::new();
+        let mut i = 0;
+        while i < lines.len() {
+            let line = lines[i];
+            if line.trim().is_empty() || line.starts_with('#') {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            let (name, first_line) = parse_entry_start(line)
+                .ok_or_else(|| FluentError::new(start + 1, "expected a message or a term"))?;
+            i += 1;
+            // continuation lines are indented, a closing brace can stay on the first column.
+            while i < lines.len()
+                && (lines[i].trim().is_empty() || lines[i].starts_with([' ', '\t', '}']))
+            {
+                i += 1;
+            }
+            let mut block = vec![first_line];
+            block.extend(&lines[start + 1..i]);
+            while block.len() > 1 && block.last().is_some_and(|line| line.trim().is_empty()) {
+                block.pop();
+            }
+
+            if !names.insert(name.clone()) {
+                return Err(FluentError::new(
+                    start + 1,
+                    format_args!("duplicate message {:?}", name),
+                ));
+            }
+            let value = parse_entry(&block, start + 1)?;
+            entries.push((name, value, start + 1));
+        }
+        Ok(Resource(entries))
+    }
+}
+
+/// Return the key name of the message or term (`-term`) declared on this line and the rest of the line.
+fn parse_entry_start(line: &str) -> Option<(String, &str)> {
+    let (name, rest) = line.split_once('=')?;
+    let name = name.trim_end();
+    let name = name.strip_prefix('-').unwrap_or(name);
+    is_identifier(name).then(|| (to_key(name), rest))
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'))
+}
+
+fn to_key(name: &str) -> String {
+    name.replace('-', "_")
+}
+
+/// Parse the value and the attributes of an entry, `block` being the rest of its first line and its continuation lines.
+fn parse_entry(block: &[&str], line: usize) -> Result<Value, FluentError> {
+    let mut value_lines = vec![];
+    let mut attributes: Vec<(String, Vec<&str>, usize)> = vec![];
+    for (i, text) in block.iter().enumerate() {
+        let attribute = (i > 0)
+            .then(|| text.trim_start().strip_prefix('.'))
+            .flatten()
+            .and_then(parse_entry_start);
+        match (attribute, attributes.last_mut()) {
+            (Some((name, rest)), _) => attributes.push((name, vec![rest], line + i)),
+            (None, Some((_, lines, _))) => lines.push(text),
+            (None, None) => value_lines.push(*text),
+        }
+    }
+    let has_value = value_lines.iter().any(|line| !line.trim().is_empty());
+
+    match (has_value, attributes.is_empty()) {
+        (true, true) => parse_pattern(&value_lines, line),
+        (false, false) => attributes
+            .into_iter()
+            .map(|(name, lines, line)| Ok((name, parse_pattern(&lines, line)?)))
+            .collect::<Result<_, _>>()
+            .map(Value::Map),
+        (true, false) => Err(FluentError::new(
+            line,
+            "messages with both a value and attributes are not supported",
+        )),
+        (false, true) => Err(FluentError::new(line, "expected a value")),
+    }
+}
+
+/// Join the lines of a pattern, removing their common indentation.
+fn dedent(lines: &[&str]) -> String {
+    let indent = lines[1..]
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    let mut pattern = lines[0].trim().to_owned();
+    for line in &lines[1..] {
+        if !pattern.is_empty() {
+            pattern.push('\n');
+        }
+        pattern.push_str(line.get(indent..).unwrap_or_else(|| line.trim_start()));
+    }
+    pattern.trim_end().to_owned()
+}
+
+fn parse_pattern(lines: &[&str], line: usize) -> Result<Value, FluentError> {
+    let source = dedent(lines);
+    // a pattern starting on the next line is not preceded by a line break.
+    let line = if lines[0].trim().is_empty() {
+        line + 1
+    } else {
+        line
+    };
+    let mut parser = Parser {
+        source: &source,
+        pos: 0,
+        line,
+    };
+    let (before, selector) = parser.pattern(false)?;
+    let Some((variants, after)) = selector else {
+        return Ok(Value::String(before));
+    };
+
+    let is_float = variants
+        .iter()
+        .filter_map(|variant| variant.key.as_deref())
+        .any(|key| key.replace("..", "").contains('.'));
+    let mut plurals = is_float
+        .then(|| Value::String("f64".to_owned()))
+        .into_iter()
+        .collect::<Vec<_>>();
+    let mut default = None;
+    for variant in variants {
+        let value = Value::String(format!("{}{}{}", before, variant.value, after));
+        match variant.key {
+            Some(key) => plurals.push(Value::Seq(vec![value, Value::String(key)])),
+            None => default = Some(Value::Seq(vec![value, Value::String("_".to_owned())])),
+        }
+    }
+    plurals.extend(default);
+    Ok(Value::Seq(plurals))
+}
+
+struct Variant {
+    /// `None` for the default variant.
+    key: Option<String>,
+    value: String,
+}
+
+type Selector = (Vec<Variant>, String);
+
+struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+    line: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: impl Display) -> FluentError {
+        let line = self.line + self.source[..self.pos].matches('\n').count();
+        FluentError::new(line, message)
+    }
+
+    fn rest(&self) -> &str {
+        &self.source[self.pos..]
+    }
+
+    fn skip_whitespaces(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn eat(&mut self, s: &str) -> bool {
+        let found = self.rest().starts_with(s);
+        if found {
+            self.pos += s.len();
+        }
+        found
+    }
+
+    fn expect(&mut self, s: &str) -> Result<(), FluentError> {
+        self.skip_whitespaces();
+        if self.eat(s) {
+            Ok(())
+        } else {
+            Err(self.error(format_args!("expected {:?}", s)))
+        }
+    }
+
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &str {
+        let rest = &self.source[self.pos..];
+        let len = rest.find(|c| !f(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    fn identifier(&mut self) -> Result<String, FluentError> {
+        let name = self.take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'));
+        if is_identifier(name) {
+            Ok(to_key(name))
+        } else {
+            Err(self.error("expected an identifier"))
+        }
+    }
+
+    /// Whether the next line starts a variant or closes the selector.
+    fn at_variant_end(&self) -> bool {
+        let rest = self.rest();
+        let Some(next_line) = rest.strip_prefix('\n') else {
+            return rest.is_empty();
+        };
+        let next_line = next_line.trim_start();
+        next_line.starts_with(['[', '*', '}'])
+    }
+
+    /// Parse a pattern until its end, or until the end of the variant.
+    fn pattern(&mut self, in_variant: bool) -> Result<(String, Option<Selector>), FluentError> {
+        let mut text = String::new();
+        let mut selector: Option<(Vec<Variant>, usize)> = None;
+        loop {
+            if in_variant && self.at_variant_end() {
+                break;
+            }
+            let Some(c) = self.rest().chars().next() else {
+                break;
+            };
+            match c {
+                '{' => {
+                    self.pos += 1;
+                    if let Some(variants) = self.placeable(&mut text, in_variant)? {
+                        if selector.is_some() {
+                            return Err(self.error("only one selector is supported by message"));
+                        }
+                        selector = Some((variants, text.len()));
+                    }
+                }
+                '}' => return Err(self.error("unbalanced closing brace")),
+                // variants values are dedented line by line.
+                '\n' if in_variant => {
+                    text.push('\n');
+                    self.pos += 1;
+                    self.skip_whitespaces();
+                }
+                c => {
+                    text.push(c);
+                    self.pos += c.len_utf8();
+                }
+            }
+        }
+        Ok(match selector {
+            Some((variants, at)) => {
+                let after = text.split_off(at);
+                (text, Some((variants, after)))
+            }
+            None => (text, None),
+        })
+    }
+
+    /// Parse a placeable after its opening brace, writing it in `text` or returning the variants if it is a selector.
+    fn placeable(
+        &mut self,
+        text: &mut String,
+        in_variant: bool,
+    ) -> Result<Option<Vec<Variant>>, FluentError> {
+        self.skip_whitespaces();
+        let variable = self.rest().starts_with('$');
+        let expression = self.inline_expression()?;
+        self.skip_whitespaces();
+        if !self.eat("->") {
+            text.push_str(&expression);
+            self.expect("}")?;
+            return Ok(None);
+        }
+        if in_variant {
+            return Err(self.error("nested selectors are not supported"));
+        }
+        if !variable || expression != "{{ count }}" {
+            return Err(self.error("selectors are only supported on the $count variable"));
+        }
+        let mut variants = vec![];
+        loop {
+            self.skip_whitespaces();
+            if self.eat("}") {
+                break;
+            }
+            let is_default = self.eat("*");
+            self.expect("[")?;
+            self.skip_whitespaces();
+            let key = self
+                .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '=' | '_'))
+                .to_owned();
+            self.expect("]")?;
+            self.skip_whitespaces_in_line();
+            let (value, _) = self.pattern(true)?;
+            variants.push(Variant {
+                key: (!is_default).then_some(key),
+                value: value.trim_end().to_owned(),
+            });
+        }
+        match variants
+            .iter()
+            .filter(|variant| variant.key.is_none())
+            .count()
+        {
+            1 => Ok(Some(variants)),
+            _ => Err(self.error("a selector must have exactly one default variant")),
+        }
+    }
+
+    fn skip_whitespaces_in_line(&mut self) {
+        self.take_while(|c| c == ' ' || c == '\t');
+    }
+
+    /// Parse an inline expression, returning its equivalent in the locale files syntax.
+    fn inline_expression(&mut self) -> Result<String, FluentError> {
+        let Some(c) = self.rest().chars().next() else {
+            return Err(self.error("expected an expression"));
+        };
+        match c {
+            '"' => {
+                self.pos += 1;
+                self.string_literal()
+            }
+            '$' => {
+                self.pos += 1;
+                Ok(format!("{{{{ {} }}}}", self.identifier()?))
+            }
+            '{' => {
+                self.pos += 1;
+                self.skip_whitespaces();
+                let expression = self.inline_expression()?;
+                self.expect("}")?;
+                Ok(expression)
+            }
+            '-' if self.rest()[1..].starts_with(|c: char| c.is_ascii_alphabetic()) => {
+                self.pos += 1;
+                let mut foreign_key = format!("{{{{ @{}", self.reference()?);
+                if self.eat("(") {
+                    for (name, value) in self.term_arguments()? {
+                        foreign_key.push_str(&format!(", {} = '{}'", name, value));
+                    }
+                }
+                foreign_key.push_str(" }}");
+                Ok(foreign_key)
+            }
+            '-' | '0'..='9' => Ok(self
+                .take_while(|c| c.is_ascii_digit() || matches!(c, '-' | '.'))
+                .to_owned()),
+            _ => {
+                let reference = self.reference()?;
+                if self.rest().starts_with('(') {
+                    return Err(self.error("functions are not supported"));
+                }
+                Ok(format!("{{{{ @{} }}}}", reference))
+            }
+        }
+    }
+
+    /// Parse a message or term reference, with its optional attribute.
+    fn reference(&mut self) -> Result<String, FluentError> {
+        let mut reference = self.identifier()?;
+        if self.eat(".") {
+            reference.push('.');
+            reference.push_str(&self.identifier()?);
+        }
+        Ok(reference)
+    }
+
+    /// Parse the named arguments of a term reference after the opening parenthesis.
+    fn term_arguments(&mut self) -> Result<Vec<(String, String)>, FluentError> {
+        let mut arguments = vec![];
+        loop {
+            self.skip_whitespaces();
+            if self.eat(")") {
+                return Ok(arguments);
+            }
+            let name = self.identifier()?;
+            self.expect(":")?;
+            self.skip_whitespaces();
+            if !self.eat("\"") {
+                return Err(self.error("only string literals are supported as term arguments"));
+            }
+            arguments.push((name, self.string_literal()?));
+            self.skip_whitespaces();
+            self.eat(",");
+        }
+    }
+
+    /// Parse a string literal after its opening quote.
+    fn string_literal(&mut self) -> Result<String, FluentError> {
+        let mut value = String::new();
+        let mut chars = self.rest().char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.pos += i + 1;
+                    return Ok(value);
+                }
+                '\\' => match chars.next() {
+                    Some((_, c @ ('"' | '\\'))) => value.push(c),
+                    Some((_, 'u')) => value.push(self.unicode_escape(&mut chars, 4)?),
+                    Some((_, 'U')) => value.push(self.unicode_escape(&mut chars, 6)?),
+                    _ => return Err(self.error("invalid escape sequence")),
+                },
+                '\n' => break,
+                c => value.push(c),
+            }
+        }
+        Err(self.error("unterminated string literal"))
+    }
+
+    fn unicode_escape(
+        &self,
+        chars: &mut std::str::CharIndices,
+        len: usize,
+    ) -> Result<char, FluentError> {
+        let digits = chars.take(len).map(|(_, c)| c).collect::<String>();
+        u32::from_str_radix(&digits, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| self.error("invalid unicode escape sequence"))
+    }
+}
+
+// -----------------------------------------
+// Deserialization
+// -----------------------------------------
+
+impl<'de> serde::Deserializer<'de> for Value {
+    type Error = FluentError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Value::String(s) => visitor.visit_string(s),
+            Value::Seq(values) => visitor.visit_seq(SeqDeserializer::new(values.into_iter())),
+            Value::Map(entries) => visitor.visit_map(MapDeserializer::new(entries.into_iter())),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, FluentError> for Value {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+/// Give the errors the line of the entry being deserialized.
+struct Entries {
+    entries: std::vec::IntoIter<(String, Value, usize)>,
+    value: Option<(Value, usize)>,
+}
+
+impl<'de> MapAccess<'de> for Entries {
+    type Error = FluentError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        let Some((key, value, line)) = self.entries.next() else {
+            return Ok(None);
+        };
+        self.value = Some((value, line));
+        seed.deserialize(key.into_deserializer())
+            .map(Some)
+            .map_err(|err: FluentError| err.at_line(line))
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let Some((value, line)) = self.value.take() else {
+            return Err(serde::de::Error::custom("value is missing"));
+        };
+        seed.deserialize(value).map_err(|err| err.at_line(line))
+    }
+}
+
+impl<'de> serde::Deserializer<'de> for Resource {
+    type Error = FluentError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_map(Entries {
+            entries: self.0.into_iter(),
+            value: None,
+        })
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string(s: &str) -> Value {
+        Value::String(s.to_owned())
+    }
+
+    fn parse(content: &str) -> Vec<(String, Value)> {
+        Resource::parse(content)
+            .unwrap()
+            .0
+            .into_iter()
+            .map(|(name, value, _)| (name, value))
+            .collect()
+    }
+
+    #[test]
+    fn test_messages() {
+        let entries = parse(
+            r#"
+# a comment
+hello = Hello { $user-name }!
+-brand-name = { "{" }Leptos{ "}" }
+about = About { -brand-name(case: "genitive") } and { hello }
+multiline =
+    first line
+      second line
+login =
+    .title = Log in
+    .placeholder = email
+"#,
+        );
+        assert_eq!(
+            entries,
+            vec![
+                ("hello".to_owned(), string("Hello {{ user_name }}!")),
+                ("brand_name".to_owned(), string("{Leptos}")),
+                (
+                    "about".to_owned(),
+                    string("About {{ @brand_name, case = 'genitive' }} and {{ @hello }}")
+                ),
+                ("multiline".to_owned(), string("first line\n  second line")),
+                (
+                    "login".to_owned(),
+                    Value::Map(vec![
+                        ("title".to_owned(), string("Log in")),
+                        ("placeholder".to_owned(), string("email")),
+                    ])
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_selector() {
+        let entries = parse(
+            r#"
+emails = You have { $count ->
+        [0] no emails
+        [one] one email
+       *[other] { $count } emails
+    }.
+ranges = { $count ->
+    [0.5] half
+    [1..=5] few
+   *[other] many
+}
+"#,
+        );
+        let variant = |value: &str, count: &str| Value::Seq(vec![string(value), string(count)]);
+        assert_eq!(
+            entries,
+            vec![
+                (
+                    "emails".to_owned(),
+                    Value::Seq(vec![
+                        variant("You have no emails.", "0"),
+                        variant("You have one email.", "one"),
+                        variant("You have {{ count }} emails.", "_"),
+                    ])
+                ),
+                (
+                    "ranges".to_owned(),
+                    Value::Seq(vec![
+                        string("f64"),
+                        variant("half", "0.5"),
+                        variant("few", "1..=5"),
+                        variant("many", "_"),
+                    ])
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |content: &str| Resource::parse(content).unwrap_err().to_string();
+        assert_eq!(
+            error("a = { NUMBER($count) }"),
+            "functions are not supported at line 1"
+        );
+        assert_eq!(
+            error("a = b\n\nb = { $gender ->\n *[other] x\n}"),
+            "selectors are only supported on the $count variable at line 3"
+        );
+        assert_eq!(error("a = b\na = c"), "duplicate message \"a\" at line 2");
+        assert_eq!(
+            error("a =\n    b\n    { c }}"),
+            "unbalanced closing brace at line 3"
+        );
+        assert_eq!(
+            error("a = b\n  .title = c"),
+            "messages with both a value and attributes are not supported at line 1"
+        );
+        assert_eq!(
+            error("not a message"),
+            "expected a message or a term at line 1"
+        );
+    }
+}
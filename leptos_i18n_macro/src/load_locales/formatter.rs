@@ -0,0 +1,177 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+/// The formatter of a variable, `{{ price, number }}`, `{{ price, currency(USD) }}` or `{{ date, datetime(short) }}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Formatter {
+    Number,
+    Currency(String),
+    Date(DateLength),
+    Time,
+    DateTime(DateLength),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateLength {
+    Short,
+    Long,
+}
+
+/// The kind of value a formatter takes, formatters of the same kind can be used for the same variable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FormatterKind {
+    Number,
+    DateTime,
+}
+
+impl Formatter {
+    /// Parse the part of a placeholder after the comma, `number` or `currency(USD)`.
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        match s {
+            "number" => return Some(Formatter::Number),
+            "date" => return Some(Formatter::Date(DateLength::Short)),
+            "time" => return Some(Formatter::Time),
+            "datetime" => return Some(Formatter::DateTime(DateLength::Short)),
+            _ => {}
+        }
+        let (name, arg) = s.split_once('(')?;
+        let arg = arg.strip_suffix(')')?.trim();
+        let length = match arg {
+            "short" => Some(DateLength::Short),
+            "long" => Some(DateLength::Long),
+            _ => None,
+        };
+        match (name.trim(), length) {
+            ("date", Some(length)) => return Some(Formatter::Date(length)),
+            ("datetime", Some(length)) => return Some(Formatter::DateTime(length)),
+            _ => {}
+        }
+        let code = s.strip_prefix("currency")?.trim_start();
+        let code = code.strip_prefix('(')?.strip_suffix(')')?.trim();
+        let is_code = code.len() == 3 && code.bytes().all(|b| b.is_ascii_alphabetic());
+        is_code.then(|| Formatter::Currency(code.to_ascii_uppercase()))
+    }
+
+    pub fn kind(&self) -> FormatterKind {
+        match self {
+            Formatter::Number | Formatter::Currency(_) => FormatterKind::Number,
+            Formatter::Date(_) | Formatter::Time | Formatter::DateTime(_) => {
+                FormatterKind::DateTime
+            }
+        }
+    }
+
+    /// Render the formatter back as written in the locales files.
+    pub fn source(&self) -> String {
+        match self {
+            Formatter::Number => "number".to_owned(),
+            Formatter::Currency(code) => format!("currency({})", code),
+            Formatter::Date(length) => format!("date({})", length.name()),
+            Formatter::Time => "time".to_owned(),
+            Formatter::DateTime(length) => format!("datetime({})", length.name()),
+        }
+    }
+
+    /// The expression formatting `value` for the given locale.
+    pub fn format(&self, value: TokenStream, locale: &str) -> TokenStream {
+        match self {
+            Formatter::Number => {
+                quote!(leptos_i18n::format::FormattedNumber::new(#value, #locale))
+            }
+            Formatter::Currency(code) => {
+                quote!(leptos_i18n::format::FormattedCurrency::new(#value, #code, #locale))
+            }
+            Formatter::Date(length) => Self::format_date_time(value, quote!(Date(#length)), locale),
+            Formatter::Time => Self::format_date_time(value, quote!(Time), locale),
+            Formatter::DateTime(length) => {
+                Self::format_date_time(value, quote!(DateTime(#length)), locale)
+            }
+        }
+    }
+
+    fn format_date_time(value: TokenStream, format: TokenStream, locale: &str) -> TokenStream {
+        quote!(leptos_i18n::format::FormattedDateTime::new(
+            #value,
+            leptos_i18n::format::DateTimeFormat::#format,
+            #locale
+        ))
+    }
+}
+
+impl DateLength {
+    fn name(self) -> &'static str {
+        match self {
+            DateLength::Short => "short",
+            DateLength::Long => "long",
+        }
+    }
+}
+
+impl ToTokens for DateLength {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let length = match self {
+            DateLength::Short => quote!(Short),
+            DateLength::Long => quote!(Long),
+        };
+        tokens.extend(quote!(leptos_i18n::format::DateLength::#length))
+    }
+}
+
+impl FormatterKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            FormatterKind::Number => "number",
+            FormatterKind::DateTime => "datetime",
+        }
+    }
+
+    /// The bound of the value given to the builder.
+    pub fn get_generic(self) -> TokenStream {
+        match self {
+            FormatterKind::Number => quote!(leptos_i18n::format::NumberFn),
+            FormatterKind::DateTime => quote!(leptos_i18n::format::DateTimeFn),
+        }
+    }
+
+    /// The bound of the value given to the builder when formatting to a string.
+    #[cfg(feature = "interpolate_display")]
+    pub fn get_string_generic(self) -> TokenStream {
+        match self {
+            FormatterKind::Number => quote!(leptos_i18n::format::NumberValue),
+            FormatterKind::DateTime => quote!(leptos_i18n::format::DateTimeValue),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_formatters() {
+        assert_eq!(Formatter::parse(" number "), Some(Formatter::Number));
+        assert_eq!(
+            Formatter::parse("currency(usd)"),
+            Some(Formatter::Currency("USD".to_owned()))
+        );
+        assert_eq!(
+            Formatter::parse("currency ( EUR )"),
+            Some(Formatter::Currency("EUR".to_owned()))
+        );
+        assert_eq!(Formatter::parse("currency"), None);
+        assert_eq!(Formatter::parse("currency(dollars)"), None);
+        assert_eq!(Formatter::parse("nubmer"), None);
+        assert_eq!(
+            Formatter::parse("datetime(short)"),
+            Some(Formatter::DateTime(DateLength::Short))
+        );
+        assert_eq!(
+            Formatter::parse("date ( long )"),
+            Some(Formatter::Date(DateLength::Long))
+        );
+        assert_eq!(Formatter::parse("time"), Some(Formatter::Time));
+        assert_eq!(Formatter::parse("time(long)"), None);
+        assert_eq!(Formatter::parse("date(medium)"), None);
+    }
+}
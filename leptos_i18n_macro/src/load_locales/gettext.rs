@@ -0,0 +1,182 @@
+use std::rc::Rc;
+
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+use super::{
+    key::Key,
+    locale::{Locale, LocalesOrNamespaces},
+    parsed_value::ParsedValue,
+};
+
+/// A source string of the default locale, with the path of its key.
+struct Source<T> {
+    source: T,
+    path: Vec<Rc<Key>>,
+}
+
+impl<T> Source<T> {
+    fn path_str(&self) -> String {
+        let names = self.path.iter().map(|key| key.name.as_str());
+        names.collect::<Vec<_>>().join(".")
+    }
+
+    fn keys(&self) -> TokenStream {
+        let idents = self.path.iter().map(|key| &key.ident);
+        quote!(#(#idents).*)
+    }
+}
+
+#[derive(Default)]
+struct Sources {
+    gettext: Vec<Source<String>>,
+    ngettext: Vec<Source<(String, String)>>,
+}
+
+/// Write the value as it is written in the locale file, `None` if it can't be a gettext source string.
+fn write_source(value: &ParsedValue, source: &mut String) -> Option<()> {
+    match value {
+        ParsedValue::String(s) => source.push_str(s),
+        ParsedValue::Variable(key) => {
+            let name = key.name.strip_prefix("var_").unwrap_or(&key.name);
+            source.push_str(&format!("{{{{ {} }}}}", name));
+        }
+        ParsedValue::FormattedCount(_) => source.push_str("{{ count }}"),
+        ParsedValue::Component { key, attrs, inner } => {
+            let name = key.name.strip_prefix("comp_").unwrap_or(&key.name);
+            let attrs = ParsedValue::attrs_source(attrs);
+            if inner.is_string() == Some("") {
+                source.push_str(&format!("<{}{}/>", name, attrs));
+            } else {
+                source.push_str(&format!("<{}{}>", name, attrs));
+                write_source(inner, source)?;
+                source.push_str(&format!("</{}>", name));
+            }
+        }
+        ParsedValue::Bloc(values) => {
+            for value in values {
+                write_source(value, source)?;
+            }
+        }
+        _ => return None,
+    }
+    Some(())
+}
+
+fn source_string(value: &ParsedValue) -> Option<String> {
+    let mut source = String::new();
+    write_source(value, &mut source)?;
+    // `gettext("")` returns the header of the catalog, don't map it to a key.
+    (!source.is_empty()).then_some(source)
+}
+
+/// Sort the sources and only keep the first key of each source string.
+fn dedup_sources<T: Ord + Clone>(sources: &mut Vec<Source<T>>) {
+    sources.sort_by_cached_key(|source| (source.source.clone(), source.path_str()));
+    sources.dedup_by(|a, b| a.source == b.source);
+}
+
+impl Sources {
+    fn collect_locale(&mut self, locale: &Locale, path: &mut Vec<Rc<Key>>) {
+        for (key, value) in &locale.keys {
+            path.push(Rc::clone(key));
+            self.collect_value(value, path);
+            path.pop();
+        }
+    }
+
+    fn collect_value(&mut self, value: &ParsedValue, path: &mut Vec<Rc<Key>>) {
+        match value {
+            ParsedValue::Subkeys(Some(subkeys)) => self.collect_locale(subkeys, path),
+            ParsedValue::Plural(plurals) => {
+                let forms = plurals.gettext_forms().and_then(|(singular, plural)| {
+                    Some((source_string(singular)?, source_string(plural)?))
+                });
+                if let Some(source) = forms {
+                    self.ngettext.push(Source {
+                        source,
+                        path: path.clone(),
+                    });
+                }
+            }
+            value => {
+                if let Some(source) = source_string(value) {
+                    self.gettext.push(Source {
+                        source,
+                        path: path.clone(),
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Create the `gettext!` and `ngettext!` macros, resolving the source strings of the default locale to the `t!` macro of their key.
+pub fn create_gettext_macros(
+    locales: &LocalesOrNamespaces,
+    default_locale: &Rc<Key>,
+) -> TokenStream {
+    fn find_default<'a>(locales: &'a [Locale], default_locale: &Key) -> Option<&'a Locale> {
+        locales
+            .iter()
+            .find(|locale| *locale.name == *default_locale)
+    }
+    let mut sources = Sources::default();
+    match locales {
+        LocalesOrNamespaces::Locales(locales) => {
+            if let Some(locale) = find_default(locales, default_locale) {
+                sources.collect_locale(locale, &mut vec![]);
+            }
+        }
+        LocalesOrNamespaces::NameSpaces(namespaces) => {
+            for namespace in namespaces {
+                if let Some(locale) = find_default(&namespace.locales, default_locale) {
+                    sources.collect_locale(locale, &mut vec![Rc::clone(&namespace.key)]);
+                }
+            }
+        }
+    }
+    dedup_sources(&mut sources.gettext);
+    dedup_sources(&mut sources.ngettext);
+
+    let gettext_arms = sources.gettext.iter().map(|source| {
+        let keys = source.keys();
+        let source = Literal::string(&source.source);
+        quote! {
+            ($ctx:expr, #source $(, $($args:tt)*)?) => {
+                leptos_i18n::t!($ctx, #keys $(, $($args)*)?)
+            };
+        }
+    });
+    let ngettext_arms = sources.ngettext.iter().map(|source| {
+        let keys = source.keys();
+        let singular = Literal::string(&source.source.0);
+        let plural = Literal::string(&source.source.1);
+        quote! {
+            ($ctx:expr, #singular, #plural, $count:expr $(, $($args:tt)*)?) => {
+                leptos_i18n::t!($ctx, #keys, count = $count $(, $($args)*)?)
+            };
+        }
+    });
+
+    quote! {
+        /// Render the key of the default locale whose value is the given source string, like gettext's `gettext`.
+        macro_rules! gettext {
+            #(#gettext_arms)*
+            ($ctx:expr, $source:literal $($rest:tt)*) => {
+                compile_error!(concat!("no key of the default locale has the value ", stringify!($source)))
+            };
+        }
+
+        /// Render the plural of the default locale whose values for a count of `1` and for the fallback
+        /// are the given source strings, like gettext's `ngettext`.
+        macro_rules! ngettext {
+            #(#ngettext_arms)*
+            ($ctx:expr, $singular:literal, $plural:literal $($rest:tt)*) => {
+                compile_error!(concat!("no plural of the default locale has the forms ", stringify!($singular), " and ", stringify!($plural)))
+            };
+        }
+
+        pub(crate) use {gettext, ngettext};
+    }
+}
@@ -0,0 +1,97 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use super::{
+    key::{Key, KeyPath},
+    locale::{Locale, LocalesOrNamespaces},
+    obfuscate,
+};
+
+/// The type of the string keys, a `HotStr` when the `hot_reload` feature is enabled.
+pub fn str_type() -> TokenStream {
+    if cfg!(feature = "hot_reload") {
+        quote!(leptos_i18n::hot_reload::HotStr)
+    } else {
+        obfuscate::str_type()
+    }
+}
+
+/// The tokens of a string key, a `HotStr` reading the value of the key at `key_path` in the locale files
+/// when the `hot_reload` feature is enabled.
+pub fn str_value(s: &str, key_path: &KeyPath, key: &Key) -> TokenStream {
+    if !cfg!(feature = "hot_reload") {
+        return obfuscate::str_value(s);
+    }
+    let files_method = files_method_ident(key_path.namespace.as_deref());
+    let path = key_path.path.iter().map(|key| &key.name);
+    let key = &key.name;
+    quote!(leptos_i18n::hot_reload::HotStr::new(
+        #s,
+        _locale.#files_method(),
+        &[#(#path,)* #key]
+    ))
+}
+
+/// The generated methods are called in the `const fn new` of the keys types, so one method is generated per namespace
+/// instead of matching on the name of the namespace.
+fn files_method_ident(namespace: Option<&Key>) -> syn::Ident {
+    match namespace {
+        Some(namespace) => format_ident!("__hot_reload_files_{}", namespace.ident),
+        None => format_ident!("__hot_reload_files"),
+    }
+}
+
+fn locale_files(default_locale: &Locale, locale: &Locale) -> Vec<String> {
+    // the keys missing in a locale fallback to the default locale, so its files are read first.
+    let files = if std::ptr::eq(default_locale, locale) {
+        &default_locale.files[..0]
+    } else {
+        &default_locale.files[..]
+    };
+    files
+        .iter()
+        .chain(&locale.files)
+        .map(|path| path.to_string_lossy().into_owned())
+        .collect()
+}
+
+fn files_method(locales: &[Locale], namespace: Option<&Key>) -> TokenStream {
+    let method_ident = files_method_ident(namespace);
+    let match_arms = locales.first().into_iter().flat_map(|default_locale| {
+        locales.iter().map(move |locale| {
+            let locale_key = &locale.top_locale_name;
+            let files = locale_files(default_locale, locale);
+            quote!(Locale::#locale_key => &[#(#files,)*])
+        })
+    });
+    quote! {
+        #[doc(hidden)]
+        #[allow(unreachable_patterns)]
+        pub const fn #method_ident(self) -> &'static [&'static str] {
+            match self {
+                #(#match_arms,)*
+                _ => &[]
+            }
+        }
+    }
+}
+
+/// Generate the `Locale::__hot_reload_files` methods, returning the files to read the keys of a locale from,
+/// nothing when the `hot_reload` feature is disabled.
+pub fn create_files_method(locales: &LocalesOrNamespaces) -> Option<TokenStream> {
+    if !cfg!(feature = "hot_reload") {
+        return None;
+    }
+    let methods = match locales {
+        LocalesOrNamespaces::Locales(locales) => vec![files_method(locales, None)],
+        LocalesOrNamespaces::NameSpaces(namespaces) => namespaces
+            .iter()
+            .map(|namespace| files_method(&namespace.locales, Some(&namespace.key)))
+            .collect(),
+    };
+    Some(quote! {
+        impl Locale {
+            #(#methods)*
+        }
+    })
+}
@@ -0,0 +1,133 @@
+use syn::{
+    braced, bracketed,
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    token, Ident, Lit, LitStr, Token,
+};
+use toml::{Table, Value};
+
+/// The configuration given to the macro as `config { default: "en", locales: ["en", "fr"] }`,
+/// instead of `[package.metadata.leptos-i18n]`.
+///
+/// The top level keys are the ones of the manifest, with `_` in place of `-` (`locales_dir`),
+/// `path` being an alias of `locales_dir`. The nested keys, like locale names, can be string literals.
+pub struct InlineConfig(pub Table);
+
+impl Parse for InlineConfig {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident = input.call(Ident::parse_any)?;
+        if ident != "config" {
+            return Err(syn::Error::new(
+                ident.span(),
+                "expected `config { .. }` or nothing",
+            ));
+        }
+        let content;
+        braced!(content in input);
+        let table = parse_table(&content, true)?;
+        if !input.is_empty() {
+            return Err(input.error("unexpected tokens after the configuration"));
+        }
+        Ok(InlineConfig(table))
+    }
+}
+
+fn parse_key(input: ParseStream, top_level: bool) -> syn::Result<String> {
+    if input.peek(LitStr) {
+        return Ok(input.parse::<LitStr>()?.value());
+    }
+    let key = input.call(Ident::parse_any)?.to_string();
+    if !top_level {
+        Ok(key)
+    } else if key == "path" {
+        Ok("locales-dir".to_owned())
+    } else {
+        Ok(key.replace('_', "-"))
+    }
+}
+
+fn parse_table(input: ParseStream, top_level: bool) -> syn::Result<Table> {
+    let mut table = Table::new();
+    while !input.is_empty() {
+        let span = input.span();
+        let key = parse_key(input, top_level)?;
+        input.parse::<Token![:]>()?;
+        let value = parse_value(input)?;
+        if table.insert(key.clone(), value).is_some() {
+            return Err(syn::Error::new(span, format!("duplicate key {:?}", key)));
+        }
+        if input.is_empty() {
+            break;
+        }
+        input.parse::<Token![,]>()?;
+    }
+    Ok(table)
+}
+
+fn parse_value(input: ParseStream) -> syn::Result<Value> {
+    if input.peek(token::Bracket) {
+        let content;
+        bracketed!(content in input);
+        let values = content.parse_terminated(parse_value, Token![,])?;
+        return Ok(Value::Array(values.into_iter().collect()));
+    }
+    if input.peek(token::Brace) {
+        let content;
+        braced!(content in input);
+        return parse_table(&content, false).map(Value::Table);
+    }
+    if input.peek(Token![-]) {
+        input.parse::<Token![-]>()?;
+        return match input.parse::<Lit>()? {
+            Lit::Int(i) => format!("-{}", i.base10_digits())
+                .parse()
+                .map(Value::Integer)
+                .map_err(|err| syn::Error::new(i.span(), err)),
+            Lit::Float(f) => f.base10_parse::<f64>().map(|f| Value::Float(-f)),
+            lit => Err(syn::Error::new(lit.span(), "expected a number after `-`")),
+        };
+    }
+    match input.parse::<Lit>()? {
+        Lit::Str(s) => Ok(Value::String(s.value())),
+        Lit::Bool(b) => Ok(Value::Boolean(b.value)),
+        Lit::Int(i) => i.base10_parse().map(Value::Integer),
+        Lit::Float(f) => f.base10_parse().map(Value::Float),
+        lit => Err(syn::Error::new(
+            lit.span(),
+            "expected a string, a boolean, a number, an array or a table",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let InlineConfig(table) = syn::parse_str(
+            r#"config {
+                default: "en",
+                locales: ["en", "fr", "fr-CA"],
+                path: "./i18n",
+                rollout: { "fr-CA": 50 },
+                gettext: true,
+                plural_constants: { below: -1 },
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(table["default"].as_str(), Some("en"));
+        assert_eq!(table["locales"].as_array().map(Vec::len), Some(3));
+        assert_eq!(table["locales-dir"].as_str(), Some("./i18n"));
+        assert_eq!(table["rollout"]["fr-CA"].as_integer(), Some(50));
+        assert_eq!(table["gettext"].as_bool(), Some(true));
+        assert_eq!(table["plural-constants"]["below"].as_integer(), Some(-1));
+
+        assert!(syn::parse_str::<InlineConfig>("cfg { default: \"en\" }").is_err());
+        assert!(
+            syn::parse_str::<InlineConfig>("config { default: \"en\", default: \"fr\" }").is_err()
+        );
+        assert!(syn::parse_str::<InlineConfig>("config { default: 'e' }").is_err());
+        assert!(syn::parse_str::<InlineConfig>("config { default: -\"en\" }").is_err());
+    }
+}
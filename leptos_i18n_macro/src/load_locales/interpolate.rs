@@ -1,10 +1,9 @@
 use std::collections::HashSet;
 
 use proc_macro2::{Span, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote};
 
-#[cfg(feature = "interpolate_display")]
-use quote::format_ident;
+use crate::backend;
 
 use super::{
     key::Key,
@@ -12,9 +11,6 @@ use super::{
     parsed_value::{InterpolateKey, ParsedValue},
 };
 
-#[cfg(feature = "debug_interpolations")]
-const MAX_KEY_GENERATE_BUILD_DEBUG: usize = 4;
-
 pub struct Interpolation {
     pub ident: syn::Ident,
     pub default_generic_ident: TokenStream,
@@ -25,7 +21,6 @@ struct Field<'a> {
     generic: syn::Ident,
     name: String,
     kind: &'a InterpolateKey,
-    #[cfg(feature = "debug_interpolations")]
     real_name: &'a str,
 }
 
@@ -45,7 +40,6 @@ impl Interpolation {
         let fields = keys_set
             .iter()
             .map(|kind| {
-                #[cfg(feature = "debug_interpolations")]
                 let real_name = kind.get_real_name();
                 let key = kind
                     .as_key()
@@ -57,14 +51,14 @@ impl Interpolation {
                     generic,
                     name,
                     kind,
-                    #[cfg(feature = "debug_interpolations")]
                     real_name,
                 }
             })
             .collect::<Vec<_>>();
 
         let type_def = Self::create_type(&ident, &fields);
-        let builder_impl = Self::builder_impl(&ident, &locale_field, &fields);
+        let missing_traits = Self::missing_traits(key, &ident, &fields);
+        let builder_impl = Self::builder_impl(key, &ident, &locale_field, &fields);
         let into_view_impl =
             Self::into_view_impl(key, &ident, &locale_field, &fields, locales, default_match);
         let debug_impl = Self::debug_impl(&builder_name, &ident, &fields);
@@ -84,6 +78,8 @@ impl Interpolation {
         let imp = quote! {
             #type_def
 
+            #missing_traits
+
             #new_impl
 
             #into_view_impl
@@ -136,78 +132,199 @@ impl Interpolation {
             .collect()
     }
 
-    #[cfg(feature = "debug_interpolations")]
-    fn generate_build_fns(ident: &syn::Ident, fields: &[Field], locale_field: &Key) -> TokenStream {
-        if fields.len() > MAX_KEY_GENERATE_BUILD_DEBUG {
-            return Self::generate_success_build_fn(ident, fields);
+    /// The name of the trait a field must implement to build the interpolation,
+    /// erroring with the name of the interpolation when it is missing.
+    fn missing_trait_ident(ident: &syn::Ident, field: &Field, suffix: &str) -> syn::Ident {
+        format_ident!("{}{}{}", ident, field.name, suffix)
+    }
+
+    /// The bound of the value of a field when formatting to a string, counts being given by value.
+    #[cfg(feature = "interpolate_display")]
+    fn string_bound(field: &Field) -> TokenStream {
+        match field.kind.get_string_generic() {
+            Ok(bound) => bound,
+            Err(plural_type) => quote!(core::marker::Copy + core::convert::Into<#plural_type>),
         }
-        let failing_builds = Self::generate_all_failing_build_fn(ident, fields, locale_field);
-        let success_build = Self::generate_success_build_fn(ident, fields);
+    }
 
-        quote! {
-            #(#failing_builds)*
+    /// Generate a trait per field, implemented by the values it accepts, so forgetting an interpolation
+    /// is reported with its name instead of the unsatisfied bounds of the empty value.
+    fn missing_traits(key: &Key, ident: &syn::Ident, fields: &[Field]) -> TokenStream {
+        let missing_trait = |field: &Field, suffix: &str, bound: TokenStream| {
+            let trait_ident = Self::missing_trait_ident(ident, field, suffix);
+            let name = match field.kind {
+                InterpolateKey::Component(_) | InterpolateKey::ComponentWithAttrs(_) => {
+                    format!("<{}>", field.real_name)
+                }
+                _ => field.real_name.to_string(),
+            };
+            let message = format!("missing interpolation `{}` for key `{}`", name, key.name);
+            let label = format!("missing `{}`", name);
+            let note = format!("give it to the macro with `{} = ...`", name);
+            quote! {
+                #[doc(hidden)]
+                #[allow(non_camel_case_types)]
+                #[diagnostic::on_unimplemented(message = #message, label = #label, note = #note)]
+                pub trait #trait_ident: #bound {}
 
-            #success_build
-        }
+                #[allow(non_camel_case_types)]
+                impl<__T: #bound> #trait_ident for __T {}
+            }
+        };
+
+        fields
+            .iter()
+            .map(|field| {
+                let view_trait = missing_trait(field, "", field.kind.get_generic());
+                #[cfg(feature = "interpolate_display")]
+                let string_trait = missing_trait(field, "_string", Self::string_bound(field));
+                #[cfg(not(feature = "interpolate_display"))]
+                let string_trait = quote!();
+                quote! {
+                    #view_trait
+                    #string_trait
+                }
+            })
+            .collect()
     }
 
+    /// Return the bounds of the build functions, one per field.
+    fn build_bounds<'a>(
+        ident: &'a syn::Ident,
+        fields: &'a [Field],
+        suffix: &'a str,
+    ) -> impl Iterator<Item = TokenStream> + 'a {
+        fields.iter().map(move |field| {
+            let generic = &field.generic;
+            let trait_ident = Self::missing_trait_ident(ident, field, suffix);
+            quote!(#generic: #trait_ident)
+        })
+    }
+
+    /// Generate `build_t_string`, used by `t_string!`, and the trait bounding it. `t_string!` can't be given components,
+    /// so a key containing some is rejected with a dedicated error instead of its missing interpolations.
     #[cfg(feature = "interpolate_display")]
-    fn generate_string_build(ident: &syn::Ident, fields: &[Field]) -> TokenStream {
-        let left_generics_string = fields.iter().filter_map(|field| {
-            let ident = &field.generic;
-            let generic = field.kind.get_string_generic().ok()?;
-            Some(quote!(#ident: #generic))
+    fn t_string_build(
+        key: &Key,
+        ident: &syn::Ident,
+        fields: &[Field],
+    ) -> (TokenStream, TokenStream) {
+        let has_components = fields.iter().any(|field| {
+            matches!(
+                field.kind,
+                InterpolateKey::Component(_) | InterpolateKey::ComponentWithAttrs(_)
+            )
         });
-
-        let right_generics_string = fields.iter().map(|field| match field.kind {
-            InterpolateKey::Count(t) => quote!(#t),
-            _ => {
-                let ident = &field.generic;
-                quote!(#ident)
+        if !has_components {
+            let build_bounds = Self::build_bounds(ident, fields, "_string");
+            let build_fn = quote! {
+                #[inline]
+                pub fn build_t_string(self) -> std::borrow::Cow<'static, str>
+                where
+                    #(#build_bounds,)*
+                {
+                    self.build_string()
+                }
+            };
+            return (quote!(), build_fn);
+        }
+        let trait_ident = format_ident!("{}_t_string", ident);
+        let message = format!(
+            "key `{}` contains components and can't be used with t_string!",
+            key.name
+        );
+        let note =
+            "use t! to render it as a view, or td_string! with the components given as closures";
+        let trait_def = quote! {
+            #[doc(hidden)]
+            #[allow(non_camel_case_types)]
+            #[diagnostic::on_unimplemented(message = #message, label = "contains components", note = #note)]
+            pub trait #trait_ident {}
+        };
+        let build_fn = quote! {
+            pub fn build_t_string(self) -> std::borrow::Cow<'static, str>
+            where
+                Self: #trait_ident,
+            {
+                // the trait is never implemented.
+                unreachable!()
             }
-        });
+        };
+        (trait_def, build_fn)
+    }
+
+    #[cfg(feature = "interpolate_display")]
+    fn generate_string_build(key: &Key, ident: &syn::Ident, fields: &[Field]) -> TokenStream {
+        let generics = fields
+            .iter()
+            .map(|field| &field.generic)
+            .collect::<Vec<_>>();
+        let build_bounds = Self::build_bounds(ident, fields, "_string").collect::<Vec<_>>();
+        let string_build_bounds = &build_bounds;
+        let (t_string_trait, t_string_build) = Self::t_string_build(key, ident, fields);
 
         quote! {
+            #t_string_trait
+
             #[allow(non_camel_case_types)]
-            impl<#(#left_generics_string,)*> #ident<#(#right_generics_string,)*> {
+            impl<#(#generics,)*> #ident<#(#generics,)*> {
                 #[inline]
-                pub fn build_display(self) -> Self {
+                pub fn build_display(self) -> Self
+                where
+                    #(#build_bounds,)*
+                {
                     self
                 }
 
-                pub fn build_string(self) -> std::borrow::Cow<'static, str> {
+                pub fn build_string(self) -> std::borrow::Cow<'static, str>
+                where
+                    #(#string_build_bounds,)*
+                {
                     self.to_string().into()
                 }
+
+                #t_string_build
             }
         }
     }
 
-    fn generate_success_build_fn(ident: &syn::Ident, fields: &[Field]) -> TokenStream {
-        let left_generics = fields.iter().map(|field| {
-            let ident = &field.generic;
-            let generic = field.kind.get_generic();
-            quote!(#ident: #generic)
-        });
+    fn generate_success_build_fn(key: &Key, ident: &syn::Ident, fields: &[Field]) -> TokenStream {
+        let generics = fields
+            .iter()
+            .map(|field| &field.generic)
+            .collect::<Vec<_>>();
+        let build_bounds = Self::build_bounds(ident, fields, "");
 
-        let right_generics = fields.iter().map(|field| {
-            let ident = &field.generic;
-            quote!(#ident)
-        });
+        // with erased views the concrete type of the interpolation does not leak in the user code.
+        let build_fn = if cfg!(feature = "erased_views") {
+            let view_type = backend::view_type();
+            let into_view = backend::into_view(quote!(self));
+            quote!(pub fn build(self) -> #view_type where #(#build_bounds,)* { #into_view })
+        } else {
+            quote!(
+                pub fn build(self) -> Self
+                where
+                    #(#build_bounds,)*
+                {
+                    self
+                }
+            )
+        };
 
         #[cfg(feature = "interpolate_display")]
-        let string_build = Self::generate_string_build(ident, fields);
+        let string_build = Self::generate_string_build(key, ident, fields);
 
+        #[cfg(not(feature = "interpolate_display"))]
+        let _ = key;
         #[cfg(not(feature = "interpolate_display"))]
         let string_build = quote!();
 
         quote! {
 
             #[allow(non_camel_case_types)]
-            impl<#(#left_generics,)*> #ident<#(#right_generics,)*> {
+            impl<#(#generics,)*> #ident<#(#generics,)*> {
                 #[inline]
-                pub fn build(self) -> Self {
-                    self
-                }
+                #build_fn
             }
 
             #string_build
@@ -215,13 +332,15 @@ impl Interpolation {
         }
     }
 
-    fn builder_impl(ident: &syn::Ident, locale_field: &Key, fields: &[Field]) -> TokenStream {
+    fn builder_impl(
+        key: &Key,
+        ident: &syn::Ident,
+        locale_field: &Key,
+        fields: &[Field],
+    ) -> TokenStream {
         let set_fns = Self::genenerate_set_fns(ident, locale_field, fields);
 
-        #[cfg(not(feature = "debug_interpolations"))]
-        let build_fns = Self::generate_success_build_fn(ident, fields);
-        #[cfg(feature = "debug_interpolations")]
-        let build_fns = Self::generate_build_fns(ident, fields, locale_field);
+        let build_fns = Self::generate_success_build_fn(key, ident, fields);
 
         quote! {
             #set_fns
@@ -230,7 +349,10 @@ impl Interpolation {
     }
 
     fn create_type(ident: &syn::Ident, fields: &[Field]) -> TokenStream {
-        let generics = fields.iter().map(|field| &field.generic);
+        let generics = fields
+            .iter()
+            .map(|field| &field.generic)
+            .collect::<Vec<_>>();
         let fields = fields.iter().map(|field| {
             let key = field.kind;
             let generic = &field.generic;
@@ -247,14 +369,14 @@ impl Interpolation {
         }
     }
 
-    fn generate_generics<'a, F: 'a, T: Clone + 'a>(
+    fn generate_generics<'a, F, T: Clone + 'a>(
         left_fields: &'a [Field],
         field_generic: Option<T>,
         right_fields: &'a [Field],
         other_field_map_fn: F,
     ) -> impl Iterator<Item = T> + 'a + Clone
     where
-        F: FnMut(&'a Field) -> T + Copy,
+        F: FnMut(&'a Field) -> T + Copy + 'a,
     {
         left_fields
             .iter()
@@ -263,99 +385,6 @@ impl Interpolation {
             .chain(right_fields.iter().map(other_field_map_fn))
     }
 
-    #[cfg(feature = "debug_interpolations")]
-    fn generate_default_constructed(
-        ident: &syn::Ident,
-        fields: &[Field],
-        locale_field: &Key,
-    ) -> TokenStream {
-        let populated_fields = fields
-            .iter()
-            .map(|field| {
-                let field_ident = field.kind;
-                let default = field.kind.get_default();
-                quote!(#field_ident: #default)
-            })
-            .chain(std::iter::once(
-                quote!(#locale_field: core::default::Default::default()),
-            ));
-
-        quote! {
-            #ident {
-                #(#populated_fields,)*
-            }
-        }
-    }
-
-    #[cfg(feature = "debug_interpolations")]
-    fn generate_all_failing_build_fn<'a>(
-        ident: &'a syn::Ident,
-        fields: &'a [Field],
-        locale_field: &Key,
-    ) -> impl Iterator<Item = TokenStream> + 'a {
-        let default_constructed = Self::generate_default_constructed(ident, fields, locale_field);
-        let output_generics = fields.iter().map(|field| {
-            let generic = field.kind.get_generic();
-            quote!(impl #generic)
-        });
-        let output = quote!(#ident<#(#output_generics,)*>);
-        let max = 1u64 << fields.len();
-        (0..max - 1).map(move |states| {
-            let fields_iter = fields.iter().enumerate().map(|(i, field)| {
-                let state = (states >> i & 1) == 1;
-                (state, field)
-            });
-            Self::generate_failing_build_fn(ident, &default_constructed, &output, fields_iter)
-        })
-    }
-
-    #[cfg(feature = "debug_interpolations")]
-    fn generate_failing_build_fn<'a, I>(
-        self_ident: &syn::Ident,
-        default_constructed: &TokenStream,
-        output: &TokenStream,
-        fields: I,
-    ) -> TokenStream
-    where
-        I: Iterator<Item = (bool, &'a Field<'a>)> + Clone,
-    {
-        use std::borrow::Cow;
-
-        let right_generics = fields.clone().map(|(set, field)| {
-            if set {
-                quote::ToTokens::to_token_stream(&field.generic)
-            } else {
-                quote!(EmptyInterpolateValue)
-            }
-        });
-        let left_generics = fields.clone().filter(|(set, _)| *set).map(|(_, field)| {
-            let ident = &field.generic;
-            let generic_bound = field.kind.get_generic();
-            quote!(#ident: #generic_bound)
-        });
-
-        let missing_fields = fields
-            .filter_map(|(set, field)| (!set).then_some(field))
-            .map(|field| match field.kind {
-                InterpolateKey::Count(_) | InterpolateKey::Variable(_) => field.real_name.into(),
-                InterpolateKey::Component(_) => format!("<{}>", field.real_name).into(),
-            })
-            .collect::<Vec<Cow<_>>>();
-
-        let error_message = format!("\nMissing interpolations keys: {:?}", missing_fields);
-
-        quote! {
-            impl<#(#left_generics,)*> #self_ident<#(#right_generics,)*> {
-                #[deprecated(note = #error_message)]
-                pub fn build(self) -> #output {
-                    panic!(#error_message);
-                    #[allow(unreachable_code)]
-                    #default_constructed
-                }
-            }
-        }
-    }
-
     fn create_field_set_fn(
         ident: &syn::Ident,
         locale_field: &Key,
@@ -404,12 +433,42 @@ impl Interpolation {
         };
         let restructure = quote!(#ident { #(#other_fields,)* #kind });
 
+        let into_view_trait = backend::into_view_trait();
+        let children_fn = backend::children_fn_type();
+        let component_into_view = backend::into_view(quote!(#kind(children)));
+        let attrs_type = backend::attributes_type();
+        let component_attrs_into_view = backend::into_view(quote!(#kind(children, attrs)));
         let fns = match kind {
             InterpolateKey::Variable(key) => (
                 quote! {
                     #[inline]
                     pub fn #key<__T>(self, #key: __T) -> #ident<#(#output_generics,)*>
-                        where __T: leptos::IntoView + core::clone::Clone + 'static
+                        where __T: #into_view_trait + core::clone::Clone + 'static
+                    {
+                        #destructure
+                        #restructure
+                    }
+                },
+                #[cfg(feature = "interpolate_display")]
+                {
+                    let string_key = format_ident!("{}_string", key.ident);
+                    quote! {
+                        #[inline]
+                        pub fn #string_key(self, #key: #output_field_generic_string) -> #ident<#(#output_generics_string,)*>
+                        {
+                            #destructure
+                            #restructure
+                        }
+                    }
+                },
+            ),
+            InterpolateKey::Formatted(key, _)
+            | InterpolateKey::Select(key)
+            | InterpolateKey::Attribute(key) => (
+                quote! {
+                    #[inline]
+                    pub fn #key<__T>(self, #key: __T) -> #ident<#(#output_generics,)*>
+                        where __T: #output_field_generic
                     {
                         #destructure
                         #restructure
@@ -433,11 +492,37 @@ impl Interpolation {
                     #[inline]
                     pub fn #key<__O, __T>(self, #key: __T) -> #ident<#(#output_generics,)*>
                     where
-                        __O: leptos::IntoView,
-                        __T: Fn(leptos::ChildrenFn) -> __O + core::clone::Clone + 'static
+                        __O: #into_view_trait,
+                        __T: Fn(#children_fn) -> __O + core::clone::Clone + 'static
                     {
                         #destructure
-                        let #key = move |children| leptos::IntoView::into_view(#key(children));
+                        let #key = move |children| #component_into_view;
+                        #restructure
+                    }
+                },
+                #[cfg(feature = "interpolate_display")]
+                {
+                    let string_key = format_ident!("{}_string", key.ident);
+                    quote! {
+                        #[inline]
+                        pub fn #string_key(self, #key: #output_field_generic_string) -> #ident<#(#output_generics_string,)*>
+                        {
+                            #destructure
+                            #restructure
+                        }
+                    }
+                },
+            ),
+            InterpolateKey::ComponentWithAttrs(key) => (
+                quote! {
+                    #[inline]
+                    pub fn #key<__O, __T>(self, #key: __T) -> #ident<#(#output_generics,)*>
+                    where
+                        __O: #into_view_trait,
+                        __T: Fn(#children_fn, #attrs_type) -> __O + core::clone::Clone + 'static
+                    {
+                        #destructure
+                        let #key = move |children, attrs| #component_attrs_into_view;
                         #restructure
                     }
                 },
@@ -506,8 +591,13 @@ impl Interpolation {
 
             let compile_warning = match field.kind {
                 InterpolateKey::Count(_) => "variable `count` is already set".to_string(),
-                InterpolateKey::Variable(_) => format!("variable `{}` is already set", field.name),
-                InterpolateKey::Component(_) => {
+                InterpolateKey::Variable(_)
+                | InterpolateKey::Formatted(..)
+                | InterpolateKey::Select(_)
+                | InterpolateKey::Attribute(_) => {
+                    format!("variable `{}` is already set", field.name)
+                }
+                InterpolateKey::Component(_) | InterpolateKey::ComponentWithAttrs(_) => {
                     format!("component `{}` is already set", field.name)
                 }
             };
@@ -516,6 +606,8 @@ impl Interpolation {
                 #[allow(non_camel_case_types)]
                 impl<#(#left_generics_empty,)*> #ident<#(#right_generics_empty,)*> {
                     #set_function
+
+                    #set_str_function
                 }
                 #[allow(non_camel_case_types)]
                 impl<#(#left_generics_already_set,)*> #ident<#(#right_generics_already_set,)*> {
@@ -565,24 +657,26 @@ impl Interpolation {
         locales: &[Locale],
         default_match: &TokenStream,
     ) -> TokenStream {
-        let left_generics = fields.iter().filter_map(|field| {
+        let left_generics = fields.iter().map(|field| {
             let ident = &field.generic;
-            let generic = field.kind.get_string_generic().ok()?;
-            Some(quote!(#ident: #generic))
+            let bound = Self::string_bound(field);
+            quote!(#ident: #bound)
         });
 
-        let right_generics = fields.iter().map(|field| match field.kind {
-            InterpolateKey::Count(t) => quote!(#t),
-            _ => {
-                let ident = &field.generic;
-                quote!(#ident)
-            }
-        });
+        let right_generics = fields.iter().map(|field| &field.generic);
 
         let fields_key = fields.iter().map(|f| f.kind);
 
         let destructure = quote!(let Self { #(#fields_key,)* #locale_field } = self;);
 
+        // the count is given by value, converted to the plural type.
+        let count_conversion = fields.iter().find_map(|field| match field.kind {
+            InterpolateKey::Count(plural_type) => Some(quote! {
+                let var_count = &core::convert::Into::<#plural_type>::into(*var_count);
+            }),
+            _ => None,
+        });
+
         let locales_impls = Self::create_locale_string_impl(key, locales, default_match);
 
         quote! {
@@ -590,6 +684,7 @@ impl Interpolation {
             impl<#(#left_generics,)*> core::fmt::Display for #ident<#(#right_generics,)*> {
                 fn fmt(&self, __formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                     #destructure
+                    #count_conversion
                     match #locale_field {
                         #(
                             #locales_impls,
@@ -625,18 +720,22 @@ impl Interpolation {
 
         let locales_impls = Self::create_locale_impl(key, locales, default_match);
 
+        let into_view_impl = backend::impl_into_view(
+            quote!(#(#left_generics,)*),
+            quote!(#ident<#(#right_generics,)*>),
+            quote! {
+                #destructure
+                match #locale_field {
+                    #(
+                        #locales_impls,
+                    )*
+                }
+            },
+        );
+
         quote! {
             #[allow(non_camel_case_types)]
-            impl<#(#left_generics,)*> leptos::IntoView for #ident<#(#right_generics,)*> {
-                fn into_view(self) -> leptos::View {
-                    #destructure
-                    match #locale_field {
-                        #(
-                            #locales_impls,
-                        )*
-                    }
-                }
-            }
+            #into_view_impl
         }
     }
 
@@ -645,28 +744,14 @@ impl Interpolation {
         locales: &'a [Locale],
         default_match: &TokenStream,
     ) -> impl Iterator<Item = TokenStream> + 'a {
-        let mut default_match = default_match.clone();
-        locales
-            .iter()
-            .enumerate()
-            .rev()
-            .filter_map(move |(i, locale)| {
-                let locale_key = &locale.top_locale_name;
-
-                let value = match locale.keys.get(key) {
-                    None | Some(ParsedValue::Default) => {
-                        default_match.extend(quote!(| Locale::#locale_key));
-                        return None;
-                    }
-                    Some(value) => value,
-                };
-
-                let ts = match i == 0 {
-                    true => quote!(#default_match => { #value }),
-                    false => quote!(Locale::#locale_key => { #value }),
-                };
-                Some(ts)
-            })
+        super::group_identical_locales(locales, default_match, move |locale| {
+            locale
+                .keys
+                .get(key)
+                .filter(|value| !matches!(value, ParsedValue::Default))
+        })
+        .into_iter()
+        .map(|(pattern, value)| quote!(#pattern => { #value }))
     }
 
     #[cfg(feature = "interpolate_display")]
@@ -675,30 +760,17 @@ impl Interpolation {
         locales: &'a [Locale],
         default_match: &TokenStream,
     ) -> impl Iterator<Item = TokenStream> + 'a {
-        let mut default_match = default_match.clone();
-        locales
-            .iter()
-            .enumerate()
-            .rev()
-            .filter_map(move |(i, locale)| {
-                let locale_key = &locale.top_locale_name;
-
-                let value = match locale.keys.get(key) {
-                    None | Some(ParsedValue::Default) => {
-                        default_match.extend(quote!(| Locale::#locale_key));
-                        return None;
-                    }
-                    Some(value) => value,
-                };
-
-                let value = value.as_string_impl();
-
-                let ts = match i == 0 {
-                    true => quote!(#default_match => { #value }),
-                    false => quote!(Locale::#locale_key => { #value }),
-                };
-                Some(ts)
-            })
+        super::group_identical_locales(locales, default_match, move |locale| {
+            locale
+                .keys
+                .get(key)
+                .filter(|value| !matches!(value, ParsedValue::Default))
+        })
+        .into_iter()
+        .map(|(pattern, value)| {
+            let value = value.as_string_impl();
+            quote!(#pattern => { #value })
+        })
     }
 }
 
@@ -0,0 +1,128 @@
+use std::{collections::BTreeSet, rc::Rc};
+
+use super::{
+    key::{Key, KeyPath},
+    locale::{Locale, LocalesOrNamespaces},
+    parsed_value::ParsedValue,
+    warning::{emit_warning, Warning},
+};
+
+/// Compare the variables and components of the keys of each locale to the ones of the default locale.
+///
+/// A mismatch, like `{{ name }}` in the default locale and `{{ nom }}` in another one, would otherwise only
+/// show up as a confusing error of the builder where the key is used, so a warning names the locale,
+/// the key and the missing and surplus interpolations.
+pub fn check_interpolations(locales: &LocalesOrNamespaces) {
+    match locales {
+        LocalesOrNamespaces::Locales(locales) => check_locales(locales, None),
+        LocalesOrNamespaces::NameSpaces(namespaces) => {
+            for namespace in namespaces {
+                check_locales(&namespace.locales, Some(&namespace.key));
+            }
+        }
+    }
+}
+
+fn check_locales(locales: &[Locale], namespace: Option<&Rc<Key>>) {
+    let Some((default_locale, locales)) = locales.split_first() else {
+        return;
+    };
+    let mut key_path = KeyPath::new(namespace.cloned());
+    for locale in locales {
+        check_locale(
+            default_locale,
+            locale,
+            &locale.top_locale_name,
+            &mut key_path,
+        );
+    }
+}
+
+/// The names of the variables and components of a value, the plural count being `count`.
+fn interpolation_names(value: &ParsedValue) -> BTreeSet<String> {
+    let keys = value.get_keys().unwrap_or_default();
+    keys.iter()
+        .map(|key| key.get_real_name().to_owned())
+        .collect()
+}
+
+fn check_locale(
+    default_locale: &Locale,
+    locale: &Locale,
+    top_locale: &Rc<Key>,
+    key_path: &mut KeyPath,
+) {
+    for (key, value) in &locale.keys {
+        // missing and surplus keys are reported when merging the locales.
+        let Some(default_value) = default_locale.keys.get(key) else {
+            continue;
+        };
+        key_path.push_key(Rc::clone(key));
+        match (default_value, value) {
+            (ParsedValue::Subkeys(Some(default_subkeys)), ParsedValue::Subkeys(Some(subkeys))) => {
+                check_locale(default_subkeys, subkeys, top_locale, key_path);
+            }
+            // explicit defaults and subkeys mismatch are handled when merging the locales.
+            (ParsedValue::Subkeys(_) | ParsedValue::Default, _)
+            | (_, ParsedValue::Subkeys(_) | ParsedValue::Default) => {}
+            _ => {
+                let default_names = interpolation_names(default_value);
+                let names = interpolation_names(value);
+                // a locale without plural forms can translate a plural with a single value.
+                let missing: Vec<String> = default_names
+                    .difference(&names)
+                    .filter(|name| *name != "count")
+                    .cloned()
+                    .collect();
+                let surplus: Vec<String> = names.difference(&default_names).cloned().collect();
+                if !missing.is_empty() || !surplus.is_empty() {
+                    emit_warning(Warning::InterpolationMismatch {
+                        locale: Rc::clone(top_locale),
+                        key_path: key_path.clone(),
+                        missing,
+                        surplus,
+                    });
+                }
+            }
+        }
+        key_path.pop_key();
+    }
+}
+
+#[cfg(all(test, feature = "json_files", not(feature = "suppress_key_warnings")))]
+mod tests {
+    use super::*;
+    use crate::load_locales::{locale::locale_from_json, warning::WARNINGS};
+
+    #[test]
+    fn test_interpolation_mismatch() {
+        let locales = [
+            locale_from_json(
+                "en",
+                r#"{ "hello": "Hello {{ name }}", "click": "<b>{{ count }}</b> clicks", "menu": { "open": "Open {{ menu }}" } }"#,
+            ),
+            locale_from_json(
+                "fr",
+                r#"{ "hello": "Bonjour {{ nom }}", "click": "<b>plusieurs</b> clics", "menu": { "open": "Ouvrir" } }"#,
+            ),
+        ];
+        check_locales(&locales, None);
+
+        let warnings = WARNINGS.with(|warnings| {
+            let mut warnings = warnings
+                .borrow()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+            warnings.sort();
+            warnings
+        });
+        assert_eq!(
+            warnings,
+            [
+                "Key \"hello\" of locale \"fr\" does not have the interpolations of the default locale: missing `name`, surplus `nom`",
+                "Key \"menu.open\" of locale \"fr\" does not have the interpolations of the default locale: missing `menu`",
+            ]
+        );
+    }
+}
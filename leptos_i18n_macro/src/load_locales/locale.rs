@@ -1,24 +1,32 @@
 use std::{
     collections::{HashMap, HashSet},
     fs::File,
+    io::{Cursor, Read},
     path::{Path, PathBuf},
     rc::Rc,
 };
 
 use super::{
-    cfg_file::ConfigFile,
+    cfg_file::{tags_loosely_eq, ConfigFile},
     error::{Error, Result},
     key::{Key, KeyPath},
     parsed_value::{InterpolateKey, ParsedValue, ParsedValueSeed},
-    warning::{emit_warning, Warning},
+    plural::{CountTypes, OrdinalOrValue, OrdinalSeed, PluralConstants, ORDINAL_KEY},
+    remote::Remote,
+    timings::{self, Stage},
+    warning::{emit_warning, missing_key, Warning},
 };
 
-#[cfg(feature = "yaml_files")]
-const FILE_EXTS: &[&str] = &["yaml", "yml"];
-#[cfg(feature = "json_files")]
-const FILE_EXTS: &[&str] = &["json"];
-#[cfg(not(any(feature = "json_files", feature = "yaml_files")))]
-const FILE_EXTS: &[&str] = &[];
+pub const FILE_EXTS: &[&str] = &[
+    #[cfg(feature = "json_files")]
+    "json",
+    #[cfg(feature = "yaml_files")]
+    "yaml",
+    #[cfg(feature = "yaml_files")]
+    "yml",
+    #[cfg(feature = "fluent")]
+    "ftl",
+];
 
 #[derive(Debug)]
 pub struct Namespace {
@@ -46,37 +54,127 @@ pub enum BuildersKeys<'a> {
     },
 }
 
-fn find_file(path: &mut PathBuf) -> Result<File> {
+/// A locale file, read from the locales directory or fetched from the `locales-url`.
+#[derive(Debug)]
+pub enum LocaleFile {
+    Local(File),
+    Remote {
+        url: String,
+        content: Cursor<Vec<u8>>,
+    },
+}
+
+impl Read for LocaleFile {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            LocaleFile::Local(file) => file.read(buf),
+            LocaleFile::Remote { content, .. } => content.read(buf),
+        }
+    }
+}
+
+/// Open the locale file at `path`, or fetch it at `remote_path` if the locales are remote.
+fn find_file(
+    path: &mut PathBuf,
+    remote_path: &Path,
+    remote: Option<&mut Remote>,
+) -> Result<LocaleFile> {
+    if let Some(remote) = remote {
+        let (url, content) = remote.get(remote_path, FILE_EXTS)?;
+        return Ok(LocaleFile::Remote {
+            url,
+            content: Cursor::new(content),
+        });
+    }
+
     let mut errs = vec![];
 
     for ext in FILE_EXTS {
         path.set_extension(ext);
         match File::open(&path) {
-            Ok(file) => return Ok(file),
+            Ok(file) => return Ok(LocaleFile::Local(file)),
             Err(err) => {
                 errs.push((path.to_owned(), err));
             }
         };
     }
 
+    if let Some(found) = find_mismatched_file(path) {
+        return Err(Error::LocaleFileNameMismatch {
+            expected: std::mem::take(path),
+            found,
+        });
+    }
+
     Err(Error::LocaleFileNotFound(errs))
 }
 
+/// Look for a file whose path only differs from `path` by the case or the separator of its name,
+/// or of the name of its directory, like `locales/en_us.json` for `locales/en-US.json`.
+fn find_mismatched_file(path: &Path) -> Option<PathBuf> {
+    fn find_entry(dir: &Path, name: &str, is_file: bool) -> Option<PathBuf> {
+        std::fs::read_dir(dir).ok()?.find_map(|entry| {
+            let entry_path = entry.ok()?.path();
+            let entry_name = if is_file {
+                let ext = entry_path.extension()?.to_str()?;
+                if !FILE_EXTS.contains(&ext) {
+                    return None;
+                }
+                entry_path.file_stem()?
+            } else {
+                entry_path.file_name()?
+            };
+            let matches =
+                entry_path.is_file() == is_file && tags_loosely_eq(entry_name.to_str()?, name);
+            matches.then_some(entry_path)
+        })
+    }
+
+    let file_name = path.file_stem()?.to_str()?;
+    let dir = path.parent()?;
+    if dir.is_dir() {
+        return find_entry(dir, file_name, true);
+    }
+    // the directory of the locale can be misnamed with namespaces.
+    let dir_name = dir.file_name()?.to_str()?;
+    let found_dir = find_entry(dir.parent()?, dir_name, false)?;
+    find_entry(&found_dir, file_name, true)
+}
+
 impl Namespace {
     pub fn new(
         locales_dir_path: &mut PathBuf,
         key: Rc<Key>,
-        locale_keys: &[Rc<Key>],
+        cfg_file: &ConfigFile,
+        mut remote: Option<&mut Remote>,
     ) -> Result<Self> {
+        let locale_keys = &cfg_file.locales;
         let mut locales = Vec::with_capacity(locale_keys.len());
-        for locale in locale_keys.iter().cloned() {
+        // the pseudo locale is added once the foreign keys of the default locale are resolved.
+        for locale in locale_keys.iter().filter(|l| !cfg_file.is_pseudo_locale(l)) {
             let file_path: &Path = key.name.as_ref();
-            locales_dir_path.push(&locale.name);
+            let locale_dir = cfg_file.file_name(locale);
+            locales_dir_path.push(locale_dir);
             locales_dir_path.push(file_path);
 
-            let locale_file = find_file(locales_dir_path)?;
-
-            let locale = Locale::new(locale_file, locales_dir_path, locale, Some(Rc::clone(&key)))?;
+            let remote_path = Path::new(locale_dir).join(file_path);
+            let locale_file = find_file(locales_dir_path, &remote_path, remote.as_deref_mut())?;
+
+            let locale = timings::time(Stage::Parse, Some(&key), Some(locale), || {
+                let mut locale = Locale::new(
+                    locale_file,
+                    locales_dir_path,
+                    Rc::clone(locale),
+                    Some(Rc::clone(&key)),
+                    cfg_file,
+                )?;
+                if remote.is_none() {
+                    locales_dir_path.pop();
+                    locale.apply_override_files(locales_dir_path, &key, cfg_file)?;
+                    locales_dir_path.push(file_path);
+                }
+                Ok(locale)
+            })?;
 
             locales.push(locale);
             locales_dir_path.pop();
@@ -109,28 +207,48 @@ impl LocalesOrNamespaces {
 
     pub fn new(manifest_dir_path: &mut PathBuf, cfg_file: &ConfigFile) -> Result<Self> {
         let locale_keys = &cfg_file.locales;
+        let mut remote = cfg_file
+            .locales_url
+            .as_deref()
+            .map(|url| Remote::new(url, manifest_dir_path))
+            .transpose()?;
         manifest_dir_path.push(&*cfg_file.locales_dir);
-        if let Some(namespace_keys) = &cfg_file.name_spaces {
+        let locales = if let Some(namespace_keys) = &cfg_file.name_spaces {
             let mut namespaces = Vec::with_capacity(namespace_keys.len());
             for namespace in namespace_keys {
                 namespaces.push(Namespace::new(
                     manifest_dir_path,
                     Rc::clone(namespace),
-                    locale_keys,
+                    cfg_file,
+                    remote.as_mut(),
                 )?);
             }
-            Ok(LocalesOrNamespaces::NameSpaces(namespaces))
+            LocalesOrNamespaces::NameSpaces(namespaces)
         } else {
             let mut locales = Vec::with_capacity(locale_keys.len());
-            for locale in locale_keys.iter().cloned() {
-                manifest_dir_path.push(&locale.name);
-                let locale_file = find_file(manifest_dir_path)?;
-                let locale = Locale::new(locale_file, manifest_dir_path, locale, None)?;
+            for locale in locale_keys.iter().filter(|l| !cfg_file.is_pseudo_locale(l)) {
+                let file_name = cfg_file.file_name(locale);
+                manifest_dir_path.push(file_name);
+                let remote_path = Path::new(file_name);
+                let locale_file = find_file(manifest_dir_path, remote_path, remote.as_mut())?;
+                let locale = timings::time(Stage::Parse, None, Some(locale), || {
+                    Locale::new(
+                        locale_file,
+                        manifest_dir_path,
+                        Rc::clone(locale),
+                        None,
+                        cfg_file,
+                    )
+                })?;
                 locales.push(locale);
                 manifest_dir_path.pop();
             }
-            Ok(LocalesOrNamespaces::Locales(locales))
+            LocalesOrNamespaces::Locales(locales)
+        };
+        if let Some(remote) = remote {
+            remote.write_lockfile()?;
         }
+        Ok(locales)
     }
 }
 
@@ -139,6 +257,8 @@ pub struct Locale {
     pub top_locale_name: Rc<Key>,
     pub name: Rc<Key>,
     pub keys: HashMap<Rc<Key>, ParsedValue>,
+    /// The local files this locale was parsed from, the overrides last.
+    pub files: Vec<PathBuf>,
 }
 
 impl Locale {
@@ -160,43 +280,183 @@ impl Locale {
     }
 
     #[cfg(feature = "yaml_files")]
-    fn de_inner(locale_file: File, seed: LocaleSeed) -> Result<Self, super::error::SerdeError> {
+    fn de_yaml(
+        locale_file: LocaleFile,
+        seed: LocaleSeed,
+    ) -> Result<Self, super::error::SerdeError> {
         let deserializer = serde_yaml::Deserializer::from_reader(locale_file);
         serde::de::DeserializeSeed::deserialize(seed, deserializer)
+            .map_err(super::error::SerdeError::Yaml)
     }
 
     #[cfg(feature = "json_files")]
-    fn de_inner(locale_file: File, seed: LocaleSeed) -> Result<Self, super::error::SerdeError> {
-        let mut deserializer = serde_json::Deserializer::from_reader(locale_file);
+    fn de_json(
+        mut locale_file: LocaleFile,
+        seed: LocaleSeed,
+        relaxed: bool,
+    ) -> Result<Self, super::error::SerdeError> {
+        if !relaxed {
+            let mut deserializer = serde_json::Deserializer::from_reader(locale_file);
+            return serde::de::DeserializeSeed::deserialize(seed, &mut deserializer)
+                .map_err(super::error::SerdeError::Json);
+        }
+        let mut content = String::new();
+        locale_file
+            .read_to_string(&mut content)
+            .map_err(|err| super::error::SerdeError::Json(serde_json::Error::io(err)))?;
+        let content = super::relaxed_json::relax(&content);
+        let mut deserializer = serde_json::Deserializer::from_str(&content);
         serde::de::DeserializeSeed::deserialize(seed, &mut deserializer)
+            .map_err(super::error::SerdeError::Json)
     }
 
-    #[cfg(not(any(feature = "json_files", feature = "yaml_files")))]
-    fn de_inner(locale_file: File, seed: LocaleSeed) -> Result<Self, super::error::SerdeError> {
-        let _ = (locale_file, seed);
-        compile_error!("No file format has been provided, supported formats are: json and yaml")
+    #[cfg(feature = "fluent")]
+    fn de_fluent(
+        locale_file: LocaleFile,
+        seed: LocaleSeed,
+    ) -> Result<Self, super::error::SerdeError> {
+        let resource = super::fluent::Resource::from_reader(locale_file)
+            .map_err(super::error::SerdeError::Fluent)?;
+        serde::de::DeserializeSeed::deserialize(seed, resource)
+            .map_err(super::error::SerdeError::Fluent)
     }
 
-    fn de(locale_file: File, path: &mut PathBuf, seed: LocaleSeed) -> Result<Self> {
-        Self::de_inner(locale_file, seed).map_err(|err| Error::LocaleFileDeser {
-            path: std::mem::take(path),
-            err,
+    /// Deserialize the file with the format of its extension, the formats can be enabled at the same time.
+    #[cfg(any(feature = "json_files", feature = "yaml_files", feature = "fluent"))]
+    fn de_inner(
+        locale_file: LocaleFile,
+        ext: &str,
+        seed: LocaleSeed,
+        relaxed_json: bool,
+    ) -> Result<Self, super::error::SerdeError> {
+        match ext {
+            #[cfg(feature = "yaml_files")]
+            "yaml" | "yml" => Self::de_yaml(locale_file, seed),
+            #[cfg(feature = "fluent")]
+            "ftl" => Self::de_fluent(locale_file, seed),
+            #[cfg(feature = "json_files")]
+            _ => Self::de_json(locale_file, seed, relaxed_json),
+            #[cfg(not(feature = "json_files"))]
+            _ => {
+                let _ = relaxed_json;
+                unreachable!("only the extensions of the enabled formats are looked for")
+            }
+        }
+    }
+
+    #[cfg(not(any(feature = "json_files", feature = "yaml_files", feature = "fluent")))]
+    fn de_inner(
+        locale_file: LocaleFile,
+        ext: &str,
+        seed: LocaleSeed,
+        relaxed_json: bool,
+    ) -> Result<Self, super::error::SerdeError> {
+        let _ = (locale_file, ext, seed, relaxed_json);
+        compile_error!(
+            "No file format has been provided, supported formats are: json, yaml and fluent"
+        )
+    }
+
+    fn de(
+        locale_file: LocaleFile,
+        path: &mut PathBuf,
+        seed: LocaleSeed,
+        relaxed_json: bool,
+    ) -> Result<Self> {
+        // errors in remote files point to their url.
+        let url = match &locale_file {
+            LocaleFile::Local(_) => None,
+            LocaleFile::Remote { url, .. } => Some(PathBuf::from(url)),
+        };
+        let ext = url
+            .as_deref()
+            .unwrap_or(path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or_default()
+            .to_owned();
+        Self::de_inner(locale_file, &ext, seed, relaxed_json).map_err(|err| {
+            Error::LocaleFileDeser {
+                path: url.unwrap_or_else(|| std::mem::take(path)),
+                err,
+            }
         })
     }
 
     pub fn new(
-        locale_file: File,
+        locale_file: LocaleFile,
         path: &mut PathBuf,
         locale: Rc<Key>,
         namespace: Option<Rc<Key>>,
+        cfg_file: &ConfigFile,
     ) -> Result<Self> {
         let seed = LocaleSeed {
             name: Rc::clone(&locale),
             top_locale_name: locale,
             key_path: KeyPath::new(namespace),
+            plural_constants: Rc::clone(&cfg_file.plural_constants),
+            count_types: Rc::clone(&cfg_file.count_types),
+            format_counts: cfg_file.format_counts,
         };
 
-        Self::de(locale_file, path, seed)
+        let is_local = matches!(locale_file, LocaleFile::Local(_));
+        let mut locale = Self::de(locale_file, path, seed, cfg_file.relaxed_json)?;
+        if is_local {
+            locale.files.push(path.clone());
+        }
+        Ok(locale)
+    }
+
+    /// Find the override files of the namespace in `locale_dir`, named like `{namespace}.{name}.{ext}`
+    /// (e.g. `home.overrides.json`), and merge them in the order of their names, each overriding the keys it declares.
+    fn apply_override_files(
+        &mut self,
+        locale_dir: &mut PathBuf,
+        namespace: &Rc<Key>,
+        cfg_file: &ConfigFile,
+    ) -> Result<()> {
+        let prefix = format!("{}.", namespace.name);
+        let mut override_files = std::fs::read_dir(&*locale_dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| {
+                let file_name = entry.ok()?.file_name().into_string().ok()?;
+                let (stem, ext) = file_name.strip_prefix(&prefix)?.rsplit_once('.')?;
+                (!stem.is_empty() && FILE_EXTS.contains(&ext)).then_some(file_name)
+            })
+            .collect::<Vec<_>>();
+        override_files.sort_unstable();
+
+        for file_name in override_files {
+            locale_dir.push(file_name);
+            let file = File::open(&*locale_dir)
+                .map_err(|err| Error::LocaleFileNotFound(vec![(locale_dir.clone(), err)]))?;
+            let overrides = Locale::new(
+                LocaleFile::Local(file),
+                locale_dir,
+                Rc::clone(&self.name),
+                Some(Rc::clone(namespace)),
+                cfg_file,
+            )?;
+            self.apply_override(overrides);
+            locale_dir.pop();
+        }
+        Ok(())
+    }
+
+    fn apply_override(&mut self, overrides: Locale) {
+        self.files.extend(overrides.files);
+        for (key, value) in overrides.keys {
+            match (self.keys.get_mut(&key), value) {
+                (
+                    Some(ParsedValue::Subkeys(Some(subkeys))),
+                    ParsedValue::Subkeys(Some(override_subkeys)),
+                ) => subkeys.apply_override(override_subkeys),
+                (_, value) => {
+                    self.keys.insert(key, value);
+                }
+            }
+        }
     }
 
     pub fn make_builder_keys(&mut self, key_path: &mut KeyPath) -> Result<BuildersKeysInner> {
@@ -225,17 +485,14 @@ impl Locale {
             if let Some(value) = self.keys.get_mut(key) {
                 value.merge(keys, default_locale, Rc::clone(&self.name), key_path)?;
             } else {
-                emit_warning(Warning::MissingKey {
-                    locale: top_locale.clone(),
-                    key_path: key_path.clone(),
-                });
+                missing_key(top_locale.clone(), key_path.clone())?;
             }
             key_path.pop_key();
         }
 
         // reverse key comparaison
         for key in self.keys.keys() {
-            if keys.0.get(key).is_none() {
+            if !keys.0.contains_key(key) {
                 key_path.push_key(Rc::clone(key));
                 emit_warning(Warning::SurplusKey {
                     locale: top_locale.clone(),
@@ -253,19 +510,32 @@ impl Locale {
     ) -> Result<BuildersKeysInner> {
         let mut locales = locales.iter_mut();
         let default_locale = locales.next().unwrap();
-        let mut key_path = KeyPath::new(namespace);
+        let mut key_path = KeyPath::new(namespace.clone());
 
-        let mut default_keys = default_locale.make_builder_keys(&mut key_path)?;
+        let default_locale_key = Rc::clone(&default_locale.name);
+        let mut default_keys = timings::time(
+            Stage::Validate,
+            namespace.as_ref(),
+            Some(&default_locale_key),
+            || default_locale.make_builder_keys(&mut key_path),
+        )?;
 
         let default_locale_name = &default_locale.name.name;
 
         for locale in locales {
             let top_locale = locale.name.clone();
-            locale.merge(
-                &mut default_keys,
-                default_locale_name,
-                top_locale,
-                &mut key_path,
+            timings::time(
+                Stage::Validate,
+                namespace.as_ref(),
+                Some(&top_locale),
+                || {
+                    locale.merge(
+                        &mut default_keys,
+                        default_locale_name,
+                        Rc::clone(&top_locale),
+                        &mut key_path,
+                    )
+                },
             )?;
         }
 
@@ -274,7 +544,7 @@ impl Locale {
         Ok(default_keys)
     }
 
-    pub fn check_locales(locales: &mut LocalesOrNamespaces) -> Result<BuildersKeys> {
+    pub fn check_locales(locales: &mut LocalesOrNamespaces) -> Result<BuildersKeys<'_>> {
         match locales {
             LocalesOrNamespaces::NameSpaces(namespaces) => {
                 let mut keys = HashMap::with_capacity(namespaces.len());
@@ -324,7 +594,7 @@ impl LocaleValue {
                     InterpolateKey::Count(plural_type) => Some(*plural_type),
                     _ => None,
                 }) else {
-                    return Ok(());
+                    return Self::check_variable_kind_conflicts(keys, key_path);
                 };
 
                 let other_type = iter.find_map(|key| match key {
@@ -337,6 +607,7 @@ impl LocaleValue {
                         key_path: std::mem::take(key_path),
                         type1: count_type,
                         type2: other_type,
+                        preview: None,
                     });
                 }
 
@@ -346,12 +617,48 @@ impl LocaleValue {
                     |key| !matches!(key, InterpolateKey::Variable(key) if key.name == "var_count"),
                 );
 
-                Ok(())
+                Self::check_variable_kind_conflicts(keys, key_path)
             }
             LocaleValue::Value(None) => Ok(()),
             LocaleValue::Subkeys { keys, .. } => keys.check_conflicts(key_path),
         }
     }
+
+    /// A variable can't be both formatted and not, formatted as different kinds of values,
+    /// both selected on and interpolated, or both in an attribute and in the text across locales.
+    /// A component can't have attributes in some tags and not in others.
+    fn check_variable_kind_conflicts(
+        keys: &HashSet<InterpolateKey>,
+        key_path: &mut KeyPath,
+    ) -> Result<()> {
+        for key in keys {
+            if let InterpolateKey::ComponentWithAttrs(component) = key {
+                if keys.contains(&InterpolateKey::Component(Rc::clone(component))) {
+                    return Err(Error::ComponentAttrsMissmatch {
+                        key_path: std::mem::take(key_path),
+                        name: key.get_real_name().to_owned(),
+                    });
+                }
+                continue;
+            }
+            let (InterpolateKey::Formatted(variable, _)
+            | InterpolateKey::Select(variable)
+            | InterpolateKey::Attribute(variable)) = key
+            else {
+                continue;
+            };
+            let conflict = keys
+                .iter()
+                .any(|other| other != key && other.as_ident() == variable.ident);
+            if conflict {
+                return Err(Error::VariableKindMissmatch {
+                    key_path: std::mem::take(key_path),
+                    name: key.get_real_name().to_owned(),
+                });
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -359,31 +666,114 @@ pub struct LocaleSeed {
     pub name: Rc<Key>,
     pub top_locale_name: Rc<Key>,
     pub key_path: KeyPath,
+    pub plural_constants: Rc<PluralConstants>,
+    pub count_types: Rc<CountTypes>,
+    pub format_counts: bool,
 }
 
-impl<'de> serde::de::Visitor<'de> for LocaleSeed {
-    type Value = HashMap<Rc<Key>, ParsedValue>;
+/// Parse a locale from JSON with the default settings, for the tests.
+#[cfg(all(test, feature = "json_files"))]
+pub(crate) fn locale_from_json(name: &str, json: &str) -> Locale {
+    let name = Rc::new(Key::new(name).unwrap());
+    let seed = LocaleSeed {
+        name: Rc::clone(&name),
+        top_locale_name: name,
+        key_path: KeyPath::new(None),
+        plural_constants: Default::default(),
+        count_types: Default::default(),
+        format_counts: false,
+    };
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    serde::de::DeserializeSeed::deserialize(seed, &mut deserializer).unwrap()
+}
 
-    fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
+impl LocaleSeed {
+    /// Deserialize the value of `locale_key`, the next value of the map.
+    pub fn deserialize_value<'de, A>(
+        &mut self,
+        locale_key: &Rc<Key>,
+        map: &mut A,
+    ) -> Result<ParsedValue, A::Error>
     where
         A: serde::de::MapAccess<'de>,
     {
-        let mut keys = HashMap::new();
-
-        while let Some(locale_key) = map.next_key()? {
-            self.key_path.push_key(Rc::clone(&locale_key));
-            let value = map.next_value_seed(ParsedValueSeed {
-                top_locale_name: &self.top_locale_name,
-                key: &locale_key,
-                key_path: &self.key_path,
-                in_plural: false,
-            })?;
-            self.key_path.pop_key();
+        self.key_path.push_key(Rc::clone(locale_key));
+        let value = map.next_value_seed(self.value_seed(locale_key));
+        self.key_path.pop_key();
+        value
+    }
+
+    /// Deserialize the value of an `"ordinal"` key, the next value of the map,
+    /// that is either the marker of an ordinal plural or the value of a subkey.
+    pub fn deserialize_ordinal<'de, A>(
+        &mut self,
+        locale_key: &Rc<Key>,
+        map: &mut A,
+    ) -> Result<OrdinalOrValue, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        self.key_path.push_key(Rc::clone(locale_key));
+        let value = map.next_value_seed(OrdinalSeed(self.value_seed(locale_key)));
+        self.key_path.pop_key();
+        value
+    }
+
+    fn value_seed<'a>(&'a self, locale_key: &'a Rc<Key>) -> ParsedValueSeed<'a> {
+        ParsedValueSeed {
+            top_locale_name: &self.top_locale_name,
+            key: locale_key,
+            key_path: &self.key_path,
+            in_plural: false,
+            plural_constants: &self.plural_constants,
+            count_types: &self.count_types,
+            format_counts: self.format_counts,
+        }
+    }
+
+    /// Deserialize the keys of the map into `keys`, `first_key` being the key already taken from it, if any.
+    pub fn deserialize_keys<'de, A>(
+        mut self,
+        mut keys: HashMap<Rc<Key>, ParsedValue>,
+        first_key: Option<Rc<Key>>,
+        mut map: A,
+    ) -> Result<HashMap<Rc<Key>, ParsedValue>, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let mut next_key = match first_key {
+            Some(key) => Some(key),
+            None => map.next_key()?,
+        };
+
+        while let Some(locale_key) = next_key {
+            let value = if locale_key.name == ORDINAL_KEY {
+                match self.deserialize_ordinal(&locale_key, &mut map)? {
+                    OrdinalOrValue::Value(value) => value,
+                    OrdinalOrValue::Ordinal(_) => {
+                        return Err(serde::de::Error::custom(Error::MisplacedOrdinal))
+                    }
+                }
+            } else {
+                self.deserialize_value(&locale_key, &mut map)?
+            };
             keys.insert(locale_key, value);
+            next_key = map.next_key()?;
         }
 
         Ok(keys)
     }
+}
+
+impl<'de> serde::de::Visitor<'de> for LocaleSeed {
+    type Value = HashMap<Rc<Key>, ParsedValue>;
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        self.deserialize_keys(HashMap::new(), None, map)
+    }
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(
@@ -410,6 +800,7 @@ impl<'de> serde::de::DeserializeSeed<'de> for LocaleSeed {
             name,
             keys,
             top_locale_name,
+            files: Vec::new(),
         })
     }
 }
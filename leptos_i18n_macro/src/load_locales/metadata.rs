@@ -0,0 +1,103 @@
+use std::{collections::HashMap, rc::Rc};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::{
+    key::{Key, KeyPath},
+    locale::{BuildersKeys, LocaleValue},
+    parsed_value::InterpolateKey,
+};
+
+fn key_path_str(key_path: &KeyPath) -> String {
+    let mut path = key_path
+        .namespace
+        .as_ref()
+        .map(|namespace| format!("{}::", namespace.name))
+        .unwrap_or_default();
+    let keys = key_path
+        .path
+        .iter()
+        .map(|key| key.name.as_str())
+        .collect::<Vec<_>>();
+    path.push_str(&keys.join("."));
+    path
+}
+
+fn interpolation_metadata(key: &InterpolateKey) -> TokenStream {
+    let name = key.get_real_name();
+    let kind = match key {
+        InterpolateKey::Variable(_) | InterpolateKey::Attribute(_) => {
+            quote!(leptos_i18n::metadata::InterpolationKind::Variable)
+        }
+        InterpolateKey::Formatted(_, kind) => {
+            let formatter = kind.name();
+            quote!(leptos_i18n::metadata::InterpolationKind::Formatted(#formatter))
+        }
+        InterpolateKey::Select(_) => quote!(leptos_i18n::metadata::InterpolationKind::Select),
+        InterpolateKey::Component(_) | InterpolateKey::ComponentWithAttrs(_) => {
+            quote!(leptos_i18n::metadata::InterpolationKind::Component)
+        }
+        InterpolateKey::Count(plural_type) => {
+            let count_type = plural_type.to_string();
+            quote!(leptos_i18n::metadata::InterpolationKind::Count(#count_type))
+        }
+    };
+    quote!(leptos_i18n::metadata::Interpolation { name: #name, kind: #kind })
+}
+
+fn collect_keys_metadata(
+    keys: &HashMap<Rc<Key>, LocaleValue>,
+    key_path: &mut KeyPath,
+    metadata: &mut Vec<(String, TokenStream)>,
+) {
+    for (key, value) in keys {
+        key_path.push_key(Rc::clone(key));
+        match value {
+            LocaleValue::Subkeys { keys, .. } => collect_keys_metadata(&keys.0, key_path, metadata),
+            LocaleValue::Value(interpolations) => {
+                let mut interpolations = interpolations
+                    .iter()
+                    .flatten()
+                    .map(|key| (key.get_real_name(), interpolation_metadata(key)))
+                    .collect::<Vec<_>>();
+                interpolations.sort_by_key(|(name, _)| *name);
+                let interpolations = interpolations.into_iter().map(|(_, ts)| ts);
+                let path = key_path_str(key_path);
+                let ts = quote! {
+                    leptos_i18n::metadata::KeyMetadata {
+                        path: #path,
+                        interpolations: &[#(#interpolations,)*],
+                    }
+                };
+                metadata.push((path, ts));
+            }
+        }
+        key_path.pop_key();
+    }
+}
+
+/// Generate the `KEYS_METADATA` constant, listing the interpolations of every key sorted by path.
+pub fn create_keys_metadata(keys: &BuildersKeys) -> TokenStream {
+    let mut metadata = vec![];
+    match keys {
+        BuildersKeys::NameSpaces { keys, .. } => {
+            for (namespace, keys) in keys {
+                let mut key_path = KeyPath::new(Some(Rc::clone(namespace)));
+                collect_keys_metadata(&keys.0, &mut key_path, &mut metadata);
+            }
+        }
+        BuildersKeys::Locales { keys, .. } => {
+            collect_keys_metadata(&keys.0, &mut KeyPath::new(None), &mut metadata);
+        }
+    }
+    metadata.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let metadata = metadata.into_iter().map(|(_, ts)| ts);
+
+    quote! {
+        /// The metadata of every key of the catalog, sorted by path (`namespace::key.subkey`).
+        ///
+        /// Use `leptos_i18n::metadata::find_key` to look up a key.
+        pub const KEYS_METADATA: &[leptos_i18n::metadata::KeyMetadata] = &[#(#metadata,)*];
+    }
+}
@@ -6,21 +6,46 @@ use std::{
 };
 
 pub mod cfg_file;
+pub mod dump;
+pub mod dynamic_keys;
+pub mod dynamic_load;
+pub mod emoji;
 pub mod error;
+pub mod fallbacks;
+#[cfg(feature = "fluent")]
+pub mod fluent;
+pub mod formatter;
+pub mod gettext;
+pub mod hot_reload;
+pub mod inline_cfg;
 pub mod interpolate;
+pub mod interpolations;
 pub mod key;
 pub mod locale;
+pub mod metadata;
+pub mod obfuscate;
 pub mod parsed_value;
 pub mod plural;
+pub mod plural_rules;
+pub mod pseudo;
+#[cfg(feature = "json_files")]
+pub mod relaxed_json;
+pub mod remote;
+pub mod renames;
+pub mod select;
+pub mod size_report;
+pub mod timings;
 pub mod warning;
 
 use cfg_file::ConfigFile;
 use error::{Error, Result};
 use interpolate::{create_empty_type, Interpolation};
-use key::Key;
+use key::{Key, KeyPath};
 use locale::{Locale, LocaleValue};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
+use renames::Renames;
+use timings::Stage;
 
 use crate::load_locales::parsed_value::ParsedValue;
 
@@ -31,35 +56,83 @@ use self::{
 
 /// Steps:
 ///
-/// 1: Locate and parse the manifest (`ConfigFile::new`)
+/// 1: Locate and parse the manifest (`ConfigFile::new`), or the configuration given to the macro (`ConfigFile::from_inline`)
 /// 2: parse each locales/namespaces files (`LocalesOrNamespaces::new`)
+/// 2.1: fill the missing keys of the locales with a fallback chain (`fallbacks::resolve_fallbacks`)
 /// 3: Resolve foreign keys (`ParsedValue::resolve_foreign_keys`)
+/// 3.1: synthesize the pseudo locale from the default locale if requested (`pseudo::add_pseudo_locale`)
+/// 3.2: write the size report if requested (`size_report::write_report`)
+/// 3.3: load the renamed keys (`Renames::new`)
+/// 3.4: collect the source strings of the default locale for the gettext macros if requested (`gettext::create_gettext_macros`)
+/// 3.5: warn about the keys whose variables and components differ from the default locale (`interpolations::check_interpolations`)
 /// 4: check the locales: (`Locale::check_locales`)
 /// 4.1: get interpolations keys of the default, meaning all variables/components/plurals of the default locale (`Locale::make_builder_keys`)
 /// 4.2: in the process reduce all values and check for default in the default locale
 /// 4.3: then merge all other locales in the default locale keys, reducing all values in the process (`Locale::merge`)
 /// 4.4: discard any surplus key and emit a warning
 /// 5: generate code (and warnings)
-pub fn load_locales() -> Result<TokenStream> {
+/// 5.1: generate the metadata of the keys (`metadata::create_keys_metadata`)
+/// 5.1.1: generate the lookup of the keys at runtime if the `dynamic_keys` feature is enabled (`dynamic_keys::create_get_by_key`)
+/// 5.2: write the code generated for the key in `LEPTOS_I18N_DUMP` if set (`dump::write`)
+/// 6: print the timings of each step if requested (`timings::print_report`)
+pub fn load_locales(inline_cfg: Option<inline_cfg::InlineConfig>) -> Result<TokenStream> {
+    let start = std::time::Instant::now();
     let mut cargo_manifest_dir: PathBuf = std::env::var("CARGO_MANIFEST_DIR")
         .map_err(Error::CargoDirEnvNotPresent)?
         .into();
 
-    let cfg_file = ConfigFile::new(&mut cargo_manifest_dir)?;
+    let cfg_file = match inline_cfg {
+        Some(inline_cfg::InlineConfig(table)) => ConfigFile::from_inline(table)?,
+        None => ConfigFile::new(&mut cargo_manifest_dir)?,
+    };
+    if cfg_file.timings {
+        timings::enable();
+    }
+    cfg_file.negative_counts.set();
+    cfg_file.missing_keys.set();
+    parsed_value::set_normalize_unicode(cfg_file.normalize_unicode);
+    emoji::set_normalize_emoji(cfg_file.normalize_emoji);
+    dump::enable(cfg_file.name_spaces.is_some())?;
+    let manifest_dir_path = cargo_manifest_dir.clone();
     let mut locales = LocalesOrNamespaces::new(&mut cargo_manifest_dir, &cfg_file)?;
 
+    fallbacks::resolve_fallbacks(&mut locales, &cfg_file);
+
     ParsedValue::resolve_foreign_keys(&locales, &cfg_file.default)?;
 
+    pseudo::add_pseudo_locale(&mut locales, &cfg_file);
+
+    if let Some(report_path) = &cfg_file.size_report {
+        size_report::write_report(&manifest_dir_path, report_path, &locales, &cfg_file.locales)?;
+    }
+
+    let renames = Renames::new(&mut cargo_manifest_dir, &locales, &cfg_file.default)?;
+
+    let hot_reload_files = hot_reload::create_files_method(&locales);
+    let dynamic_load_urls = dynamic_load::create_url_methods(&cfg_file);
+
+    // the subkeys are moved out of the locales when checking them.
+    let gettext_macros = cfg_file
+        .gettext
+        .then(|| gettext::create_gettext_macros(&locales, &cfg_file.default));
+
+    interpolations::check_interpolations(&locales);
+
     let keys = Locale::check_locales(&mut locales)?;
 
-    let locale_type = create_locale_type(keys, &cfg_file);
+    let keys_metadata = metadata::create_keys_metadata(&keys);
+    let get_by_key = dynamic_keys::create_get_by_key(&keys, &cfg_file);
+
+    let locale_type = create_locale_type(keys, &cfg_file, &renames);
     let locale_enum = create_locales_enum(&cfg_file);
 
     let warnings = generate_warnings();
 
+    dump::write(&manifest_dir_path)?;
+
     let macros_reexport = if cfg!(feature = "interpolate_display") {
         quote!(
-            pub use leptos_i18n::{t, td, td_string, td_display};
+            pub use leptos_i18n::{t, td, t_string, td_string, td_display};
         )
     } else {
         quote!(
@@ -67,12 +140,20 @@ pub fn load_locales() -> Result<TokenStream> {
         )
     };
 
-    Ok(quote! {
+    let ts = quote! {
         pub mod i18n {
             #locale_enum
 
+            #hot_reload_files
+
+            #dynamic_load_urls
+
             #locale_type
 
+            #keys_metadata
+
+            #get_by_key
+
             #[inline]
             pub fn use_i18n() -> leptos_i18n::I18nContext<Locale> {
                 leptos_i18n::use_i18n_context()
@@ -83,16 +164,36 @@ pub fn load_locales() -> Result<TokenStream> {
                 leptos_i18n::provide_i18n_context()
             }
 
+            #[inline]
+            pub fn provide_isolated_i18n_context() -> leptos_i18n::I18nContext<Locale> {
+                leptos_i18n::provide_isolated_i18n_context()
+            }
+
             #macros_reexport
 
+            #gettext_macros
+
+            pub use leptos_i18n::components;
+
             #warnings
         }
-    })
+    };
+
+    timings::print_report(start.elapsed());
+
+    Ok(ts)
 }
 
 fn create_locales_enum(cfg_file: &ConfigFile) -> TokenStream {
     let ConfigFile {
-        default, locales, ..
+        default,
+        locales,
+        beta_locales,
+        rollout,
+        font_classes,
+        font_preloads,
+        persistence,
+        ..
     } = cfg_file;
 
     let as_str_match_arms = locales
@@ -107,17 +208,109 @@ fn create_locales_enum(cfg_file: &ConfigFile) -> TokenStream {
         .map(|(variant, locale)| quote!(#locale => Some(Locale::#variant)))
         .collect::<Vec<_>>();
 
+    let is_beta = (!beta_locales.is_empty()).then(|| {
+        quote! {
+            fn is_beta(self) -> bool {
+                matches!(self, #(Locale::#beta_locales)|*)
+            }
+        }
+    });
+
+    let rollout_percentage = (!rollout.is_empty()).then(|| {
+        let match_arms = rollout
+            .iter()
+            .map(|(locale, percentage)| quote!(Locale::#locale => Some(#percentage)));
+        quote! {
+            fn rollout_percentage(self) -> Option<u8> {
+                match self {
+                    #(#match_arms,)*
+                    _ => None
+                }
+            }
+        }
+    });
+
+    let font_class = (!font_classes.is_empty()).then(|| {
+        let match_arms = font_classes
+            .iter()
+            .map(|(locale, class)| quote!(Locale::#locale => Some(#class)));
+        quote! {
+            #[allow(unreachable_patterns)]
+            fn font_class(self) -> Option<&'static str> {
+                match self {
+                    #(#match_arms,)*
+                    _ => None
+                }
+            }
+        }
+    });
+
+    let font_preloads = (!font_preloads.is_empty()).then(|| {
+        let match_arms = font_preloads
+            .iter()
+            .map(|(locale, urls)| quote!(Locale::#locale => &[#(#urls,)*]));
+        quote! {
+            #[allow(unreachable_patterns)]
+            fn font_preloads(self) -> &'static [&'static str] {
+                match self {
+                    #(#match_arms,)*
+                    _ => &[]
+                }
+            }
+        }
+    });
+
+    let dynamic_load_urls = dynamic_load::create_urls_trait_method(cfg_file);
+
+    // the macro expands to a compilation error if the `local_storage` feature is disabled.
+    let persistence = (*persistence == cfg_file::Persistence::LocalStorage).then(|| {
+        quote! {
+            fn persistence() -> leptos_i18n::storage::Persistence {
+                leptos_i18n::__local_storage_persistence!()
+            }
+        }
+    });
+
     let derives = if cfg!(feature = "serde") {
-        quote!(#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, serde::Serialize, serde::Deserialize)])
+        quote!(#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)])
     } else {
-        quote!(#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)])
+        quote!(#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)])
     };
 
+    // (de)serialize the locales as their tag, still accepting the name of the variant for the values serialized before.
+    let variants = locales.iter().map(|key| {
+        let ident = &key.ident;
+        let name = &key.name;
+        if cfg!(feature = "serde") && *ident != name {
+            let variant_name = ident.to_string();
+            quote!(#[serde(rename = #name, alias = #variant_name)] #ident)
+        } else {
+            quote!(#ident)
+        }
+    });
+
     quote! {
         #derives
         #[allow(non_camel_case_types)]
         pub enum Locale {
-            #(#locales,)*
+            #(#variants,)*
+        }
+
+        impl Locale {
+            /// All the available locales, the default locale being the first one.
+            pub const ALL: &'static [Locale] = &[#(Locale::#locales,)*];
+
+            /// Return the tag of the locale, as declared in the configuration.
+            pub const fn as_str(self) -> &'static str {
+                match self {
+                    #(#as_str_match_arms,)*
+                }
+            }
+
+            /// Return an iterator over all the available locales, the default locale being the first one.
+            pub fn iter() -> impl Iterator<Item = Self> {
+                Self::ALL.iter().copied()
+            }
         }
 
         impl Default for Locale {
@@ -126,13 +319,25 @@ fn create_locales_enum(cfg_file: &ConfigFile) -> TokenStream {
             }
         }
 
+        impl core::fmt::Display for Locale {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(Locale::as_str(*self))
+            }
+        }
+
+        impl core::str::FromStr for Locale {
+            type Err = leptos_i18n::LocaleParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                <Locale as leptos_i18n::Locale>::try_from_str(s)
+            }
+        }
+
         impl leptos_i18n::Locale for Locale {
             type Keys = I18nKeys;
 
             fn as_str(self) -> &'static str {
-                match self {
-                    #(#as_str_match_arms,)*
-                }
+                Locale::as_str(self)
             }
             fn from_str(s: &str) -> Option<Self> {
                 match s.trim() {
@@ -140,12 +345,21 @@ fn create_locales_enum(cfg_file: &ConfigFile) -> TokenStream {
                     _ => None
                 }
             }
+            fn get_all() -> &'static [Self] {
+                Locale::ALL
+            }
+            #is_beta
+            #rollout_percentage
+            #font_class
+            #font_preloads
+            #dynamic_load_urls
+            #persistence
         }
     }
 }
 
 struct Subkeys<'a> {
-    original_key: &'a syn::Ident,
+    original_key: &'a Rc<Key>,
     key: syn::Ident,
     mod_key: syn::Ident,
     locales: &'a [Locale],
@@ -153,8 +367,8 @@ struct Subkeys<'a> {
 }
 
 impl<'a> Subkeys<'a> {
-    pub fn new(key: &'a Key, locales: &'a [Locale], keys: &'a BuildersKeysInner) -> Self {
-        let original_key = &key.ident;
+    pub fn new(key: &'a Rc<Key>, locales: &'a [Locale], keys: &'a BuildersKeysInner) -> Self {
+        let original_key = key;
         let mod_key = format_ident!("sk_{}", key.ident);
         let key = format_ident!("{}_subkeys", key.ident);
         Subkeys {
@@ -180,16 +394,94 @@ fn get_default_match(
     quote!(Locale::#default_locale #(| Locale::#missing_keys)*)
 }
 
+/// Group the locales with identical values, so each distinct value is generated once.
+///
+/// The default locale comes first, matched by `default_match` and by the locales without a value,
+/// so two locales identical after the fallback resolution (like `en` and `en-US`) share a single match arm.
+fn group_identical_locales<'a, T: PartialEq>(
+    locales: &'a [Locale],
+    default_match: &TokenStream,
+    get_value: impl Fn(&'a Locale) -> Option<T>,
+) -> Vec<(TokenStream, T)> {
+    let mut groups: Vec<(TokenStream, T)> = vec![];
+    let mut missing = vec![];
+    for (i, locale) in locales.iter().enumerate() {
+        let locale_key = &locale.top_locale_name;
+        let Some(value) = get_value(locale) else {
+            missing.push(locale_key);
+            continue;
+        };
+        if i == 0 {
+            groups.push((default_match.clone(), value));
+            continue;
+        }
+        match groups
+            .iter_mut()
+            .find(|(_, group_value)| *group_value == value)
+        {
+            Some((pattern, _)) => pattern.extend(quote!(| Locale::#locale_key)),
+            None => groups.push((quote!(Locale::#locale_key), value)),
+        }
+    }
+    if let Some((pattern, _)) = groups.first_mut() {
+        pattern.extend(quote!(#(| Locale::#missing)*));
+    }
+    groups
+}
+
+/// The context of the type generated for a level of keys: the top level, a namespace or subkeys.
+struct TypeContext<'a> {
+    default_locale: &'a Key,
+    top_locales: &'a HashSet<&'a Key>,
+    all_renames: &'a Renames,
+    key_path: KeyPath,
+    is_namespace: bool,
+}
+
+impl<'a> TypeContext<'a> {
+    fn new(
+        default_locale: &'a Key,
+        top_locales: &'a HashSet<&'a Key>,
+        all_renames: &'a Renames,
+        namespace: Option<Rc<Key>>,
+    ) -> Self {
+        TypeContext {
+            default_locale,
+            top_locales,
+            all_renames,
+            is_namespace: namespace.is_some(),
+            key_path: KeyPath::new(namespace),
+        }
+    }
+
+    fn subkey(&self, key: &Rc<Key>) -> Self {
+        let mut key_path = self.key_path.clone();
+        key_path.push_key(Rc::clone(key));
+        TypeContext {
+            key_path,
+            is_namespace: true,
+            ..*self
+        }
+    }
+}
+
 fn create_locale_type_inner(
-    default_locale: &Key,
+    ctx: &TypeContext,
     type_ident: &syn::Ident,
-    top_locales: &HashSet<&Key>,
     locales: &[Locale],
     keys: &HashMap<Rc<Key>, LocaleValue>,
-    is_namespace: bool,
 ) -> TokenStream {
+    let TypeContext {
+        default_locale,
+        top_locales,
+        all_renames,
+        ref key_path,
+        is_namespace,
+    } = *ctx;
     let default_match = get_default_match(default_locale, top_locales, locales);
 
+    let renames = all_renames.get(key_path);
+
     let string_keys = keys
         .iter()
         .filter(|(_, value)| matches!(value, LocaleValue::Value(None)))
@@ -198,7 +490,12 @@ fn create_locale_type_inner(
 
     let string_fields = string_keys
         .iter()
-        .map(|key| quote!(pub #key: &'static str))
+        .flat_map(|key| {
+            renames::decl_with_aliases(renames, key, |key| {
+                let str_type = dynamic_load::str_type();
+                quote!(pub #key: #str_type)
+            })
+        })
         .collect::<Vec<_>>();
 
     let subkeys = keys
@@ -212,12 +509,10 @@ fn create_locale_type_inner(
     let subkeys_ts = subkeys.iter().map(|sk| {
         let subkey_mod_ident = &sk.mod_key;
         let subkey_impl = create_locale_type_inner(
-            default_locale,
+            &ctx.subkey(sk.original_key),
             &sk.key,
-            top_locales,
             sk.locales,
             &sk.keys.0,
-            true,
         );
         quote! {
             pub mod #subkey_mod_ident {
@@ -228,20 +523,26 @@ fn create_locale_type_inner(
         }
     });
 
-    let subkeys_fields = subkeys.iter().map(|sk| {
-        let original_key = &sk.original_key;
+    let subkeys_fields = subkeys.iter().flat_map(|sk| {
         let key = &sk.key;
         let mod_ident = &sk.mod_key;
-        quote!(pub #original_key: subkeys::#mod_ident::#key)
+        renames::decl_with_aliases(
+            renames,
+            sk.original_key,
+            move |original_key| quote!(pub #original_key: subkeys::#mod_ident::#key),
+        )
     });
 
     let subkeys_field_new = subkeys
         .iter()
-        .map(|sk| {
-            let original_key = &sk.original_key;
+        .flat_map(|sk| {
             let key = &sk.key;
             let mod_ident = &sk.mod_key;
-            quote!(#original_key: subkeys::#mod_ident::#key::new(_locale))
+            renames::init_with_aliases(
+                renames,
+                sk.original_key,
+                move |original_key| quote!(#original_key: subkeys::#mod_ident::#key::new(_locale)),
+            )
         })
         .collect::<Vec<_>>();
 
@@ -268,39 +569,74 @@ fn create_locale_type_inner(
         })
         .collect::<Vec<_>>();
 
-    let builder_fields = builders.iter().map(|(key, inter)| {
+    let builder_fields = builders.iter().flat_map(|(key, inter)| {
         let inter_ident = &inter.default_generic_ident;
-        quote!(pub #key: builders::#inter_ident)
+        renames::decl_with_aliases(
+            renames,
+            key,
+            move |key| quote!(pub #key: builders::#inter_ident),
+        )
     });
 
     let init_builder_fields: Vec<TokenStream> = builders
         .iter()
-        .map(|(key, inter)| {
+        .flat_map(|(key, inter)| {
             let ident = &inter.ident;
-            quote!(#key: builders::#ident::new(_locale))
+            renames::init_with_aliases(
+                renames,
+                key,
+                move |key| quote!(#key: builders::#ident::new(_locale)),
+            )
         })
         .collect();
 
     let default_locale = locales.first().unwrap();
 
-    let new_match_arms = locales.iter().enumerate().map(|(i, locale)| {
-        let filled_string_fields =
-            string_keys
-                .iter()
-                .filter_map(|&key| match locale.keys.get(key) {
-                    Some(ParsedValue::String(str_value)) => Some(quote!(#key: #str_value)),
-                    _ => {
-                        let str_value = default_locale
-                            .keys
-                            .get(key)
-                            .and_then(ParsedValue::is_string)?;
-                        Some(quote!(#key: #str_value))
-                    }
-                });
+    let string_values = group_identical_locales(locales, &default_match, |locale| {
+        // with dynamic loading only the strings of the default locale are compiled in.
+        let locale = if cfg!(feature = "dynamic_load_strings") {
+            default_locale
+        } else {
+            locale
+        };
+        let values = string_keys
+            .iter()
+            .map(|&key| match locale.keys.get(key) {
+                Some(ParsedValue::String(str_value)) => Some(str_value.as_str()),
+                _ => default_locale
+                    .keys
+                    .get(key)
+                    .and_then(ParsedValue::is_string),
+            })
+            .collect::<Vec<_>>();
+        Some(values)
+    });
+
+    for (i, &key) in string_keys.iter().enumerate() {
+        let mut string_path = key_path.clone();
+        string_path.push_key(Rc::clone(key));
+        dump::record(&string_path, || {
+            let match_arms = string_values.iter().filter_map(|(pattern, values)| {
+                let str_value = dynamic_load::str_value(values[i]?, key_path, key);
+                Some(quote!(#pattern => #str_value))
+            });
+            quote!(#(#match_arms,)*)
+        });
+    }
+
+    let new_match_arms = string_values.iter().map(|(pattern, values)| {
+        let filled_string_fields = string_keys
+            .iter()
+            .zip(values)
+            .flat_map(|(&key, str_value)| {
+                str_value.iter().flat_map(move |&str_value| {
+                    renames::init_with_aliases(renames, key, move |alias| {
+                        let str_value = dynamic_load::str_value(str_value, key_path, key);
+                        quote!(#alias: #str_value)
+                    })
+                })
+            });
 
-        let ident = &locale.top_locale_name;
-        let pattern = (i != 0).then(|| quote!(Locale::#ident));
-        let pattern = pattern.as_ref().unwrap_or(&default_match);
         quote! {
             #pattern => #type_ident {
                 #(#filled_string_fields,)*
@@ -310,6 +646,12 @@ fn create_locale_type_inner(
         }
     });
 
+    for (key, inter) in &builders {
+        let mut builder_path = key_path.clone();
+        builder_path.push_key(Rc::clone(key));
+        dump::record(&builder_path, || inter.imp.clone());
+    }
+
     let builder_impls = builders.iter().map(|(_, inter)| &inter.imp);
 
     let builder_module = builders.is_empty().not().then(move || {
@@ -362,7 +704,12 @@ fn create_locale_type_inner(
         (None, None)
     };
 
-    quote! {
+    let allow_deprecated = renames
+        .is_empty()
+        .not()
+        .then(|| quote!(#[allow(deprecated)]));
+
+    let type_impl = quote! {
         #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
         #[allow(non_camel_case_types, non_snake_case)]
         pub struct #type_ident {
@@ -371,6 +718,7 @@ fn create_locale_type_inner(
             #(#subkeys_fields,)*
         }
 
+        #allow_deprecated
         impl #type_ident {
 
             #const_values
@@ -389,7 +737,11 @@ fn create_locale_type_inner(
         #builder_module
 
         #subkeys_module
-    }
+    };
+
+    dump::record(key_path, || type_impl.clone());
+
+    type_impl
 }
 
 fn create_namespace_mod_ident(namespace_ident: &syn::Ident) -> syn::Ident {
@@ -402,19 +754,21 @@ fn create_namespaces_types(
     namespaces: &[Namespace],
     top_locales: &HashSet<&Key>,
     keys: &HashMap<Rc<Key>, BuildersKeysInner>,
+    renames: &Renames,
 ) -> TokenStream {
     let namespaces_ts = namespaces.iter().map(|namespace| {
         let namespace_ident = &namespace.key.ident;
         let namespace_module_ident = create_namespace_mod_ident(namespace_ident);
         let keys = keys.get(&namespace.key).unwrap();
-        let type_impl = create_locale_type_inner(
-            default_locale,
-            namespace_ident,
-            top_locales,
-            &namespace.locales,
-            &keys.0,
-            true,
-        );
+        let type_impl = timings::time(Stage::Codegen, Some(&namespace.key), None, || {
+            let ctx = TypeContext::new(
+                default_locale,
+                top_locales,
+                renames,
+                Some(Rc::clone(&namespace.key)),
+            );
+            create_locale_type_inner(&ctx, namespace_ident, &namespace.locales, &keys.0)
+        });
         quote! {
             pub mod #namespace_module_ident {
                 use super::Locale;
@@ -492,7 +846,7 @@ fn create_namespaces_types(
     }
 }
 
-fn create_locale_type(keys: BuildersKeys, cfg_file: &ConfigFile) -> TokenStream {
+fn create_locale_type(keys: BuildersKeys, cfg_file: &ConfigFile, renames: &Renames) -> TokenStream {
     let top_locales = cfg_file.locales.iter().map(Deref::deref).collect();
     let default_locale = cfg_file.default.as_ref();
 
@@ -504,14 +858,54 @@ fn create_locale_type(keys: BuildersKeys, cfg_file: &ConfigFile) -> TokenStream
             namespaces,
             &top_locales,
             &keys,
+            renames,
         ),
-        BuildersKeys::Locales { locales, keys } => create_locale_type_inner(
-            default_locale,
-            &i18n_keys_ident,
-            &top_locales,
-            locales,
-            &keys.0,
-            false,
-        ),
+        BuildersKeys::Locales { locales, keys } => {
+            timings::time(Stage::Codegen, None, None, || {
+                let ctx = TypeContext::new(default_locale, &top_locales, renames, None);
+                create_locale_type_inner(&ctx, &i18n_keys_ident, locales, &keys.0)
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn locale(name: &str, value: &str) -> Locale {
+        let name = Rc::new(Key::new(name).unwrap());
+        let key = Rc::new(Key::new("key").unwrap());
+        Locale {
+            top_locale_name: Rc::clone(&name),
+            name,
+            keys: HashMap::from([(key, ParsedValue::String(value.to_owned()))]),
+            files: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_group_identical_locales() {
+        let locales = [
+            locale("en", "color"),
+            locale("fr", "couleur"),
+            locale("en_US", "color"),
+            locale("en_GB", "colour"),
+        ];
+        let groups = group_identical_locales(&locales, &quote!(Locale::en), |locale| {
+            locale.keys.values().next()
+        });
+        let patterns = groups
+            .iter()
+            .map(|(pattern, _)| pattern.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            patterns,
+            [
+                "Locale :: en | Locale :: en_US",
+                "Locale :: fr",
+                "Locale :: en_GB"
+            ]
+        );
     }
 }
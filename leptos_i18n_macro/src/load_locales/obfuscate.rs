@@ -0,0 +1,36 @@
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+// must match the keystream of `leptos_i18n::obfuscate`.
+const KEY_STEP: u8 = 0x9d;
+
+fn keystream(key: u8, index: usize) -> u8 {
+    key.wrapping_add((index as u8).wrapping_mul(KEY_STEP))
+}
+
+/// The type of the string keys.
+pub fn str_type() -> TokenStream {
+    if cfg!(feature = "obfuscate_strings") {
+        quote!(leptos_i18n::obfuscate::ObfuscatedStr)
+    } else {
+        quote!(&'static str)
+    }
+}
+
+/// The tokens of a string of the locales, an obfuscated string when the `obfuscate_strings` feature is enabled.
+pub fn str_value(s: &str) -> TokenStream {
+    if !cfg!(feature = "obfuscate_strings") {
+        return quote!(#s);
+    }
+    // the key only needs to vary between strings, not to be secret.
+    let key = s
+        .bytes()
+        .fold(0x5a_u8, |key, byte| key.rotate_left(3) ^ byte);
+    let bytes = s
+        .bytes()
+        .enumerate()
+        .map(|(i, byte)| byte ^ keystream(key, i))
+        .collect::<Vec<_>>();
+    let bytes = Literal::byte_string(&bytes);
+    quote!(leptos_i18n::obfuscate::ObfuscatedStr::new(#bytes, #key))
+}
@@ -1,22 +1,129 @@
 use std::{
-    cell::RefCell,
+    borrow::Cow,
+    cell::{Cell, RefCell},
     collections::{HashMap, HashSet},
     rc::Rc,
 };
 
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
-use serde::de::{value::MapAccessDeserializer, DeserializeSeed};
+use unicode_normalization::{is_nfc, UnicodeNormalization};
 
 use super::{
     error::{Error, Result},
+    formatter::{Formatter, FormatterKind},
     key::{Key, KeyPath},
     locale::{Locale, LocaleSeed, LocaleValue, LocalesOrNamespaces},
-    plural::{PluralType, Plurals},
+    obfuscate,
+    plural::{
+        CountTypes, OrdinalOrValue, PluralConstants, PluralKey, PluralType, Plurals,
+        COUNT_TYPE_KEY, ORDINAL_KEY,
+    },
+    plural_rules::PluralRuleType,
+    select::{Select, SELECT_KEY},
 };
+use crate::backend;
 
 thread_local! {
     pub static FOREIGN_KEYS: RefCell<HashSet<(Rc<Key>, KeyPath)>> = RefCell::new(HashSet::new());
+    static NORMALIZE_UNICODE: Cell<bool> = const { Cell::new(true) };
+}
+
+/// Set if the values are normalized to NFC when parsed, the default.
+pub fn set_normalize_unicode(normalize: bool) {
+    NORMALIZE_UNICODE.with(|normalize_unicode| normalize_unicode.set(normalize));
+}
+
+/// Normalize a value to NFC, so the strings typed in different editors compare equal
+/// and the placeholders match the identifiers of the code, normalized to NFC by rustc.
+fn normalize(value: &str) -> Cow<'_, str> {
+    if !NORMALIZE_UNICODE.with(Cell::get) || is_nfc(value) {
+        Cow::Borrowed(value)
+    } else {
+        Cow::Owned(value.nfc().collect())
+    }
+}
+
+/// The characters a backslash escapes, to write a literal `{{`, `}}`, `<tag>` or `$t(` in a value.
+const ESCAPABLE_CHARS: &[char] = &['{', '}', '<', '>', '$', '\\'];
+
+/// The attributes of a tag, with their values as written in the tag.
+type TagAttrs<'a> = Vec<(&'a str, Option<&'a str>)>;
+
+/// Split the content of a tag into its name and its attributes, `link href="{{ url }}"`.
+fn split_tag(tag: &str) -> (&str, &str) {
+    match tag.split_once(char::is_whitespace) {
+        Some((name, attrs)) => (name, attrs.trim()),
+        None => (tag, ""),
+    }
+}
+
+/// Parse the attributes of a tag, `href="{{ url }}" target=_blank disabled`, `None` if they are malformed.
+fn parse_tag_attrs(mut attrs: &str) -> Option<TagAttrs<'_>> {
+    let mut parsed = vec![];
+    loop {
+        attrs = attrs.trim_start();
+        if attrs.is_empty() {
+            return Some(parsed);
+        }
+        let name_end = attrs
+            .find(|c: char| c.is_whitespace() || c == '=')
+            .unwrap_or(attrs.len());
+        let (name, rest) = attrs.split_at(name_end);
+        let valid_name = name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | ':'));
+        if name.is_empty() || !valid_name {
+            return None;
+        }
+        let rest = rest.trim_start();
+        let Some(rest) = rest.strip_prefix('=') else {
+            parsed.push((name, None));
+            attrs = rest;
+            continue;
+        };
+        let rest = rest.trim_start();
+        let (value, rest) = match rest.chars().next()? {
+            quote @ ('"' | '\'') => rest[1..].split_once(quote)?,
+            _ => rest.split_at(rest.find(char::is_whitespace).unwrap_or(rest.len())),
+        };
+        parsed.push((name, Some(value)));
+        attrs = rest;
+    }
+}
+
+/// Whether the character at `index` is escaped, preceded by an odd number of backslashes.
+fn is_escaped(value: &str, index: usize) -> bool {
+    let backslashes = value.as_bytes()[..index]
+        .iter()
+        .rev()
+        .take_while(|b| **b == b'\\')
+        .count();
+    backslashes % 2 == 1
+}
+
+/// Like `str::split_once`, skipping the escaped occurrences of `pat`.
+fn split_once_unescaped<'a>(value: &'a str, pat: &str) -> Option<(&'a str, &'a str)> {
+    let (i, _) = value
+        .match_indices(pat)
+        .find(|(i, _)| !is_escaped(value, *i))?;
+    Some((&value[..i], &value[i + pat.len()..]))
+}
+
+/// Remove the backslashes escaping a special character, the other backslashes are kept.
+fn unescape(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match chars.peek() {
+            Some(next) if c == '\\' && ESCAPABLE_CHARS.contains(next) => {
+                unescaped.push(*next);
+                chars.next();
+            }
+            _ => unescaped.push(c),
+        }
+    }
+    unescaped
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -33,19 +140,41 @@ pub enum ParsedValue {
     Plural(Plurals),
     String(String),
     Variable(Rc<Key>),
+    /// `{{ count }}` in a plural, formatted with the number separators of the locale.
+    FormattedCount(Rc<Key>),
+    /// `{{ price, number }}`, formatted for the locale.
+    Formatted {
+        key: Rc<Key>,
+        formatter: Formatter,
+        locale: Rc<Key>,
+    },
+    /// A map with a `$select` key, choosing a branch from the value of a variable.
+    Select(Select),
     Component {
         key: Rc<Key>,
+        attrs: Vec<ComponentAttr>,
         inner: Box<Self>,
     },
     Bloc(Vec<Self>),
     Subkeys(Option<Locale>),
 }
 
+/// An attribute of a component tag, like `href="{{ url }}"`, the value is `None` for a boolean attribute like `disabled`.
+///
+/// Only variables are interpolated in the values, as a `String` or a `Bloc` of strings and variables.
+pub type ComponentAttr = (String, Option<ParsedValue>);
+
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
 pub enum InterpolateKey {
     Count(PluralType),
     Variable(Rc<Key>),
+    Formatted(Rc<Key>, FormatterKind),
+    Select(Rc<Key>),
+    /// A variable interpolated in the attribute of a component.
+    Attribute(Rc<Key>),
     Component(Rc<Key>),
+    /// A component whose tag has attributes, they are given to the component along its children.
+    ComponentWithAttrs(Rc<Key>),
 }
 
 impl ParsedValue {
@@ -107,6 +236,12 @@ impl ParsedValue {
                     key_path, top_locale
                 )))
             }
+            ParsedValue::Select(_) => {
+                return Err(Error::Custom(format!(
+                    "foreign key to selects is not supported yet, at key {} in locale {:?}",
+                    key_path, top_locale
+                )))
+            }
             _ => {}
         }
 
@@ -128,11 +263,18 @@ impl ParsedValue {
         path: &KeyPath,
     ) -> Result<()> {
         match self {
-            ParsedValue::Variable(_) | ParsedValue::String(_) | ParsedValue::Default => Ok(()),
+            ParsedValue::Variable(_)
+            | ParsedValue::FormattedCount(_)
+            | ParsedValue::Formatted { .. }
+            | ParsedValue::String(_)
+            | ParsedValue::Default => Ok(()),
             ParsedValue::Subkeys(_) => Ok(()), // unreachable ?
             ParsedValue::Plural(inner) => {
                 inner.resolve_foreign_keys(values, top_locale, default_locale, path)
             }
+            ParsedValue::Select(select) => select.try_for_each_value(|value| {
+                value.resolve_foreign_key(values, top_locale, default_locale, path)
+            }),
             ParsedValue::Component { inner, .. } => {
                 inner.resolve_foreign_key(values, top_locale, default_locale, path)
             }
@@ -169,15 +311,27 @@ impl ParsedValue {
         key_path: &KeyPath,
     ) -> Result<Self> {
         match self {
-            ParsedValue::Default | ParsedValue::ForeignKey(_) | ParsedValue::String(_) => {
-                Ok(self.clone())
-            }
+            ParsedValue::Default
+            | ParsedValue::ForeignKey(_)
+            | ParsedValue::String(_)
+            | ParsedValue::FormattedCount(_)
+            | ParsedValue::Formatted { .. } => Ok(self.clone()),
             ParsedValue::Variable(key) => match args.get(&key.name) {
                 Some(value) => Ok(ParsedValue::String(value.to_owned())),
                 None => Ok(ParsedValue::Variable(Rc::clone(key))),
             },
-            ParsedValue::Component { key, inner } => Ok(ParsedValue::Component {
+            ParsedValue::Component { key, attrs, inner } => Ok(ParsedValue::Component {
                 key: Rc::clone(key),
+                attrs: attrs
+                    .iter()
+                    .map(|(name, value)| {
+                        let value = value
+                            .as_ref()
+                            .map(|value| value.populate(args, foreign_key, locale, key_path))
+                            .transpose()?;
+                        Ok((name.clone(), value))
+                    })
+                    .collect::<Result<_>>()?,
                 inner: Box::new(inner.populate(args, foreign_key, locale, key_path)?),
             }),
             ParsedValue::Bloc(bloc) => bloc
@@ -185,24 +339,41 @@ impl ParsedValue {
                 .map(|value| value.populate(args, foreign_key, locale, key_path))
                 .collect::<Result<_>>()
                 .map(ParsedValue::Bloc),
-            ParsedValue::Subkeys(_) | ParsedValue::Plural(_) => Err(Error::InvalidForeignKey {
-                foreign_key: foreign_key.to_owned(),
-                locale: Rc::clone(locale),
-                key_path: key_path.to_owned(),
-            }),
+            ParsedValue::Subkeys(_) | ParsedValue::Plural(_) | ParsedValue::Select(_) => {
+                Err(Error::InvalidForeignKey {
+                    foreign_key: foreign_key.to_owned(),
+                    locale: Rc::clone(locale),
+                    key_path: key_path.to_owned(),
+                })
+            }
         }
     }
 
     pub fn get_keys_inner(&self, keys: &mut Option<HashSet<InterpolateKey>>) {
         match self {
-            ParsedValue::String(_) | ParsedValue::Subkeys(_) | ParsedValue::Default => {}
+            // the count is already registered by the plural
+            ParsedValue::String(_)
+            | ParsedValue::Subkeys(_)
+            | ParsedValue::Default
+            | ParsedValue::FormattedCount(_) => {}
             ParsedValue::Variable(key) => {
                 keys.get_or_insert_with(HashSet::new)
                     .insert(InterpolateKey::Variable(Rc::clone(key)));
             }
-            ParsedValue::Component { key, inner } => {
+            ParsedValue::Formatted { key, formatter, .. } => {
                 keys.get_or_insert_with(HashSet::new)
-                    .insert(InterpolateKey::Component(Rc::clone(key)));
+                    .insert(InterpolateKey::Formatted(Rc::clone(key), formatter.kind()));
+            }
+            ParsedValue::Component { key, attrs, inner } => {
+                let keys_set = keys.get_or_insert_with(HashSet::new);
+                if attrs.is_empty() {
+                    keys_set.insert(InterpolateKey::Component(Rc::clone(key)));
+                } else {
+                    keys_set.insert(InterpolateKey::ComponentWithAttrs(Rc::clone(key)));
+                    for value in attrs.iter().filter_map(|(_, value)| value.as_ref()) {
+                        value.get_attr_keys(keys_set);
+                    }
+                }
                 inner.get_keys_inner(keys);
             }
             ParsedValue::Bloc(values) => {
@@ -210,6 +381,7 @@ impl ParsedValue {
                     value.get_keys_inner(keys)
                 }
             }
+            ParsedValue::Select(select) => select.get_keys_inner(keys),
             ParsedValue::Plural(plurals) => {
                 plurals.get_keys_inner(keys);
                 let plural_type = plurals.get_type();
@@ -223,12 +395,117 @@ impl ParsedValue {
         }
     }
 
+    /// The variables of the value of an attribute.
+    fn get_attr_keys(&self, keys: &mut HashSet<InterpolateKey>) {
+        match self {
+            ParsedValue::Variable(key) => {
+                keys.insert(InterpolateKey::Attribute(Rc::clone(key)));
+            }
+            ParsedValue::Bloc(values) => {
+                for value in values {
+                    value.get_attr_keys(keys);
+                }
+            }
+            _ => {}
+        }
+    }
+
     pub fn get_keys(&self) -> Option<HashSet<InterpolateKey>> {
         let mut keys = None;
         self.get_keys_inner(&mut keys);
         keys
     }
 
+    /// Render the value back to text for error messages, with the placeholders highlighted as `>>{{ name }}<<`.
+    ///
+    /// Plural branches are separated by `|` and prefixed by the plural type.
+    pub fn preview(&self) -> String {
+        let mut preview = String::new();
+        self.preview_inner(&mut preview);
+        preview
+    }
+
+    fn preview_inner(&self, preview: &mut String) {
+        match self {
+            ParsedValue::Default | ParsedValue::Subkeys(_) => {}
+            ParsedValue::String(s) => preview.push_str(s),
+            ParsedValue::Variable(key) => {
+                let name = key.name.strip_prefix("var_").unwrap_or(&key.name);
+                preview.push_str(&format!(">>{{{{ {} }}}}<<", name));
+            }
+            ParsedValue::FormattedCount(_) => preview.push_str(">>{{ count }}<<"),
+            ParsedValue::Formatted { key, formatter, .. } => {
+                let name = key.name.strip_prefix("var_").unwrap_or(&key.name);
+                preview.push_str(&format!(">>{{{{ {}, {} }}}}<<", name, formatter.source()));
+            }
+            ParsedValue::Component { key, attrs, inner } => {
+                let name = key.name.strip_prefix("comp_").unwrap_or(&key.name);
+                let attrs = Self::attrs_source(attrs);
+                if inner.is_string() == Some("") {
+                    preview.push_str(&format!(">><{}{}/><<", name, attrs));
+                } else {
+                    preview.push_str(&format!(">><{}{}><<", name, attrs));
+                    inner.preview_inner(preview);
+                    preview.push_str(&format!(">></{}><<", name));
+                }
+            }
+            ParsedValue::Bloc(values) => {
+                for value in values {
+                    value.preview_inner(preview);
+                }
+            }
+            ParsedValue::Plural(plurals) => {
+                preview.push_str(&format!("[{}] ", plurals.get_type()));
+                let mut first = true;
+                let _: Result<_, ()> = plurals.try_for_each_value(|value| {
+                    if !std::mem::take(&mut first) {
+                        preview.push_str(" | ");
+                    }
+                    value.preview_inner(preview);
+                    Ok(())
+                });
+            }
+            ParsedValue::Select(select) => {
+                let name = select
+                    .key
+                    .name
+                    .strip_prefix("var_")
+                    .unwrap_or(&select.key.name);
+                preview.push_str(&format!("[select {}] ", name));
+                for (i, (branch, value)) in select.branches.iter().enumerate() {
+                    if i > 0 {
+                        preview.push_str(" | ");
+                    }
+                    preview.push_str(&format!("{}: ", branch));
+                    value.preview_inner(preview);
+                }
+            }
+            ParsedValue::ForeignKey(foreign_key) => match &*foreign_key.borrow() {
+                ForeignKey::NotSet(key_path, _) => {
+                    let path = key_path.to_string();
+                    preview.push_str(&format!(">>$t({})<<", path.trim_matches('"')))
+                }
+                ForeignKey::Set(value) => value.preview_inner(preview),
+            },
+        }
+    }
+
+    /// Replace `{{ count }}` by its formatted version.
+    fn format_counts(&mut self, locale: &Rc<Key>) {
+        match self {
+            ParsedValue::Variable(key) if key.name == "var_count" => {
+                *self = ParsedValue::FormattedCount(Rc::clone(locale))
+            }
+            ParsedValue::Component { inner, .. } => inner.format_counts(locale),
+            ParsedValue::Bloc(values) => {
+                for value in values {
+                    value.format_counts(locale)
+                }
+            }
+            _ => {}
+        }
+    }
+
     pub fn is_string(&self) -> Option<&str> {
         match self {
             ParsedValue::String(value) => Some(value),
@@ -245,9 +522,13 @@ impl ParsedValue {
         if let Some(variable) = Self::find_variable(value, key_path, locale) {
             return variable;
         }
+        // else look for `$t(...)` foreign keys
+        if let Some(foreign_key) = Self::find_translation_reference(value, key_path, locale) {
+            return foreign_key;
+        }
 
         // else it's just a string
-        ParsedValue::String(value.to_string())
+        ParsedValue::String(unescape(value))
     }
 
     pub fn make_locale_value(&mut self, key_path: &mut KeyPath) -> Result<LocaleValue> {
@@ -294,9 +575,30 @@ impl ParsedValue {
                 | ParsedValue::Plural(_)
                 | ParsedValue::String(_)
                 | ParsedValue::Variable(_)
+                | ParsedValue::FormattedCount(_)
+                | ParsedValue::Formatted { .. }
+                | ParsedValue::Select(_)
                 | ParsedValue::ForeignKey(_),
                 LocaleValue::Value(keys),
             ) => {
+                // catch conflicting counts here, where the offending value is still known.
+                if let ParsedValue::Plural(plurals) = &*self {
+                    let plural_type = plurals.get_type();
+                    let other_type = keys.iter().flatten().find_map(|key| match key {
+                        InterpolateKey::Count(other_type) if *other_type != plural_type => {
+                            Some(*other_type)
+                        }
+                        _ => None,
+                    });
+                    if let Some(other_type) = other_type {
+                        return Err(Error::PluralTypeMissmatch {
+                            key_path: std::mem::take(key_path),
+                            type1: other_type,
+                            type2: plural_type,
+                            preview: Some((top_locale, self.preview())),
+                        });
+                    }
+                }
                 self.get_keys_inner(keys);
                 Ok(())
             }
@@ -308,7 +610,7 @@ impl ParsedValue {
         }
     }
 
-    fn parse_key_path(path: &str) -> Option<KeyPath> {
+    pub fn parse_key_path(path: &str) -> Option<KeyPath> {
         let (mut key_path, path) = if let Some((namespace, rest)) = path.split_once("::") {
             let namespace = Key::new(namespace)?;
 
@@ -357,8 +659,8 @@ impl ParsedValue {
     }
 
     fn find_variable(value: &str, key_path: &KeyPath, locale: &Rc<Key>) -> Option<Self> {
-        let (before, rest) = value.split_once("{{")?;
-        let (ident, after) = rest.split_once("}}")?;
+        let (before, rest) = split_once_unescaped(value, "{{")?;
+        let (ident, after) = split_once_unescaped(rest, "}}")?;
 
         let ident = ident.trim();
 
@@ -370,6 +672,16 @@ impl ParsedValue {
         let this = match first_char {
             // foreign key
             '@' => Self::parse_foreign_key(ident, locale, key_path)?,
+            // formatted variable
+            _ if ident.contains(',') => {
+                let (ident, formatter) = ident.split_once(',')?;
+                let key = Key::new(&format!("var_{}", ident.trim()))?;
+                ParsedValue::Formatted {
+                    key: Rc::new(key),
+                    formatter: Formatter::parse(formatter)?,
+                    locale: Rc::clone(locale),
+                }
+            }
             // variable key
             _ => {
                 let ident = Key::new(&format!("var_{}", ident))?;
@@ -380,44 +692,162 @@ impl ParsedValue {
         Some(ParsedValue::Bloc(vec![before, this, after]))
     }
 
-    fn find_valid_component(value: &str) -> Option<(Rc<Key>, &str, &str, &str)> {
+    /// `$t(key.path, arg = 'value')` is another syntax for `{{ @key.path, arg = 'value' }}`.
+    fn find_translation_reference(
+        value: &str,
+        key_path: &KeyPath,
+        locale: &Rc<Key>,
+    ) -> Option<Self> {
+        let (before, rest) = split_once_unescaped(value, "$t(")?;
+        let (ident, after) = split_once_unescaped(rest, ")")?;
+
+        let this = Self::parse_foreign_key(&format!("@{}", ident.trim()), locale, key_path)?;
+
+        let before = Self::new(before, key_path, locale);
+        let after = Self::new(after, key_path, locale);
+
+        Some(ParsedValue::Bloc(vec![before, this, after]))
+    }
+
+    fn find_valid_component(value: &str) -> Option<(Rc<Key>, TagAttrs<'_>, &str, &str, &str)> {
         let mut skip_sum = 0;
         loop {
-            let (before, key, after, skip) = Self::find_opening_tag(&value[skip_sum..])?;
-            if let Some((key, beetween, after)) = Self::find_closing_tag(after, key) {
-                let before_len = skip_sum + before.len();
-                let before = &value[..before_len];
-                break Some((Rc::new(key), before, beetween, after));
-            } else {
-                skip_sum += skip;
+            let (before, tag, after, skip) = Self::find_opening_tag(&value[skip_sum..])?;
+            let before = &value[..skip_sum + before.len()];
+            // a self-closing tag `<br/>` is a component with no children.
+            let self_closing = tag.strip_suffix('/').filter(|tag| !tag.starts_with('/'));
+            let (name, attrs) = split_tag(self_closing.unwrap_or(tag).trim_end());
+            // tags with malformed attributes are left as is.
+            if let Some(attrs) = parse_tag_attrs(attrs) {
+                if self_closing.is_some() {
+                    if let Some(key) = Key::new(&format!("comp_{}", name)) {
+                        break Some((Rc::new(key), attrs, before, "", after));
+                    }
+                } else if let Some((key, beetween, after)) = Self::find_closing_tag(after, name) {
+                    break Some((Rc::new(key), attrs, before, beetween, after));
+                }
             }
+            skip_sum += skip;
         }
     }
 
     fn find_component(value: &str, key_path: &KeyPath, locale: &Rc<Key>) -> Option<Self> {
-        let (key, before, beetween, after) = Self::find_valid_component(value)?;
+        let (key, attrs, before, beetween, after) = Self::find_valid_component(value)?;
 
         let before = ParsedValue::new(before, key_path, locale);
         let beetween = ParsedValue::new(beetween, key_path, locale);
         let after = ParsedValue::new(after, key_path, locale);
 
+        let attrs = attrs
+            .into_iter()
+            .map(|(name, value)| {
+                let value = value.map(|value| {
+                    let mut value = Self::parse_attr_value(value);
+                    value.reduce();
+                    value
+                });
+                (name.to_owned(), value)
+            })
+            .collect();
+
         let this = ParsedValue::Component {
             key,
+            attrs,
             inner: beetween.into(),
         };
 
         Some(ParsedValue::Bloc(vec![before, this, after]))
     }
 
+    /// Only variables are interpolated in the value of an attribute.
+    fn parse_attr_value(value: &str) -> Self {
+        let variable = split_once_unescaped(value, "{{").and_then(|(before, rest)| {
+            let (ident, after) = split_once_unescaped(rest, "}}")?;
+            let key = Key::new(&format!("var_{}", ident.trim()))?;
+            Some((before, key, after))
+        });
+        let Some((before, key, after)) = variable else {
+            return ParsedValue::String(unescape(value));
+        };
+        ParsedValue::Bloc(vec![
+            ParsedValue::String(unescape(before)),
+            ParsedValue::Variable(Rc::new(key)),
+            Self::parse_attr_value(after),
+        ])
+    }
+
+    /// Write the attributes as in the locale file, ` href="{{ url }}" disabled`.
+    pub fn attrs_source(attrs: &[ComponentAttr]) -> String {
+        fn write_value(value: &ParsedValue, source: &mut String) {
+            match value {
+                ParsedValue::String(s) => source.push_str(s),
+                ParsedValue::Variable(key) => {
+                    let name = key.name.strip_prefix("var_").unwrap_or(&key.name);
+                    source.push_str(&format!("{{{{ {} }}}}", name));
+                }
+                ParsedValue::Bloc(values) => {
+                    for value in values {
+                        write_value(value, source);
+                    }
+                }
+                _ => {}
+            }
+        }
+        let mut source = String::new();
+        for (name, value) in attrs {
+            source.push(' ');
+            source.push_str(name);
+            if let Some(value) = value {
+                source.push_str("=\"");
+                write_value(value, &mut source);
+                source.push('"');
+            }
+        }
+        source
+    }
+
+    /// The attributes given to a component, a `(&'static str, Attribute)` tuple for each.
+    fn attrs_tokens(attrs: &[ComponentAttr]) -> Vec<TokenStream> {
+        fn value_parts(value: &ParsedValue, parts: &mut Vec<TokenStream>) {
+            match value {
+                ParsedValue::String(s) => parts.push(quote!(#s)),
+                ParsedValue::Variable(key) => parts.push(quote!(#key)),
+                ParsedValue::Bloc(values) => {
+                    for value in values {
+                        value_parts(value, parts);
+                    }
+                }
+                _ => {}
+            }
+        }
+        attrs
+            .iter()
+            .map(|(name, value)| {
+                let value = value.as_ref().map(|value| match value {
+                    ParsedValue::String(s) => quote!(#s),
+                    value => {
+                        let mut parts = vec![];
+                        value_parts(value, &mut parts);
+                        let fmt = "{}".repeat(parts.len());
+                        quote!(std::format!(#fmt, #(#parts),*))
+                    }
+                });
+                let attribute = backend::attribute(value);
+                quote!((#name, #attribute))
+            })
+            .collect()
+    }
+
     fn find_closing_tag<'a>(value: &'a str, key: &str) -> Option<(Key, &'a str, &'a str)> {
         let key_ident = Key::new(&format!("comp_{}", key))?;
         let mut indices = None;
         let mut depth = 0;
-        let iter = value.match_indices('<').filter_map(|(i, _)| {
-            value[i + 1..]
-                .split_once('>')
-                .map(|(ident, _)| (i, ident.trim()))
-        });
+        let iter = value
+            .match_indices('<')
+            .filter(|(i, _)| !is_escaped(value, *i))
+            .filter_map(|(i, _)| {
+                split_once_unescaped(&value[i + 1..], ">").map(|(ident, _)| (i, ident.trim()))
+            });
         for (i, ident) in iter {
             if let Some(closing_tag) = ident.strip_prefix('/').map(str::trim_start) {
                 if closing_tag != key {
@@ -429,7 +859,7 @@ impl ParsedValue {
                 } else {
                     depth -= 1;
                 }
-            } else if ident == key {
+            } else if split_tag(ident).0 == key && !ident.ends_with('/') {
                 depth += 1;
             }
         }
@@ -443,8 +873,8 @@ impl ParsedValue {
     }
 
     fn find_opening_tag(value: &str) -> Option<(&str, &str, &str, usize)> {
-        let (before, rest) = value.split_once('<')?;
-        let (ident, after) = rest.split_once('>')?;
+        let (before, rest) = split_once_unescaped(value, "<")?;
+        let (ident, after) = split_once_unescaped(rest, ">")?;
 
         let skip = before.len() + ident.len() + 2;
 
@@ -453,7 +883,11 @@ impl ParsedValue {
 
     pub fn reduce(&mut self) {
         match self {
-            ParsedValue::Variable(_) | ParsedValue::String(_) | ParsedValue::Default => {}
+            ParsedValue::Variable(_)
+            | ParsedValue::FormattedCount(_)
+            | ParsedValue::Formatted { .. }
+            | ParsedValue::String(_)
+            | ParsedValue::Default => {}
             ParsedValue::ForeignKey(foreign_key) => {
                 let value = foreign_key.get_mut().as_inner_mut("reduce");
                 value.reduce();
@@ -466,6 +900,12 @@ impl ParsedValue {
                     Ok(())
                 });
             }
+            ParsedValue::Select(select) => {
+                let _: Result<_, ()> = select.try_for_each_value_mut(|value| {
+                    value.reduce();
+                    Ok(())
+                });
+            }
             ParsedValue::Component { inner, .. } => inner.reduce(),
             ParsedValue::Subkeys(Some(subkeys)) => {
                 for value in subkeys.keys.values_mut() {
@@ -493,6 +933,7 @@ impl ParsedValue {
         match self {
             ParsedValue::Default => {}    // default in a bloc ? skip
             ParsedValue::Plural(_) => {}  // same for plural, can't be in a bloc
+            ParsedValue::Select(_) => {}  // same for select
             ParsedValue::Subkeys(_) => {} // same for subkeys
             ParsedValue::ForeignKey(foreign_key) => {
                 foreign_key
@@ -511,9 +952,15 @@ impl ParsedValue {
                 }
             }
             ParsedValue::Variable(key) => bloc.push(ParsedValue::Variable(key)),
-            ParsedValue::Component { key, mut inner } => {
+            ParsedValue::FormattedCount(locale) => bloc.push(ParsedValue::FormattedCount(locale)),
+            formatted @ ParsedValue::Formatted { .. } => bloc.push(formatted),
+            ParsedValue::Component {
+                key,
+                attrs,
+                mut inner,
+            } => {
                 inner.reduce();
-                bloc.push(ParsedValue::Component { key, inner });
+                bloc.push(ParsedValue::Component { key, attrs, inner });
             }
             ParsedValue::Bloc(inner) => {
                 for value in inner {
@@ -527,12 +974,37 @@ impl ParsedValue {
         match self {
             ParsedValue::Subkeys(_) | ParsedValue::Default => {}
             ParsedValue::String(s) if s.is_empty() => {}
-            ParsedValue::String(s) => tokens.push(quote!(leptos::IntoView::into_view(#s))),
+            ParsedValue::String(s) => tokens.push(backend::into_view(obfuscate::str_value(s))),
             ParsedValue::Plural(plurals) => tokens.push(plurals.to_token_stream()),
+            ParsedValue::Select(select) => tokens.push(select.to_token_stream()),
             ParsedValue::Variable(key) => {
-                tokens.push(quote!(leptos::IntoView::into_view(core::clone::Clone::clone(&#key))))
+                tokens.push(backend::into_view(quote!(core::clone::Clone::clone(&#key))))
+            }
+            ParsedValue::FormattedCount(locale) => {
+                let locale = &locale.name;
+                tokens.push(backend::into_view(quote!({
+                    let var_count = core::clone::Clone::clone(&var_count);
+                    move || leptos_i18n::format::FormattedNumber::new(var_count(), #locale)
+                })))
+            }
+            ParsedValue::Formatted {
+                key,
+                formatter,
+                locale,
+            } => {
+                let call = match formatter.kind() {
+                    FormatterKind::Number => quote!(leptos_i18n::format::NumberFn::call(&#key)),
+                    FormatterKind::DateTime => {
+                        quote!(leptos_i18n::format::DateTimeFn::call(&#key))
+                    }
+                };
+                let formatted = formatter.format(call, &locale.name);
+                tokens.push(backend::into_view(quote!({
+                    let #key = core::clone::Clone::clone(&#key);
+                    move || #formatted
+                })))
             }
-            ParsedValue::Component { key, inner } => {
+            ParsedValue::Component { key, attrs, inner } => {
                 let captured_keys = inner.get_keys().map(|keys| {
                     let keys = keys
                         .into_iter()
@@ -544,8 +1016,14 @@ impl ParsedValue {
                     #captured_keys
                     move || Into::into(#inner)
                 });
-                let boxed_fn = quote!(leptos::ToChildren::to_children(#f));
-                tokens.push(quote!(leptos::IntoView::into_view(core::clone::Clone::clone(&#key)(#boxed_fn))))
+                let boxed_fn = backend::to_children(f);
+                let call = if attrs.is_empty() {
+                    quote!(core::clone::Clone::clone(&#key)(#boxed_fn))
+                } else {
+                    let attrs = Self::attrs_tokens(attrs);
+                    quote!(core::clone::Clone::clone(&#key)(#boxed_fn, std::vec![#(#attrs),*]))
+                };
+                tokens.push(backend::into_view(call))
             }
             ParsedValue::Bloc(values) => {
                 for value in values {
@@ -563,14 +1041,39 @@ impl ParsedValue {
         match self {
             ParsedValue::Subkeys(_) | ParsedValue::Default => {}
             ParsedValue::String(s) if s.is_empty() => {}
-            ParsedValue::String(s) => tokens.push(quote!(core::fmt::Display::fmt(#s, __formatter))),
+            ParsedValue::String(s) => {
+                let s = obfuscate::str_value(s);
+                tokens.push(quote!(core::fmt::Display::fmt(&#s, __formatter)))
+            }
             ParsedValue::Plural(plurals) => tokens.push(plurals.as_string_impl()),
+            ParsedValue::Select(select) => tokens.push(select.as_string_impl()),
             ParsedValue::Variable(key) => {
                 tokens.push(quote!(core::fmt::Display::fmt(#key, __formatter)))
             }
-            ParsedValue::Component { key, inner } => {
+            ParsedValue::FormattedCount(locale) => {
+                let locale = &locale.name;
+                tokens.push(quote!(core::fmt::Display::fmt(
+                    &leptos_i18n::format::FormattedNumber::new(*var_count, #locale),
+                    __formatter
+                )))
+            }
+            ParsedValue::Formatted {
+                key,
+                formatter,
+                locale,
+            } => {
+                let formatted =
+                    formatter.format(quote!(core::clone::Clone::clone(#key)), &locale.name);
+                tokens.push(quote!(core::fmt::Display::fmt(&#formatted, __formatter)))
+            }
+            ParsedValue::Component { key, attrs, inner } => {
                 let inner = inner.as_string_impl();
-                tokens.push(quote!(leptos_i18n::display::DisplayComponent::fmt(#key, __formatter, |__formatter| #inner)))
+                if attrs.is_empty() {
+                    tokens.push(quote!(leptos_i18n::display::DisplayComponent::fmt(#key, __formatter, |__formatter| #inner)))
+                } else {
+                    let attrs = Self::attrs_tokens(attrs);
+                    tokens.push(quote!(leptos_i18n::display::DisplayComponent::fmt_with_attrs(#key, __formatter, &[#(#attrs),*], |__formatter| #inner)))
+                }
             }
             ParsedValue::Bloc(values) => {
                 for value in values {
@@ -623,40 +1126,66 @@ impl ForeignKey {
 impl InterpolateKey {
     pub fn as_ident(&self) -> syn::Ident {
         match self {
-            InterpolateKey::Variable(key) | InterpolateKey::Component(key) => key.ident.clone(),
+            InterpolateKey::Variable(key)
+            | InterpolateKey::Formatted(key, _)
+            | InterpolateKey::Select(key)
+            | InterpolateKey::Attribute(key)
+            | InterpolateKey::Component(key)
+            | InterpolateKey::ComponentWithAttrs(key) => key.ident.clone(),
             InterpolateKey::Count(_) => format_ident!("var_count"),
         }
     }
 
     pub fn as_key(&self) -> Option<&Key> {
         match self {
-            InterpolateKey::Variable(key) | InterpolateKey::Component(key) => Some(key),
+            InterpolateKey::Variable(key)
+            | InterpolateKey::Formatted(key, _)
+            | InterpolateKey::Select(key)
+            | InterpolateKey::Attribute(key)
+            | InterpolateKey::Component(key)
+            | InterpolateKey::ComponentWithAttrs(key) => Some(key),
             InterpolateKey::Count(_) => None,
         }
     }
 
-    #[cfg(feature = "debug_interpolations")]
     pub fn get_real_name(&self) -> &str {
         match self {
             InterpolateKey::Count(_) => "count",
-            InterpolateKey::Variable(key) => key.name.strip_prefix("var_").unwrap(),
-            InterpolateKey::Component(key) => key.name.strip_prefix("comp_").unwrap(),
+            InterpolateKey::Variable(key)
+            | InterpolateKey::Formatted(key, _)
+            | InterpolateKey::Select(key)
+            | InterpolateKey::Attribute(key) => key.name.strip_prefix("var_").unwrap(),
+            InterpolateKey::Component(key) | InterpolateKey::ComponentWithAttrs(key) => {
+                key.name.strip_prefix("comp_").unwrap()
+            }
         }
     }
 
     pub fn get_generic(&self) -> TokenStream {
         match self {
             InterpolateKey::Variable(_) => {
-                quote!(leptos::IntoView + core::clone::Clone + 'static)
+                let into_view = backend::into_view_trait();
+                quote!(#into_view + core::clone::Clone + 'static)
+            }
+            InterpolateKey::Formatted(_, kind) => kind.get_generic(),
+            InterpolateKey::Select(_) => quote!(leptos_i18n::select::SelectFn),
+            InterpolateKey::Attribute(_) => {
+                quote!(core::fmt::Display + core::clone::Clone + 'static)
             }
             InterpolateKey::Count(plural_type) => {
                 quote!(Fn() -> #plural_type + core::clone::Clone + 'static)
             }
-            InterpolateKey::Component(_) => quote!(
-                Fn(leptos::ChildrenFn) -> leptos::View
-                    + core::clone::Clone
-                    + 'static
-            ),
+            InterpolateKey::Component(_) => {
+                let children_fn = backend::children_fn_type();
+                let view = backend::view_type();
+                quote!(Fn(#children_fn) -> #view + core::clone::Clone + 'static)
+            }
+            InterpolateKey::ComponentWithAttrs(_) => {
+                let children_fn = backend::children_fn_type();
+                let attrs = backend::attributes_type();
+                let view = backend::view_type();
+                quote!(Fn(#children_fn, #attrs) -> #view + core::clone::Clone + 'static)
+            }
         }
     }
 
@@ -664,23 +1193,13 @@ impl InterpolateKey {
     pub fn get_string_generic(&self) -> Result<TokenStream, PluralType> {
         match self {
             InterpolateKey::Count(t) => Err(*t),
-            InterpolateKey::Variable(_) => Ok(quote!(core::fmt::Display)),
-            InterpolateKey::Component(_) => Ok(quote!(leptos_i18n::display::DisplayComponent)),
-        }
-    }
-
-    #[cfg(feature = "debug_interpolations")]
-    pub fn get_default(&self) -> TokenStream {
-        match self {
-            InterpolateKey::Variable(_) => {
-                quote!(())
+            InterpolateKey::Variable(_) | InterpolateKey::Attribute(_) => {
+                Ok(quote!(core::fmt::Display))
             }
-            InterpolateKey::Count(plural_type) => match plural_type {
-                PluralType::F32 | PluralType::F64 => quote!(|| 0.0),
-                _ => quote!(|| 0),
-            },
-            InterpolateKey::Component(_) => {
-                quote!(|_: leptos::ChildrenFn| core::default::Default::default())
+            InterpolateKey::Formatted(_, kind) => Ok(kind.get_string_generic()),
+            InterpolateKey::Select(_) => Ok(quote!(core::convert::AsRef<str>)),
+            InterpolateKey::Component(_) | InterpolateKey::ComponentWithAttrs(_) => {
+                Ok(quote!(leptos_i18n::display::DisplayComponent))
             }
         }
     }
@@ -698,9 +1217,9 @@ impl ToTokens for ParsedValue {
         self.flatten(&mut tokens);
 
         match &tokens[..] {
-            [] => quote!(leptos::View::default()),
+            [] => backend::empty_view(),
             [value] => value.clone(),
-            values => quote!(leptos::CollectView::collect_view([#(#values,)*])),
+            values => backend::collect_view(values),
         }
     }
 
@@ -715,6 +1234,9 @@ pub struct ParsedValueSeed<'a> {
     pub in_plural: bool,
     pub key_path: &'a KeyPath,
     pub key: &'a Rc<Key>,
+    pub plural_constants: &'a Rc<PluralConstants>,
+    pub count_types: &'a Rc<CountTypes>,
+    pub format_counts: bool,
 }
 
 impl<'de> serde::de::DeserializeSeed<'de> for ParsedValueSeed<'_> {
@@ -728,6 +1250,30 @@ impl<'de> serde::de::DeserializeSeed<'de> for ParsedValueSeed<'_> {
     }
 }
 
+impl ParsedValueSeed<'_> {
+    /// Return the count type configured for the plural at the current key path, if any.
+    pub fn count_type(&self) -> Option<PluralType> {
+        self.count_types.get(self.key_path).copied()
+    }
+
+    fn check_plurals(plurals: Plurals) -> Result<ParsedValue> {
+        let (invalid_fallback, fallback_count, should_have_fallback, has_category) =
+            plurals.check_deserialization();
+
+        if invalid_fallback {
+            Err(Error::InvalidFallback)
+        } else if fallback_count > 1 {
+            Err(Error::MultipleFallbacks)
+        } else if fallback_count == 0 && has_category {
+            Err(Error::MissingOtherCategory)
+        } else if fallback_count == 0 && should_have_fallback {
+            Err(Error::MissingFallback(plurals.get_type()))
+        } else {
+            Ok(ParsedValue::Plural(plurals))
+        }
+    }
+}
+
 impl<'de> serde::de::Visitor<'de> for ParsedValueSeed<'_> {
     type Value = ParsedValue;
 
@@ -735,10 +1281,26 @@ impl<'de> serde::de::Visitor<'de> for ParsedValueSeed<'_> {
     where
         E: serde::de::Error,
     {
-        Ok(ParsedValue::new(v, self.key_path, self.top_locale_name))
+        if cfg!(feature = "no_components") {
+            if let Some((key, ..)) = ParsedValue::find_valid_component(v) {
+                let name = key
+                    .name
+                    .strip_prefix("comp_")
+                    .unwrap_or(&key.name)
+                    .to_owned();
+                return Err(serde::de::Error::custom(Error::DisabledComponent { name }));
+            }
+        }
+        let v = normalize(v);
+        let v = super::emoji::normalize_emoji(&v);
+        let mut value = ParsedValue::new(&v, self.key_path, self.top_locale_name);
+        if self.in_plural && self.format_counts {
+            value.format_counts(self.top_locale_name);
+        }
+        Ok(value)
     }
 
-    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
     where
         A: serde::de::MapAccess<'de>,
     {
@@ -746,15 +1308,114 @@ impl<'de> serde::de::Visitor<'de> for ParsedValueSeed<'_> {
             return Err(serde::de::Error::custom(Error::PluralSubkeys));
         }
 
-        let map_de = MapAccessDeserializer::new(map);
+        // a map whose keys are counts ("0", "2..=4", "_", ...) is a plural, else it's subkeys.
+        let first_key = map.next_key::<PluralKey>()?;
+        let first_key = match first_key {
+            Some(PluralKey(key)) if key == SELECT_KEY => {
+                let variable: String = map.next_value()?;
+                let select = Select::from_serde_map(&variable, map, self)?;
+                return Ok(ParsedValue::Select(select));
+            }
+            Some(PluralKey(key)) if Plurals::is_plural_key(&key) => {
+                self.in_plural = true;
+                let plurals =
+                    Plurals::from_serde_map(key, None, PluralRuleType::Cardinal, map, self)?;
+                return Self::check_plurals(plurals).map_err(serde::de::Error::custom);
+            }
+            Some(PluralKey(key)) => Some(
+                Key::try_new(&key)
+                    .map(Rc::new)
+                    .map_err(serde::de::Error::custom)?,
+            ),
+            None => None,
+        };
 
         let seed = LocaleSeed {
             name: Rc::clone(self.key),
             top_locale_name: Rc::clone(self.top_locale_name),
             key_path: self.key_path.to_owned(),
+            plural_constants: Rc::clone(self.plural_constants),
+            count_types: Rc::clone(self.count_types),
+            format_counts: self.format_counts,
         };
 
-        seed.deserialize(map_de).map(Some).map(ParsedValue::Subkeys)
+        let mut keys = HashMap::new();
+        let first_key = match first_key {
+            // `"count_type"` is either the type of a plural declared as a map or a subkey,
+            // it's a plural if it's followed by counts.
+            Some(key) if key.name == COUNT_TYPE_KEY => {
+                let value = seed.clone().deserialize_value(&key, &mut map)?;
+                let next_key = map.next_key::<PluralKey>()?;
+                let count_type = value.is_string().and_then(PluralType::parse);
+                match (next_key, count_type) {
+                    (Some(PluralKey(next_key)), Some(count_type))
+                        if Plurals::is_plural_key(&next_key) || next_key == ORDINAL_KEY =>
+                    {
+                        self.in_plural = true;
+                        let plurals = Plurals::from_serde_map(
+                            next_key,
+                            Some(count_type),
+                            PluralRuleType::Cardinal,
+                            map,
+                            self,
+                        )?;
+                        return Self::check_plurals(plurals).map_err(serde::de::Error::custom);
+                    }
+                    (next_key, _) => {
+                        keys.insert(key, value);
+                        match next_key {
+                            Some(PluralKey(next_key)) => Some(
+                                Key::try_new(&next_key)
+                                    .map(Rc::new)
+                                    .map_err(serde::de::Error::custom)?,
+                            ),
+                            None => None,
+                        }
+                    }
+                }
+            }
+            // `"ordinal": true` marks an ordinal plural declared as a map, a string or a map being a subkey.
+            Some(key) if key.name == ORDINAL_KEY => {
+                match seed.clone().deserialize_ordinal(&key, &mut map)? {
+                    OrdinalOrValue::Ordinal(ordinal) => {
+                        let PluralKey(next_key) = map
+                            .next_key()?
+                            .ok_or(Error::EmptyPlural)
+                            .map_err(serde::de::Error::custom)?;
+                        self.in_plural = true;
+                        let plurals = Plurals::from_serde_map(
+                            next_key,
+                            None,
+                            PluralRuleType::from_ordinal(ordinal),
+                            map,
+                            self,
+                        )?;
+                        return Self::check_plurals(plurals).map_err(serde::de::Error::custom);
+                    }
+                    OrdinalOrValue::Value(value) => {
+                        keys.insert(key, value);
+                        map.next_key::<PluralKey>()?
+                            .map(|PluralKey(next_key)| Key::try_new(&next_key).map(Rc::new))
+                            .transpose()
+                            .map_err(serde::de::Error::custom)?
+                    }
+                }
+            }
+            first_key => first_key,
+        };
+
+        let keys = seed.clone().deserialize_keys(keys, first_key, map)?;
+        let LocaleSeed {
+            name,
+            top_locale_name,
+            ..
+        } = seed;
+        Ok(ParsedValue::Subkeys(Some(Locale {
+            name,
+            keys,
+            top_locale_name,
+            files: Vec::new(),
+        })))
     }
 
     fn visit_unit<E>(self) -> std::result::Result<Self::Value, E>
@@ -775,20 +1436,7 @@ impl<'de> serde::de::Visitor<'de> for ParsedValueSeed<'_> {
         }
         let plurals = Plurals::from_serde_seq(map, self)?;
 
-        let (invalid_fallback, fallback_count, should_have_fallback) =
-            plurals.check_deserialization();
-
-        if invalid_fallback {
-            Err(serde::de::Error::custom(Error::InvalidFallback))
-        } else if fallback_count > 1 {
-            Err(serde::de::Error::custom(Error::MultipleFallbacks))
-        } else if fallback_count == 0 && should_have_fallback {
-            Err(serde::de::Error::custom(Error::MissingFallback(
-                plurals.get_type(),
-            )))
-        } else {
-            Ok(ParsedValue::Plural(plurals))
-        }
+        Self::check_plurals(plurals).map_err(serde::de::Error::custom)
     }
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -835,6 +1483,31 @@ mod tests {
         )
     }
 
+    #[test]
+    fn parse_formatted_variable() {
+        let value = new_parsed_value("total: {{ price, currency(USD) }}");
+
+        assert_eq!(
+            value,
+            ParsedValue::Bloc(vec![
+                ParsedValue::String("total: ".to_string()),
+                ParsedValue::Formatted {
+                    key: new_key("var_price"),
+                    formatter: Formatter::Currency("USD".to_string()),
+                    locale: new_key("locale_key"),
+                },
+                ParsedValue::String(String::new())
+            ])
+        );
+
+        let value = new_parsed_value("{{ price, unknown }}");
+
+        assert_eq!(
+            value,
+            ParsedValue::String("{{ price, unknown }}".to_string())
+        );
+    }
+
     #[test]
     fn parse_comp() {
         let value = new_parsed_value("before <comp>inner</comp> after");
@@ -845,6 +1518,7 @@ mod tests {
                 ParsedValue::String("before ".to_string()),
                 ParsedValue::Component {
                     key: new_key("comp_comp"),
+                    attrs: vec![],
                     inner: Box::new(ParsedValue::String("inner".to_string()))
                 },
                 ParsedValue::String(" after".to_string())
@@ -864,10 +1538,12 @@ mod tests {
                 ParsedValue::String("before ".to_string()),
                 ParsedValue::Component {
                     key: new_key("comp_comp"),
+                    attrs: vec![],
                     inner: Box::new(ParsedValue::Bloc(vec![
                         ParsedValue::String("inner before".to_string()),
                         ParsedValue::Component {
                             key: new_key("comp_comp"),
+                            attrs: vec![],
                             inner: Box::new(ParsedValue::String("inner inner".to_string()))
                         },
                         ParsedValue::String("inner after".to_string()),
@@ -888,10 +1564,177 @@ mod tests {
                 ParsedValue::String("<p>test".to_string()),
                 ParsedValue::Component {
                     key: new_key("comp_h3"),
+                    attrs: vec![],
                     inner: Box::new(ParsedValue::String("this is a h3".to_string()))
                 },
                 ParsedValue::String("not closing p".to_string())
             ])
         )
     }
+
+    #[test]
+    fn parse_self_closing_comp() {
+        let value = new_parsed_value("first line<br/>second <b>line<icon /></b>");
+
+        assert_eq!(
+            value,
+            ParsedValue::Bloc(vec![
+                ParsedValue::String("first line".to_string()),
+                ParsedValue::Component {
+                    key: new_key("comp_br"),
+                    attrs: vec![],
+                    inner: Box::new(ParsedValue::String(String::new()))
+                },
+                ParsedValue::Bloc(vec![
+                    ParsedValue::String("second ".to_string()),
+                    ParsedValue::Component {
+                        key: new_key("comp_b"),
+                        attrs: vec![],
+                        inner: Box::new(ParsedValue::Bloc(vec![
+                            ParsedValue::String("line".to_string()),
+                            ParsedValue::Component {
+                                key: new_key("comp_icon"),
+                                attrs: vec![],
+                                inner: Box::new(ParsedValue::String(String::new()))
+                            },
+                            ParsedValue::String(String::new())
+                        ]))
+                    },
+                    ParsedValue::String(String::new())
+                ])
+            ])
+        )
+    }
+
+    #[test]
+    fn parse_comp_attrs() {
+        let value = new_parsed_value(
+            "see <link href=\"/users/{{ id }}\" target='_blank' download>profile</link>",
+        );
+
+        assert_eq!(
+            value,
+            ParsedValue::Bloc(vec![
+                ParsedValue::String("see ".to_string()),
+                ParsedValue::Component {
+                    key: new_key("comp_link"),
+                    attrs: vec![
+                        (
+                            "href".to_string(),
+                            Some(ParsedValue::Bloc(vec![
+                                ParsedValue::String("/users/".to_string()),
+                                ParsedValue::Variable(new_key("var_id")),
+                            ]))
+                        ),
+                        (
+                            "target".to_string(),
+                            Some(ParsedValue::String("_blank".to_string()))
+                        ),
+                        ("download".to_string(), None),
+                    ],
+                    inner: Box::new(ParsedValue::String("profile".to_string()))
+                },
+                ParsedValue::String(String::new())
+            ])
+        );
+
+        // malformed attributes leave the tag as is.
+        let value = new_parsed_value("<link href=\"unclosed>text</link>");
+        assert_eq!(
+            value,
+            ParsedValue::String("<link href=\"unclosed>text</link>".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_translation_reference() {
+        let value = new_parsed_value("before $t(common::app_name, count = '2') after");
+
+        assert_eq!(
+            value,
+            ParsedValue::Bloc(vec![
+                ParsedValue::String("before ".to_string()),
+                ParsedValue::ForeignKey(RefCell::new(ForeignKey::NotSet(
+                    ParsedValue::parse_key_path("common::app_name").unwrap(),
+                    HashMap::from([("var_count".to_string(), "2".to_string())])
+                ))),
+                ParsedValue::String(" after".to_string())
+            ])
+        );
+        assert_eq!(
+            new_parsed_value("$t(not a key)"),
+            ParsedValue::String("$t(not a key)".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_escaped() {
+        assert_eq!(
+            new_parsed_value(r"\{{ not a var }}, \<b>not a comp\</b>, \$t(not.a.key)"),
+            ParsedValue::String("{{ not a var }}, <b>not a comp</b>, $t(not.a.key)".to_string())
+        );
+        assert_eq!(
+            new_parsed_value(r"C:\path \\{{ var }} \\\{{ }}"),
+            ParsedValue::Bloc(vec![
+                ParsedValue::String(r"C:\path \".to_string()),
+                ParsedValue::Variable(new_key("var_var")),
+                ParsedValue::String(r" \{{ }}".to_string())
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_mixed_escaped() {
+        let value = new_parsed_value(r"\{{ {{ var }} \}} <b>\</b> {{ count }}</b>");
+
+        assert_eq!(
+            value,
+            ParsedValue::Bloc(vec![
+                ParsedValue::Bloc(vec![
+                    ParsedValue::String("{{ ".to_string()),
+                    ParsedValue::Variable(new_key("var_var")),
+                    ParsedValue::String(" }} ".to_string())
+                ]),
+                ParsedValue::Component {
+                    key: new_key("comp_b"),
+                    attrs: vec![],
+                    inner: Box::new(ParsedValue::Bloc(vec![
+                        ParsedValue::String("</b> ".to_string()),
+                        ParsedValue::Variable(new_key("var_count")),
+                        ParsedValue::String(String::new())
+                    ]))
+                },
+                ParsedValue::String(String::new())
+            ])
+        );
+    }
+
+    #[test]
+    fn preview() {
+        let value = new_parsed_value("Hi <b>{{ name }}</b>, see $t(common.help)");
+
+        assert_eq!(
+            value.preview(),
+            "Hi >><b><<>>{{ name }}<<>></b><<, see >>$t(common.help)<<"
+        );
+    }
+
+    #[test]
+    fn normalize_nfc() {
+        // "é" as "e" followed by a combining acute accent.
+        let value = normalize("Caf\u{65}\u{301} {{ cre\u{300}me }}");
+        assert_eq!(value, "Caf\u{e9} {{ cr\u{e8}me }}");
+        assert_eq!(
+            new_parsed_value(&value),
+            ParsedValue::Bloc(vec![
+                ParsedValue::String("Caf\u{e9} ".to_string()),
+                ParsedValue::Variable(new_key("var_cr\u{e8}me")),
+                ParsedValue::String(String::new())
+            ])
+        );
+
+        set_normalize_unicode(false);
+        assert_eq!(normalize("Caf\u{65}\u{301}"), "Caf\u{65}\u{301}");
+        set_normalize_unicode(true);
+    }
 }
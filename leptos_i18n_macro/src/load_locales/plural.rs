@@ -1,5 +1,6 @@
 use std::{
-    collections::HashSet,
+    cell::Cell,
+    collections::{HashMap, HashSet},
     marker::PhantomData,
     ops::{Bound, Not},
     rc::Rc,
@@ -9,6 +10,7 @@ use std::{
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 
+use crate::backend;
 use crate::load_locales::{
     key::{Key, KeyPath},
     locale::LocalesOrNamespaces,
@@ -17,30 +19,55 @@ use crate::load_locales::{
 use super::{
     error::{Error, Result},
     parsed_value::{InterpolateKey, ParsedValue, ParsedValueSeed},
+    plural_rules::{self, PluralCategory, PluralCondition, PluralRuleType},
 };
 
-#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, Default, Hash, PartialEq, Eq, Clone, Copy)]
 pub enum PluralType {
     I8,
     I16,
+    #[default]
     I32,
     I64,
+    I128,
     U8,
     U16,
     U32,
     U64,
+    U128,
     F32,
     F64,
 }
 
-impl Default for PluralType {
-    fn default() -> Self {
-        Self::I32
+/// The key setting the count type of a plural declared as a map, `"count_type": "f64"`.
+pub const COUNT_TYPE_KEY: &str = "count_type";
+
+/// The key marking a plural declared as a map as ordinal, `"ordinal": true`, its categories then following the ordinal rules.
+pub const ORDINAL_KEY: &str = "ordinal";
+
+/// The count types of plurals set in the configuration, by key path.
+pub type CountTypes = HashMap<KeyPath, PluralType>;
+
+impl PluralType {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim() {
+            "i8" => Some(PluralType::I8),
+            "i16" => Some(PluralType::I16),
+            "i32" => Some(PluralType::I32),
+            "i64" => Some(PluralType::I64),
+            "i128" => Some(PluralType::I128),
+            "u8" => Some(PluralType::U8),
+            "u16" => Some(PluralType::U16),
+            "u32" => Some(PluralType::U32),
+            "u64" => Some(PluralType::U64),
+            "u128" => Some(PluralType::U128),
+            "f32" => Some(PluralType::F32),
+            "f64" => Some(PluralType::F64),
+            _ => None,
+        }
     }
 }
 
-type DefaultPluralType = i32;
-
 impl core::fmt::Display for PluralType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -48,10 +75,12 @@ impl core::fmt::Display for PluralType {
             PluralType::I16 => f.write_str("i16"),
             PluralType::I32 => f.write_str("i32"),
             PluralType::I64 => f.write_str("i64"),
+            PluralType::I128 => f.write_str("i128"),
             PluralType::U8 => f.write_str("u8"),
             PluralType::U16 => f.write_str("u16"),
             PluralType::U32 => f.write_str("u32"),
             PluralType::U64 => f.write_str("u64"),
+            PluralType::U128 => f.write_str("u128"),
             PluralType::F32 => f.write_str("f32"),
             PluralType::F64 => f.write_str("f64"),
         }
@@ -65,10 +94,12 @@ impl ToTokens for PluralType {
             PluralType::I16 => quote!(i16),
             PluralType::I32 => quote!(i32),
             PluralType::I64 => quote!(i64),
+            PluralType::I128 => quote!(i128),
             PluralType::U8 => quote!(u8),
             PluralType::U16 => quote!(u16),
             PluralType::U32 => quote!(u32),
             PluralType::U64 => quote!(u64),
+            PluralType::U128 => quote!(u128),
             PluralType::F32 => quote!(f32),
             PluralType::F64 => quote!(f64),
         };
@@ -81,6 +112,53 @@ impl PluralType {
     const fn should_have_fallback(self) -> bool {
         matches!(self, PluralType::F64 | PluralType::F32)
     }
+
+    /// The count to match on, depending on how negative counts are handled.
+    fn count(self, count: TokenStream) -> TokenStream {
+        if NegativeCounts::get() == NegativeCounts::Literal {
+            return count;
+        }
+        match self {
+            PluralType::I8
+            | PluralType::I16
+            | PluralType::I32
+            | PluralType::I64
+            | PluralType::I128 => {
+                quote!(#self::saturating_abs(#count))
+            }
+            PluralType::F32 | PluralType::F64 => quote!(#self::abs(#count)),
+            PluralType::U8
+            | PluralType::U16
+            | PluralType::U32
+            | PluralType::U64
+            | PluralType::U128 => count,
+        }
+    }
+}
+
+/// How negative counts are matched against the plurals.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NegativeCounts {
+    /// Negative counts are matched as is, `-1` only matches a branch covering `-1`.
+    #[default]
+    Literal,
+    /// The absolute value of the count is matched, `-1` matches the same branch as `1`, like ICU does.
+    Absolute,
+}
+
+thread_local! {
+    static NEGATIVE_COUNTS: Cell<NegativeCounts> = const { Cell::new(NegativeCounts::Literal) };
+}
+
+impl NegativeCounts {
+    /// Set the policy used by the code generation of the plurals.
+    pub fn set(self) {
+        NEGATIVE_COUNTS.with(|negative_counts| negative_counts.set(self));
+    }
+
+    fn get() -> Self {
+        NEGATIVE_COUNTS.with(Cell::get)
+    }
 }
 
 pub type PluralsInner<T> = Vec<(Plural<T>, ParsedValue)>;
@@ -91,10 +169,12 @@ pub enum Plurals {
     I16(PluralsInner<i16>),
     I32(PluralsInner<i32>),
     I64(PluralsInner<i64>),
+    I128(PluralsInner<i128>),
     U8(PluralsInner<u8>),
     U16(PluralsInner<u16>),
     U32(PluralsInner<u32>),
     U64(PluralsInner<u64>),
+    U128(PluralsInner<u128>),
     F32(PluralsInner<f32>),
     F64(PluralsInner<f64>),
 }
@@ -107,10 +187,12 @@ impl Plurals {
             Plurals::I16(plurals) => Self::to_tokens_integers_string(plurals),
             Plurals::I32(plurals) => Self::to_tokens_integers_string(plurals),
             Plurals::I64(plurals) => Self::to_tokens_integers_string(plurals),
+            Plurals::I128(plurals) => Self::to_tokens_integers_string(plurals),
             Plurals::U8(plurals) => Self::to_tokens_integers_string(plurals),
             Plurals::U16(plurals) => Self::to_tokens_integers_string(plurals),
             Plurals::U32(plurals) => Self::to_tokens_integers_string(plurals),
             Plurals::U64(plurals) => Self::to_tokens_integers_string(plurals),
+            Plurals::U128(plurals) => Self::to_tokens_integers_string(plurals),
             Plurals::F32(plurals) => Self::to_tokens_floats_string(plurals),
             Plurals::F64(plurals) => Self::to_tokens_floats_string(plurals),
         }
@@ -139,10 +221,12 @@ impl Plurals {
             Plurals::I16(v) => inner(v, keys),
             Plurals::I32(v) => inner(v, keys),
             Plurals::I64(v) => inner(v, keys),
+            Plurals::I128(v) => inner(v, keys),
             Plurals::U8(v) => inner(v, keys),
             Plurals::U16(v) => inner(v, keys),
             Plurals::U32(v) => inner(v, keys),
             Plurals::U64(v) => inner(v, keys),
+            Plurals::U128(v) => inner(v, keys),
             Plurals::F32(v) => inner(v, keys),
             Plurals::F64(v) => inner(v, keys),
         }
@@ -166,10 +250,12 @@ impl Plurals {
             Plurals::I16(v) => inner(v, f),
             Plurals::I32(v) => inner(v, f),
             Plurals::I64(v) => inner(v, f),
+            Plurals::I128(v) => inner(v, f),
             Plurals::U8(v) => inner(v, f),
             Plurals::U16(v) => inner(v, f),
             Plurals::U32(v) => inner(v, f),
             Plurals::U64(v) => inner(v, f),
+            Plurals::U128(v) => inner(v, f),
             Plurals::F32(v) => inner(v, f),
             Plurals::F64(v) => inner(v, f),
         }
@@ -193,34 +279,107 @@ impl Plurals {
             Plurals::I16(v) => inner(v, f),
             Plurals::I32(v) => inner(v, f),
             Plurals::I64(v) => inner(v, f),
+            Plurals::I128(v) => inner(v, f),
             Plurals::U8(v) => inner(v, f),
             Plurals::U16(v) => inner(v, f),
             Plurals::U32(v) => inner(v, f),
             Plurals::U64(v) => inner(v, f),
+            Plurals::U128(v) => inner(v, f),
             Plurals::F32(v) => inner(v, f),
             Plurals::F64(v) => inner(v, f),
         }
     }
 
+    /// Return the values rendered for a count of `1` and for the fallback,
+    /// the singular and plural forms of gettext's `ngettext`.
+    pub fn gettext_forms(&self) -> Option<(&ParsedValue, &ParsedValue)> {
+        fn inner<T: PluralNumber>(v: &PluralsInner<T>) -> Option<(&ParsedValue, &ParsedValue)> {
+            let one = T::from_u64(1)?;
+            // the first branch matching is rendered, like the generated `match`.
+            let (_, singular) = v.iter().find(|(plural, _)| plural.matches_one(one))?;
+            let (_, plural) = v.iter().find(|(plural, _)| plural.is_fallback())?;
+            Some((singular, plural))
+        }
+        match self {
+            Plurals::I8(v) => inner(v),
+            Plurals::I16(v) => inner(v),
+            Plurals::I32(v) => inner(v),
+            Plurals::I64(v) => inner(v),
+            Plurals::I128(v) => inner(v),
+            Plurals::U8(v) => inner(v),
+            Plurals::U16(v) => inner(v),
+            Plurals::U32(v) => inner(v),
+            Plurals::U64(v) => inner(v),
+            Plurals::U128(v) => inner(v),
+            Plurals::F32(v) => inner(v),
+            Plurals::F64(v) => inner(v),
+        }
+    }
+
     pub const fn get_type(&self) -> PluralType {
         match self {
             Plurals::I8(_) => PluralType::I8,
             Plurals::I16(_) => PluralType::I16,
             Plurals::I32(_) => PluralType::I32,
             Plurals::I64(_) => PluralType::I64,
+            Plurals::I128(_) => PluralType::I128,
             Plurals::U8(_) => PluralType::U8,
             Plurals::U16(_) => PluralType::U16,
             Plurals::U32(_) => PluralType::U32,
             Plurals::U64(_) => PluralType::U64,
+            Plurals::U128(_) => PluralType::U128,
             Plurals::F32(_) => PluralType::F32,
             Plurals::F64(_) => PluralType::F64,
         }
     }
 
+    /// The operands of the count if some plurals use plural categories.
+    fn operands<T: PluralNumber>(plurals: &[(Plural<T>, ParsedValue)]) -> Option<TokenStream> {
+        plurals.iter().any(|(plural, _)| plural.has_category()).then(|| {
+            quote!(let plural_operands = leptos_i18n::plurals::PluralOperands::from(plural_count);)
+        })
+    }
+
+    fn to_match_arm<T: PluralInteger>(plural: &Plural<T>, value: TokenStream) -> TokenStream {
+        if plural.has_category() {
+            let condition = Self::to_condition(plural);
+            quote!(_ if #condition => #value)
+        } else {
+            quote!(#plural => #value)
+        }
+    }
+
+    fn to_match_statement<T: PluralInteger>(
+        plurals: &[(Plural<T>, ParsedValue)],
+        count: TokenStream,
+        match_arms: impl Iterator<Item = TokenStream>,
+    ) -> TokenStream {
+        match Self::operands(plurals) {
+            None => quote! {
+                match #count {
+                    #(
+                        #match_arms,
+                    )*
+                }
+            },
+            Some(operands) => quote! {
+                {
+                    let plural_count = #count;
+                    #operands
+                    match plural_count {
+                        #(
+                            #match_arms,
+                        )*
+                    }
+                }
+            },
+        }
+    }
+
     fn to_tokens_integers<T: PluralInteger>(plurals: &[(Plural<T>, ParsedValue)]) -> TokenStream {
         let match_arms = plurals
             .iter()
-            .map(|(plural, value)| quote!(#plural => #value));
+            .map(|(plural, value)| Self::to_match_arm(plural, value.to_token_stream()));
 
         let mut captured_values = None;
 
@@ -234,45 +393,32 @@ impl Plurals {
                 .map(|key| quote!(let #key = core::clone::Clone::clone(&#key);));
             quote!(#(#keys)*)
         });
-        let match_statement = quote! {
-            match var_count() {
-                #(
-                    #match_arms,
-                )*
-            }
-        };
-
-        quote! {
-            leptos::IntoView::into_view(
-                {
-                    #captured_values
-                    move || #match_statement
-                },
+        let count = T::TYPE.count(quote!(var_count()));
+        let match_statement = Self::to_match_statement(plurals, count, match_arms);
 
-            )
-        }
+        backend::into_view(quote! {
+            {
+                #captured_values
+                move || #match_statement
+            }
+        })
     }
 
     #[cfg(feature = "interpolate_display")]
     fn to_tokens_integers_string<T: PluralInteger>(
         plurals: &[(Plural<T>, ParsedValue)],
     ) -> TokenStream {
-        let match_arms = plurals.iter().map(|(plural, value)| {
-            let value = value.as_string_impl();
-            quote!(#plural => #value)
-        });
+        let match_arms = plurals
+            .iter()
+            .map(|(plural, value)| Self::to_match_arm(plural, value.as_string_impl()));
 
-        quote! {
-            match *var_count {
-                #(
-                    #match_arms,
-                )*
-            }
-        }
+        let count = T::TYPE.count(quote!(*var_count));
+        Self::to_match_statement(plurals, count, match_arms)
     }
 
-    fn to_condition<T: PluralFloats>(plural: &Plural<T>) -> Option<TokenStream> {
+    fn to_condition<T: PluralNumber>(plural: &Plural<T>) -> Option<TokenStream> {
         match plural {
+            Plural::Category { condition, .. } => Some(quote!(#condition)),
             Plural::Exact(exact) => Some(quote!(plural_count == #exact)),
             Plural::Range { .. } => {
                 Some(quote!(core::ops::RangeBounds::contains(&(#plural), &plural_count)))
@@ -312,18 +458,18 @@ impl Plurals {
             quote!(#(#keys)*)
         });
 
-        quote! {
-            leptos::IntoView::into_view(
-                {
-                    #captured_values
-                    move || {
-                        let plural_count = var_count();
-                        #ifs
-                    }
-                },
-
-            )
-        }
+        let count = T::TYPE.count(quote!(var_count()));
+        let operands = Self::operands(plurals);
+        backend::into_view(quote! {
+            {
+                #captured_values
+                move || {
+                    let plural_count = #count;
+                    #operands
+                    #ifs
+                }
+            }
+        })
     }
 
     #[cfg(feature = "interpolate_display")]
@@ -343,9 +489,12 @@ impl Plurals {
             #(else #ifs)*
         };
 
+        let count = T::TYPE.count(quote!(*var_count));
+        let operands = Self::operands(plurals);
         quote! {
             {
-                let plural_count = *var_count;
+                let plural_count = #count;
+                #operands
                 #ifs
             }
         }
@@ -380,10 +529,12 @@ impl Plurals {
             Plurals::I16(plurals) => Self::deserialize_all_pairs(seq, plurals, parsed_value_seed),
             Plurals::I32(plurals) => Self::deserialize_all_pairs(seq, plurals, parsed_value_seed),
             Plurals::I64(plurals) => Self::deserialize_all_pairs(seq, plurals, parsed_value_seed),
+            Plurals::I128(plurals) => Self::deserialize_all_pairs(seq, plurals, parsed_value_seed),
             Plurals::U8(plurals) => Self::deserialize_all_pairs(seq, plurals, parsed_value_seed),
             Plurals::U16(plurals) => Self::deserialize_all_pairs(seq, plurals, parsed_value_seed),
             Plurals::U32(plurals) => Self::deserialize_all_pairs(seq, plurals, parsed_value_seed),
             Plurals::U64(plurals) => Self::deserialize_all_pairs(seq, plurals, parsed_value_seed),
+            Plurals::U128(plurals) => Self::deserialize_all_pairs(seq, plurals, parsed_value_seed),
             Plurals::F32(plurals) => Self::deserialize_all_pairs(seq, plurals, parsed_value_seed),
             Plurals::F64(plurals) => Self::deserialize_all_pairs(seq, plurals, parsed_value_seed),
         }
@@ -395,10 +546,12 @@ impl Plurals {
             PluralType::I16 => Self::I16(vec![]),
             PluralType::I32 => Self::I32(vec![]),
             PluralType::I64 => Self::I64(vec![]),
+            PluralType::I128 => Self::I128(vec![]),
             PluralType::U8 => Self::U8(vec![]),
             PluralType::U16 => Self::U16(vec![]),
             PluralType::U32 => Self::U32(vec![]),
             PluralType::U64 => Self::U64(vec![]),
+            PluralType::U128 => Self::U128(vec![]),
             PluralType::F32 => Self::F32(vec![]),
             PluralType::F64 => Self::F64(vec![]),
         }
@@ -411,23 +564,173 @@ impl Plurals {
     where
         A: serde::de::SeqAccess<'de>,
     {
+        // a type given in the locale file takes precedence over the configured one.
+        let count_type = parsed_value_seed.count_type().unwrap_or_default();
         let type_or_plural = seq
-            .next_element_seed(TypeOrPluralSeed(parsed_value_seed))?
+            .next_element_seed(TypeOrPluralSeed(parsed_value_seed, count_type))?
             .ok_or_else(|| Error::EmptyPlural)
             .map_err(serde::de::Error::custom)?;
 
         let mut plurals = match type_or_plural {
             TypeOrPlural::Type(plural_type) => Self::from_type(plural_type),
-            TypeOrPlural::Plural(plural) => Plurals::I32(vec![plural]),
+            TypeOrPlural::Plural(plurals) => plurals,
         };
 
         plurals.deserialize_inner(seq, parsed_value_seed)?;
         Ok(plurals)
     }
 
+    /// Whether a key of a map is a count rather than a subkey, the map then being a plural.
+    pub fn is_plural_key(key: &str) -> bool {
+        let key = key.trim_start();
+        Key::new(key).is_none()
+            && key.starts_with(|c: char| c.is_ascii_digit() || matches!(c, '-' | '.' | '_' | '{'))
+    }
+
+    /// Deserialize a plural declared as a map of counts to values, `first_key` being the key already taken from it.
+    ///
+    /// `count_type` is the type given by a `"count_type"` entry already taken from the map, if any,
+    /// and `rule_type` the rules given by an `"ordinal"` entry.
+    pub fn from_serde_map<'de, A>(
+        first_key: String,
+        mut count_type: Option<PluralType>,
+        mut rule_type: PluralRuleType,
+        mut map: A,
+        parsed_value_seed: ParsedValueSeed,
+    ) -> Result<Self, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        // the counts are parsed once the type and the rules are known, they can be declared after them.
+        let mut pairs = vec![];
+        let mut next_key = Some(first_key);
+        while let Some(key) = next_key {
+            if key == COUNT_TYPE_KEY {
+                let value: String = map.next_value()?;
+                let plural_type = PluralType::parse(&value)
+                    .ok_or(Error::InvalidPluralType(value))
+                    .map_err(serde::de::Error::custom)?;
+                count_type = Some(plural_type);
+            } else if key == ORDINAL_KEY {
+                rule_type = PluralRuleType::from_ordinal(map.next_value()?);
+            } else {
+                let value = map.next_value_seed(parsed_value_seed)?;
+                pairs.push((key, value));
+            }
+            next_key = map.next_key::<PluralKey>()?.map(|PluralKey(key)| key);
+        }
+        let count_type = count_type
+            .or_else(|| parsed_value_seed.count_type())
+            .unwrap_or_default();
+        let mut plurals = Self::from_type(count_type);
+        plurals
+            .push_pairs(pairs, rule_type, parsed_value_seed)
+            .map_err(serde::de::Error::custom)?;
+        Ok(plurals)
+    }
+
+    fn parse_pairs<T: PluralNumber>(
+        plurals: &mut PluralsInner<T>,
+        pairs: Vec<(String, ParsedValue)>,
+        rule_type: PluralRuleType,
+        parsed_value_seed: ParsedValueSeed,
+    ) -> Result<()> {
+        for (key, value) in pairs {
+            let plural = Plural::with_rules(
+                &key,
+                parsed_value_seed.plural_constants,
+                &parsed_value_seed.top_locale_name.name,
+                rule_type,
+            )?;
+            plurals.push((plural, value));
+        }
+        Ok(())
+    }
+
+    fn push_pairs(
+        &mut self,
+        pairs: Vec<(String, ParsedValue)>,
+        rule_type: PluralRuleType,
+        parsed_value_seed: ParsedValueSeed,
+    ) -> Result<()> {
+        match self {
+            Plurals::I8(plurals) => Self::parse_pairs(plurals, pairs, rule_type, parsed_value_seed),
+            Plurals::I16(plurals) => {
+                Self::parse_pairs(plurals, pairs, rule_type, parsed_value_seed)
+            }
+            Plurals::I32(plurals) => {
+                Self::parse_pairs(plurals, pairs, rule_type, parsed_value_seed)
+            }
+            Plurals::I64(plurals) => {
+                Self::parse_pairs(plurals, pairs, rule_type, parsed_value_seed)
+            }
+            Plurals::I128(plurals) => {
+                Self::parse_pairs(plurals, pairs, rule_type, parsed_value_seed)
+            }
+            Plurals::U8(plurals) => Self::parse_pairs(plurals, pairs, rule_type, parsed_value_seed),
+            Plurals::U16(plurals) => {
+                Self::parse_pairs(plurals, pairs, rule_type, parsed_value_seed)
+            }
+            Plurals::U32(plurals) => {
+                Self::parse_pairs(plurals, pairs, rule_type, parsed_value_seed)
+            }
+            Plurals::U64(plurals) => {
+                Self::parse_pairs(plurals, pairs, rule_type, parsed_value_seed)
+            }
+            Plurals::U128(plurals) => {
+                Self::parse_pairs(plurals, pairs, rule_type, parsed_value_seed)
+            }
+            Plurals::F32(plurals) => {
+                Self::parse_pairs(plurals, pairs, rule_type, parsed_value_seed)
+            }
+            Plurals::F64(plurals) => {
+                Self::parse_pairs(plurals, pairs, rule_type, parsed_value_seed)
+            }
+        }
+    }
+
+    fn deserialize_pair_inner<'de, D, T>(
+        plurals: &mut PluralsInner<T>,
+        deserializer: D,
+        parsed_value_seed: ParsedValueSeed,
+    ) -> Result<(), D::Error>
+    where
+        D: serde::Deserializer<'de>,
+        T: PluralNumber,
+    {
+        let plural_seed = PluralStructSeed::<T>(parsed_value_seed, PhantomData);
+        let pair = serde::de::DeserializeSeed::deserialize(plural_seed, deserializer)?;
+        plurals.push(pair);
+        Ok(())
+    }
+
+    fn deserialize_pair<'de, D>(
+        &mut self,
+        deserializer: D,
+        parsed_value_seed: ParsedValueSeed,
+    ) -> Result<(), D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match self {
+            Plurals::I8(p) => Self::deserialize_pair_inner(p, deserializer, parsed_value_seed),
+            Plurals::I16(p) => Self::deserialize_pair_inner(p, deserializer, parsed_value_seed),
+            Plurals::I32(p) => Self::deserialize_pair_inner(p, deserializer, parsed_value_seed),
+            Plurals::I64(p) => Self::deserialize_pair_inner(p, deserializer, parsed_value_seed),
+            Plurals::I128(p) => Self::deserialize_pair_inner(p, deserializer, parsed_value_seed),
+            Plurals::U8(p) => Self::deserialize_pair_inner(p, deserializer, parsed_value_seed),
+            Plurals::U16(p) => Self::deserialize_pair_inner(p, deserializer, parsed_value_seed),
+            Plurals::U32(p) => Self::deserialize_pair_inner(p, deserializer, parsed_value_seed),
+            Plurals::U64(p) => Self::deserialize_pair_inner(p, deserializer, parsed_value_seed),
+            Plurals::U128(p) => Self::deserialize_pair_inner(p, deserializer, parsed_value_seed),
+            Plurals::F32(p) => Self::deserialize_pair_inner(p, deserializer, parsed_value_seed),
+            Plurals::F64(p) => Self::deserialize_pair_inner(p, deserializer, parsed_value_seed),
+        }
+    }
+
     fn check_de_inner<T: PluralNumber>(
         plurals: &[(Plural<T>, ParsedValue)],
-    ) -> (bool, usize, bool) {
+    ) -> (bool, usize, bool, bool) {
         // easy to avoid compile warning, check if a fallback is not at the end position
         let invalid_fallback = plurals
             .iter()
@@ -447,23 +750,28 @@ impl Plurals {
             .filter(|(plural, _)| matches!(plural, Plural::Fallback))
             .count();
 
+        let has_category = plurals.iter().any(|(plural, _)| plural.has_category());
+
         (
             invalid_fallback,
             fallback_count,
             T::TYPE.should_have_fallback(),
+            has_category,
         )
     }
 
-    pub fn check_deserialization(&self) -> (bool, usize, bool) {
+    pub fn check_deserialization(&self) -> (bool, usize, bool, bool) {
         match self {
             Plurals::I8(plurals) => Self::check_de_inner(plurals),
             Plurals::I16(plurals) => Self::check_de_inner(plurals),
             Plurals::I32(plurals) => Self::check_de_inner(plurals),
             Plurals::I64(plurals) => Self::check_de_inner(plurals),
+            Plurals::I128(plurals) => Self::check_de_inner(plurals),
             Plurals::U8(plurals) => Self::check_de_inner(plurals),
             Plurals::U16(plurals) => Self::check_de_inner(plurals),
             Plurals::U32(plurals) => Self::check_de_inner(plurals),
             Plurals::U64(plurals) => Self::check_de_inner(plurals),
+            Plurals::U128(plurals) => Self::check_de_inner(plurals),
             Plurals::F32(plurals) => Self::check_de_inner(plurals),
             Plurals::F64(plurals) => Self::check_de_inner(plurals),
         }
@@ -477,10 +785,12 @@ impl ToTokens for Plurals {
             Plurals::I16(plurals) => Self::to_tokens_integers(plurals).to_tokens(tokens),
             Plurals::I32(plurals) => Self::to_tokens_integers(plurals).to_tokens(tokens),
             Plurals::I64(plurals) => Self::to_tokens_integers(plurals).to_tokens(tokens),
+            Plurals::I128(plurals) => Self::to_tokens_integers(plurals).to_tokens(tokens),
             Plurals::U8(plurals) => Self::to_tokens_integers(plurals).to_tokens(tokens),
             Plurals::U16(plurals) => Self::to_tokens_integers(plurals).to_tokens(tokens),
             Plurals::U32(plurals) => Self::to_tokens_integers(plurals).to_tokens(tokens),
             Plurals::U64(plurals) => Self::to_tokens_integers(plurals).to_tokens(tokens),
+            Plurals::U128(plurals) => Self::to_tokens_integers(plurals).to_tokens(tokens),
             Plurals::F32(plurals) => Self::to_tokens_floats(plurals).to_tokens(tokens),
             Plurals::F64(plurals) => Self::to_tokens_floats(plurals).to_tokens(tokens),
         }
@@ -490,8 +800,16 @@ impl ToTokens for Plurals {
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
 pub enum Plural<T> {
     Exact(T),
-    Range { start: Option<T>, end: Bound<T> },
+    Range {
+        start: Option<T>,
+        end: Bound<T>,
+    },
     Multiple(Vec<Self>),
+    /// A CLDR plural category, "other" being a fallback.
+    Category {
+        category: PluralCategory,
+        condition: Rc<PluralCondition>,
+    },
     Fallback,
 }
 
@@ -505,6 +823,29 @@ pub trait PluralNumber: FromStr + ToTokens + PartialOrd + Copy {
     fn from_f64(v: f64) -> Option<Self>;
 }
 
+/// A constant declared in the configuration, usable as a plural bound with `{{ name }}`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PluralConstant {
+    Integer(i64),
+    Float(f64),
+}
+
+pub type PluralConstants = HashMap<String, PluralConstant>;
+
+impl PluralConstant {
+    fn get<T: PluralNumber>(self, name: &str) -> Result<T> {
+        let (value, found) = match self {
+            PluralConstant::Integer(v) => (T::from_i64(v), PluralType::I64),
+            PluralConstant::Float(v) => (T::from_f64(v), PluralType::F64),
+        };
+        value.ok_or_else(|| Error::PluralConstantType {
+            name: name.to_string(),
+            found,
+            expected: T::TYPE,
+        })
+    }
+}
+
 pub trait PluralInteger: PluralNumber {}
 
 pub trait PluralFloats: PluralNumber {}
@@ -520,8 +861,80 @@ impl<T: PluralNumber> Plural<T> {
         Plural::Multiple(plurals)
     }
 
-    pub fn new(s: &str) -> Result<Self> {
+    /// Whether this plural uses a plural category, and must be matched with the plural rules of the locale.
+    fn has_category(&self) -> bool {
+        match self {
+            Plural::Category { .. } => true,
+            Plural::Multiple(plurals) => plurals.iter().any(Self::has_category),
+            _ => false,
+        }
+    }
+
+    /// Whether a count of `1` matches this plural, the `one` category being assumed to contain it.
+    fn matches_one(&self, one: T) -> bool {
+        match self {
+            Plural::Exact(exact) => *exact == one,
+            Plural::Range { start, end } => {
+                start.iter().all(|start| *start <= one)
+                    && match *end {
+                        Bound::Included(end) => one <= end,
+                        Bound::Excluded(end) => one < end,
+                        Bound::Unbounded => true,
+                    }
+            }
+            Plural::Multiple(plurals) => plurals.iter().any(|plural| plural.matches_one(one)),
+            Plural::Category { category, .. } => *category == PluralCategory::One,
+            Plural::Fallback => true,
+        }
+    }
+
+    fn is_fallback(&self) -> bool {
+        matches!(self, Plural::Fallback)
+    }
+
+    fn from_category(
+        category: PluralCategory,
+        locale: &str,
+        rule_type: PluralRuleType,
+    ) -> Result<Self> {
+        if category == PluralCategory::Other {
+            return Ok(Self::Fallback);
+        }
+        match plural_rules::condition(locale, category, rule_type)? {
+            Some(condition) => Ok(Self::Category {
+                category,
+                condition: Rc::new(condition),
+            }),
+            None => Err(Error::UnusedPluralCategory {
+                category,
+                rule_type,
+                locale: locale.to_string(),
+            }),
+        }
+    }
+
+    pub fn new(s: &str, constants: &PluralConstants, locale: &str) -> Result<Self> {
+        Self::with_rules(s, constants, locale, PluralRuleType::Cardinal)
+    }
+
+    /// Parse a plural, its categories following the given rules of the locale.
+    pub fn with_rules(
+        s: &str,
+        constants: &PluralConstants,
+        locale: &str,
+        rule_type: PluralRuleType,
+    ) -> Result<Self> {
         let parse = |s: &str| {
+            if let Some(name) = s
+                .strip_prefix("{{")
+                .and_then(|s| s.strip_suffix("}}"))
+                .map(str::trim)
+            {
+                return match constants.get(name) {
+                    Some(constant) => constant.get(name),
+                    None => Err(Error::UnknownPluralConstant(name.to_string())),
+                };
+            }
             s.parse::<T>().map_err(|_| Error::PluralParse {
                 plural: s.to_string(),
                 plural_type: T::TYPE,
@@ -532,10 +945,14 @@ impl<T: PluralNumber> Plural<T> {
             return Ok(Self::Fallback);
         };
 
+        if let Some(category) = PluralCategory::from_name(s) {
+            return Self::from_category(category, locale, rule_type);
+        }
+
         if s.contains('|') {
             return s
                 .split('|')
-                .map(|s| Self::new(s))
+                .map(|s| Self::with_rules(s, constants, locale, rule_type))
                 .collect::<Result<_>>()
                 .map(Self::Multiple)
                 .map(Self::flatten);
@@ -600,7 +1017,8 @@ impl<T: PluralNumber> ToTokens for Plural<T> {
             } => {
                 quote!(#start..#end)
             }
-            Plural::Fallback => quote!(_),
+            // categories are matched with a guard.
+            Plural::Fallback | Plural::Category { .. } => quote!(_),
             Plural::Multiple(matchs) => {
                 let mut matchs = matchs.iter().map(Self::to_token_stream);
                 if let Some(first) = matchs.next() {
@@ -618,9 +1036,9 @@ impl<T: PluralNumber> ToTokens for Plural<T> {
 }
 
 #[derive(Debug, Clone, Copy)]
-struct PluralSeed<T>(PhantomData<T>);
+struct PluralSeed<'a, T>(&'a PluralConstants, &'a str, PhantomData<T>);
 
-impl<'de, T: PluralNumber> serde::de::DeserializeSeed<'de> for PluralSeed<T> {
+impl<'de, T: PluralNumber> serde::de::DeserializeSeed<'de> for PluralSeed<'_, T> {
     type Value = Plural<T>;
     fn deserialize<D>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error>
     where
@@ -630,7 +1048,7 @@ impl<'de, T: PluralNumber> serde::de::DeserializeSeed<'de> for PluralSeed<T> {
     }
 }
 
-impl<'de, T: PluralNumber> serde::de::Visitor<'de> for PluralSeed<T> {
+impl<'de, T: PluralNumber> serde::de::Visitor<'de> for PluralSeed<'_, T> {
     type Value = Plural<T>;
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -704,7 +1122,192 @@ impl<'de, T: PluralNumber> serde::de::Visitor<'de> for PluralSeed<T> {
     where
         E: serde::de::Error,
     {
-        Plural::new(s).map_err(serde::de::Error::custom)
+        Plural::new(s, self.0, self.1).map_err(serde::de::Error::custom)
+    }
+}
+
+/// The value of an `"ordinal"` key, the marker of an ordinal plural declared as a map or the value of a subkey.
+pub enum OrdinalOrValue {
+    Ordinal(bool),
+    Value(ParsedValue),
+}
+
+pub struct OrdinalSeed<'a>(pub ParsedValueSeed<'a>);
+
+impl<'de> serde::de::DeserializeSeed<'de> for OrdinalSeed<'_> {
+    type Value = OrdinalOrValue;
+
+    fn deserialize<D>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> serde::de::Visitor<'de> for OrdinalSeed<'_> {
+    type Value = OrdinalOrValue;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "a boolean marking an ordinal plural, ")?;
+        serde::de::Visitor::expecting(&self.0, formatter)
+    }
+
+    fn visit_bool<E>(self, v: bool) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(OrdinalOrValue::Ordinal(v))
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        serde::de::Visitor::visit_str(self.0, v).map(OrdinalOrValue::Value)
+    }
+
+    fn visit_unit<E>(self) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        serde::de::Visitor::visit_unit(self.0).map(OrdinalOrValue::Value)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        serde::de::Visitor::visit_seq(self.0, seq).map(OrdinalOrValue::Value)
+    }
+
+    fn visit_map<A>(self, map: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        serde::de::Visitor::visit_map(self.0, map).map(OrdinalOrValue::Value)
+    }
+}
+
+/// The key of a map that may be a plural, YAML allowing to write counts as numbers.
+pub struct PluralKey(pub String);
+
+struct PluralKeyVisitor;
+
+impl<'de> serde::Deserialize<'de> for PluralKey {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(PluralKeyVisitor)
+    }
+}
+
+impl<'de> serde::de::Visitor<'de> for PluralKeyVisitor {
+    type Value = PluralKey;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "a string or a number")
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(PluralKey(v.to_string()))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(PluralKey(v.to_string()))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(PluralKey(v.to_string()))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(PluralKey(v.to_string()))
+    }
+}
+
+struct NegativeCountsVisitor;
+
+impl<'de> serde::Deserialize<'de> for NegativeCounts {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(NegativeCountsVisitor)
+    }
+}
+
+impl<'de> serde::de::Visitor<'de> for NegativeCountsVisitor {
+    type Value = NegativeCounts;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "either \"literal\" or \"absolute\"")
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "literal" => Ok(NegativeCounts::Literal),
+            "absolute" => Ok(NegativeCounts::Absolute),
+            _ => Err(E::unknown_variant(v, &["literal", "absolute"])),
+        }
+    }
+}
+
+struct PluralConstantVisitor;
+
+impl<'de> serde::Deserialize<'de> for PluralConstant {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(PluralConstantVisitor)
+    }
+}
+
+impl<'de> serde::de::Visitor<'de> for PluralConstantVisitor {
+    type Value = PluralConstant;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "a number")
+    }
+
+    fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(PluralConstant::Integer(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        i64::try_from(v)
+            .map(PluralConstant::Integer)
+            .map_err(|_| E::invalid_value(serde::de::Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(PluralConstant::Float(v))
     }
 }
 
@@ -761,9 +1364,16 @@ impl<'de, T: PluralNumber> serde::de::Visitor<'de> for PluralStructSeed<'_, T> {
         let mut value = None;
         while let Some(field) = map.next_key()? {
             match field {
-                PluralField::Plural => {
-                    deser_field(&mut plural, &mut map, PluralSeed(PhantomData), "count")?
-                }
+                PluralField::Plural => deser_field(
+                    &mut plural,
+                    &mut map,
+                    PluralSeed(
+                        self.0.plural_constants,
+                        &self.0.top_locale_name.name,
+                        PhantomData,
+                    ),
+                    "count",
+                )?,
                 PluralField::Value => deser_field(&mut value, &mut map, self.0, "count")?,
             }
         }
@@ -781,7 +1391,12 @@ impl<'de, T: PluralNumber> serde::de::Visitor<'de> for PluralStructSeed<'_, T> {
         let Some(value) = seq.next_element_seed(self.0)? else {
             return Err(serde::de::Error::invalid_length(0, &"at least 1 element"));
         };
-        let plural = PluralSeed(PhantomData).visit_seq(seq)?;
+        let plural = PluralSeed(
+            self.0.plural_constants,
+            &self.0.top_locale_name.name,
+            PhantomData,
+        )
+        .visit_seq(seq)?;
 
         Ok((plural, value))
     }
@@ -832,10 +1447,11 @@ impl<'de> serde::de::Visitor<'de> for PluralFieldVisitor {
 
 enum TypeOrPlural {
     Type(PluralType),
-    Plural((Plural<DefaultPluralType>, ParsedValue)),
+    /// The first plural, parsed with the configured count type.
+    Plural(Plurals),
 }
 
-struct TypeOrPluralSeed<'a>(pub ParsedValueSeed<'a>);
+struct TypeOrPluralSeed<'a>(pub ParsedValueSeed<'a>, pub PluralType);
 
 impl<'de> serde::de::DeserializeSeed<'de> for TypeOrPluralSeed<'_> {
     type Value = TypeOrPlural;
@@ -862,18 +1478,9 @@ impl<'de> serde::de::Visitor<'de> for TypeOrPluralSeed<'_> {
     where
         E: serde::de::Error,
     {
-        match v.trim() {
-            "i8" => Ok(TypeOrPlural::Type(PluralType::I8)),
-            "i16" => Ok(TypeOrPlural::Type(PluralType::I16)),
-            "i32" => Ok(TypeOrPlural::Type(PluralType::I32)),
-            "i64" => Ok(TypeOrPlural::Type(PluralType::I64)),
-            "u8" => Ok(TypeOrPlural::Type(PluralType::U8)),
-            "u16" => Ok(TypeOrPlural::Type(PluralType::U16)),
-            "u32" => Ok(TypeOrPlural::Type(PluralType::U32)),
-            "u64" => Ok(TypeOrPlural::Type(PluralType::U64)),
-            "f32" => Ok(TypeOrPlural::Type(PluralType::F32)),
-            "f64" => Ok(TypeOrPlural::Type(PluralType::F64)),
-            _ => Err(serde::de::Error::custom(Error::InvalidPluralType(
+        match PluralType::parse(v) {
+            Some(plural_type) => Ok(TypeOrPlural::Type(plural_type)),
+            None => Err(serde::de::Error::custom(Error::InvalidPluralType(
                 v.to_string(),
             ))),
         }
@@ -883,16 +1490,18 @@ impl<'de> serde::de::Visitor<'de> for TypeOrPluralSeed<'_> {
     where
         A: serde::de::MapAccess<'de>,
     {
-        let plural_seed = PluralStructSeed::<DefaultPluralType>(self.0, PhantomData);
-        plural_seed.visit_map(map).map(TypeOrPlural::Plural)
+        let mut plurals = Plurals::from_type(self.1);
+        plurals.deserialize_pair(serde::de::value::MapAccessDeserializer::new(map), self.0)?;
+        Ok(TypeOrPlural::Plural(plurals))
     }
 
     fn visit_seq<A>(self, seq: A) -> std::result::Result<Self::Value, A::Error>
     where
         A: serde::de::SeqAccess<'de>,
     {
-        let plural_seed = PluralStructSeed::<DefaultPluralType>(self.0, PhantomData);
-        plural_seed.visit_seq(seq).map(TypeOrPlural::Plural)
+        let mut plurals = Plurals::from_type(self.1);
+        plurals.deserialize_pair(serde::de::value::SeqAccessDeserializer::new(seq), self.0)?;
+        Ok(TypeOrPlural::Plural(plurals))
     }
 }
 
@@ -902,21 +1511,21 @@ mod tests {
 
     #[test]
     fn test_exact() {
-        let plural = Plural::new("0").unwrap();
+        let plural = Plural::new("0", &PluralConstants::new(), "en").unwrap();
 
         assert_eq!(plural, Plural::Exact(0));
     }
 
     #[test]
     fn test_fallback() {
-        let plural = Plural::<i32>::new("_").unwrap();
+        let plural = Plural::<i32>::new("_", &PluralConstants::new(), "en").unwrap();
 
         assert_eq!(plural, Plural::Fallback);
     }
 
     #[test]
     fn test_range() {
-        let plural = Plural::new("0..6").unwrap();
+        let plural = Plural::new("0..6", &PluralConstants::new(), "en").unwrap();
 
         assert_eq!(
             plural,
@@ -929,7 +1538,7 @@ mod tests {
 
     #[test]
     fn test_range_unbounded_end() {
-        let plural = Plural::new("0..").unwrap();
+        let plural = Plural::new("0..", &PluralConstants::new(), "en").unwrap();
 
         assert_eq!(
             plural,
@@ -942,7 +1551,7 @@ mod tests {
 
     #[test]
     fn test_range_included_end() {
-        let plural = Plural::new("0..=6").unwrap();
+        let plural = Plural::new("0..=6", &PluralConstants::new(), "en").unwrap();
 
         assert_eq!(
             plural,
@@ -955,7 +1564,7 @@ mod tests {
 
     #[test]
     fn test_range_unbounded_start() {
-        let plural = Plural::new("..=6").unwrap();
+        let plural = Plural::new("..=6", &PluralConstants::new(), "en").unwrap();
 
         assert_eq!(
             plural,
@@ -968,14 +1577,15 @@ mod tests {
 
     #[test]
     fn test_range_full() {
-        let plural = Plural::<i32>::new("..").unwrap();
+        let plural = Plural::<i32>::new("..", &PluralConstants::new(), "en").unwrap();
 
         assert_eq!(plural, Plural::Fallback);
     }
 
     #[test]
     fn test_multiple() {
-        let plural = Plural::<i32>::new("5 | 5..8 | 70..=80").unwrap();
+        let plural =
+            Plural::<i32>::new("5 | 5..8 | 70..=80", &PluralConstants::new(), "en").unwrap();
 
         assert_eq!(
             plural,
@@ -995,10 +1605,93 @@ mod tests {
 
     #[test]
     fn test_multiple_with_fallback() {
-        let plural = Plural::<i32>::new("5 | 5..8 | 70..=80 | _").unwrap();
+        let plural =
+            Plural::<i32>::new("5 | 5..8 | 70..=80 | _", &PluralConstants::new(), "en").unwrap();
 
         assert_eq!(plural, Plural::Fallback);
     }
+
+    #[test]
+    fn test_categories() {
+        let constants = PluralConstants::new();
+        let plural = Plural::<i32>::new("0 | one", &constants, "en").unwrap();
+        assert!(plural.has_category());
+        assert_eq!(
+            Plural::<i32>::new("other", &constants, "ru").unwrap(),
+            Plural::Fallback
+        );
+        assert!(matches!(
+            Plural::<i32>::new("few", &constants, "en"),
+            Err(Error::UnusedPluralCategory {
+                category: PluralCategory::Few,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Plural::<i32>::new("one", &constants, "tlh"),
+            Err(Error::UnknownPluralRules(_))
+        ));
+
+        let plural = Plural::<u32>::with_rules("few", &constants, "en", PluralRuleType::Ordinal);
+        assert!(plural.unwrap().has_category());
+        assert!(matches!(
+            Plural::<u32>::with_rules("many", &constants, "en", PluralRuleType::Ordinal),
+            Err(Error::UnusedPluralCategory {
+                category: PluralCategory::Many,
+                rule_type: PluralRuleType::Ordinal,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_constants() {
+        let constants = PluralConstants::from([
+            ("few".to_string(), PluralConstant::Integer(2)),
+            ("many".to_string(), PluralConstant::Integer(10)),
+        ]);
+        let plural =
+            Plural::<u8>::new("{{ few }}..{{many}} | {{ many }}", &constants, "en").unwrap();
+
+        assert_eq!(
+            plural,
+            Plural::Multiple(vec![
+                Plural::Range {
+                    start: Some(2),
+                    end: Bound::Included(9)
+                },
+                Plural::Exact(10)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_constants_errors() {
+        let constants = PluralConstants::from([
+            ("negative".to_string(), PluralConstant::Integer(-1)),
+            ("half".to_string(), PluralConstant::Float(0.5)),
+        ]);
+
+        assert!(matches!(
+            Plural::<u8>::new("{{ unknown }}..", &constants, "en"),
+            Err(Error::UnknownPluralConstant(name)) if name == "unknown"
+        ));
+        assert!(matches!(
+            Plural::<u8>::new("{{ negative }}", &constants, "en"),
+            Err(Error::PluralConstantType { .. })
+        ));
+        assert!(matches!(
+            Plural::<i32>::new("{{ half }}", &constants, "en"),
+            Err(Error::PluralConstantType { .. })
+        ));
+        assert_eq!(
+            Plural::<f32>::new("{{ negative }}..{{ half }}", &constants, "en").unwrap(),
+            Plural::Range {
+                start: Some(-1.0),
+                end: Bound::Excluded(0.5)
+            }
+        );
+    }
 }
 
 mod plural_number_impl {
@@ -1059,9 +1752,9 @@ mod plural_number_impl {
         };
     }
 
-    impl_num!((i8, I8)(i16, I16)(i32, I32)(i64, I64)(u8, U8)(u16, U16)(
-        u32, U32
-    )(u64, U64));
+    impl_num!((i8, I8)(i16, I16)(i32, I32)(i64, I64)(i128, I128)(u8, U8)(
+        u16, U16
+    )(u32, U32)(u64, U64)(u128, U128));
 
     impl_floats!((f32, F32)(f64, F64));
 }
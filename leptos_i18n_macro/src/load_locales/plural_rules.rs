@@ -0,0 +1,533 @@
+//! The CLDR cardinal and ordinal plural rules, compiled in the generated code only for the declared locales,
+//! so no plural rules data is linked in the binary.
+
+use proc_macro2::{Literal, TokenStream};
+use quote::{quote, ToTokens};
+
+use super::error::{Error, Result};
+
+/// A CLDR plural category.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "zero" => Some(PluralCategory::Zero),
+            "one" => Some(PluralCategory::One),
+            "two" => Some(PluralCategory::Two),
+            "few" => Some(PluralCategory::Few),
+            "many" => Some(PluralCategory::Many),
+            "other" => Some(PluralCategory::Other),
+            _ => None,
+        }
+    }
+}
+
+impl core::fmt::Display for PluralCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PluralCategory::Zero => f.write_str("zero"),
+            PluralCategory::One => f.write_str("one"),
+            PluralCategory::Two => f.write_str("two"),
+            PluralCategory::Few => f.write_str("few"),
+            PluralCategory::Many => f.write_str("many"),
+            PluralCategory::Other => f.write_str("other"),
+        }
+    }
+}
+
+/// The kind of plural rules, cardinal for quantities ("1 item") or ordinal for positions ("1st").
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Default)]
+pub enum PluralRuleType {
+    #[default]
+    Cardinal,
+    Ordinal,
+}
+
+impl PluralRuleType {
+    /// The rules marked by the value of an `"ordinal"` entry.
+    pub const fn from_ordinal(ordinal: bool) -> Self {
+        if ordinal {
+            PluralRuleType::Ordinal
+        } else {
+            PluralRuleType::Cardinal
+        }
+    }
+}
+
+impl core::fmt::Display for PluralRuleType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PluralRuleType::Cardinal => f.write_str("cardinal"),
+            PluralRuleType::Ordinal => f.write_str("ordinal"),
+        }
+    }
+}
+
+/// The operands of a number a rule can be about, see `leptos_i18n::plurals::PluralOperands`.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+enum Operand {
+    N,
+    I,
+    V,
+    W,
+    F,
+    T,
+    /// The compact decimal exponent (`e` or `c`), always 0 as counts are never in compact notation.
+    E,
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+struct Relation {
+    operand: Operand,
+    modulo: Option<u64>,
+    negated: bool,
+    ranges: Vec<(u64, u64)>,
+}
+
+/// The condition of a plural category, a disjunction of conjunctions of relations.
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+pub struct PluralCondition(Vec<Vec<Relation>>);
+
+impl PluralCondition {
+    /// Parse a rule in the CLDR syntax, like `v = 0 and i % 10 = 2..4 and i % 100 != 12..14`.
+    fn parse(rule: &str) -> Option<Self> {
+        rule.split(" or ")
+            .map(|and_condition| {
+                and_condition
+                    .split(" and ")
+                    .map(Relation::parse)
+                    .collect::<Option<Vec<_>>>()
+            })
+            .collect::<Option<Vec<_>>>()
+            .map(PluralCondition)
+    }
+}
+
+impl Relation {
+    fn parse(relation: &str) -> Option<Self> {
+        let (expr, negated, ranges) = if let Some((expr, ranges)) = relation.split_once("!=") {
+            (expr, true, ranges)
+        } else {
+            let (expr, ranges) = relation.split_once('=')?;
+            (expr, false, ranges)
+        };
+        let (operand, modulo) = match expr.split_once('%') {
+            Some((operand, modulo)) => (operand, Some(modulo.trim().parse().ok()?)),
+            None => (expr, None),
+        };
+        let operand = match operand.trim() {
+            "n" => Operand::N,
+            "i" => Operand::I,
+            "v" => Operand::V,
+            "w" => Operand::W,
+            "f" => Operand::F,
+            "t" => Operand::T,
+            "e" | "c" => Operand::E,
+            _ => return None,
+        };
+        let ranges = ranges
+            .split(',')
+            .map(|range| match range.split_once("..") {
+                Some((start, end)) => Some((start.trim().parse().ok()?, end.trim().parse().ok()?)),
+                None => range.trim().parse().ok().map(|value| (value, value)),
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(Relation {
+            operand,
+            modulo,
+            negated,
+            ranges,
+        })
+    }
+
+    /// Return the literal of an integer compared to the operand, `i` being an `u128` and the others `u64`.
+    fn integer_literal(&self, value: u64) -> Literal {
+        match self.operand {
+            Operand::I => Literal::u128_suffixed(value as u128),
+            _ => Literal::u64_suffixed(value),
+        }
+    }
+
+    fn range_to_tokens(&self, value: &TokenStream, (start, end): (u64, u64)) -> TokenStream {
+        // `n` is the only operand that can have a fractional part, a range only matches integers.
+        match self.operand {
+            Operand::N if start == end => {
+                let start = Literal::f64_suffixed(start as f64);
+                quote!(#value == #start)
+            }
+            Operand::N => {
+                let start = Literal::f64_suffixed(start as f64);
+                let end = Literal::f64_suffixed(end as f64);
+                quote!(#value % 1f64 == 0f64 && (#start..=#end).contains(&#value))
+            }
+            _ if start == end => {
+                let start = self.integer_literal(start);
+                quote!(#value == #start)
+            }
+            _ => {
+                let start = self.integer_literal(start);
+                let end = self.integer_literal(end);
+                quote!((#start..=#end).contains(&#value))
+            }
+        }
+    }
+}
+
+impl ToTokens for Relation {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let operand = match self.operand {
+            Operand::N => quote!(plural_operands.n),
+            Operand::I => quote!(plural_operands.i),
+            Operand::V => quote!(plural_operands.v),
+            Operand::W => quote!(plural_operands.w),
+            Operand::F => quote!(plural_operands.f),
+            Operand::T => quote!(plural_operands.t),
+            Operand::E => quote!(0u64),
+        };
+        let value = match (self.modulo, self.operand) {
+            (None, _) => operand,
+            (Some(modulo), Operand::N) => {
+                let modulo = Literal::f64_suffixed(modulo as f64);
+                quote!((#operand % #modulo))
+            }
+            (Some(modulo), _) => {
+                let modulo = self.integer_literal(modulo);
+                quote!((#operand % #modulo))
+            }
+        };
+        let ranges = self
+            .ranges
+            .iter()
+            .map(|range| self.range_to_tokens(&value, *range))
+            .collect::<Vec<_>>();
+        let is_single_call = matches!(&self.ranges[..], [(start, end)] if start != end)
+            && self.operand != Operand::N;
+        let relation = if ranges.len() > 1 || (self.negated && !is_single_call) {
+            quote!((#(#ranges)||*))
+        } else {
+            quote!(#(#ranges)*)
+        };
+        if self.negated {
+            tokens.extend(quote!(!#relation))
+        } else {
+            tokens.extend(relation)
+        }
+    }
+}
+
+impl ToTokens for PluralCondition {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let or_conditions = self.0.iter().map(|relations| quote!(#(#relations)&&*));
+        tokens.extend(quote!(#(#or_conditions)||*))
+    }
+}
+
+/// The rules of the plural categories of a language, "other" being the fallback of every language.
+/// Return `None` if the language of the locale is unknown.
+fn rules_of(locale: &str) -> Option<&'static [(PluralCategory, &'static str)]> {
+    use PluralCategory::*;
+
+    const MILLIONS: &str = "e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5";
+
+    let mut subtags = locale.split(['-', '_']);
+    let language = subtags.next().unwrap_or_default().to_ascii_lowercase();
+    let region = subtags
+        .find(|subtag| subtag.len() == 2)
+        .map(str::to_ascii_uppercase);
+    let rules: &[(PluralCategory, &str)] = match (language.as_str(), region.as_deref()) {
+        ("ja" | "zh" | "ko" | "vi" | "th" | "id" | "ms" | "my" | "lo" | "km" | "yue", _) => &[],
+        ("en" | "de" | "nl" | "sv" | "et" | "fi" | "gl" | "ur" | "sw" | "yi", _) => {
+            &[(One, "i = 1 and v = 0")]
+        }
+        (
+            "af" | "az" | "bg" | "el" | "eu" | "hu" | "ka" | "kk" | "ky" | "ml" | "mn" | "mr" | "nb"
+            | "ne" | "nn" | "no" | "ps" | "so" | "sq" | "ta" | "te" | "tr" | "uz",
+            _,
+        ) => &[(One, "n = 1")],
+        ("am" | "as" | "bn" | "fa" | "gu" | "hi" | "kn" | "zu", _) => &[(One, "i = 0 or n = 1")],
+        ("es", _) => &[(One, "n = 1"), (Many, MILLIONS)],
+        ("it" | "ca", _) | ("pt", Some("PT")) => &[(One, "i = 1 and v = 0"), (Many, MILLIONS)],
+        ("fr", _) => &[(One, "i = 0,1"), (Many, MILLIONS)],
+        ("pt", _) => &[(One, "i = 0..1"), (Many, MILLIONS)],
+        ("da", _) => &[(One, "n = 1 or t != 0 and i = 0,1")],
+        ("is", _) => &[(
+            One,
+            "t = 0 and i % 10 = 1 and i % 100 != 11 or t % 10 = 1 and t % 100 != 11",
+        )],
+        ("mk", _) => &[(
+            One,
+            "v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11",
+        )],
+        ("fil" | "tl", _) => &[(
+            One,
+            "v = 0 and i = 1,2,3 or v = 0 and i % 10 != 4,6,9 or v != 0 and f % 10 != 4,6,9",
+        )],
+        ("lv", _) => &[
+            (
+                Zero,
+                "n % 10 = 0 or n % 100 = 11..19 or v = 2 and f % 100 = 11..19",
+            ),
+            (
+                One,
+                "n % 10 = 1 and n % 100 != 11 or v = 2 and f % 10 = 1 and f % 100 != 11 or v != 2 and f % 10 = 1",
+            ),
+        ],
+        ("ro", _) => &[
+            (One, "i = 1 and v = 0"),
+            (Few, "v != 0 or n = 0 or n != 1 and n % 100 = 1..19"),
+        ],
+        ("ru" | "uk", _) => &[
+            (One, "v = 0 and i % 10 = 1 and i % 100 != 11"),
+            (Few, "v = 0 and i % 10 = 2..4 and i % 100 != 12..14"),
+            (
+                Many,
+                "v = 0 and i % 10 = 0 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 11..14",
+            ),
+        ],
+        ("be", _) => &[
+            (One, "n % 10 = 1 and n % 100 != 11"),
+            (Few, "n % 10 = 2..4 and n % 100 != 12..14"),
+            (Many, "n % 10 = 0 or n % 10 = 5..9 or n % 100 = 11..14"),
+        ],
+        ("pl", _) => &[
+            (One, "i = 1 and v = 0"),
+            (Few, "v = 0 and i % 10 = 2..4 and i % 100 != 12..14"),
+            (
+                Many,
+                "v = 0 and i != 1 and i % 10 = 0..1 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 12..14",
+            ),
+        ],
+        ("cs" | "sk", _) => &[
+            (One, "i = 1 and v = 0"),
+            (Few, "i = 2..4 and v = 0"),
+            (Many, "v != 0"),
+        ],
+        ("hr" | "sr" | "bs" | "sh", _) => &[
+            (
+                One,
+                "v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11",
+            ),
+            (
+                Few,
+                "v = 0 and i % 10 = 2..4 and i % 100 != 12..14 or f % 10 = 2..4 and f % 100 != 12..14",
+            ),
+        ],
+        ("lt", _) => &[
+            (One, "n % 10 = 1 and n % 100 != 11..19"),
+            (Few, "n % 10 = 2..9 and n % 100 != 11..19"),
+            (Many, "f != 0"),
+        ],
+        ("sl", _) => &[
+            (One, "v = 0 and i % 100 = 1"),
+            (Two, "v = 0 and i % 100 = 2"),
+            (Few, "v = 0 and i % 100 = 3..4 or v != 0"),
+        ],
+        ("he" | "iw", _) => &[
+            (One, "i = 1 and v = 0 or i = 0 and v != 0"),
+            (Two, "i = 2 and v = 0"),
+        ],
+        ("ar", _) => &[
+            (Zero, "n = 0"),
+            (One, "n = 1"),
+            (Two, "n = 2"),
+            (Few, "n % 100 = 3..10"),
+            (Many, "n % 100 = 11..99"),
+        ],
+        ("mt", _) => &[
+            (One, "n = 1"),
+            (Two, "n = 2"),
+            (Few, "n = 0 or n % 100 = 3..10"),
+            (Many, "n % 100 = 11..19"),
+        ],
+        ("ga", _) => &[
+            (One, "n = 1"),
+            (Two, "n = 2"),
+            (Few, "n = 3..6"),
+            (Many, "n = 7..10"),
+        ],
+        ("gd", _) => &[
+            (One, "n = 1,11"),
+            (Two, "n = 2,12"),
+            (Few, "n = 3..10,13..19"),
+        ],
+        ("cy", _) => &[
+            (Zero, "n = 0"),
+            (One, "n = 1"),
+            (Two, "n = 2"),
+            (Few, "n = 3"),
+            (Many, "n = 6"),
+        ],
+        _ => return None,
+    };
+    Some(rules)
+}
+
+/// The ordinal rules of the plural categories of a language, "other" being the fallback of every language.
+/// Return `None` if the language of the locale is unknown.
+fn ordinal_rules_of(locale: &str) -> Option<&'static [(PluralCategory, &'static str)]> {
+    use PluralCategory::*;
+
+    let language = locale
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let rules: &[(PluralCategory, &str)] = match language.as_str() {
+        "ja" | "zh" | "ko" | "th" | "id" | "my" | "km" | "yue" | "de" | "nl" | "et" | "fi"
+        | "gl" | "ur" | "sw" | "yi" | "af" | "bg" | "el" | "eu" | "ky" | "ml" | "mn" | "nb"
+        | "nn" | "no" | "ps" | "so" | "ta" | "te" | "tr" | "uz" | "am" | "fa" | "kn" | "zu"
+        | "es" | "pt" | "da" | "is" | "lv" | "ru" | "pl" | "cs" | "sk" | "hr" | "sr" | "bs"
+        | "sh" | "lt" | "sl" | "he" | "iw" | "ar" | "mt" => &[],
+        "fr" | "vi" | "ms" | "lo" | "ro" | "fil" | "tl" | "ga" => &[(One, "n = 1")],
+        "en" => &[
+            (One, "n % 10 = 1 and n % 100 != 11"),
+            (Two, "n % 10 = 2 and n % 100 != 12"),
+            (Few, "n % 10 = 3 and n % 100 != 13"),
+        ],
+        "sv" => &[(One, "n % 10 = 1,2 and n % 100 != 11,12")],
+        "hu" => &[(One, "n = 1,5")],
+        "it" => &[(Many, "n = 11,8,80,800")],
+        "ca" => &[(One, "n = 1,3"), (Two, "n = 2"), (Few, "n = 4")],
+        "ne" => &[(One, "n = 1..4")],
+        "sq" => &[(One, "n = 1"), (Many, "n % 10 = 4 and n % 100 != 14")],
+        "kk" => &[(Many, "n % 10 = 6 or n % 10 = 9 or n % 10 = 0 and n != 0")],
+        "ka" => &[(One, "i = 1"), (Many, "i = 0 or i % 100 = 2..20,40,60,80")],
+        "uk" => &[(Few, "n % 10 = 3 and n % 100 != 13")],
+        "be" => &[(Few, "n % 10 = 2,3 and n % 100 != 12,13")],
+        "mk" => &[
+            (One, "i % 10 = 1 and i % 100 != 11"),
+            (Two, "i % 10 = 2 and i % 100 != 12"),
+            (Many, "i % 10 = 7,8 and i % 100 != 17,18"),
+        ],
+        "az" => &[
+            (One, "i % 10 = 1,2,5,7,8 or i % 100 = 20,50,70,80"),
+            (
+                Few,
+                "i % 10 = 3,4 or i % 1000 = 100,200,300,400,500,600,700,800,900",
+            ),
+            (Many, "i = 0 or i % 10 = 6 or i % 100 = 40,60,90"),
+        ],
+        "bn" | "as" => &[
+            (One, "n = 1,5,7,8,9,10"),
+            (Two, "n = 2,3"),
+            (Few, "n = 4"),
+            (Many, "n = 6"),
+        ],
+        "hi" | "gu" => &[
+            (One, "n = 1"),
+            (Two, "n = 2,3"),
+            (Few, "n = 4"),
+            (Many, "n = 6"),
+        ],
+        "mr" => &[(One, "n = 1"), (Two, "n = 2,3"), (Few, "n = 4")],
+        "gd" => &[(One, "n = 1,11"), (Two, "n = 2,12"), (Few, "n = 3,13")],
+        "cy" => &[
+            (Zero, "n = 0,7,8,9"),
+            (One, "n = 1"),
+            (Two, "n = 2"),
+            (Few, "n = 3,4"),
+            (Many, "n = 5,6"),
+        ],
+        _ => return None,
+    };
+    Some(rules)
+}
+
+/// Return the condition of a plural category in the rules of the language of the given locale,
+/// `None` if the language does not use this category.
+pub fn condition(
+    locale: &str,
+    category: PluralCategory,
+    rule_type: PluralRuleType,
+) -> Result<Option<PluralCondition>> {
+    let rules = match rule_type {
+        PluralRuleType::Cardinal => rules_of(locale),
+        PluralRuleType::Ordinal => ordinal_rules_of(locale),
+    };
+    let rules = rules.ok_or_else(|| Error::UnknownPluralRules(locale.to_string()))?;
+    let condition = rules.iter().find(|(c, _)| *c == category).map(|(_, rule)| {
+        PluralCondition::parse(rule).unwrap_or_else(|| {
+            unreachable!(
+                "invalid plural rule {:?}. If you got this error please open an issue on github.",
+                rule
+            )
+        })
+    });
+    Ok(condition)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rules_parse() {
+        for locale in [
+            "en", "fr", "pt-PT", "lv", "ru", "pl", "hr", "lt", "ar", "fil", "gd",
+        ] {
+            for (_, rule) in rules_of(locale).unwrap() {
+                assert!(PluralCondition::parse(rule).is_some(), "{}", rule);
+            }
+        }
+        assert!(rules_of("tlh").is_none());
+        for locale in ["en", "it", "ca", "kk", "ka", "mk", "az", "bn", "cy"] {
+            for (_, rule) in ordinal_rules_of(locale).unwrap() {
+                assert!(PluralCondition::parse(rule).is_some(), "{}", rule);
+            }
+        }
+        assert!(ordinal_rules_of("tlh").is_none());
+    }
+
+    #[test]
+    fn test_condition_tokens() {
+        let few = condition("ru", PluralCategory::Few, PluralRuleType::Cardinal)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            few.to_token_stream().to_string(),
+            quote!(
+                plural_operands.v == 0u64
+                    && (2u128..=4u128).contains(&(plural_operands.i % 10u128))
+                    && !(12u128..=14u128).contains(&(plural_operands.i % 100u128))
+            )
+            .to_string()
+        );
+        assert_eq!(
+            condition("fr", PluralCategory::One, PluralRuleType::Cardinal)
+                .unwrap()
+                .unwrap()
+                .to_token_stream()
+                .to_string(),
+            quote!((plural_operands.i == 0u128 || plural_operands.i == 1u128)).to_string()
+        );
+        assert!(matches!(
+            condition("ja", PluralCategory::One, PluralRuleType::Cardinal),
+            Ok(None)
+        ));
+        assert!(matches!(
+            condition("tlh", PluralCategory::One, PluralRuleType::Cardinal),
+            Err(Error::UnknownPluralRules(_))
+        ));
+        let two = condition("en", PluralCategory::Two, PluralRuleType::Ordinal)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            two.to_token_stream().to_string(),
+            quote!((plural_operands.n % 10f64) == 2f64 && !((plural_operands.n % 100f64) == 12f64))
+                .to_string()
+        );
+        assert!(matches!(
+            condition("de", PluralCategory::One, PluralRuleType::Ordinal),
+            Ok(None)
+        ));
+    }
+}
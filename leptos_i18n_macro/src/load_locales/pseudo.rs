@@ -0,0 +1,214 @@
+use std::rc::Rc;
+
+use super::{
+    cfg_file::ConfigFile,
+    key::Key,
+    locale::{Locale, LocalesOrNamespaces},
+    parsed_value::{ForeignKey, ParsedValue},
+};
+
+/// Add the pseudo locale to the locales (and to each namespace), synthesized from the default locale.
+///
+/// Every value is wrapped in brackets, its letters accented and padded by about a third of its length,
+/// so the hardcoded strings, the truncated strings and the layout overflows stand out.
+/// The variables, components and plural rules are the ones of the default locale.
+pub fn add_pseudo_locale(locales: &mut LocalesOrNamespaces, cfg_file: &ConfigFile) {
+    let Some(pseudo_locale) = &cfg_file.pseudo_locale else {
+        return;
+    };
+    match locales {
+        LocalesOrNamespaces::Locales(locales) => add_locale(locales, pseudo_locale),
+        LocalesOrNamespaces::NameSpaces(namespaces) => {
+            for namespace in namespaces {
+                add_locale(&mut namespace.locales, pseudo_locale);
+            }
+        }
+    }
+}
+
+fn add_locale(locales: &mut Vec<Locale>, pseudo_locale: &Rc<Key>) {
+    let Some(default_locale) = locales.first() else {
+        return;
+    };
+    let locale = pseudo_subkeys(default_locale, pseudo_locale, pseudo_locale);
+    locales.push(locale);
+}
+
+fn pseudo_subkeys(locale: &Locale, name: &Rc<Key>, top_locale: &Rc<Key>) -> Locale {
+    let keys = locale
+        .keys
+        .iter()
+        .map(|(key, value)| (Rc::clone(key), pseudo_value(value, top_locale)))
+        .collect();
+    Locale {
+        top_locale_name: Rc::clone(top_locale),
+        name: Rc::clone(name),
+        keys,
+        files: vec![],
+    }
+}
+
+fn pseudo_value(value: &ParsedValue, top_locale: &Rc<Key>) -> ParsedValue {
+    match value {
+        ParsedValue::Subkeys(Some(subkeys)) => {
+            ParsedValue::Subkeys(Some(pseudo_subkeys(subkeys, &subkeys.name, top_locale)))
+        }
+        ParsedValue::Plural(plurals) => {
+            let mut plurals = plurals.clone();
+            let _: Result<_, ()> = plurals.try_for_each_value_mut(|value| {
+                *value = pseudo_value(value, top_locale);
+                Ok(())
+            });
+            ParsedValue::Plural(plurals)
+        }
+        ParsedValue::Select(select) => {
+            let mut select = select.clone();
+            let _: Result<_, ()> = select.try_for_each_value_mut(|value| {
+                *value = pseudo_value(value, top_locale);
+                Ok(())
+            });
+            ParsedValue::Select(select)
+        }
+        ParsedValue::Default | ParsedValue::Subkeys(None) => value.clone(),
+        _ => {
+            let mut value = value.clone();
+            let len = accent_value(&mut value);
+            let padding = "~".repeat(len.div_ceil(3));
+            ParsedValue::Bloc(vec![
+                ParsedValue::String("[".to_owned()),
+                value,
+                ParsedValue::String(format!(" {}]", padding)),
+            ])
+        }
+    }
+}
+
+/// Accent the letters of the strings of a value, leaving the variables and components untouched,
+/// and return the number of characters of its text.
+fn accent_value(value: &mut ParsedValue) -> usize {
+    match value {
+        ParsedValue::String(s) => {
+            *s = s.chars().map(accent).collect();
+            s.chars().count()
+        }
+        ParsedValue::Bloc(values) => values.iter_mut().map(accent_value).sum(),
+        ParsedValue::Component { inner, .. } => accent_value(inner),
+        ParsedValue::ForeignKey(foreign_key) => match foreign_key.get_mut() {
+            ForeignKey::Set(value) => accent_value(value),
+            ForeignKey::NotSet(..) => 0,
+        },
+        ParsedValue::Variable(_)
+        | ParsedValue::FormattedCount(_)
+        | ParsedValue::Formatted { .. }
+        | ParsedValue::Plural(_)
+        | ParsedValue::Select(_)
+        | ParsedValue::Subkeys(_)
+        | ParsedValue::Default => 0,
+    }
+}
+
+fn accent(c: char) -> char {
+    match c {
+        'a' => 'á',
+        'b' => 'ƀ',
+        'c' => 'ç',
+        'd' => 'ð',
+        'e' => 'é',
+        'f' => 'ƒ',
+        'g' => 'ĝ',
+        'h' => 'ĥ',
+        'i' => 'í',
+        'j' => 'ĵ',
+        'k' => 'ķ',
+        'l' => 'ļ',
+        'm' => 'ɱ',
+        'n' => 'ñ',
+        'o' => 'ö',
+        'p' => 'þ',
+        'q' => 'ǫ',
+        'r' => 'ŕ',
+        's' => 'š',
+        't' => 'ţ',
+        'u' => 'û',
+        'v' => 'ṽ',
+        'w' => 'ŵ',
+        'x' => 'ẋ',
+        'y' => 'ý',
+        'z' => 'ž',
+        'A' => 'Å',
+        'B' => 'Ɓ',
+        'C' => 'Ç',
+        'D' => 'Ð',
+        'E' => 'É',
+        'F' => 'Ƒ',
+        'G' => 'Ĝ',
+        'H' => 'Ĥ',
+        'I' => 'Î',
+        'J' => 'Ĵ',
+        'K' => 'Ķ',
+        'L' => 'Ļ',
+        'M' => 'Ṁ',
+        'N' => 'Ñ',
+        'O' => 'Ö',
+        'P' => 'Þ',
+        'Q' => 'Ǫ',
+        'R' => 'Ŕ',
+        'S' => 'Š',
+        'T' => 'Ţ',
+        'U' => 'Û',
+        'V' => 'Ṽ',
+        'W' => 'Ŵ',
+        'X' => 'Ẋ',
+        'Y' => 'Ý',
+        'Z' => 'Ž',
+        c => c,
+    }
+}
+
+#[cfg(all(test, feature = "json_files"))]
+mod tests {
+    use super::*;
+    use crate::load_locales::locale::locale_from_json;
+
+    fn value_at(locale: &Locale, path: &[&str]) -> ParsedValue {
+        let path = path
+            .iter()
+            .map(|key| Rc::new(Key::new(key).unwrap()))
+            .collect::<Vec<_>>();
+        let mut value = locale.get_value_at(&path).unwrap().clone();
+        value.reduce();
+        value
+    }
+
+    #[test]
+    fn test_pseudo_locale() {
+        let mut locales = vec![locale_from_json(
+            "en",
+            r#"{ "hello": "Hello", "click": "Click <b>{{ name }}</b>", "menu": { "open": "Open" } }"#,
+        )];
+        let pseudo_locale = Rc::new(Key::new("en-XA").unwrap());
+        add_locale(&mut locales, &pseudo_locale);
+
+        let pseudo = &locales[1];
+        assert_eq!(pseudo.top_locale_name, pseudo_locale);
+        assert_eq!(
+            value_at(pseudo, &["hello"]),
+            ParsedValue::String("[Ĥéļļö ~~]".to_owned())
+        );
+        assert_eq!(
+            value_at(pseudo, &["menu", "open"]),
+            ParsedValue::String("[Öþéñ ~~]".to_owned())
+        );
+        let ParsedValue::Bloc(bloc) = value_at(pseudo, &["click"]) else {
+            panic!("expected a bloc");
+        };
+        let [ParsedValue::String(start), ParsedValue::Component { key, inner, .. }, ParsedValue::String(end)] =
+            bloc.as_slice()
+        else {
+            panic!("expected a component between two strings, got {:?}", bloc);
+        };
+        assert_eq!((start.as_str(), end.as_str()), ("[Çļíçķ ", " ~~]"));
+        assert_eq!(key.name, "comp_b");
+        assert!(matches!(&**inner, ParsedValue::Variable(_)));
+    }
+}
@@ -0,0 +1,310 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use sha2::{Digest, Sha256};
+
+use super::error::{Error, Result};
+
+const LOCKFILE: &str = "i18n.lock";
+/// The environment variable enabling the fetch of the remote locale files, off by default so builds stay hermetic.
+pub const FETCH_VAR: &str = "LEPTOS_I18N_FETCH";
+
+fn hash(content: &[u8]) -> String {
+    Sha256::digest(content)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+fn env_flag(name: &str) -> bool {
+    std::env::var(name).is_ok_and(|value| !matches!(value.as_str(), "" | "0" | "false"))
+}
+
+/// Fetch the locale files from the `locales-url` of the configuration.
+///
+/// The hash of each fetched file is pinned in the `i18n.lock` file next to the manifest,
+/// a file whose content changed is an error until its entry is removed from the lockfile.
+/// Files are cached by hash in the temporary directory, so a pinned file is only fetched once.
+///
+/// Nothing is fetched unless `LEPTOS_I18N_FETCH` is set, only the pinned files of the cache are used.
+/// In CI (`CI` is set) the lockfile must exist and pin every file, it is never written.
+#[derive(Debug)]
+pub struct Remote {
+    base_url: String,
+    lockfile_path: PathBuf,
+    cache_dir: PathBuf,
+    lock: BTreeMap<String, String>,
+    lock_changed: bool,
+    fetch: bool,
+    locked: bool,
+    curl: &'static str,
+}
+
+impl Remote {
+    pub fn new(base_url: &str, manifest_dir_path: &Path) -> Result<Self> {
+        Self::with_options(
+            base_url,
+            manifest_dir_path,
+            std::env::temp_dir().join("leptos_i18n"),
+            env_flag(FETCH_VAR),
+            env_flag("CI"),
+        )
+    }
+
+    fn with_options(
+        base_url: &str,
+        manifest_dir_path: &Path,
+        cache_dir: PathBuf,
+        fetch: bool,
+        locked: bool,
+    ) -> Result<Self> {
+        let lockfile_path = manifest_dir_path.join(LOCKFILE);
+        let lock = match std::fs::read_to_string(&lockfile_path) {
+            Ok(content) => toml::de::from_str(&content).map_err(Error::LockfileDeser)?,
+            Err(_) if locked => return Err(Error::LockfileMissing(lockfile_path)),
+            Err(_) => BTreeMap::new(),
+        };
+        Ok(Remote {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            lockfile_path,
+            cache_dir,
+            lock,
+            lock_changed: false,
+            fetch,
+            locked,
+            curl: "curl",
+        })
+    }
+
+    fn read_cache(&self, url: &str) -> Option<Vec<u8>> {
+        let expected = self.lock.get(url)?;
+        let content = std::fs::read(self.cache_dir.join(expected)).ok()?;
+        (&hash(&content) == expected).then_some(content)
+    }
+
+    fn fetch(&self, url: &str) -> Result<Vec<u8>, String> {
+        let output = Command::new(self.curl)
+            .args(["--fail", "--silent", "--show-error", "--location", url])
+            .output()
+            .map_err(|err| {
+                format!(
+                    "{} is needed to fetch the remote locales but could not be run: {}",
+                    self.curl, err
+                )
+            })?;
+        if output.status.success() {
+            Ok(output.stdout)
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(format!(
+                "{} failed ({}): {}",
+                self.curl,
+                output.status,
+                stderr.trim()
+            ))
+        }
+    }
+
+    /// Return the url and the content of the file at `path` (`locale` or `locale/namespace`),
+    /// trying each supported extension.
+    pub fn get(&mut self, path: &Path, exts: &[&str]) -> Result<(String, Vec<u8>)> {
+        let urls = exts
+            .iter()
+            .map(|ext| format!("{}/{}.{}", self.base_url, path.display(), ext))
+            .collect::<Vec<_>>();
+
+        if let Some(cached) = urls
+            .iter()
+            .find_map(|url| Some((url.clone(), self.read_cache(url)?)))
+        {
+            return Ok(cached);
+        }
+
+        if !self.fetch {
+            return Err(Error::RemoteLocaleNotCached(path.to_owned()));
+        }
+
+        let mut errs = vec![];
+        for url in urls {
+            let content = match self.fetch(&url) {
+                Ok(content) => content,
+                Err(err) => {
+                    errs.push((url, err));
+                    continue;
+                }
+            };
+            let found = hash(&content);
+            match self.lock.get(&url) {
+                Some(expected) if expected != &found => {
+                    return Err(Error::RemoteLocaleHashMismatch {
+                        url,
+                        expected: expected.clone(),
+                        found,
+                    })
+                }
+                Some(_) => {}
+                None if self.locked => return Err(Error::RemoteLocaleNotPinned(url)),
+                None => {
+                    self.lock.insert(url.clone(), found.clone());
+                    self.lock_changed = true;
+                }
+            }
+            // the cache is only an optimization, failing to write it is not an error.
+            let _ = std::fs::create_dir_all(&self.cache_dir)
+                .and_then(|_| std::fs::write(self.cache_dir.join(found), &content));
+            return Ok((url, content));
+        }
+
+        Err(Error::RemoteLocaleFetch(errs))
+    }
+
+    /// Write the lockfile if new files were pinned.
+    pub fn write_lockfile(&self) -> Result<()> {
+        if !self.lock_changed {
+            return Ok(());
+        }
+        let mut content =
+            "# This file is generated by leptos_i18n, it pins the content of the remote locale files.\n"
+                .to_owned();
+        for (url, hash) in &self.lock {
+            content.push_str(&format!("{:?} = {:?}\n", url, hash));
+        }
+        std::fs::write(&self.lockfile_path, content).map_err(|err| Error::LockfileWrite {
+            path: self.lockfile_path.clone(),
+            err,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A directory served with `file://` urls, a manifest directory and a cache, all empty.
+    struct TestDirs {
+        root: PathBuf,
+    }
+
+    impl TestDirs {
+        fn new(name: &str) -> Self {
+            let root = std::env::temp_dir().join(format!(
+                "leptos_i18n_remote_{}_{}",
+                name,
+                std::process::id()
+            ));
+            let _ = std::fs::remove_dir_all(&root);
+            for dir in ["served", "manifest", "cache"] {
+                std::fs::create_dir_all(root.join(dir)).unwrap();
+            }
+            TestDirs { root }
+        }
+
+        fn url(&self) -> String {
+            format!("file://{}", self.root.join("served").display())
+        }
+
+        fn serve(&self, file: &str, content: &str) {
+            std::fs::write(self.root.join("served").join(file), content).unwrap();
+        }
+
+        fn remote(&self, fetch: bool, locked: bool) -> Result<Remote> {
+            Remote::with_options(
+                &self.url(),
+                &self.root.join("manifest"),
+                self.root.join("cache"),
+                fetch,
+                locked,
+            )
+        }
+    }
+
+    impl Drop for TestDirs {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.root);
+        }
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(
+            hash(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_fetch_and_pin() {
+        let dirs = TestDirs::new("pin");
+        dirs.serve("en.json", r#"{ "hello": "Hello" }"#);
+
+        // nothing is fetched without the opt-in.
+        let mut remote = dirs.remote(false, false).unwrap();
+        let err = remote.get(Path::new("en"), &["json"]).unwrap_err();
+        assert!(matches!(err, Error::RemoteLocaleNotCached(_)));
+
+        let mut remote = dirs.remote(true, false).unwrap();
+        let (url, content) = remote.get(Path::new("en"), &["yaml", "json"]).unwrap();
+        assert_eq!(url, format!("{}/en.json", dirs.url()));
+        assert_eq!(content, br#"{ "hello": "Hello" }"#);
+        remote.write_lockfile().unwrap();
+        let lockfile = std::fs::read_to_string(dirs.root.join("manifest").join(LOCKFILE)).unwrap();
+        assert!(lockfile.contains(&hash(&content)));
+
+        // the pinned file is read from the cache, without fetching it.
+        std::fs::remove_file(dirs.root.join("served").join("en.json")).unwrap();
+        let mut remote = dirs.remote(false, true).unwrap();
+        let (_, cached) = remote.get(Path::new("en"), &["json"]).unwrap();
+        assert_eq!(cached, content);
+    }
+
+    #[test]
+    fn test_lockfile_mismatch() {
+        let dirs = TestDirs::new("mismatch");
+        dirs.serve("en.json", r#"{ "hello": "Hello" }"#);
+        let mut remote = dirs.remote(true, false).unwrap();
+        remote.get(Path::new("en"), &["json"]).unwrap();
+        remote.write_lockfile().unwrap();
+
+        dirs.serve("en.json", r#"{ "hello": "Hi" }"#);
+        std::fs::remove_dir_all(dirs.root.join("cache")).unwrap();
+        let mut remote = dirs.remote(true, false).unwrap();
+        let err = remote.get(Path::new("en"), &["json"]).unwrap_err();
+        assert!(matches!(err, Error::RemoteLocaleHashMismatch { .. }));
+    }
+
+    #[test]
+    fn test_locked() {
+        let dirs = TestDirs::new("locked");
+        dirs.serve("en.json", r#"{ "hello": "Hello" }"#);
+        let err = dirs.remote(true, true).unwrap_err();
+        assert!(matches!(err, Error::LockfileMissing(_)));
+
+        std::fs::write(dirs.root.join("manifest").join(LOCKFILE), "").unwrap();
+        let mut remote = dirs.remote(true, true).unwrap();
+        let err = remote.get(Path::new("en"), &["json"]).unwrap_err();
+        assert!(matches!(err, Error::RemoteLocaleNotPinned(_)));
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let dirs = TestDirs::new("errors");
+        let mut remote = dirs.remote(true, false).unwrap();
+        let Error::RemoteLocaleFetch(errs) = remote.get(Path::new("en"), &["json"]).unwrap_err()
+        else {
+            panic!("expected a fetch error");
+        };
+        assert!(errs[0].1.starts_with("curl failed"));
+
+        remote.curl = "leptos-i18n-missing-curl";
+        let Error::RemoteLocaleFetch(errs) = remote.get(Path::new("en"), &["json"]).unwrap_err()
+        else {
+            panic!("expected a fetch error");
+        };
+        assert!(errs[0]
+            .1
+            .starts_with("leptos-i18n-missing-curl is needed to fetch the remote locales"));
+    }
+}
@@ -0,0 +1,141 @@
+use std::{collections::HashMap, path::PathBuf, rc::Rc};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::{
+    error::{Error, Result},
+    key::{Key, KeyPath},
+    locale::LocalesOrNamespaces,
+    parsed_value::ParsedValue,
+};
+
+const RENAMES_FILE: &str = "renames.toml";
+
+#[derive(Debug)]
+pub struct Rename {
+    pub old: Rc<Key>,
+    pub new: Rc<Key>,
+    pub note: String,
+}
+
+/// Renamed keys, grouped by the path of their parent.
+#[derive(Debug, Default)]
+pub struct Renames(HashMap<KeyPath, Vec<Rename>>);
+
+impl Renames {
+    fn parse_path(path: &str, old: &str, new: &str) -> Result<KeyPath> {
+        ParsedValue::parse_key_path(path).ok_or_else(|| Error::InvalidRename {
+            old: old.to_owned(),
+            new: new.to_owned(),
+            reason: "invalid key path",
+        })
+    }
+
+    /// Load the `renames.toml` file at the root of the locales directory, if any.
+    pub fn new(
+        locales_dir_path: &mut PathBuf,
+        locales: &LocalesOrNamespaces,
+        default_locale: &Rc<Key>,
+    ) -> Result<Self> {
+        locales_dir_path.push(RENAMES_FILE);
+        let renames_str = std::fs::read_to_string(&locales_dir_path);
+        locales_dir_path.pop();
+
+        let Ok(renames_str) = renames_str else {
+            return Ok(Renames::default());
+        };
+
+        let raw_renames: HashMap<String, String> =
+            toml::de::from_str(&renames_str).map_err(Error::RenamesFileDeser)?;
+
+        let mut renames = Renames::default();
+
+        for (old, new) in &raw_renames {
+            let err = |reason| Error::InvalidRename {
+                old: old.clone(),
+                new: new.clone(),
+                reason,
+            };
+            let mut old_path = Self::parse_path(old, old, new)?;
+            let mut new_path = Self::parse_path(new, old, new)?;
+
+            if locales.get_value_at(default_locale, &new_path).is_none() {
+                return Err(err("the new key does not exist in the default locale"));
+            }
+            if locales.get_value_at(default_locale, &old_path).is_some() {
+                return Err(err("the old key still exist in the default locale"));
+            }
+
+            let note = format!("renamed to {}", new_path);
+            let (Some(old_key), Some(new_key)) = (old_path.pop_key(), new_path.pop_key()) else {
+                unreachable!("parse_key_path always return at least one key.")
+            };
+            if old_path != new_path {
+                return Err(err("a key can only be renamed within the same parent"));
+            }
+
+            renames.0.entry(old_path).or_default().push(Rename {
+                old: old_key,
+                new: new_key,
+                note,
+            });
+        }
+
+        Ok(renames)
+    }
+
+    /// Return the renamed keys whose parent is at the given path.
+    pub fn get(&self, parent: &KeyPath) -> &[Rename] {
+        self.0.get(parent).map(Vec::as_slice).unwrap_or_default()
+    }
+}
+
+fn with_aliases<'a, F>(
+    renames: &'a [Rename],
+    key: &'a Key,
+    f: F,
+    deprecate: bool,
+) -> impl Iterator<Item = TokenStream> + 'a
+where
+    F: Fn(&Key) -> TokenStream + 'a,
+{
+    let field = f(key);
+    let aliases = renames
+        .iter()
+        .filter(move |rename| &*rename.new == key)
+        .map(move |rename| {
+            let ts = f(&rename.old);
+            if deprecate {
+                let note = &rename.note;
+                quote!(#[deprecated(note = #note)] #ts)
+            } else {
+                ts
+            }
+        });
+    std::iter::once(field).chain(aliases)
+}
+
+/// Return the field declaration for the given key, followed by the deprecated declarations of its old names.
+pub fn decl_with_aliases<'a, F>(
+    renames: &'a [Rename],
+    key: &'a Key,
+    f: F,
+) -> impl Iterator<Item = TokenStream> + 'a
+where
+    F: Fn(&Key) -> TokenStream + 'a,
+{
+    with_aliases(renames, key, f, true)
+}
+
+/// Return the field initialization for the given key, followed by the initializations of its old names.
+pub fn init_with_aliases<'a, F>(
+    renames: &'a [Rename],
+    key: &'a Key,
+    f: F,
+) -> impl Iterator<Item = TokenStream> + 'a
+where
+    F: Fn(&Key) -> TokenStream + 'a,
+{
+    with_aliases(renames, key, f, false)
+}
@@ -0,0 +1,205 @@
+use std::{collections::HashSet, rc::Rc};
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+use super::{
+    error::Error,
+    key::Key,
+    parsed_value::{InterpolateKey, ParsedValue, ParsedValueSeed},
+    plural::PluralKey,
+};
+use crate::backend;
+
+/// The key of a map declaring a select, its value being the name of the variable to match on.
+pub const SELECT_KEY: &str = "$select";
+
+/// The branch used when the variable match none of the others.
+const OTHER_BRANCH: &str = "other";
+
+/// A value chosen by matching a variable on strings, like ICU `select`:
+///
+/// ```json
+/// {
+///   "$select": "gender",
+///   "male": "He replied",
+///   "female": "She replied",
+///   "other": "They replied"
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Select {
+    pub key: Rc<Key>,
+    /// The branches, the `other` fallback being the last one.
+    pub branches: Vec<(String, ParsedValue)>,
+}
+
+impl Select {
+    /// Deserialize the branches of a select, the `$select` key and the variable being already taken from the map.
+    pub fn from_serde_map<'de, A>(
+        variable: &str,
+        mut map: A,
+        parsed_value_seed: ParsedValueSeed,
+    ) -> Result<Self, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let key = Key::try_new(&format!("var_{}", variable.trim()))
+            .map(Rc::new)
+            .map_err(serde::de::Error::custom)?;
+        let mut branches = vec![];
+        let mut other = None;
+        while let Some(PluralKey(branch)) = map.next_key()? {
+            // the branches are plain strings, nested plurals, selects or subkeys are not supported.
+            let value: String = map.next_value()?;
+            let value = serde::de::Visitor::visit_str(parsed_value_seed, &value)?;
+            if branch == OTHER_BRANCH {
+                other = Some(value);
+            } else {
+                branches.push((branch, value));
+            }
+        }
+        let Some(other) = other else {
+            return Err(serde::de::Error::custom(Error::MissingSelectOther(
+                variable.to_owned(),
+            )));
+        };
+        branches.push((OTHER_BRANCH.to_owned(), other));
+        Ok(Select { key, branches })
+    }
+
+    pub fn try_for_each_value<F, E>(&self, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(&ParsedValue) -> Result<(), E>,
+    {
+        self.branches.iter().try_for_each(|(_, value)| f(value))
+    }
+
+    pub fn try_for_each_value_mut<F, E>(&mut self, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(&mut ParsedValue) -> Result<(), E>,
+    {
+        self.branches.iter_mut().try_for_each(|(_, value)| f(value))
+    }
+
+    pub fn get_keys_inner(&self, keys: &mut Option<HashSet<InterpolateKey>>) {
+        for (_, value) in &self.branches {
+            value.get_keys_inner(keys);
+        }
+        keys.get_or_insert_with(HashSet::new)
+            .insert(InterpolateKey::Select(Rc::clone(&self.key)));
+    }
+
+    fn to_match_statement(
+        &self,
+        value: TokenStream,
+        to_tokens: impl Fn(&ParsedValue) -> TokenStream,
+    ) -> TokenStream {
+        let match_arms = self.branches.iter().map(|(branch, value)| {
+            let value = to_tokens(value);
+            if branch == OTHER_BRANCH {
+                quote!(_ => #value)
+            } else {
+                quote!(#branch => #value)
+            }
+        });
+        quote! {
+            match core::convert::AsRef::<str>::as_ref(#value) {
+                #(
+                    #match_arms,
+                )*
+            }
+        }
+    }
+
+    #[cfg(feature = "interpolate_display")]
+    pub fn as_string_impl(&self) -> TokenStream {
+        let key = &self.key;
+        self.to_match_statement(quote!(#key), ParsedValue::as_string_impl)
+    }
+}
+
+impl ToTokens for Select {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let mut captured_values = None;
+        self.get_keys_inner(&mut captured_values);
+
+        let captured_values = captured_values.map(|keys| {
+            let keys = keys
+                .into_iter()
+                .map(|key| quote!(let #key = core::clone::Clone::clone(&#key);));
+            quote!(#(#keys)*)
+        });
+        let key = &self.key;
+        let match_statement = self.to_match_statement(
+            quote!(&leptos_i18n::select::SelectFn::call(&#key)),
+            ParsedValue::to_token_stream,
+        );
+
+        backend::into_view(quote! {
+            {
+                #captured_values
+                move || #match_statement
+            }
+        })
+        .to_tokens(tokens)
+    }
+}
+
+#[cfg(all(test, feature = "json_files"))]
+mod tests {
+    use super::*;
+    use crate::load_locales::{
+        key::KeyPath,
+        plural::{CountTypes, PluralConstants},
+    };
+
+    fn parse(json: &str) -> Result<ParsedValue, serde_json::Error> {
+        let key_path = KeyPath::new(None);
+        let locale = Rc::new(Key::new("en").unwrap());
+        let key = Rc::new(Key::new("key").unwrap());
+        let plural_constants = Rc::new(PluralConstants::default());
+        let count_types = Rc::new(CountTypes::default());
+        let seed = ParsedValueSeed {
+            top_locale_name: &locale,
+            in_plural: false,
+            key_path: &key_path,
+            key: &key,
+            plural_constants: &plural_constants,
+            count_types: &count_types,
+            format_counts: false,
+        };
+        serde::de::DeserializeSeed::deserialize(seed, &mut serde_json::Deserializer::from_str(json))
+    }
+
+    #[test]
+    fn parse_select() {
+        let value = parse(
+            r#"{ "$select": "gender", "other": "They replied", "female": "She replied to {{ name }}" }"#,
+        )
+        .unwrap();
+        let ParsedValue::Select(select) = value else {
+            panic!("expected a select, got {:?}", value);
+        };
+        assert_eq!(select.key.name, "var_gender");
+        let branches = select
+            .branches
+            .iter()
+            .map(|(branch, _)| branch.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(branches, ["female", "other"]);
+        let keys = ParsedValue::Select(select).get_keys().unwrap();
+        assert!(keys.contains(&InterpolateKey::Select(Rc::new(
+            Key::new("var_gender").unwrap()
+        ))));
+        assert!(keys.contains(&InterpolateKey::Variable(Rc::new(
+            Key::new("var_name").unwrap()
+        ))));
+    }
+
+    #[test]
+    fn select_without_other() {
+        let err = parse(r#"{ "$select": "gender", "male": "He replied" }"#).unwrap_err();
+        assert!(err.to_string().contains("other"));
+    }
+}
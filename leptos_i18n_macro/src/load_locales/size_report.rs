@@ -0,0 +1,377 @@
+use std::{fmt::Write, path::Path, rc::Rc};
+
+use super::{
+    error::{Error, Result},
+    key::Key,
+    locale::{Locale, LocalesOrNamespaces},
+    parsed_value::{InterpolateKey, ParsedValue},
+};
+
+#[derive(Debug, Default, Clone, Copy)]
+struct LocaleStats {
+    keys: usize,
+    string_bytes: usize,
+    interpolated: usize,
+    plurals: usize,
+    defaulted: usize,
+    /// The variables and components of the interpolated keys.
+    interpolations: usize,
+    components: usize,
+    plain_keys: usize,
+    plain_bytes: usize,
+    /// The bytes of the dot-separated paths of the keys, like `namespace.key.subkey`.
+    key_path_bytes: usize,
+    json_files: usize,
+    yaml_files: usize,
+    fluent_files: usize,
+}
+
+impl LocaleStats {
+    fn add_value(&mut self, value: &ParsedValue, path_len: usize) {
+        match value {
+            ParsedValue::Subkeys(Some(subkeys)) => self.add_keys(subkeys, Some(path_len)),
+            ParsedValue::Subkeys(None) => {}
+            ParsedValue::Default => {
+                self.keys += 1;
+                self.defaulted += 1;
+                self.key_path_bytes += path_len;
+            }
+            value => {
+                self.keys += 1;
+                self.key_path_bytes += path_len;
+                if let ParsedValue::Plural(_) = value {
+                    self.plurals += 1;
+                }
+                let string_bytes = Self::string_bytes(value);
+                self.string_bytes += string_bytes;
+                match value.get_keys() {
+                    Some(keys) => {
+                        self.interpolated += 1;
+                        self.interpolations += keys.len();
+                        self.components += keys
+                            .iter()
+                            .filter(|key| {
+                                matches!(
+                                    key,
+                                    InterpolateKey::Component(_)
+                                        | InterpolateKey::ComponentWithAttrs(_)
+                                )
+                            })
+                            .count();
+                    }
+                    None => {
+                        self.plain_keys += 1;
+                        self.plain_bytes += string_bytes;
+                    }
+                }
+            }
+        }
+    }
+
+    fn string_bytes(value: &ParsedValue) -> usize {
+        match value {
+            ParsedValue::Default
+            | ParsedValue::Subkeys(_)
+            | ParsedValue::Variable(_)
+            | ParsedValue::FormattedCount(_)
+            | ParsedValue::Formatted { .. } => 0,
+            ParsedValue::String(s) => s.len(),
+            ParsedValue::Component { inner, .. } => Self::string_bytes(inner),
+            ParsedValue::Bloc(values) => values.iter().map(Self::string_bytes).sum(),
+            ParsedValue::ForeignKey(foreign_key) => {
+                Self::string_bytes(foreign_key.borrow().as_inner("string_bytes"))
+            }
+            ParsedValue::Plural(plurals) => {
+                let mut sum = 0;
+                let _: Result<(), ()> = plurals.try_for_each_value(|value| {
+                    sum += Self::string_bytes(value);
+                    Ok(())
+                });
+                sum
+            }
+            ParsedValue::Select(select) => select
+                .branches
+                .iter()
+                .map(|(_, value)| Self::string_bytes(value))
+                .sum(),
+        }
+    }
+
+    /// Add the keys of a locale, `parent_len` being the length of the path of their parent, if any.
+    fn add_keys(&mut self, locale: &Locale, parent_len: Option<usize>) {
+        for (key, value) in &locale.keys {
+            let path_len = match parent_len {
+                Some(parent_len) => parent_len + 1 + key.name.len(),
+                None => key.name.len(),
+            };
+            self.add_value(value, path_len);
+        }
+    }
+
+    fn add_locale(&mut self, locale: &Locale, namespace: Option<&Key>) {
+        self.add_keys(locale, namespace.map(|namespace| namespace.name.len()));
+        for file in &locale.files {
+            match file.extension().and_then(|ext| ext.to_str()) {
+                Some("json") => self.json_files += 1,
+                Some("yaml" | "yml") => self.yaml_files += 1,
+                Some("ftl") => self.fluent_files += 1,
+                _ => {}
+            }
+        }
+    }
+
+    fn sum(stats: &[LocaleStats], get: impl Fn(&LocaleStats) -> usize) -> usize {
+        stats.iter().map(get).sum()
+    }
+}
+
+/// Return the features changing the generated code, if they are enabled, and what they generate for these locales.
+///
+/// The first locale is the default one: the keys have the same interpolations in every locale,
+/// so the generated items are counted once on the default locale.
+fn features(stats: &[LocaleStats]) -> Vec<(&'static str, bool, String)> {
+    let default = stats.first().copied().unwrap_or_default();
+    let fetched = stats.get(1..).unwrap_or_default();
+    vec![
+        (
+            "json_files",
+            cfg!(feature = "json_files"),
+            format!(
+                "{} files parsed at compile time",
+                LocaleStats::sum(stats, |stats| stats.json_files)
+            ),
+        ),
+        (
+            "yaml_files",
+            cfg!(feature = "yaml_files"),
+            format!(
+                "{} files parsed at compile time",
+                LocaleStats::sum(stats, |stats| stats.yaml_files)
+            ),
+        ),
+        (
+            "fluent",
+            cfg!(feature = "fluent"),
+            format!(
+                "{} files parsed at compile time",
+                LocaleStats::sum(stats, |stats| stats.fluent_files)
+            ),
+        ),
+        (
+            "serde",
+            cfg!(feature = "serde"),
+            "2 trait implementations on `Locale`".to_string(),
+        ),
+        (
+            "interpolate_display",
+            cfg!(feature = "interpolate_display"),
+            format!(
+                "{} `Display` implementations and {} string setters",
+                default.interpolated, default.interpolations
+            ),
+        ),
+        (
+            "debug_interpolations",
+            cfg!(feature = "debug_interpolations"),
+            format!("{} deprecated setters", default.interpolations),
+        ),
+        (
+            "erased_views",
+            cfg!(feature = "erased_views"),
+            format!("{} interpolated views erased", default.interpolated),
+        ),
+        (
+            "no_components",
+            cfg!(feature = "no_components"),
+            format!("{} component interpolations rejected", default.components),
+        ),
+        (
+            "obfuscate_strings",
+            cfg!(feature = "obfuscate_strings"),
+            format!(
+                "{} bytes of strings obfuscated",
+                LocaleStats::sum(stats, |stats| stats.string_bytes)
+            ),
+        ),
+        (
+            "hot_reload",
+            cfg!(feature = "hot_reload"),
+            format!("{} strings reloadable", default.plain_keys),
+        ),
+        (
+            "dynamic_load_strings",
+            cfg!(feature = "dynamic_load_strings"),
+            format!(
+                "{} bytes of strings fetched at runtime instead of compiled in",
+                LocaleStats::sum(fetched, |stats| stats.plain_bytes)
+            ),
+        ),
+        (
+            "dynamic_keys",
+            cfg!(feature = "dynamic_keys"),
+            format!(
+                "{} match arms and {} bytes of key paths",
+                default.keys, default.key_path_bytes
+            ),
+        ),
+        (
+            "usage",
+            cfg!(feature = "usage"),
+            format!("{} bytes of key paths", default.key_path_bytes),
+        ),
+    ]
+}
+
+fn collect_stats(locales: &LocalesOrNamespaces, locale_keys: &[Rc<Key>]) -> Vec<LocaleStats> {
+    let mut stats = vec![LocaleStats::default(); locale_keys.len()];
+    let mut add_locales = |locales: &[Locale], namespace: Option<&Key>| {
+        for locale in locales {
+            let Some(i) = locale_keys
+                .iter()
+                .position(|key| key == &locale.top_locale_name)
+            else {
+                continue;
+            };
+            stats[i].add_locale(locale, namespace);
+        }
+    };
+    match locales {
+        LocalesOrNamespaces::Locales(locales) => add_locales(locales, None),
+        LocalesOrNamespaces::NameSpaces(namespaces) => {
+            for namespace in namespaces {
+                add_locales(&namespace.locales, Some(&namespace.key));
+            }
+        }
+    }
+    stats
+}
+
+fn generate_report(locales: &LocalesOrNamespaces, locale_keys: &[Rc<Key>]) -> String {
+    let stats = collect_stats(locales, locale_keys);
+    let mut report = String::new();
+
+    // writing to a String can't fail
+    let _ = writeln!(report, "leptos_i18n size report");
+    let _ = writeln!(report, "=======================");
+    let _ = writeln!(report);
+    let _ = writeln!(report, "Locales:");
+    for (locale, stats) in locale_keys.iter().zip(&stats) {
+        let _ = writeln!(
+            report,
+            "  {}: {} bytes of strings, {} keys ({} interpolated, {} plurals, {} defaulted)",
+            locale.name,
+            stats.string_bytes,
+            stats.keys,
+            stats.interpolated,
+            stats.plurals,
+            stats.defaulted
+        );
+    }
+    let total: usize = stats.iter().map(|stats| stats.string_bytes).sum();
+    let _ = writeln!(report, "  total: {} bytes of strings", total);
+    let _ = writeln!(report);
+    let _ = writeln!(report, "Features:");
+    for (feature, enabled, generated) in features(&stats) {
+        let state = if enabled { "enabled" } else { "disabled" };
+        let _ = writeln!(report, "  {} ({}): {}", feature, state, generated);
+    }
+
+    report
+}
+
+pub fn write_report(
+    manifest_dir_path: &Path,
+    report_path: &str,
+    locales: &LocalesOrNamespaces,
+    locale_keys: &[Rc<Key>],
+) -> Result<()> {
+    let path = manifest_dir_path.join(report_path);
+    let report = generate_report(locales, locale_keys);
+    std::fs::write(&path, report).map_err(|err| Error::SizeReport { path, err })
+}
+
+#[cfg(all(test, feature = "json_files"))]
+mod tests {
+    use super::*;
+    use crate::load_locales::locale::locale_from_json;
+
+    fn locales() -> (LocalesOrNamespaces, Vec<Rc<Key>>) {
+        let en = locale_from_json(
+            "en",
+            r#"{
+                "hello": "Hello",
+                "click_count": "You clicked <b>{{ count }}</b> times",
+                "home": { "title": "Home" }
+            }"#,
+        );
+        let fr = locale_from_json(
+            "fr",
+            r#"{
+                "hello": "Bonjour",
+                "click_count": "Vous avez cliqué <b>{{ count }}</b> fois",
+                "home": { "title": "Accueil" }
+            }"#,
+        );
+        let keys = vec![Rc::clone(&en.name), Rc::clone(&fr.name)];
+        (LocalesOrNamespaces::Locales(vec![en, fr]), keys)
+    }
+
+    #[test]
+    fn test_stats() {
+        let (locales, keys) = locales();
+        let stats = collect_stats(&locales, &keys);
+        let en = stats[0];
+        assert_eq!((en.keys, en.interpolated, en.plain_keys), (3, 1, 2));
+        assert_eq!((en.interpolations, en.components), (2, 1));
+        assert_eq!(en.plain_bytes, "Hello".len() + "Home".len());
+        assert_eq!(
+            en.key_path_bytes,
+            "hello".len() + "click_count".len() + "home.title".len()
+        );
+        assert_eq!(stats[1].plain_bytes, "Bonjour".len() + "Accueil".len());
+    }
+
+    #[test]
+    fn test_features() {
+        let (locales, keys) = locales();
+        let stats = collect_stats(&locales, &keys);
+        let features = features(&stats);
+        let generated = |name: &str| {
+            features
+                .iter()
+                .find(|(feature, _, _)| *feature == name)
+                .map(|(_, _, generated)| generated.as_str())
+                .unwrap()
+        };
+        assert_eq!(
+            generated("interpolate_display"),
+            "1 `Display` implementations and 2 string setters"
+        );
+        assert_eq!(
+            generated("no_components"),
+            "1 component interpolations rejected"
+        );
+        // only the strings of the other locales are fetched.
+        assert_eq!(
+            generated("dynamic_load_strings"),
+            "14 bytes of strings fetched at runtime instead of compiled in"
+        );
+        assert_eq!(
+            generated("dynamic_keys"),
+            "3 match arms and 26 bytes of key paths"
+        );
+
+        let report = generate_report(&locales, &keys);
+        assert!(report.contains(
+            "  en: 27 bytes of strings, 3 keys (1 interpolated, 0 plurals, 0 defaulted)"
+        ));
+        assert!(report.contains(&format!(
+            "  usage ({}): 26 bytes of key paths",
+            if cfg!(feature = "usage") {
+                "enabled"
+            } else {
+                "disabled"
+            }
+        )));
+    }
+}
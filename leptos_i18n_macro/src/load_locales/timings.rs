@@ -0,0 +1,103 @@
+use std::{
+    cell::RefCell,
+    fmt::Display,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use super::key::Key;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Validate,
+    Codegen,
+}
+
+#[derive(Debug)]
+pub struct Timing {
+    stage: Stage,
+    namespace: Option<Rc<Key>>,
+    locale: Option<Rc<Key>>,
+    duration: Duration,
+}
+
+thread_local! {
+    // `None` when the timings are not requested.
+    static TIMINGS: RefCell<Option<Vec<Timing>>> = const { RefCell::new(None) };
+}
+
+/// Start recording the timings, discarding the ones of a previous expansion.
+pub fn enable() {
+    TIMINGS.with(|timings| *timings.borrow_mut() = Some(Vec::new()));
+}
+
+/// Run `f`, recording how long it took if the timings are enabled.
+pub fn time<T>(
+    stage: Stage,
+    namespace: Option<&Rc<Key>>,
+    locale: Option<&Rc<Key>>,
+    f: impl FnOnce() -> T,
+) -> T {
+    let start = Instant::now();
+    let output = f();
+    let duration = start.elapsed();
+    TIMINGS.with(|timings| {
+        if let Some(timings) = timings.borrow_mut().as_mut() {
+            timings.push(Timing {
+                stage,
+                namespace: namespace.cloned(),
+                locale: locale.cloned(),
+                duration,
+            });
+        }
+    });
+    output
+}
+
+/// Print the recorded timings to stderr, if enabled.
+pub fn print_report(total: Duration) {
+    let Some(timings) = TIMINGS.with(|timings| timings.borrow_mut().take()) else {
+        return;
+    };
+    eprintln!("leptos_i18n: load_locales! timings");
+    for timing in &timings {
+        eprintln!("  {}", timing);
+    }
+    for stage in [Stage::Parse, Stage::Validate, Stage::Codegen] {
+        let stage_total: Duration = timings
+            .iter()
+            .filter(|timing| timing.stage == stage)
+            .map(|timing| timing.duration)
+            .sum();
+        eprintln!("  {} total: {:.2?}", stage, stage_total);
+    }
+    eprintln!("  total: {:.2?}", total);
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => f.pad("parse"),
+            Stage::Validate => f.pad("validate"),
+            Stage::Codegen => f.pad("codegen"),
+        }
+    }
+}
+
+impl Display for Timing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.namespace.is_none() && self.locale.is_none() {
+            write!(f, "{}", self.stage)?;
+        } else {
+            write!(f, "{:<8}", self.stage)?;
+        }
+        if let Some(namespace) = &self.namespace {
+            write!(f, " namespace {:?}", namespace.name)?;
+        }
+        if let Some(locale) = &self.locale {
+            write!(f, " locale {:?}", locale.name)?;
+        }
+        write!(f, ": {:.2?}", self.duration)
+    }
+}
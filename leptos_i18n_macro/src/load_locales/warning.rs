@@ -2,13 +2,32 @@ use proc_macro2::TokenStream;
 #[cfg(not(feature = "nightly"))]
 use quote::{format_ident, quote};
 
-use super::key::{Key, KeyPath};
-use std::{cell::RefCell, fmt::Display, rc::Rc};
+use super::{
+    error::{Error, Result},
+    key::{Key, KeyPath},
+};
+use std::{
+    cell::{Cell, RefCell},
+    fmt::Display,
+    rc::Rc,
+};
 
 #[derive(Debug)]
 pub enum Warning {
-    MissingKey { locale: Rc<Key>, key_path: KeyPath },
-    SurplusKey { locale: Rc<Key>, key_path: KeyPath },
+    MissingKey {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+    },
+    SurplusKey {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+    },
+    InterpolationMismatch {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+        missing: Vec<String>,
+        surplus: Vec<String>,
+    },
 }
 
 thread_local! {
@@ -21,17 +40,111 @@ pub fn emit_warning(warning: Warning) {
     }
 }
 
+/// How a key missing in a locale other than the default is handled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MissingKeys {
+    /// Emit a warning, the key falling back to the value of the default locale.
+    #[default]
+    Warn,
+    /// Abort the compilation.
+    Error,
+}
+
+thread_local! {
+    static MISSING_KEYS: Cell<MissingKeys> = const { Cell::new(MissingKeys::Warn) };
+}
+
+impl MissingKeys {
+    /// Set the policy used when merging the locales.
+    pub fn set(self) {
+        MISSING_KEYS.with(|missing_keys| missing_keys.set(self));
+    }
+
+    fn get() -> Self {
+        MISSING_KEYS.with(Cell::get)
+    }
+}
+
+/// Report a key of the default locale missing in another locale, according to the `missing-keys` policy.
+pub fn missing_key(locale: Rc<Key>, key_path: KeyPath) -> Result<()> {
+    match MissingKeys::get() {
+        MissingKeys::Warn => {
+            emit_warning(Warning::MissingKey { locale, key_path });
+            Ok(())
+        }
+        MissingKeys::Error => Err(Error::MissingKeyInLocale { locale, key_path }),
+    }
+}
+
+struct MissingKeysVisitor;
+
+impl<'de> serde::Deserialize<'de> for MissingKeys {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(MissingKeysVisitor)
+    }
+}
+
+impl<'de> serde::de::Visitor<'de> for MissingKeysVisitor {
+    type Value = MissingKeys;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "either \"warn\" or \"error\"")
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "warn" => Ok(MissingKeys::Warn),
+            "error" => Ok(MissingKeys::Error),
+            _ => Err(E::unknown_variant(v, &["warn", "error"])),
+        }
+    }
+}
+
 impl Display for Warning {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Warning::MissingKey { locale, key_path } => {
-                write!(f, "Missing key {} in locale {:?}", key_path, locale)
-            }
+            Warning::MissingKey { locale, key_path } => write!(
+                f,
+                "Missing key {} in locale {:?}, the value of the default locale is used",
+                key_path, locale
+            ),
             Warning::SurplusKey { locale, key_path } => write!(
                 f,
                 "Key {} is present in locale {:?} but not in default locale, it is ignored",
                 key_path, locale
             ),
+            Warning::InterpolationMismatch {
+                locale,
+                key_path,
+                missing,
+                surplus,
+            } => {
+                write!(
+                    f,
+                    "Key {} of locale {:?} does not have the interpolations of the default locale:",
+                    key_path, locale
+                )?;
+                let lists = [("missing", missing), ("surplus", surplus)];
+                let lists = lists.iter().filter(|(_, names)| !names.is_empty());
+                for (i, (kind, names)) in lists.enumerate() {
+                    let sep = if i == 0 { " " } else { ", " };
+                    let names = names.iter().map(|name| format!("`{}`", name));
+                    write!(
+                        f,
+                        "{}{} {}",
+                        sep,
+                        kind,
+                        names.collect::<Vec<_>>().join(", ")
+                    )?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -2,6 +2,8 @@ use proc_macro2::{TokenStream, TokenTree};
 use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 use syn::{buffer::Cursor, parse::ParseBuffer, Expr, Ident, Token};
 
+use crate::backend;
+
 pub enum InterpolatedValue {
     // form t!(i18n, key, count)
     Var(Ident),
@@ -25,6 +27,19 @@ pub enum InterpolatedValue {
     },
 }
 
+impl InterpolatedValue {
+    /// Return the key of the component, if this is a component.
+    #[cfg(feature = "interpolate_display")]
+    pub fn component_key(&self) -> Option<&Ident> {
+        match self {
+            InterpolatedValue::Var(_) | InterpolatedValue::AssignedVar { .. } => None,
+            InterpolatedValue::Comp(key)
+            | InterpolatedValue::AssignedComp { key, .. }
+            | InterpolatedValue::DirectComp { key, .. } => Some(key),
+        }
+    }
+}
+
 fn check_component_end(input: Cursor) -> bool {
     // check for "/>" with either a ',' or end of stream after.
     let cursor = match input.punct() {
@@ -58,6 +73,9 @@ fn parse_view_component(input: &ParseBuffer) -> syn::Result<(Ident, TokenStream)
 impl syn::parse::Parse for InterpolatedValue {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let is_comp = input.peek(Token![<]);
+        if is_comp && cfg!(feature = "no_components") {
+            return Err(input.error("components are disabled by the \"no_components\" feature"));
+        }
         if is_comp {
             input.parse::<Token![<]>()?;
         }
@@ -125,7 +143,8 @@ impl InterpolatedValue {
                 attrs,
             } => {
                 let comp_ident = format_ident(key, false, string);
-                quote!(#comp_ident(move |__children| leptos::view! { <#comp_name #attrs>{move || __children()}</#comp_name> }))
+                let view = backend::component_view(comp_name, attrs, quote!(__children));
+                quote!(#comp_ident(move |__children| #view))
             }
         }
     }
@@ -15,6 +15,9 @@ pub enum OutputType {
     Builder,
     #[cfg(feature = "interpolate_display")]
     String,
+    /// The output of `t_string!`, a string rejecting the keys with components.
+    #[cfg(feature = "interpolate_display")]
+    TString,
     #[cfg(feature = "interpolate_display")]
     Display,
 }
@@ -34,6 +37,24 @@ pub fn t_macro(
     t_macro_inner(input, input_type, output_type).into()
 }
 
+#[cfg(feature = "interpolate_display")]
+pub fn t_string_macro(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(tokens as ParsedInput);
+    let component = input
+        .interpolations
+        .iter()
+        .flatten()
+        .find_map(interpolate::InterpolatedValue::component_key);
+    if let Some(key) = component {
+        let msg = format!(
+            "component <{}> can't be rendered to a string by t_string!, use a key without components or t!",
+            key
+        );
+        return syn::Error::new(key.span(), msg).into_compile_error().into();
+    }
+    t_macro_inner(input, InputType::Context, OutputType::TString).into()
+}
+
 pub fn t_macro_inner(
     input: ParsedInput,
     input_type: InputType,
@@ -84,6 +105,8 @@ impl OutputType {
             #[cfg(feature = "interpolate_display")]
             OutputType::String => quote!(build_string),
             #[cfg(feature = "interpolate_display")]
+            OutputType::TString => quote!(build_t_string),
+            #[cfg(feature = "interpolate_display")]
             OutputType::Display => quote!(build_display),
         }
     }
@@ -92,7 +115,7 @@ impl OutputType {
         match self {
             OutputType::View | OutputType::Builder => false,
             #[cfg(feature = "interpolate_display")]
-            OutputType::String | OutputType::Display => true,
+            OutputType::String | OutputType::TString | OutputType::Display => true,
         }
     }
 
@@ -101,16 +124,41 @@ impl OutputType {
             OutputType::View => quote!(move || #ts),
             OutputType::Builder => ts,
             #[cfg(feature = "interpolate_display")]
-            OutputType::String | OutputType::Display => ts,
+            OutputType::String | OutputType::TString | OutputType::Display => ts,
         }
     }
 }
 
 impl InputType {
     pub fn get_key<T: ToTokens>(self, input: T, keys: Keys) -> TokenStream {
+        if cfg!(feature = "usage") {
+            return self.get_key_with_usage(input, keys);
+        }
         match self {
             InputType::Context => quote!(leptos_i18n::I18nContext::get_keys(#input).#keys),
             InputType::Locale => quote!(leptos_i18n::Locale::get_keys(#input).#keys),
         }
     }
+
+    /// Record the use of the key in the current locale before accessing it, for the `usage` feature.
+    fn get_key_with_usage<T: ToTokens>(self, input: T, keys: Keys) -> TokenStream {
+        let key_path = keys.path();
+        let (locale, get_keys) = match self {
+            InputType::Context => (
+                quote!(leptos_i18n::I18nContext::get_locale_untracked(_input)),
+                quote!(leptos_i18n::I18nContext::get_keys(_input)),
+            ),
+            InputType::Locale => (
+                quote!(_input),
+                quote!(leptos_i18n::Locale::get_keys(_input)),
+            ),
+        };
+        quote! {
+            {
+                let _input = #input;
+                leptos_i18n::usage::record(leptos_i18n::Locale::as_str(#locale), #key_path);
+                #get_keys.#keys
+            }
+        }
+    }
 }
@@ -1,5 +1,6 @@
 use proc_macro2::Ident;
 use quote::{quote, ToTokens, TokenStreamExt};
+use syn::ext::IdentExt;
 use syn::token::Comma;
 use syn::{Expr, Token};
 
@@ -69,6 +70,19 @@ impl syn::parse::Parse for Keys {
     }
 }
 
+impl Keys {
+    /// The path of the key, like `parent.key`.
+    pub fn path(&self) -> String {
+        match self {
+            Keys::SingleKey(key) => key.unraw().to_string(),
+            Keys::Subkeys(keys) => {
+                let keys = keys.iter().map(|key| key.unraw().to_string());
+                keys.collect::<Vec<_>>().join(".")
+            }
+        }
+    }
+}
+
 impl ToTokens for Keys {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         match self {
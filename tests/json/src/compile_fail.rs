@@ -0,0 +1,5 @@
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("ui/*.rs");
+}
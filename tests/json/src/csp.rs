@@ -0,0 +1,93 @@
+use crate::i18n::*;
+use leptos::*;
+use leptos_i18n::components::{DirectionalInput, IconPosition};
+
+/// Return the names of the attributes of the tags of `html`, ignoring the quoted values.
+fn attribute_names(html: &str) -> Vec<String> {
+    let mut names = vec![];
+    let mut chars = html.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '<' {
+            continue;
+        }
+        // skip the tag name
+        while chars
+            .next_if(|c| !c.is_ascii_whitespace() && *c != '>')
+            .is_some()
+        {}
+        let mut name = String::new();
+        while let Some(c) = chars.next() {
+            match c {
+                '>' => break,
+                '"' | '\'' => while chars.next_if(|next| *next != c).is_some() {},
+                c if c.is_ascii_whitespace() || c == '=' || c == '/' => {
+                    if !name.is_empty() {
+                        names.push(std::mem::take(&mut name));
+                    }
+                }
+                c => name.push(c.to_ascii_lowercase()),
+            }
+        }
+        if !name.is_empty() {
+            names.push(name);
+        }
+    }
+    names
+}
+
+/// Check that a view is allowed by a strict Content Security Policy, like
+/// `script-src 'nonce-...' 'strict-dynamic'; style-src 'nonce-...'`: no inline event handlers,
+/// no `javascript:` URLs, no inline styles and no inline scripts.
+fn assert_csp_compatible(rendered: &str) {
+    let names = attribute_names(rendered);
+    assert!(
+        !names
+            .iter()
+            .any(|name| name.starts_with("on") || name == "style"),
+        "inline event handler or style in {rendered:?}"
+    );
+    let lowercase = rendered.to_lowercase();
+    assert!(
+        !lowercase.contains("javascript:"),
+        "javascript URL in {rendered:?}"
+    );
+    assert!(
+        !lowercase.contains("<script"),
+        "inline script in {rendered:?}"
+    );
+}
+
+fn render(view: impl IntoView) -> String {
+    view.into_view().render_to_string().to_string()
+}
+
+#[test]
+fn translations_csp_compatible() {
+    let count = 12;
+    assert_csp_compatible(&render(td!(Locale::en, click_count, count)));
+    assert_csp_compatible(&render(
+        td!(Locale::fr, interpolate_variable_and_comp, <b> = <b />, count),
+    ));
+    let id = "\" onclick=\"alert(1)";
+    assert_csp_compatible(&render(
+        td!(Locale::en, link_attrs, id, <link> = |children, attrs| html::a().attrs(attrs).child(children())),
+    ));
+    assert_csp_compatible(&td_string!(Locale::en, link_attrs, id, <link> = "a"));
+}
+
+#[test]
+fn directional_input_csp_compatible() {
+    let runtime = create_runtime();
+    let rendered = render(view! {
+        <DirectionalInput
+            placeholder="Search"
+            class="search"
+            icon=|| view! { <i /> }
+            icon_position=IconPosition::End
+        />
+    });
+    assert_csp_compatible(&rendered);
+    assert!(rendered.contains("directional-input search\""));
+    assert!(rendered.contains("directional-input-icon directional-input-icon-end\""));
+    runtime.dispose();
+}
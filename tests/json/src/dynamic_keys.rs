@@ -0,0 +1,59 @@
+use crate::i18n::*;
+use common::*;
+use leptos_i18n::dynamic_keys::Interpolations;
+
+#[test]
+fn string_by_key() {
+    let en = Locale::en.get_by_key("click_to_inc").unwrap();
+    assert_eq!(en.as_str(), Some("Click to increment the counter"));
+    let fr = Locale::fr.get_by_key("click_to_inc").unwrap();
+    assert_eq!(fr.as_str(), Some("Cliquez pour incrémenter le compteur"));
+    let subkey = Locale::en.get_by_key("subkeys.subkey_1").unwrap();
+    assert_eq!(subkey.as_str(), Some("subkey_1"));
+}
+
+#[test]
+fn missing_key() {
+    assert!(Locale::en.get_by_key("missing").is_none());
+    assert!(Locale::en.get_by_key("subkeys").is_none());
+}
+
+#[test]
+fn builder_by_key() {
+    let interpolations = Interpolations::from_iter([("count", 12)]).component("b", |children| {
+        view! { <span>{children}</span> }.into_view()
+    });
+    let en = Locale::en
+        .get_by_key("interpolate_variable_and_comp")
+        .unwrap();
+    assert_eq!(en.as_str(), None);
+    assert_eq_rendered!(en.build(&interpolations), "<span>12</span>");
+    let missing_component = Interpolations::from_iter([("count", 34)]);
+    let fr = Locale::fr
+        .get_by_key("interpolate_variable_and_comp")
+        .unwrap();
+    assert_eq_rendered!(fr.build(&missing_component), "34");
+}
+
+#[test]
+fn plural_and_select_by_key() {
+    let value = Locale::en.get_by_key("range_plural").unwrap();
+    let interpolations = Interpolations::new().value("count", 3);
+    assert_eq_rendered!(value.build(&interpolations), "3 apples (few)");
+    let interpolations = Interpolations::new().value("count", "not a number");
+    assert_eq_rendered!(value.build(&interpolations), "No apples");
+
+    let value = Locale::en.get_by_key("replied").unwrap();
+    let interpolations = Interpolations::from_iter([("gender", "female"), ("name", "Alex")]);
+    assert_eq_rendered!(value.build(&interpolations), "She replied to Alex");
+}
+
+#[test]
+fn formatted_by_key() {
+    let value = Locale::en.get_by_key("formatted_total").unwrap();
+    let interpolations = Interpolations::from_iter([("price", "1234.5"), ("quantity", "12000")]);
+    assert_eq_rendered!(
+        value.build(&interpolations),
+        "Total: $1,234.50 for 12,000 items"
+    );
+}
@@ -0,0 +1,39 @@
+use crate::i18n::*;
+use common::*;
+use leptos_i18n::raw_html::RawHtml;
+
+#[test]
+fn variables_escaped() {
+    let count = "<script>alert(1)</script>";
+    let en = td!(Locale::en, click_count, count);
+    assert_eq_rendered!(
+        en,
+        "You clicked &lt;script&gt;alert(1)&lt;/script&gt; times"
+    );
+    let en = td!(Locale::en, interpolate_variable_and_comp, <b> = <b />, count);
+    assert_eq_rendered!(en, "<b>&lt;script&gt;alert(1)&lt;/script&gt;</b>");
+}
+
+#[test]
+fn attributes_escaped() {
+    let id = "\" onclick=\"alert(1)";
+    let en = td!(Locale::en, link_attrs, id, <link> = |children, attrs| html::a().attrs(attrs).child(children()));
+    assert_eq_rendered!(
+        en,
+        "See <a href=\"/users/&quot; onclick=&quot;alert(1)\" class=\"link\">your profile</a>"
+    );
+    let en = td_string!(Locale::en, link_attrs, id, <link> = "a");
+    assert_eq!(
+        en,
+        "See <a href=\"/users/&quot; onclick=&quot;alert(1)\" class=\"link\">your profile</a>"
+    );
+}
+
+#[test]
+fn raw_html() {
+    let count = RawHtml::dangerously_trust("<i>3</i>");
+    let en = td!(Locale::en, click_count, count = count.clone());
+    assert_eq_rendered!(en, "You clicked <span><i>3</i></span> times");
+    let en = td_string!(Locale::en, click_count, count);
+    assert_eq!(en, "You clicked <i>3</i> times");
+}
@@ -58,3 +58,11 @@ fn populated_foreign_key() {
     let fr = td!(Locale::fr, populated_foreign_key);
     assert_eq!(fr, "before Vous avez cliqué 32 fois after");
 }
+
+#[test]
+fn translation_reference() {
+    let en = td!(Locale::en, translation_reference);
+    assert_eq!(en, "before subkey_1 and You clicked 3 times after");
+    let fr = td!(Locale::fr, translation_reference);
+    assert_eq!(fr, "before subkey_1 and Vous avez cliqué 4 fois after");
+}
@@ -0,0 +1,49 @@
+use crate::i18n::*;
+use common::*;
+
+#[test]
+fn formatted_total() {
+    let price = || 1234.5;
+    let quantity = || 12000u32;
+    let en = td!(Locale::en, formatted_total, price, quantity);
+    assert_eq_rendered!(en, "Total: $1,234.50 for 12,000 items");
+    let fr = td!(Locale::fr, formatted_total, price, quantity);
+    assert_eq_rendered!(
+        fr,
+        "Total : 1\u{202f}234,50\u{a0}€ pour 12\u{202f}000 articles"
+    );
+}
+
+#[test]
+fn formatted_total_string() {
+    let en = td_string!(Locale::en, formatted_total, price = -5, quantity = 1);
+    assert_eq!(en, "Total: -$5.00 for 1 items");
+    let fr = td_string!(Locale::fr, formatted_total, price = 0.5, quantity = 3);
+    assert_eq!(fr, "Total : 0,50\u{a0}€ pour 3 articles");
+}
+
+#[test]
+fn formatted_date() {
+    let date = || leptos_i18n::format::DateTime::new(2024, 1, 31, 13, 5).unwrap();
+    let updated = || leptos_i18n::format::DateTime::from_unix_timestamp(0);
+    let en = td!(Locale::en, formatted_date, date, updated);
+    assert_eq_rendered!(
+        en,
+        "Published on January 31, 2024 at 13:05, updated 1/1/1970 00:00"
+    );
+    let fr = td!(Locale::fr, formatted_date, date, updated);
+    assert_eq_rendered!(
+        fr,
+        "Publié le 31 janvier 2024 à 13:05, mis à jour le 01/01/1970 00:00"
+    );
+}
+
+#[test]
+fn formatted_date_string() {
+    let date = leptos_i18n::format::DateTime::new(2024, 12, 1, 9, 30).unwrap();
+    let en = td_string!(Locale::en, formatted_date, date, updated = date);
+    assert_eq!(
+        en,
+        "Published on December 1, 2024 at 09:30, updated 12/1/2024 09:30"
+    );
+}
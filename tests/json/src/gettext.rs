@@ -0,0 +1,39 @@
+use crate::i18n::*;
+use common::*;
+
+#[test]
+fn gettext() {
+    let runtime = create_runtime();
+    let i18n = provide_i18n_context();
+
+    let en = gettext!(i18n, "Click to change language");
+    assert_eq_rendered!(en, "Click to change language");
+    let en = gettext!(i18n, "Start as {{ name }}", name = "Alex");
+    assert_eq_rendered!(en, "Start as Alex");
+    let b = |children: ChildrenFn| view! { <b>{children}</b> };
+    let en = gettext!(i18n, "<b>subkey_2</b>", <b>);
+    assert_eq_rendered!(en, "<b>subkey_2</b>");
+
+    i18n.set_locale(Locale::fr);
+    let fr = gettext!(i18n, "Click to change language");
+    assert_eq_rendered!(fr, "Cliquez pour changez de langue");
+
+    runtime.dispose();
+}
+
+#[test]
+fn ngettext() {
+    let runtime = create_runtime();
+    let i18n = provide_i18n_context();
+
+    let en = ngettext!(i18n, "One apple", "{{ count }} apples", || 1);
+    assert_eq_rendered!(en, "One apple");
+    let en = ngettext!(i18n, "One apple", "{{ count }} apples", || 3);
+    assert_eq_rendered!(en, "3 apples (few)");
+
+    i18n.set_locale(Locale::fr);
+    let fr = ngettext!(i18n, "One apple", "{{ count }} apples", || 7);
+    assert_eq_rendered!(fr, "7 pommes");
+
+    runtime.dispose();
+}
@@ -0,0 +1,122 @@
+use crate::i18n::*;
+use common::*;
+
+fn expected(locale: Locale) -> &'static str {
+    match locale {
+        Locale::en => "Click to change language",
+        Locale::fr => "Cliquez pour changez de langue",
+        Locale::en_XA => "[Çļíçķ ţö çĥáñĝé ļáñĝûáĝé ~~~~~~~~]",
+    }
+}
+
+fn locale_of(i: usize) -> Locale {
+    if i % 2 == 0 {
+        Locale::en
+    } else {
+        Locale::fr
+    }
+}
+
+#[test]
+fn fork() {
+    let runtime = create_runtime();
+    let i18n = provide_i18n_context();
+    i18n.set_locale(Locale::fr);
+    i18n.set_region(Some("ca"));
+
+    let fork = i18n.fork();
+    assert_eq!(fork.get_locale_untracked(), Locale::fr);
+    assert_eq!(fork.region().as_deref(), Some("CA"));
+
+    fork.set_locale(Locale::en);
+    fork.set_region(None);
+    assert_eq!(i18n.get_locale_untracked(), Locale::fr);
+    assert_eq!(i18n.region().as_deref(), Some("CA"));
+
+    i18n.set_locale(Locale::en);
+    fork.set_locale(Locale::fr);
+    assert_eq!(
+        t_string!(i18n, click_to_change_lang).to_string(),
+        expected(Locale::en)
+    );
+    assert_eq!(
+        t_string!(fork, click_to_change_lang).to_string(),
+        expected(Locale::fr)
+    );
+
+    runtime.dispose();
+}
+
+#[test]
+fn provided_fork_shadows_parent() {
+    let runtime = create_runtime();
+    let i18n = provide_i18n_context();
+    i18n.set_locale(Locale::en);
+
+    let in_child = run_as_child(move || {
+        let fork = use_i18n().fork();
+        fork.set_locale(Locale::fr);
+        fork.provide();
+        let i18n = use_i18n();
+        t_string!(i18n, click_to_change_lang).to_string()
+    });
+    assert_eq!(in_child, expected(Locale::fr));
+    assert_eq!(use_i18n().get_locale_untracked(), Locale::en);
+
+    runtime.dispose();
+}
+
+#[test]
+fn isolated_contexts_in_shared_runtime() {
+    let runtime = create_runtime();
+    // a context left over by a previous request.
+    provide_i18n_context().set_locale(Locale::fr);
+
+    // create every context before rendering any, so the requests are interleaved.
+    let contexts = (0..200)
+        .map(|i| {
+            run_as_child(move || {
+                let reused = provide_i18n_context();
+                let isolated = provide_isolated_i18n_context();
+                isolated.set_locale(locale_of(i));
+                // the context provided by the parent is reused, the isolated one is not.
+                assert_eq!(reused.get_locale_untracked(), Locale::fr);
+                assert_eq!(use_i18n().get_locale_untracked(), locale_of(i));
+                isolated
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for (i, i18n) in contexts.into_iter().enumerate().rev() {
+        assert_eq!(
+            t_string!(i18n, click_to_change_lang).to_string(),
+            expected(locale_of(i))
+        );
+    }
+    assert_eq!(use_i18n().get_locale_untracked(), Locale::fr);
+
+    runtime.dispose();
+}
+
+#[test]
+fn isolated_contexts_across_threads() {
+    std::thread::scope(|scope| {
+        for thread in 0..16 {
+            scope.spawn(move || {
+                for i in 0..100 {
+                    let runtime = create_runtime();
+                    let i18n = provide_isolated_i18n_context();
+                    let locale = locale_of(thread + i);
+                    i18n.set_locale(locale);
+                    std::thread::yield_now();
+                    assert_eq!(
+                        t_string!(i18n, click_to_change_lang).to_string(),
+                        expected(locale)
+                    );
+                    assert_eq!(use_i18n().get_locale_untracked(), locale);
+                    runtime.dispose();
+                }
+            });
+        }
+    });
+}
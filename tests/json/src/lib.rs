@@ -2,8 +2,23 @@
 #![deny(warnings)]
 leptos_i18n::load_locales!();
 
+mod compile_fail;
+mod csp;
 mod defaulted;
+mod dynamic_keys;
+mod escaping;
 mod foreign;
+mod formatted;
+mod gettext;
+mod isolation;
+mod locale_tags;
+mod metadata;
 mod plurals;
+mod pseudo;
+mod renames;
+mod router;
+mod select;
+mod sitemap;
 mod subkeys;
 mod tests;
+mod usage;
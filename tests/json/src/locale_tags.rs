@@ -0,0 +1,280 @@
+use crate::i18n::*;
+use leptos_i18n::{Locale as _, LocaleParseError};
+
+#[test]
+fn from_tag() {
+    assert_eq!(Locale::from_tag("fr"), Some(Locale::fr));
+    assert_eq!(Locale::from_tag("fr-CA"), Some(Locale::fr));
+    assert_eq!(Locale::from_tag("en_GB"), Some(Locale::en));
+    assert_eq!(Locale::from_tag("de-DE"), None);
+    assert_eq!(Locale::from_tag("FR"), Some(Locale::fr));
+    assert_eq!(Locale::from_tag("FR_ca"), Some(Locale::fr));
+    assert_eq!(Locale::from_tag("fr-Latn-CA"), Some(Locale::fr));
+    assert_eq!(Locale::from_tag("fr-x-private"), Some(Locale::fr));
+}
+
+#[test]
+fn find_locale_with_tag() {
+    assert_eq!(
+        Locale::find_locale_with_tag(&["de", "fr-CA", "en"]),
+        (Locale::fr, Some("fr-CA".to_string()))
+    );
+    assert_eq!(
+        Locale::find_locale_with_tag(&["fr", "en"]),
+        (Locale::fr, None)
+    );
+    assert_eq!(Locale::find_locale_with_tag(&["de"]), (Locale::en, None));
+}
+
+#[test]
+fn find_best_match() {
+    assert_eq!(
+        Locale::find_best_match("de-DE, fr-CA;q=0.9, en;q=0.8"),
+        Locale::fr
+    );
+    assert_eq!(
+        Locale::find_best_match("fr;q=0.4, de, en-GB;q=0.5"),
+        Locale::en
+    );
+    assert_eq!(Locale::find_best_match("fr;q=0, de"), Locale::en);
+    assert_eq!(Locale::find_best_match(""), Locale::en);
+}
+
+#[test]
+fn font_hints() {
+    assert_eq!(Locale::en.font_class(), None);
+    assert_eq!(Locale::fr.font_class(), Some("font-fr"));
+    assert_eq!(Locale::en.font_preloads(), &[] as &[&str]);
+    assert_eq!(
+        Locale::fr.font_preloads(),
+        &["/fonts/fr-regular.woff2", "/fonts/fr-bold.woff2"]
+    );
+}
+
+#[test]
+fn try_from_str() {
+    assert_eq!(Locale::try_from_str("fr"), Ok(Locale::fr));
+    assert_eq!(Locale::try_from_str(""), Err(LocaleParseError::Empty));
+    assert_eq!(
+        Locale::try_from_str("de"),
+        Err(LocaleParseError::Unknown("de".to_owned()))
+    );
+    assert_eq!(
+        Locale::try_from_str("fr; DROP TABLE"),
+        Err(LocaleParseError::Malformed("fr; DROP TABLE".to_owned()))
+    );
+    // tags are not matched, only the exact names of the locales.
+    assert_eq!(
+        Locale::try_from_str("fr-CA"),
+        Err(LocaleParseError::Unknown("fr-CA".to_owned()))
+    );
+}
+
+#[test]
+fn try_from_tag() {
+    assert_eq!(Locale::try_from_tag(" fr-CA "), Ok(Locale::fr));
+    assert_eq!(Locale::try_from_tag("  "), Err(LocaleParseError::Empty));
+    assert_eq!(
+        Locale::try_from_tag("de-DE"),
+        Err(LocaleParseError::Unknown("de-DE".to_owned()))
+    );
+    assert_eq!(
+        Locale::try_from_tag("fr-CA-<script>"),
+        Err(LocaleParseError::Malformed("fr-CA-<script>".to_owned()))
+    );
+}
+
+#[test]
+fn try_from_persisted() {
+    let runtime = leptos::create_runtime();
+    assert_eq!(Locale::try_from_persisted("fr"), Ok(Locale::fr));
+    leptos_i18n::rollout::provide_locale_gate(|locale| locale != "fr");
+    assert_eq!(
+        Locale::try_from_persisted("fr"),
+        Err(LocaleParseError::Unavailable("fr"))
+    );
+    assert_eq!(Locale::try_from_persisted("en"), Ok(Locale::en));
+    runtime.dispose();
+}
+
+#[test]
+fn find_locale_from_headers() {
+    // no runtime, like in a background job or an API handler.
+    let headers = |name: &str| match name {
+        "accept-language" => Some("de-DE, fr-CA;q=0.9".to_owned()),
+        _ => None,
+    };
+    let (locale, tag) = leptos_i18n::request::find_locale_with_tag::<Locale, _>(&headers);
+    assert_eq!((locale, tag.as_deref()), (Locale::fr, Some("fr-CA")));
+    assert_eq!(
+        td_string!(locale, click_count, count = 3),
+        "Vous avez cliqué 3 fois"
+    );
+
+    let headers = |name: &str| match name {
+        "accept-language" => Some("fr".to_owned()),
+        "cookie" => Some("theme=dark; i18n_pref_locale=en".to_owned()),
+        _ => None,
+    };
+    let locale = leptos_i18n::request::find_locale::<Locale, _>(&headers);
+    assert_eq!(locale, Locale::en);
+}
+
+#[test]
+fn locale_enum_utilities() {
+    assert_eq!(Locale::ALL, &[Locale::en, Locale::fr, Locale::en_XA]);
+    assert_eq!(Locale::iter().collect::<Vec<_>>(), Locale::ALL);
+    assert_eq!(Locale::fr.as_str(), "fr");
+    assert_eq!(Locale::fr.to_string(), "fr");
+    assert_eq!("fr".parse::<Locale>(), Ok(Locale::fr));
+    assert_eq!(
+        "de".parse::<Locale>(),
+        Err(LocaleParseError::Unknown("de".to_owned()))
+    );
+    for locale in Locale::iter() {
+        assert_eq!(locale.to_string().parse(), Ok(locale));
+    }
+    assert!(Locale::en < Locale::fr);
+}
+
+#[test]
+fn system_locales() {
+    let runtime = leptos::create_runtime();
+    leptos_i18n::system::provide_system_locales(|| vec!["de-DE".into(), "fr-CA".into()]);
+    assert_eq!(
+        leptos_i18n::system::fetch_system_locale::<Locale>(),
+        (Locale::fr, Some("fr-CA".to_owned()))
+    );
+    let i18n = provide_i18n_context();
+    assert_eq!(i18n.get_locale_untracked(), Locale::fr);
+    runtime.dispose();
+
+    // the persisted locale takes precedence.
+    struct Storage;
+
+    impl leptos_i18n::storage::LocaleStorage for Storage {
+        fn load(&self) -> Option<String> {
+            Some("en".to_owned())
+        }
+
+        fn save(&self, _: &'static str) {}
+    }
+
+    let runtime = leptos::create_runtime();
+    leptos_i18n::system::provide_system_locales(|| vec!["fr".into()]);
+    leptos_i18n::storage::provide_locale_storage(Storage);
+    let i18n = provide_i18n_context();
+    assert_eq!(i18n.get_locale_untracked(), Locale::en);
+    runtime.dispose();
+}
+
+#[test]
+fn cookie_options() {
+    use leptos_i18n::cookie::{provide_cookie_options, CookieOptions};
+
+    let headers = |name: &str| match name {
+        "cookie" => Some("i18n_pref_locale=en; lang=fr".to_owned()),
+        _ => None,
+    };
+    let runtime = leptos::create_runtime();
+    assert_eq!(
+        leptos_i18n::request::find_locale::<Locale, _>(&headers),
+        Locale::en
+    );
+    provide_cookie_options(CookieOptions::default().name("lang"));
+    assert_eq!(
+        leptos_i18n::request::find_locale::<Locale, _>(&headers),
+        Locale::fr
+    );
+    runtime.dispose();
+}
+
+#[test]
+fn key_value_storage() {
+    use leptos_i18n::storage::{provide_locale_storage, KeyValueStorage};
+    use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+    let values = Rc::new(RefCell::new(HashMap::<String, String>::new()));
+    let runtime = leptos::create_runtime();
+    let (get, set) = (values.clone(), values.clone());
+    provide_locale_storage(KeyValueStorage::new((
+        move |key: &str| get.borrow().get(key).cloned(),
+        move |key: &str, value: &str| {
+            set.borrow_mut().insert(key.to_owned(), value.to_owned());
+        },
+    )));
+    let i18n = provide_i18n_context();
+    assert_eq!(i18n.get_locale_untracked(), Locale::en);
+    i18n.set_locale(Locale::fr);
+    runtime.dispose();
+    assert_eq!(
+        values.borrow().get("i18n_pref_locale").map(String::as_str),
+        Some("fr")
+    );
+
+    let runtime = leptos::create_runtime();
+    let get = values.clone();
+    provide_locale_storage(
+        KeyValueStorage::new((
+            move |key: &str| get.borrow().get(key).cloned(),
+            |_: &str, _: &str| {},
+        ))
+        .with_keys("lang", "format"),
+    );
+    values
+        .borrow_mut()
+        .insert("lang".to_owned(), "fr".to_owned());
+    let i18n = provide_i18n_context();
+    assert_eq!(i18n.get_locale_untracked(), Locale::fr);
+    runtime.dispose();
+}
+
+#[test]
+fn transfer_state() {
+    use leptos_i18n::transfer::{provide_i18n_state, I18nState};
+
+    let runtime = leptos::create_runtime();
+    let i18n = provide_i18n_context();
+    assert!(i18n.set_locale_tag("fr-CA"));
+    i18n.set_currency(Some("EUR"));
+    let encoded = i18n.state().encode();
+    runtime.dispose();
+    assert_eq!(
+        encoded,
+        "locale=fr\nformat_tag=fr-CA\npreferences=currency=EUR"
+    );
+
+    let state = I18nState::decode(&encoded).unwrap();
+    let runtime = leptos::create_runtime();
+    provide_i18n_state(state.clone());
+    let i18n = provide_i18n_context();
+    assert_eq!(i18n.get_locale_untracked(), Locale::fr);
+    assert_eq!(i18n.format_locale(), "fr-CA");
+    assert_eq!(i18n.currency().as_deref(), Some("EUR"));
+    runtime.dispose();
+
+    let runtime = leptos::create_runtime();
+    let i18n = provide_i18n_context();
+    assert_eq!(i18n.get_locale_untracked(), Locale::en);
+    assert_eq!(i18n.restore(&state), Ok(()));
+    assert_eq!(i18n.state(), state);
+    let unknown = I18nState {
+        locale: "de".to_owned(),
+        ..Default::default()
+    };
+    assert_eq!(
+        i18n.restore(&unknown),
+        Err(LocaleParseError::Unknown("de".to_owned()))
+    );
+    assert_eq!(i18n.get_locale_untracked(), Locale::fr);
+    runtime.dispose();
+
+    assert_eq!(
+        I18nState::decode("format_tag=fr"),
+        Err(LocaleParseError::Empty)
+    );
+    assert_eq!(
+        I18nState::decode("locale=<fr>"),
+        Err(LocaleParseError::Malformed("<fr>".to_owned()))
+    );
+}
@@ -0,0 +1,58 @@
+use crate::i18n::*;
+use leptos_i18n::metadata::{find_key, Interpolation, InterpolationKind};
+
+#[test]
+fn keys_metadata_sorted() {
+    assert!(KEYS_METADATA
+        .windows(2)
+        .all(|keys| keys[0].path < keys[1].path));
+}
+
+#[test]
+fn string_key_metadata() {
+    let key = find_key(KEYS_METADATA, "click_to_inc").unwrap();
+    assert!(key.is_string());
+}
+
+#[test]
+fn interpolation_metadata() {
+    let key = find_key(KEYS_METADATA, "interpolate_variable_and_comp").unwrap();
+    assert_eq!(
+        key.interpolations,
+        [
+            Interpolation {
+                name: "b",
+                kind: InterpolationKind::Component
+            },
+            Interpolation {
+                name: "count",
+                kind: InterpolationKind::Variable
+            },
+        ]
+    );
+}
+
+#[test]
+fn plural_metadata() {
+    let key = find_key(KEYS_METADATA, "u32_plural").unwrap();
+    assert_eq!(key.count_type(), Some("u32"));
+    let key = find_key(KEYS_METADATA, "subkeys.subkey_3").unwrap();
+    assert_eq!(key.count_type(), Some("i32"));
+}
+
+#[test]
+fn formatted_metadata() {
+    let key = find_key(KEYS_METADATA, "formatted_total").unwrap();
+    assert_eq!(key.variables().collect::<Vec<_>>(), ["price", "quantity"]);
+    assert_eq!(
+        key.interpolations[0].kind,
+        InterpolationKind::Formatted("number")
+    );
+}
+
+#[test]
+fn select_metadata() {
+    let key = find_key(KEYS_METADATA, "replied").unwrap();
+    assert_eq!(key.variables().collect::<Vec<_>>(), ["gender", "name"]);
+    assert_eq!(key.interpolations[0].kind, InterpolationKind::Select);
+}
@@ -178,3 +178,176 @@ fn f32_or_plural_string() {
         assert_eq!(fr, "fallback avec tuple vide");
     }
 }
+
+#[test]
+fn range_plural_string() {
+    // count = 0
+    let count = 0;
+    let en = td_string!(Locale::en, range_plural, count);
+    assert_eq!(en.to_string(), "No apples");
+    let fr = td_string!(Locale::fr, range_plural, count);
+    assert_eq!(fr.to_string(), "Pas de pommes");
+
+    // count = 1
+    let count = 1;
+    let en = td_string!(Locale::en, range_plural, count);
+    assert_eq!(en.to_string(), "One apple");
+    let fr = td_string!(Locale::fr, range_plural, count);
+    assert_eq!(fr.to_string(), "Une pomme");
+
+    // count = 2..=4
+    for count in 2..=4 {
+        let en = td_string!(Locale::en, range_plural, count);
+        assert_eq!(en.to_string(), format!("{} apples (few)", count));
+        let fr = td_string!(Locale::fr, range_plural, count);
+        assert_eq!(fr.to_string(), format!("{} pommes (quelques)", count));
+    }
+
+    // count = _
+    for count in [-5, 5, 21] {
+        let en = td_string!(Locale::en, range_plural, count);
+        assert_eq!(en.to_string(), format!("{} apples", count));
+        let fr = td_string!(Locale::fr, range_plural, count);
+        assert_eq!(fr.to_string(), format!("{} pommes", count));
+    }
+}
+
+#[test]
+fn u128_plural_string() {
+    // count = 0
+    let count = 0;
+    let en = td_string!(Locale::en, u128_plural, count);
+    assert_eq!(en.to_string(), "No events");
+    let fr = td_string!(Locale::fr, u128_plural, count);
+    assert_eq!(fr.to_string(), "Aucun événement");
+
+    // count = 1..18446744073709551616
+    for count in [1, u64::MAX as u128] {
+        let en = td_string!(Locale::en, u128_plural, count);
+        assert_eq!(en.to_string(), "Less than 2^64 events");
+        let fr = td_string!(Locale::fr, u128_plural, count);
+        assert_eq!(fr.to_string(), "Moins de 2^64 événements");
+    }
+
+    // count = _
+    for count in [u64::MAX as u128 + 1, u128::MAX] {
+        let en = td_string!(Locale::en, u128_plural, count);
+        assert_eq!(en.to_string(), format!("{} events, that's a lot", count));
+        let fr = td_string!(Locale::fr, u128_plural, count);
+        assert_eq!(
+            fr.to_string(),
+            format!("{} événements, ça fait beaucoup", count)
+        );
+    }
+}
+
+#[test]
+fn category_plural() {
+    // en: one = 1, fr: one = 0..1
+    for i in [0, 1, -1] {
+        let count = move || i;
+        let fr = td!(Locale::fr, category_plural, count);
+        assert_eq_rendered!(fr, format!("{} objet", i));
+    }
+    let count = move || 1;
+    let en = td!(Locale::en, category_plural, count);
+    assert_eq_rendered!(en, "1 item");
+
+    // fr: many = millions
+    let count = move || 2_000_000;
+    let fr = td!(Locale::fr, category_plural, count);
+    assert_eq_rendered!(fr, "2000000 d'objets");
+
+    // other
+    for i in [0, 2, 21, 2_000_000] {
+        let count = move || i;
+        let en = td!(Locale::en, category_plural, count);
+        assert_eq_rendered!(en, format!("{} items", i));
+    }
+    let count = move || 2;
+    let fr = td!(Locale::fr, category_plural, count);
+    assert_eq_rendered!(fr, "2 objets");
+}
+
+#[test]
+fn f64_category_plural_string() {
+    // count = 0
+    let count = 0.0;
+    let en = td_string!(Locale::en, f64_category_plural, count);
+    assert_eq!(en.to_string(), "no distance");
+
+    // en: one = 1 without fraction digits, fr: one = 0..2
+    let count = 1.0;
+    let en = td_string!(Locale::en, f64_category_plural, count);
+    assert_eq!(en.to_string(), "1 mile");
+    let count = 1.5;
+    let en = td_string!(Locale::en, f64_category_plural, count);
+    assert_eq!(en.to_string(), "1.5 miles");
+    let fr = td_string!(Locale::fr, f64_category_plural, count);
+    assert_eq!(fr.to_string(), "1.5 kilomètre");
+
+    // other
+    let count = 2.5;
+    let fr = td_string!(Locale::fr, f64_category_plural, count);
+    assert_eq!(fr.to_string(), "2.5 kilomètres");
+}
+
+#[test]
+fn count_type_plural_string() {
+    let en = td_string!(Locale::en, progress_plural, count = 0.0);
+    assert_eq!(en, "Not started");
+    let fr = td_string!(Locale::fr, progress_plural, count = 100.0);
+    assert_eq!(fr, "Terminé");
+    let en = td_string!(Locale::en, progress_plural, count = 42.5);
+    assert_eq!(en, "42.5% done");
+
+    let count = u64::MAX;
+    let en = td_string!(Locale::en, downloads_plural, count);
+    assert_eq!(en, format!("{} downloads", count));
+    let fr = td_string!(Locale::fr, downloads_plural, count = 0);
+    assert_eq!(fr, "Aucun téléchargement");
+}
+
+#[test]
+fn count_type_subkeys() {
+    let en = td_string!(Locale::en, count_type_subkeys.count_type);
+    assert_eq!(en, "f64");
+    let fr = td_string!(Locale::fr, count_type_subkeys.other);
+    assert_eq!(fr, "pas un pluriel");
+}
+
+#[test]
+fn ordinal_plural() {
+    let ordinals = [
+        (1, "1st"),
+        (2, "2nd"),
+        (3, "3rd"),
+        (4, "4th"),
+        (11, "11th"),
+        (12, "12th"),
+        (13, "13th"),
+        (21, "21st"),
+        (22, "22nd"),
+        (103, "103rd"),
+        (111, "111th"),
+    ];
+    for (i, expected) in ordinals {
+        let count = move || i;
+        let en = td!(Locale::en, ordinal_plural, count);
+        assert_eq_rendered!(en, expected);
+    }
+    // fr: one = 1
+    let count = move || 1u32;
+    let fr = td!(Locale::fr, ordinal_plural, count);
+    assert_eq_rendered!(fr, "1er");
+    let fr = td_string!(Locale::fr, ordinal_plural, count = 2);
+    assert_eq!(fr, "2e");
+}
+
+#[test]
+fn ordinal_subkeys() {
+    let en = td_string!(Locale::en, ordinal_subkeys.ordinal);
+    assert_eq!(en, "first");
+    let fr = td_string!(Locale::fr, ordinal_subkeys.cardinal);
+    assert_eq!(fr, "un");
+}
@@ -0,0 +1,23 @@
+use crate::i18n::*;
+use common::*;
+use leptos_i18n::Locale as _;
+
+#[test]
+fn pseudo_strings() {
+    let pseudo = td!(Locale::en_XA, click_to_change_lang);
+    assert_eq_rendered!(pseudo, "[Çļíçķ ţö çĥáñĝé ļáñĝûáĝé ~~~~~~~~]");
+    let pseudo = td_string!(Locale::en_XA, click_count, count = 3);
+    assert_eq!(pseudo.to_string(), "[Ýöû çļíçķéð 3 ţíɱéš ~~~~~~]");
+}
+
+#[test]
+fn pseudo_plurals() {
+    let pseudo = td!(Locale::en_XA, range_plural, count = || 1);
+    assert_eq_rendered!(pseudo, "[Öñé áþþļé ~~~]");
+}
+
+#[test]
+fn pseudo_not_negotiated() {
+    assert_eq!(Locale::find_best_match("en-XA, fr;q=0.5"), Locale::fr);
+    assert_eq!(Locale::from_tag("en-XA"), Some(Locale::en_XA));
+}
@@ -0,0 +1,31 @@
+use crate::i18n::*;
+use common::*;
+
+// the old names still resolve, but are deprecated: `expect` fails the build if they are not.
+#[test]
+#[expect(deprecated)]
+fn renamed_string() {
+    let en = td!(Locale::en, click_to_increment);
+    assert_eq!(en, "Click to increment the counter");
+    let fr = td!(Locale::fr, click_to_increment);
+    assert_eq!(fr, "Cliquez pour incrémenter le compteur");
+}
+
+#[test]
+#[expect(deprecated)]
+fn renamed_interpolation() {
+    let count = || 3;
+    let en = td!(Locale::en, old_click_count, count);
+    assert_eq_rendered!(en, "You clicked 3 times");
+    let fr = td!(Locale::fr, old_click_count, count);
+    assert_eq_rendered!(fr, "Vous avez cliqué 3 fois");
+}
+
+#[test]
+#[expect(deprecated)]
+fn renamed_subkey() {
+    let en = td!(Locale::en, subkeys.first_subkey);
+    assert_eq!(en, "subkey_1");
+    let fr = td!(Locale::fr, subkeys.first_subkey);
+    assert_eq!(fr, "subkey_1");
+}
@@ -0,0 +1,30 @@
+use crate::i18n::*;
+use leptos_i18n::router::{localize_path, strip_locale_prefix};
+
+#[test]
+fn strip_prefix() {
+    assert!(matches!(
+        strip_locale_prefix::<Locale>("/fr/about"),
+        Some((Locale::fr, "/about"))
+    ));
+    assert!(matches!(
+        strip_locale_prefix::<Locale>("/en?tab=1"),
+        Some((Locale::en, "?tab=1"))
+    ));
+    assert!(strip_locale_prefix::<Locale>("/french/about").is_none());
+    assert!(strip_locale_prefix::<Locale>("/about").is_none());
+    assert!(strip_locale_prefix::<Locale>("fr/about").is_none());
+}
+
+#[test]
+fn localize() {
+    assert_eq!(localize_path(Locale::fr, "/about"), "/fr/about");
+    assert_eq!(
+        localize_path(Locale::fr, "/en/about?tab=1"),
+        "/fr/about?tab=1"
+    );
+    assert_eq!(localize_path(Locale::en, "/en"), "/en");
+    assert_eq!(localize_path(Locale::fr, "/"), "/fr");
+    assert_eq!(localize_path(Locale::fr, "about"), "about");
+    assert_eq!(localize_path(Locale::fr, "//example.com"), "//example.com");
+}
@@ -0,0 +1,45 @@
+use crate::i18n::*;
+use common::*;
+
+#[derive(Clone, Copy)]
+enum Gender {
+    Female,
+    Unknown,
+}
+
+impl AsRef<str> for Gender {
+    fn as_ref(&self) -> &str {
+        match self {
+            Gender::Female => "female",
+            Gender::Unknown => "unknown",
+        }
+    }
+}
+
+#[test]
+fn select() {
+    let name = "Alex";
+    let en = td!(Locale::en, replied, gender = || "male", name);
+    assert_eq_rendered!(en, "He replied to Alex");
+    let en = td!(Locale::en, replied, gender = || Gender::Female, name);
+    assert_eq_rendered!(en, "She replied to Alex");
+    let fr = td!(Locale::fr, replied, gender = "female", name);
+    assert_eq_rendered!(fr, "Elle a répondu à Alex");
+}
+
+#[test]
+fn select_other() {
+    let name = "Alex";
+    let en = td!(Locale::en, replied, gender = || Gender::Unknown, name);
+    assert_eq_rendered!(en, "They replied to Alex");
+    let fr = td!(Locale::fr, replied, gender = || String::from("other"), name);
+    assert_eq_rendered!(fr, "Iel a répondu à Alex");
+}
+
+#[test]
+fn select_string() {
+    let en = td_string!(Locale::en, replied, gender = "female", name = "Alex");
+    assert_eq!(en, "She replied to Alex");
+    let fr = td_string!(Locale::fr, replied, gender = Gender::Unknown, name = "Alex");
+    assert_eq!(fr, "Iel a répondu à Alex");
+}
@@ -0,0 +1,33 @@
+use crate::i18n::*;
+use leptos_i18n::sitemap::{generate_sitemap, localized_path};
+
+#[test]
+fn localized_paths() {
+    assert_eq!(localized_path(Locale::en, "/"), "/en");
+    assert_eq!(localized_path(Locale::fr, "/about"), "/fr/about");
+    assert_eq!(localized_path(Locale::fr, "about/"), "/fr/about");
+}
+
+#[test]
+fn sitemap_alternates() {
+    let sitemap = generate_sitemap::<Locale, _>("https://example.com/", ["/about"]);
+
+    let expected_url = concat!(
+        "    <xhtml:link rel=\"alternate\" hreflang=\"en\" href=\"https://example.com/en/about\"/>\n",
+        "    <xhtml:link rel=\"alternate\" hreflang=\"fr\" href=\"https://example.com/fr/about\"/>\n",
+        "    <xhtml:link rel=\"alternate\" hreflang=\"x-default\" href=\"https://example.com/en/about\"/>\n",
+    );
+
+    assert!(sitemap.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+    assert!(sitemap.contains("<loc>https://example.com/en/about</loc>"));
+    assert!(sitemap.contains("<loc>https://example.com/fr/about</loc>"));
+    assert_eq!(sitemap.matches("<url>").count(), 2);
+    assert_eq!(sitemap.matches(expected_url).count(), 2);
+    assert!(sitemap.ends_with("</urlset>\n"));
+}
+
+#[test]
+fn sitemap_escape() {
+    let sitemap = generate_sitemap::<Locale, _>("https://example.com", ["/search?a=1&b=2"]);
+    assert!(sitemap.contains("<loc>https://example.com/en/search?a=1&amp;b=2</loc>"));
+}
@@ -76,3 +76,16 @@ fn subkey_3() {
     let fr = td!(Locale::fr, subkeys.subkey_3, count);
     assert_eq_rendered!(fr, "3");
 }
+
+#[test]
+fn nested_subkeys() {
+    let en = td!(Locale::en, subkeys.nested_subkeys.hero.title);
+    assert_eq!(en, "Welcome");
+    let fr = td!(Locale::fr, subkeys.nested_subkeys.hero.title);
+    assert_eq!(fr, "Bienvenue");
+    let name = || "Alice";
+    let en = td!(Locale::en, subkeys.nested_subkeys.hero.cta, name);
+    assert_eq_rendered!(en, "Start as Alice");
+    let fr = td!(Locale::fr, subkeys.nested_subkeys.hero.cta, name);
+    assert_eq_rendered!(fr, "Commencer en tant que Alice");
+}
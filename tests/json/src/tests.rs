@@ -46,6 +46,12 @@ fn click_count_string() {
     assert_eq!(fr, "Vous avez cliqué beaucoups de fois");
 }
 
+// t_string! needs a context, make sure it expands to a string.
+#[allow(dead_code)]
+fn click_count_t_string(i18n: leptos_i18n::I18nContext<Locale>) -> String {
+    t_string!(i18n, click_count, count = 3).into_owned()
+}
+
 #[test]
 fn subkey_3() {
     let count = || 0;
@@ -73,3 +79,46 @@ fn interpolate_variable_and_comp() {
     let fr = td!(Locale::fr, interpolate_variable_and_comp, <b> = <span/>, count = 34);
     assert_eq_rendered!(fr, "<span>34</span>");
 }
+
+#[test]
+fn escaped() {
+    let en = td_string!(Locale::en, escaped, name = "Alex");
+    assert_eq!(
+        en,
+        "Write {{ name }} to insert Alex, and <b>text</b> for bold"
+    );
+    let fr = td_string!(Locale::fr, escaped, name = "Alex");
+    assert_eq!(
+        fr,
+        "Écrivez {{ name }} pour insérer Alex, et <b>texte</b> pour du gras"
+    );
+}
+
+#[test]
+fn self_closing() {
+    let en = td!(Locale::en, self_closing, <br> = |_| view! { <br/> });
+    assert_eq_rendered!(en, "Line one<br/>line two");
+    let fr = td!(Locale::fr, self_closing, <br> = |_| view! { <br/> });
+    assert_eq_rendered!(fr, "Ligne un<br/>ligne deux");
+    let en = td_string!(Locale::en, self_closing, <br> = "br");
+    assert_eq!(en, "Line one<br/>line two");
+}
+
+#[test]
+fn component_attrs() {
+    let en = td!(Locale::en, link_attrs, id = 42, <link> = |children, attrs| html::a().attrs(attrs).child(children()));
+    assert_eq_rendered!(
+        en,
+        "See <a href=\"/users/42\" class=\"link\">your profile</a>"
+    );
+    let fr = td!(Locale::fr, link_attrs, id = 42, <link> = |children, attrs| html::a().attrs(attrs).child(children()));
+    assert_eq_rendered!(
+        fr,
+        "Voir <a class=\"link\" href=\"/fr/users/42\">votre profil</a>"
+    );
+    let en = td_string!(Locale::en, link_attrs, id = 42, <link> = "a");
+    assert_eq!(
+        en,
+        "See <a href=\"/users/42\" class=\"link\">your profile</a>"
+    );
+}
@@ -0,0 +1,25 @@
+use crate::i18n::*;
+use leptos_i18n::usage;
+
+#[test]
+fn usage() {
+    let en = td!(Locale::en, subkeys.subkey_1);
+    assert_eq!(en, "subkey_1");
+    td!(Locale::fr, subkeys.subkey_1);
+    td!(Locale::fr, subkeys.subkey_1);
+
+    // the other tests render keys concurrently, only look at the ones rendered here.
+    let snapshot = usage::snapshot();
+    let count = |locale: &str| {
+        snapshot
+            .iter()
+            .find(|usage| usage.locale == locale && usage.key_path == "subkeys.subkey_1")
+            .map(|usage| usage.count)
+    };
+    assert!(count("en") >= Some(1));
+    assert!(count("fr") >= Some(2));
+
+    let encoded = usage::encode();
+    assert!(encoded.starts_with("{\"en\":{"));
+    assert!(encoded.contains("\"subkeys.subkey_1\":"));
+}
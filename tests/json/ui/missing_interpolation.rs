@@ -0,0 +1,13 @@
+// the locales dir is relative to the project generated by trybuild, in `target/tests/trybuild/json`.
+leptos_i18n::load_locales!(config {
+    default: "en",
+    locales: ["en"],
+    path: "../../../../ui/locales",
+});
+
+use i18n::*;
+use leptos_i18n::td;
+
+fn main() {
+    let _ = td!(Locale::en, click_count);
+}
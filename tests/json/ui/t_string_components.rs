@@ -0,0 +1,14 @@
+// the locales dir is relative to the project generated by trybuild, in `target/tests/trybuild/json`.
+leptos_i18n::load_locales!(config {
+    default: "en",
+    locales: ["en"],
+    path: "../../../../ui/locales",
+});
+
+use i18n::*;
+use leptos_i18n::t_string;
+
+fn main() {
+    let i18n = use_i18n();
+    let _ = t_string!(i18n, important_text);
+}
@@ -0,0 +1,50 @@
+use crate::i18n::*;
+use common::*;
+
+#[test]
+fn translated_key() {
+    let fr_ca = td!(Locale::fr_CA, first_namespace.common_key);
+    assert_eq!(fr_ca, "premier espace de noms");
+}
+
+#[test]
+fn key_from_fallback() {
+    let fr_ca = td!(Locale::fr_CA, first_namespace.click_to_change_lang);
+    assert_eq!(fr_ca, "Cliquez pour changez de langue");
+    let fr_ca = td!(Locale::fr_CA, first_namespace.plural_only_en, count = || 0);
+    assert_eq_rendered!(fr_ca, "pas de plurals en français");
+}
+
+#[test]
+fn plural_from_fallback() {
+    for i in [0, 3, 21] {
+        let count = move || i;
+        let fr_ca = td!(Locale::fr_CA, second_namespace.click_count, count);
+        assert_eq_rendered!(fr_ca, format!("Vous avez cliqué {} fois", i));
+    }
+}
+
+#[test]
+fn subkeys_from_fallback() {
+    let fr_ca = td!(Locale::fr_CA, second_namespace.subkeys.subkey_1);
+    assert_eq!(fr_ca, "sous-clé 1");
+    let b = |children: ChildrenFn| view! { <b>{children}</b> };
+    let fr_ca = td!(Locale::fr_CA, second_namespace.subkeys.subkey_2, <b>);
+    assert_eq_rendered!(fr_ca, "<b>subkey_2</b>");
+    let fr_ca = td!(Locale::fr_CA, second_namespace.subkeys.overridden_subkey);
+    assert_eq!(fr_ca, "subkey override");
+}
+
+#[test]
+fn foreign_keys_from_fallback() {
+    let fr_ca = td!(
+        Locale::fr_CA,
+        second_namespace.foreign_key_to_same_namespace
+    );
+    assert_eq!(fr_ca, "before deuxième espace de noms after");
+    let fr_ca = td!(
+        Locale::fr_CA,
+        second_namespace.foreign_key_to_another_namespace
+    );
+    assert_eq!(fr_ca, "before premier espace de noms after");
+}
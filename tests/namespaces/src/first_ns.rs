@@ -50,3 +50,19 @@ fn plural_only_en() {
     let fr = td!(Locale::fr, first_namespace.plural_only_en, count);
     assert_eq_rendered!(fr, "pas de plurals en français");
 }
+
+#[test]
+fn get_by_key() {
+    let en = Locale::en.get_by_key("first_namespace.common_key").unwrap();
+    assert_eq!(en.as_str(), Some("first namespace"));
+    let interpolations = leptos_i18n::dynamic_keys::Interpolations::new().value("count", 100);
+    let en = Locale::en
+        .get_by_key("first_namespace.plural_only_en")
+        .unwrap();
+    assert_eq_rendered!(en.build(&interpolations), "unbounded end");
+    let fr = Locale::fr
+        .get_by_key("first_namespace.plural_only_en")
+        .unwrap();
+    assert_eq_rendered!(fr.build(&interpolations), "pas de plurals en français");
+    assert!(Locale::en.get_by_key("common_key").is_none());
+}
@@ -1,6 +1,8 @@
 #![deny(warnings)]
 leptos_i18n::load_locales!();
 
+#[cfg(test)]
+mod fallbacks;
 #[cfg(test)]
 mod first_ns;
 #[cfg(test)]
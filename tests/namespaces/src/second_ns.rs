@@ -91,3 +91,19 @@ fn foreign_key_to_another_namespace() {
     );
     assert_eq!(fr, "before premier namespace after");
 }
+
+#[test]
+fn overridden_key() {
+    let en = td!(Locale::en, second_namespace.overridden_key);
+    assert_eq!(en, "base value");
+    let fr = td!(Locale::fr, second_namespace.overridden_key);
+    assert_eq!(fr, "second override");
+}
+
+#[test]
+fn overridden_subkey() {
+    let en = td!(Locale::en, second_namespace.subkeys.overridden_subkey);
+    assert_eq!(en, "base value");
+    let fr = td!(Locale::fr, second_namespace.subkeys.overridden_subkey);
+    assert_eq!(fr, "subkey override");
+}